@@ -0,0 +1,332 @@
+use bevy::prelude::{
+	App, Assets, BackgroundColor, BuildChildren, Color, Commands, Component,
+	default, DespawnRecursiveExt, DetectChanges, Entity, EventWriter,
+	FlexDirection, Handle, Image, ImageBundle, Input, IntoSystemConfigs,
+	KeyCode, NodeBundle, Plugin, PositionType, Query, Ref, Res, ResMut,
+	Resource, Style, Text, TextBundle, TextStyle, UiImage, UiRect, Update, Val,
+	With, ZIndex
+};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+use bevy::render::texture::ImageSampler;
+
+use crate::automata::{
+	AUTOMATON_HISTORY, AUTOMATON_LENGTH, History, SpatialSpectrum
+};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, Focused, Palette, TOAST_Z_INDEX
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the analysis view, a panel at the
+/// right edge of the window that pictures the
+/// [power&#32;spectrum](crate::automata::Automaton::power_spectrum) of every
+/// generation in the [history](History) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane), and names the
+/// characteristic wavelength of their [mean&#32;spectrum](SpatialSpectrum).
+/// The spectra quantify the periodic spatial structure of a rule, e.g., the
+/// regular background of Rule 110. The [AnalysisView] resource governs whether
+/// the panel is shown.
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<AnalysisView>()
+			.add_systems(Update, maybe_toggle_analysis)
+			.add_systems(
+				Update,
+				update_analysis
+					.after(maybe_toggle_analysis)
+					.after(apply_actions)
+					.after(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the analysis view is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct AnalysisView(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The frame of the analysis view, which holds its [label](AnalysisLabel) and
+/// its [spectrogram](Spectrogram).
+#[derive(Component)]
+struct AnalysisFrame;
+
+/// The label of the analysis view, which names the characteristic wavelength.
+#[derive(Component)]
+struct AnalysisLabel;
+
+/// The spectrogram of the analysis view, and the [image](Image) into which it
+/// is drawn.
+#[derive(Component)]
+struct Spectrogram(Handle<Image>);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F5`, toggle the [analysis&#32;view](AnalysisView), and announce the new
+/// setting.
+fn maybe_toggle_analysis(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut view: ResMut<AnalysisView>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.analysis)
+	{
+		view.0 = !view.0;
+		let message = match view.0
+		{
+			true => Message::AnalysisOn,
+			false => Message::AnalysisOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the [analysis&#32;view](AnalysisView) is shown, keep its
+/// [label](AnalysisLabel) and its [spectrogram](Spectrogram) in step with the
+/// [history](History) of the [focused](Focused)&#32;[pane](crate::ecs::Pane),
+/// analyzing it anew whenever the history, the focus, the
+/// [palette](Palette), or the [language](Language) changes.
+#[allow(clippy::too_many_arguments)]
+fn update_analysis(
+	view: Res<AnalysisView>,
+	palette: Res<Palette>,
+	language: Res<Language>,
+	pane: Query<(Ref<History>, Ref<Focused>)>,
+	frames: Query<Entity, With<AnalysisFrame>>,
+	spectrograms: Query<&Spectrogram>,
+	mut labels: Query<&mut Text, With<AnalysisLabel>>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands
+) {
+	if !view.0
+	{
+		if view.is_changed()
+		{
+			for entity in &frames
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+		return
+	}
+	let Ok((history, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let Ok(spectrogram) = spectrograms.get_single() else
+	{
+		build_analysis(
+			&history,
+			&palette,
+			*language,
+			&mut images,
+			&mut commands
+		);
+		return
+	};
+	let redraw = history.is_changed()
+		|| focused.is_added()
+		|| palette.is_changed()
+		|| language.is_changed();
+	if redraw
+	{
+		if let Some(image) = images.get_mut(&spectrogram.0)
+		{
+			*image = draw(&history, &palette);
+		}
+		if let Ok(mut text) = labels.get_single_mut()
+		{
+			text.sections[0].value = label(&history, *language);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [frame](AnalysisFrame) of the analysis view at the right edge of
+/// the window, holding the [label](AnalysisLabel) and the
+/// [spectrogram](Spectrogram) of the specified [history](History).
+fn build_analysis(
+	history: &History,
+	palette: &Palette,
+	language: Language,
+	images: &mut Assets<Image>,
+	commands: &mut Commands
+) {
+	let image = images.add(draw(history, palette));
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						right: Val::Px(ANALYSIS_MARGIN),
+						top: Val::Percent(ANALYSIS_TOP),
+						padding: UiRect::all(Val::Px(ANALYSIS_PADDING)),
+						flex_direction: FlexDirection::Column,
+						..default()
+					},
+					background_color: BackgroundColor(ANALYSIS_BACKGROUND),
+					z_index: ZIndex::Global(ANALYSIS_Z_INDEX),
+					..default()
+				},
+				AnalysisFrame
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					TextBundle::from_section(
+						label(history, language),
+						TextStyle {
+							font_size: ANALYSIS_FONT_SIZE,
+							color: ANALYSIS_TEXT_COLOR,
+							..default()
+						}
+					),
+					AnalysisLabel
+				)
+			);
+			builder.spawn(
+				(
+					ImageBundle {
+						style: Style {
+							width: Val::Px(
+								(FREQUENCIES * SPECTROGRAM_SCALE) as f32
+							),
+							height: Val::Px(
+								(AUTOMATON_HISTORY * SPECTROGRAM_SCALE) as f32
+							),
+							margin: UiRect::top(Val::Px(ANALYSIS_PADDING)),
+							..default()
+						},
+						image: UiImage::new(image.clone()),
+						..default()
+					},
+					Spectrogram(image)
+				)
+			);
+		});
+}
+
+/// Answer the text of the [label](AnalysisLabel) for the specified
+/// [history](History), in the specified [language](Language): the
+/// characteristic wavelength of its [mean&#32;spectrum](SpatialSpectrum).
+fn label(history: &History, language: Language) -> String
+{
+	match SpatialSpectrum::new(history.iter()).wavelength()
+	{
+		Some(wavelength) => language.format(
+			Message::Wavelength,
+			&[&format!("{:.1}", wavelength)]
+		),
+		None => language.text(Message::NoWavelength).to_string()
+	}
+}
+
+/// Draw the spectrogram of the specified [history](History) into an
+/// [image](Image), one row of pixels per generation, in the same order as the
+/// grid, and one column per nonzero spatial frequency, from the longest
+/// wavelength at the left to the shortest at the right. The brighter the
+/// pixel, the greater the power of that frequency in that generation, relative
+/// to the greatest power in the whole history.
+fn draw(history: &History, palette: &Palette) -> Image
+{
+	let spectra = history.iter()
+		.map(|automaton| automaton.power_spectrum())
+		.collect::<Vec<_>>();
+	let greatest = spectra.iter()
+		.flat_map(|spectrum| spectrum[1 ..].iter())
+		.fold(0.0f32, |greatest, &power| greatest.max(power));
+	let [red, green, blue, _] = palette.live.as_rgba_u8();
+	let mut data = Vec::with_capacity(FREQUENCIES * spectra.len() * 4);
+	for spectrum in &spectra
+	{
+		for &power in &spectrum[1 ..]
+		{
+			let intensity = match greatest > 0.0
+			{
+				true => power / greatest,
+				false => 0.0
+			};
+			data.extend_from_slice(
+				&[red, green, blue, (intensity * 255.0) as u8]
+			);
+		}
+	}
+	let mut image = Image::new(
+		Extent3d {
+			width: FREQUENCIES as u32,
+			height: spectra.len() as u32,
+			depth_or_array_layers: 1
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb
+	);
+	// Keep the frequencies crisp when stretched.
+	image.sampler = ImageSampler::nearest();
+	image
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of nonzero spatial frequencies pictured by the
+/// [spectrogram](Spectrogram), one per column of pixels.
+const FREQUENCIES: usize = AUTOMATON_LENGTH / 2;
+
+/// The number of pixels of the window covered by each pixel of the
+/// [spectrogram](Spectrogram), in each direction.
+const SPECTROGRAM_SCALE: usize = 4;
+
+/// The distance between the [frame](AnalysisFrame) of the analysis view and
+/// the right edge of the window, in pixels.
+const ANALYSIS_MARGIN: f32 = 12.0;
+
+/// The distance between the [frame](AnalysisFrame) of the analysis view and
+/// the top edge of the window, as a percentage of the height of the window,
+/// which keeps the view clear of the rule icon above it.
+const ANALYSIS_TOP: f32 = 25.0;
+
+/// The padding within the [frame](AnalysisFrame) of the analysis view, in
+/// pixels.
+const ANALYSIS_PADDING: f32 = 8.0;
+
+/// The font size of the [label](AnalysisLabel) of the analysis view.
+const ANALYSIS_FONT_SIZE: f32 = 18.0;
+
+/// The [color](Color) of the [label](AnalysisLabel) of the analysis view.
+const ANALYSIS_TEXT_COLOR: Color = Color::YELLOW;
+
+/// The [color](Color) behind the analysis view.
+const ANALYSIS_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
+
+/// The [z-index](ZIndex) of the analysis view, which lies above the panes, but
+/// beneath the tooltip, the growing tape, and toasts.
+const ANALYSIS_Z_INDEX: i32 = TOAST_Z_INDEX - 2;
@@ -0,0 +1,139 @@
+use bevy::prelude::{
+	App, BackgroundColor, Color, DetectChanges, DetectChangesMut, EventWriter,
+	Input, IntoSystemConfigs, KeyCode, Plugin, Query, Res, ResMut, Resource,
+	Update
+};
+
+use crate::automata::{AUTOMATON_HISTORY, AUTOMATON_LENGTH, Scrollback};
+use crate::ecs::{
+	accept_digit, Announcement, AutomatonRuleBuilder, CellOverlay, ScrollOffset,
+	update_scroll
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+use crate::light_cone::{APEX_TINT, LightCone, maybe_select_apex};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for ancestry tracing, which replaces the
+/// [light&#32;cone](LightCone) of the selected cell with its ancestry: the
+/// cells of earlier generations that actually determined it, traced back
+/// through the [scrollback](Scrollback). The [AncestryTracing] resource
+/// governs whether the ancestry replaces the light cone.
+pub struct AncestryPlugin;
+
+impl Plugin for AncestryPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<AncestryTracing>()
+			.add_systems(Update, maybe_toggle_ancestry.before(accept_digit))
+			.add_systems(
+				Update,
+				update_ancestry
+					.after(maybe_toggle_ancestry)
+					.after(maybe_select_apex)
+					.after(update_scroll)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the selected cell shows its ancestry rather than its
+/// [light&#32;cone](LightCone).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct AncestryTracing(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `A`, toggle [ancestry&#32;tracing](AncestryTracing), and announce the
+/// new setting.
+fn maybe_toggle_ancestry(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
+	mut tracing: ResMut<AncestryTracing>,
+	mut announcements: EventWriter<Announcement>
+) {
+	// While a hexadecimal rule is being entered, `A` is a digit.
+	if keys.just_pressed(bindings.ancestry)
+		&& !builder.claims(bindings.ancestry)
+	{
+		tracing.0 = !tracing.0;
+		let message = match tracing.0
+		{
+			true => Message::AncestryOn,
+			false => Message::AncestryOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While [ancestry&#32;tracing](AncestryTracing) is on, tint the
+/// [overlay](CellOverlay) of the selected cell, and of each of its
+/// [ancestors](Scrollback::ancestry) on screen, and clear the others. The
+/// trace begins from whichever generation the selected cell shows, so trace
+/// anew whenever the selection changes or the view
+/// [scrolls](ScrollOffset). Cells of difference panes have no ancestry.
+fn update_ancestry(
+	tracing: Res<AncestryTracing>,
+	cone: Res<LightCone>,
+	scroll: Res<ScrollOffset>,
+	scrollbacks: Query<&Scrollback>,
+	mut overlays: Query<(&CellOverlay, &mut BackgroundColor)>
+) {
+	let changed = tracing.is_changed()
+		|| cone.is_changed()
+		|| scroll.is_changed();
+	if !tracing.0 || !changed
+	{
+		return
+	}
+	let mut ancestors = [[false; AUTOMATON_LENGTH]; AUTOMATON_HISTORY];
+	if let Some(subject) = cone.0
+	{
+		if let Ok(scrollback) = scrollbacks.get(subject.pane)
+		{
+			let back = scroll.0 + AUTOMATON_HISTORY - 1 - subject.row;
+			let index = AUTOMATON_LENGTH - subject.column - 1;
+			// Trace no further back than the top of the view.
+			let ancestry = scrollback.ancestry(back, index, subject.row);
+			for (step, generation) in ancestry.into_iter().enumerate()
+			{
+				let row = subject.row - step - 1;
+				for index in generation
+				{
+					ancestors[row][AUTOMATON_LENGTH - index - 1] = true;
+				}
+			}
+		}
+	}
+	for (overlay, mut color) in &mut overlays
+	{
+		let position = overlay.0;
+		let tint = match cone.0
+		{
+			Some(subject) if subject == position => APEX_TINT,
+			Some(subject) if subject.pane == position.pane
+				&& ancestors[position.row][position.column] => ANCESTOR_TINT,
+			_ => Color::NONE
+		};
+		color.set_if_neq(BackgroundColor(tint));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tint of the ancestors of the selected cell.
+const ANCESTOR_TINT: Color = Color::rgba(0.2, 0.9, 0.4, 0.5);
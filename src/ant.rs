@@ -0,0 +1,94 @@
+use bevy::prelude::{
+	App, BackgroundColor, DetectChanges, IntoSystemConfigs, Local, Plugin,
+	Query, Res, ResMut, Update
+};
+
+use crate::automata::Colony;
+use crate::ecs::{
+	CellPosition, evolve, Generation, paint_cells, Palette, repaint,
+	update_palette, update_scroll
+};
+use crate::effects::blend;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for [Langton's&#32;ants](Colony), which
+/// wander the grid of every [pane](crate::ecs::Pane) in place of the usual
+/// elementary automata. The [evolver](evolve) keeps time for the ants, so
+/// pausing, resuming, and the heartbeat all work as usual, and each generation
+/// moves every ant one step. Insert the [colony](Colony) and set the
+/// [mode](crate::ecs::Mode) to [ant](crate::ecs::Mode::Ant) before adding
+/// this plugin.
+pub struct AntPlugin;
+
+impl Plugin for AntPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Update, step_ants.after(evolve))
+			.add_systems(
+				Update,
+				paint_ants
+					.after(step_ants)
+					.after(update_scroll)
+					.after(update_palette)
+					.after(paint_cells)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whenever new [generations](Generation) begin, move every ant of the
+/// [colony](Colony) one step per generation.
+fn step_ants(
+	generation: Res<Generation>,
+	mut colony: ResMut<Colony>,
+	mut last: Local<u64>
+) {
+	if generation.is_added()
+	{
+		*last = generation.0;
+	}
+	for _ in *last .. generation.0
+	{
+		colony.step();
+	}
+	*last = generation.0;
+}
+
+/// Paint every cell according to the state of the corresponding cell of the
+/// [colony](Colony), ramping from the dead color for state `0` to the live
+/// color for the last state. Paint the cells beneath the ants in the pressed
+/// color instead, so that the ants stand out. Other systems paint the cells
+/// from the [histories](crate::automata::History), e.g., on hover or after a
+/// change of [palette](Palette), so paint every frame, but only touch the
+/// cells whose colors actually change.
+fn paint_ants(
+	colony: Res<Colony>,
+	palette: Res<Palette>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>
+) {
+	let last = (colony.turns().states() - 1) as f32;
+	for (position, color) in &mut cells
+	{
+		let (row, column) = (position.row, position.column);
+		let occupied = colony.ants().iter()
+			.any(|ant| ant.row == row && ant.column == column);
+		let paint = match occupied
+		{
+			true => palette.pressed,
+			false =>
+			{
+				let state = colony.state(row, column) as f32;
+				blend(palette.dead, palette.live, state / last)
+			}
+		};
+		repaint(color, BackgroundColor(paint));
+	}
+}
@@ -1,9 +1,31 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::fmt;
-use std::ops::{Index, IndexMut};
+use std::f32::consts::TAU;
+use std::io::{self, Write};
+use std::ops::{Index, IndexMut, Range, RangeInclusive};
+use std::str::FromStr;
+#[cfg(any(
+	feature = "scripting",
+	all(feature = "wasm-rules", not(target_family = "wasm"))
+))]
+use std::sync::Arc;
 
-use bevy::prelude::Resource;
+use bevy::prelude::{Component, Resource};
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
 use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use serde::de::Error;
+
+#[cfg(feature = "scripting")]
+use crate::scripting::ScriptedRule;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+use crate::wasm_rules::WasmRule;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                   Rules.                                   //
@@ -46,7 +68,8 @@ use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
 ///
 /// [Wolfram&#32;coding]: https://en.wikipedia.org/wiki/Wolfram_code
 /// [Rule&#32;110]: https://en.wikipedia.org/wiki/Rule_110
-#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Resource)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AutomatonRule(u8);
 
 impl AutomatonRule
@@ -58,6 +81,110 @@ impl AutomatonRule
 	{
 		self.0 & (1 << ordinal) != 0
 	}
+
+	/// Answer the [rule](AutomatonRule) that results from flipping the
+	/// specified bit of the receiver's Wolfram code. `bit` must be in `[0,7]`.
+	/// Because each bit governs exactly one neighborhood, the answer differs
+	/// from the receiver in the fate of exactly one population.
+	pub const fn mutate(self, bit: u8) -> Self
+	{
+		assert!(bit <= 7);
+		AutomatonRule(self.0 ^ (1 << bit))
+	}
+
+	/// Answer the mirror image of the receiver, i.e., the rule that treats the
+	/// left and right neighbors in swapped roles. Evolving a mirrored
+	/// automaton under the mirrored rule produces the mirror image of the
+	/// original evolution.
+	pub const fn mirror(self) -> Self
+	{
+		let mut mirror = 0;
+		let mut ordinal = 0;
+		while ordinal < 8
+		{
+			if self.next_cell(ordinal)
+			{
+				// Swap the left (4) and right (1) bits of the ordinal.
+				let swapped = (ordinal & 0b010)
+					| (ordinal & 0b100) >> 2
+					| (ordinal & 0b001) << 2;
+				mirror |= 1 << swapped;
+			}
+			ordinal += 1;
+		}
+		AutomatonRule(mirror)
+	}
+
+	/// Answer the complement of the receiver, i.e., the rule that treats live
+	/// and dead cells in swapped roles. Evolving a complemented automaton
+	/// under the complemented rule produces the complement of the original
+	/// evolution.
+	pub const fn complement(self) -> Self
+	{
+		// The complement of the neighborhood with ordinal k has ordinal 7 - k,
+		// so reverse the bits, and then flip the outcomes.
+		AutomatonRule(!self.0.reverse_bits())
+	}
+
+	/// Answer the mirrored complement of the receiver, which is the same as
+	/// the complemented mirror.
+	pub const fn mirrored_complement(self) -> Self
+	{
+		self.mirror().complement()
+	}
+
+	/// Answer the rules equivalent to the receiver under mirroring and
+	/// complementation: its [mirror](Self::mirror), its
+	/// [complement](Self::complement), and its
+	/// [mirrored&#32;complement](Self::mirrored_complement), in that order.
+	/// Rules that are their own mirror images or complements appear among
+	/// their own equivalents.
+	pub const fn equivalents(self) -> [Self; 3]
+	{
+		[self.mirror(), self.complement(), self.mirrored_complement()]
+	}
+
+	/// Answer the representative of the receiver's equivalence class under
+	/// mirroring and complementation, i.e., whichever of the receiver, its
+	/// [mirror](Self::mirror), its [complement](Self::complement), and its
+	/// [mirrored&#32;complement](Self::mirrored_complement) has the least
+	/// Wolfram code. Rules of the same class evolve alike, up to reflection and
+	/// inversion, so the 256 rules amount to only [EQUIVALENCE_CLASSES]
+	/// distinct behaviors.
+	pub const fn representative(self) -> Self
+	{
+		let mut least = self.0;
+		let equivalents = self.equivalents();
+		let mut i = 0;
+		while i < equivalents.len()
+		{
+			if equivalents[i].0 < least
+			{
+				least = equivalents[i].0;
+			}
+			i += 1;
+		}
+		AutomatonRule(least)
+	}
+
+	/// Answer the [representative](Self::representative) of every equivalence
+	/// class, in ascending order of Wolfram code.
+	pub fn representatives() -> Vec<Self>
+	{
+		(0 ..= u8::MAX)
+			.map(AutomatonRule)
+			.filter(|rule| rule.representative() == *rule)
+			.collect()
+	}
+
+	/// Answer the [representative](Self::representative) of a random
+	/// equivalence class, such that every class is equally likely. Unlike a
+	/// uniformly random rule, this never favors classes with more members, and
+	/// never answers a rule that merely reflects or inverts another.
+	pub fn random_representative() -> Self
+	{
+		*Self::representatives().choose(&mut thread_rng()).unwrap()
+	}
 }
 
 impl From<u8> for AutomatonRule
@@ -70,6 +197,41 @@ impl From<u8> for AutomatonRule
 	}
 }
 
+impl From<AutomatonRule> for u8
+{
+	/// Recover the Wolfram code from the [rule](AutomatonRule).
+	fn from(value: AutomatonRule) -> Self
+	{
+		value.0
+	}
+}
+
+impl FromStr for AutomatonRule
+{
+	type Err = String;
+
+	/// Parse a rule from either its Wolfram code or one of the
+	/// [aliases](RULE_ALIASES) of a well-known rule, ignoring case. The
+	/// Wolfram code may be given in decimal, in binary with a `0b` prefix,
+	/// e.g., `0b01101110`, or in hexadecimal with a `0x` prefix, e.g., `0x6E`.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		if let Some(&(_, code, _)) = RULE_ALIASES.iter()
+			.find(|(alias, _, _)| alias.eq_ignore_ascii_case(s))
+		{
+			return Ok(AutomatonRule(code))
+		}
+		let (digits, radix) = strip_radix(s).unwrap_or((s, 10));
+		u8::from_str_radix(digits, radix)
+			.map(AutomatonRule)
+			.map_err(|_| format!(
+				"unknown rule '{}': expected a Wolfram code between 0 and 255, \
+					inclusive, or the name of a well-known rule",
+				s
+			))
+	}
+}
+
 impl Display for AutomatonRule
 {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
@@ -79,306 +241,3664 @@ impl Display for AutomatonRule
 }
 
 ////////////////////////////////////////////////////////////////////////////////
-//                                 Automata.                                  //
+//                                Rule kinds.                                 //
 ////////////////////////////////////////////////////////////////////////////////
 
-/// [Automaton] represents a [1-dimensional&#32;cellular&#32;automaton]. The
-/// automaton itself is a sequence of cells, each represented by a `bool`, which
-/// may be occupied (`true`) or vacant (`false`). The rightmost cell has the
-/// index `0`, and the leftmost cell has the index `K-1`. A
-/// [rule](AutomatonRule) may be applied to an automaton to produce the next
-/// generation. `K` is the length of the automaton, in cells, and must be ≥3,
-/// which sadly is unenforceable on the `stable` channel. Note that the two ends
-/// of the automaton are considered adjacent for the purpose of computing the
-/// next generation.
-///
-/// N.B.: Rust does not guarantee a packed representation for a `bool` array; in
-/// fact, LLVM does not pack arrays of `u1` at this time, so the representation
-/// will not be maximally efficient on space. It will still have relatively good
-/// spatial and temporal performance, however, and this approach obviates the
-/// need for any external crates, e.g.,
-/// [`bitvec`](https://crates.io/crates/bitvec), and permits derivation of
-/// [Copy].
-///
-/// [1-dimensional&#32;cellular&#32;automaton]: https://en.wikipedia.org/wiki/Elementary_cellular_automaton
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(test, derive(PartialEq, Eq))]
-pub struct Automaton<const K: usize = AUTOMATON_LENGTH>([bool; K]);
+/// [RuleKind] enumerates the ways in which the next generation of an
+/// [automaton](Automaton) may be computed from the current one.
+#[derive(Clone, Debug)]
+pub enum RuleKind
+{
+	/// An elementary rule, given by its [Wolfram&#32;code](AutomatonRule).
+	Wolfram(AutomatonRule),
 
-impl<const K: usize> Automaton<K>
+	/// A user-supplied [script](ScriptedRule), which may implement arbitrary
+	/// update logic. Available only with the `scripting` feature.
+	#[cfg(feature = "scripting")]
+	Scripted(Arc<ScriptedRule>),
+
+	/// A user-supplied [WebAssembly&#32;module](WasmRule), which may implement
+	/// arbitrary update logic in any language that compiles to WebAssembly.
+	/// Available only for native builds with the `wasm-rules` feature.
+	#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+	Wasm(Arc<WasmRule>)
+}
+
+impl RuleKind
 {
-	/// Construct a new [Automaton] that is completely vacant, i.e., each cell
-	/// is unoccupied.
-	pub const fn new() -> Self
+	/// Compute the successor [automaton][Automaton] in accordance with the
+	/// receiver.
+	pub fn next<const K: usize>(&self, automaton: &Automaton<K>) -> Automaton<K>
 	{
-		Self([false; K])
+		match self
+		{
+			RuleKind::Wolfram(rule) => automaton.next(*rule),
+			#[cfg(feature = "scripting")]
+			RuleKind::Scripted(script) => script.next(automaton),
+			#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+			RuleKind::Wasm(module) => module.next(automaton)
+		}
 	}
 
-	/// Compute the successor [automaton][Automaton] in accordance with the
-	/// specified [rule](AutomatonRule).
-	pub fn next(&self, rule: AutomatonRule) -> Self
+	/// Compute the `n`-th successor of the specified [automaton][Automaton] in
+	/// accordance with the receiver. Elementary rules evolve
+	/// [in&#32;bulk](Automaton::next_n).
+	pub fn next_n<const K: usize>(
+		&self,
+		automaton: &Automaton<K>,
+		n: usize
+	) -> Automaton<K>
 	{
-		let mut next = [false; K];
-		// Compute the leading edge cell, treating the final cell of the
-		// automaton as its right neighbor.
-		let ordinal = compute_ordinal(self[1], self[0], self[K - 1]);
-		next[0] = rule.next_cell(ordinal);
-		// Computing the medial cells is trivial.
-		for i in 1 ..= K - 2
+		match self
 		{
-			let ordinal = compute_ordinal(
-				self[i + 1],
-				self[i],
-				self[i - 1]
-			);
-			next[i] = rule.next_cell(ordinal);
+			RuleKind::Wolfram(rule) => automaton.next_n(*rule, n),
+			#[allow(unreachable_patterns)]
+			_ => (0 .. n).fold(*automaton, |automaton, _| self.next(&automaton))
 		}
-		// Compute the trailing edge cell, treating the initial cell of the
-		// automaton as its left neighbor.
-		let ordinal = compute_ordinal(self[0], self[K - 1], self[K - 2]);
-		next[K - 1] = rule.next_cell(ordinal);
-		Automaton(next)
 	}
 
-	/// Answer an [iterator](Iterator) that traverse the cells of the
-	/// [automaton](Automaton) in right-to-left order.
-	pub fn iter(&self) -> impl Iterator<Item=&bool>
+	/// Answer the indices of the neighbors of the cell at the specified index,
+	/// i.e., its left neighbor, the cell itself, and its right neighbor, whose
+	/// states determined the fate of the cell when the receiver evolved the
+	/// specified [automaton](Automaton). A set of neighbors determines the fate
+	/// if their states alone force it, whatever the states of the others.
+	/// Answer every neighbor that belongs to some smallest such set, so a rule
+	/// that ignores a neighbor never credits it, and a rule that fixes the fate
+	/// of the cell credits none. The automaton must comprise at least three
+	/// cells.
+	pub fn determinants<const K: usize>(
+		&self,
+		automaton: &Automaton<K>,
+		index: usize
+	) -> Vec<usize>
 	{
-		self.0.iter()
+		// The neighbors in the order of the bits of an ordinal, from 4 to 1.
+		let neighbors = [(index + 1) % K, index, (index + K - 1) % K];
+		let bit = |position: usize| 4 >> position;
+		// Tabulate the fate of the cell under every possible neighborhood.
+		let fates: [bool; 8] = std::array::from_fn(|ordinal| {
+			let mut variant = *automaton;
+			for (position, &neighbor) in neighbors.iter().enumerate()
+			{
+				variant[neighbor] = ordinal & bit(position) != 0;
+			}
+			self.next(&variant)[index]
+		});
+		let actual = neighbors.iter()
+			.enumerate()
+			.filter(|&(_, &neighbor)| automaton[neighbor])
+			.fold(0, |ordinal, (position, _)| ordinal | bit(position));
+		let fate = fates[actual];
+		// A set of neighbors, given as a mask of ordinal bits, forces the fate
+		// if every neighborhood that agrees with the actual one on the set
+		// yields the same fate. The full set always forces the fate.
+		let forces = |mask: usize| {
+			(0 .. 8)
+				.filter(|ordinal| ordinal & mask == actual & mask)
+				.all(|ordinal| fates[ordinal] == fate)
+		};
+		let smallest = (0 .. 8usize)
+			.filter(|&mask| forces(mask))
+			.map(|mask| mask.count_ones())
+			.min()
+			.unwrap();
+		let credited = (0 .. 8usize)
+			.filter(|&mask| mask.count_ones() == smallest && forces(mask))
+			.fold(0, |union, mask| union | mask);
+		neighbors.iter()
+			.enumerate()
+			.filter(|&(position, _)| credited & bit(position) != 0)
+			.map(|(_, &neighbor)| neighbor)
+			.collect()
+	}
+
+	/// Answer a terse label for the receiver, suitable for lists of rules:
+	/// the bare Wolfram code for an elementary rule, or the file name for a
+	/// script or module.
+	pub fn abbreviation(&self) -> String
+	{
+		match self
+		{
+			RuleKind::Wolfram(rule) => rule.0.to_string(),
+			#[cfg(feature = "scripting")]
+			RuleKind::Scripted(script) => script.name(),
+			#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+			RuleKind::Wasm(module) => module.name()
+		}
 	}
 }
 
-/// Note that we cannot auto-derive [Default] because of the generic parameter,
-/// so we manually implement it here.
-impl<const K: usize> Default for Automaton<K>
+impl From<AutomatonRule> for RuleKind
 {
-	/// Construct a new [Automaton] that is completely vacant, i.e., each cell
-	/// is unoccupied.
-	fn default() -> Self
+	fn from(value: AutomatonRule) -> Self
 	{
-		Self::new()
+		RuleKind::Wolfram(value)
 	}
 }
 
-impl<const K: usize> From<u64> for Automaton<K>
+impl PartialEq for RuleKind
 {
-	/// Initialize an [automaton](Automaton) by treating the specified `u64` as
-	/// a bit vector of up to 64 bits. Ignore high bits beyond index `K`.
-	fn from(value: u64) -> Self
+	/// Elementary rules are equal if their Wolfram codes are equal. Scripts and
+	/// modules are equal only if they are the very same script or module.
+	fn eq(&self, other: &Self) -> bool
 	{
-		assert!(K <= 0u64.count_zeros() as usize);
-		let mut next = [false; K];
-		for i in 0 ..= K - 1
+		match (self, other)
 		{
-			next[i] = value & (1 << i) != 0;
+			(RuleKind::Wolfram(a), RuleKind::Wolfram(b)) => a == b,
+			#[cfg(feature = "scripting")]
+			(RuleKind::Scripted(a), RuleKind::Scripted(b)) => Arc::ptr_eq(a, b),
+			#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+			(RuleKind::Wasm(a), RuleKind::Wasm(b)) => Arc::ptr_eq(a, b),
+			#[allow(unreachable_patterns)]
+			_ => false
 		}
-		Automaton(next)
 	}
 }
 
-impl<const K: usize> Display for Automaton<K>
+impl Eq for RuleKind {}
+
+impl Display for RuleKind
 {
-	/// Render an automaton with a prefix that specifies its length followed by
-	/// a densely-packed series of `X` and `•` that represent occupancy and
-	/// vacancy, respectively.
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
 	{
-		write!(f, "Automaton[{}]: ", K)?;
-		for i in 0 ..= K - 1
+		match self
 		{
-			write!(f, "{}", if self[i] { "X" } else { "•" })?;
+			RuleKind::Wolfram(rule) => write!(f, "{}", rule),
+			#[cfg(feature = "scripting")]
+			RuleKind::Scripted(script) => write!(f, "Script {}", script.name()),
+			#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+			RuleKind::Wasm(module) => write!(f, "Module {}", module.name())
 		}
-		Ok(())
 	}
 }
 
-impl<const K: usize> Index<usize> for Automaton<K>
+////////////////////////////////////////////////////////////////////////////////
+//                              Rule schedules.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [RuleSchedule] is a nonempty rotation of [rules](RuleKind), applied one per
+/// generation. The [active](Self::active) rule governs the next
+/// generation; once applied, the schedule [advances](Self::advance) to the
+/// following rule, wrapping around to the first rule after the last. A schedule
+/// comprising a single rule is therefore equivalent to that rule alone.
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct RuleSchedule
 {
-	type Output = bool;
+	/// The rules of the schedule, in order of application. Never empty.
+	rules: Vec<RuleKind>,
 
-	#[inline]
-	fn index(&self, index: usize) -> &Self::Output
-	{
-		&self.0[index]
-	}
+	/// The index of the [active](Self::active) rule within
+	/// [rules](Self::rules).
+	active: usize
 }
 
-impl<const K: usize> IndexMut<usize> for Automaton<K>
+impl RuleSchedule
 {
-	#[inline]
-	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	/// Answer the [rule](RuleKind) that will govern the next generation.
+	pub fn active(&self) -> &RuleKind
 	{
-		&mut self.0[index]
+		&self.rules[self.active]
 	}
-}
-
-////////////////////////////////////////////////////////////////////////////////
-//                                 Histories.                                 //
-////////////////////////////////////////////////////////////////////////////////
 
-/// The last `N` generations of a [cellular&#32;automaton](Automaton). Each
-/// automaton comprises `K` cells.
-#[derive(Debug, Resource)]
-pub struct History<
-	const K: usize = AUTOMATON_LENGTH,
-	const N: usize = AUTOMATON_HISTORY
->(
-	ConstGenericRingBuffer<Automaton<K>, N>
-);
+	/// Answer the index of the [active](Self::active) rule.
+	pub fn active_index(&self) -> usize
+	{
+		self.active
+	}
 
-impl<const K: usize, const N: usize> History<K, N>
-{
-	/// Construct an empty [History].
-	pub fn new() -> Self
+	/// Answer the [active](Self::active) rule, and then advance the schedule
+	/// so that the following rule becomes active.
+	pub fn advance(&mut self) -> RuleKind
 	{
-		let mut ring = ConstGenericRingBuffer::new();
-		for _ in 0 .. N
-		{
-			ring.push(Automaton::default());
-		}
-		assert!(ring.is_full());
-		Self(ring)
+		let rule = self.active().clone();
+		self.active = (self.active + 1) % self.rules.len();
+		rule
 	}
 
-	/// Answer a reference to the [automaton](Automaton) that represents the
-	/// newest generation.
-	/// [default](Default::default)&#32;[automaton](Automaton).
-	pub fn newest(&self) -> &Automaton<K>
+	/// Replace the [active](Self::active) rule with the one provided.
+	pub fn replace_active(&mut self, rule: impl Into<RuleKind>)
 	{
-		self.0.back().unwrap()
+		self.rules[self.active] = rule.into();
 	}
 
-	/// Answer a reference to the [automaton](Automaton) that represents the
-	/// oldest generation.
-	#[allow(dead_code)]
-	pub fn oldest(&self) -> &Automaton<K>
+	/// Append the specified [rule](RuleKind) to the end of the schedule.
+	pub fn push(&mut self, rule: impl Into<RuleKind>)
 	{
-		self.0.front().unwrap()
+		self.rules.push(rule.into());
 	}
 
-	/// Replace the [newest](Self::newest)&#32;[automaton](Automaton) with the
-	/// one provided. This is provided to support user customization of the
-	/// seed.
-	pub fn replace(&mut self, replacement: Automaton<K>)
+	/// Remove the last [rule](RuleKind) from the schedule, but only if
+	/// another rule remains afterward. Answer the removed rule, if any.
+	pub fn pop(&mut self) -> Option<RuleKind>
 	{
-		match self.0.back_mut()
+		if self.rules.len() > 1
 		{
-			Some(newest) => *newest = replacement,
-			None => self.0.push(replacement)
+			let rule = self.rules.pop();
+			self.active %= self.rules.len();
+			rule
+		}
+		else
+		{
+			None
 		}
 	}
 
-	/// Evolve the [newest](Self::newest)&#32;[automaton](Automaton) according
-	/// to the specified [rule](AutomatonRule). Append the result to the
-	/// [history](History). If the [history](History) is full, then the
-	/// [oldest](Self::oldest)&#32;[automaton](Automaton) will be forgotten.
-	pub fn evolve(&mut self, rule: AutomatonRule)
+	/// Answer the number of [rules](RuleKind) in the schedule, which is never
+	/// empty.
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize
 	{
-		self.0.push(self.newest().next(rule));
+		self.rules.len()
 	}
 
-	/// Answer an iterator that traverses the [history](History) from
-	/// [oldest](Self::oldest) to [newest](Self::newest).
-	pub fn iter(&self) -> impl Iterator<Item=&Automaton<K>>
+	/// Answer an iterator that traverses the [rules](RuleKind) of the
+	/// schedule in order of application.
+	pub fn iter(&self) -> impl Iterator<Item=&RuleKind>
 	{
-		self.0.iter()
+		self.rules.iter()
+	}
+
+	/// Answer an iterator that mutably traverses the [rules](RuleKind) of the
+	/// schedule in order of application. This is provided to support reloading
+	/// of [scripts](ScriptedRule).
+	#[allow(dead_code)]
+	pub fn iter_mut(&mut self) -> impl Iterator<Item=&mut RuleKind>
+	{
+		self.rules.iter_mut()
 	}
 }
 
-impl<const K: usize, const N: usize> Default for History<K, N>
+impl Default for RuleSchedule
 {
+	/// Construct a schedule comprising only the [default](Default::default)
+	/// [rule](AutomatonRule).
 	fn default() -> Self
 	{
-		Self::new()
+		AutomatonRule::default().into()
 	}
 }
 
-impl<const K: usize, const N: usize> From<Automaton<K>> for History<K, N>
+impl From<AutomatonRule> for RuleSchedule
 {
-	/// Given a single [automaton](Automaton), start a new (history)[History]
-	/// that uses the automaton as its first generation.
-	fn from(value: Automaton<K>) -> Self
+	/// Construct a schedule comprising only the specified
+	/// [rule](AutomatonRule).
+	fn from(value: AutomatonRule) -> Self
 	{
-		let mut history = Self::default();
-		history.replace(value);
-		history
+		RuleKind::from(value).into()
 	}
 }
 
-impl<const K: usize, const N: usize> Index<usize> for History<K, N>
+impl From<RuleKind> for RuleSchedule
 {
-	type Output = Automaton<K>;
-
-	/// Borrow the `index`-th cell. `index` is zero-based.
-	#[inline]
-	fn index(&self, index: usize) -> &Self::Output
+	/// Construct a schedule comprising only the specified [rule](RuleKind).
+	fn from(value: RuleKind) -> Self
 	{
-		&self.0[index]
+		RuleSchedule { rules: vec![value], active: 0 }
 	}
 }
 
-impl<const K: usize, const N: usize> IndexMut<usize> for History<K, N>
+impl From<Vec<AutomatonRule>> for RuleSchedule
 {
-	/// Mutably borrow the `index`-th cell. `index` is zero-based.
-	#[inline]
-	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	/// Construct a schedule that applies the specified [rules](AutomatonRule)
+	/// in rotation, beginning with the first. `value` must not be empty.
+	fn from(value: Vec<AutomatonRule>) -> Self
 	{
-		&mut self.0[index]
+		assert!(!value.is_empty());
+		RuleSchedule {
+			rules: value.into_iter().map(RuleKind::from).collect(),
+			active: 0
+		}
 	}
 }
 
-////////////////////////////////////////////////////////////////////////////////
-//                                 Utilities.                                 //
-////////////////////////////////////////////////////////////////////////////////
-
-/// Compute the population ordinal for some unspecified [rule](AutomatonRule)
-/// based on the occupancy of the left, middle, and right cells of some
-/// unspecified [automaton](Automaton). The result will be value in `[0,7]`.
-#[inline]
-const fn compute_ordinal(left: bool, middle: bool, right: bool) -> u8
+impl Display for RuleSchedule
 {
-	let left = if left { 4u8 } else { 0 };
-	let middle = if middle { 2u8 } else { 0 };
-	let right = if right { 1u8 } else { 0 };
-	let ordinal = left | middle | right;
-	// Note that we cannot test range containment directly here because
-	// `contains` is not a `const fn`.
-	assert!(ordinal <= 7);
-	ordinal
+	/// Render a lone rule just as [RuleKind] would, but render several rules as
+	/// a comma-separated list of [abbreviations](RuleKind::abbreviation).
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self.rules.as_slice()
+		{
+			[rule] => write!(f, "{}", rule),
+			rules =>
+			{
+				write!(f, "Rules ")?;
+				for (i, rule) in rules.iter().enumerate()
+				{
+					if i > 0
+					{
+						write!(f, ", ")?;
+					}
+					match rule
+					{
+						RuleKind::Wolfram(rule) => write!(f, "#{}", rule.0)?,
+						#[allow(unreachable_patterns)]
+						rule => write!(f, "{}", rule.abbreviation())?
+					}
+				}
+				Ok(())
+			}
+		}
+	}
 }
 
 ////////////////////////////////////////////////////////////////////////////////
-//                                 Constants.                                 //
+//                                 Rule maps.                                 //
 ////////////////////////////////////////////////////////////////////////////////
 
-/// The length of all [cellular&#32;automata](Automaton) in this application.
-pub const AUTOMATON_LENGTH: usize = 64;
-
-/// The number of generations to preserve during the evolution of a
-/// [cellular&#32;automaton](Automaton). This serves as the size of the
-/// [RingBuffer] that supports the singleton [History].
-pub const AUTOMATON_HISTORY: usize = 50;
-
-////////////////////////////////////////////////////////////////////////////////
-//                                   Tests.                                   //
-////////////////////////////////////////////////////////////////////////////////
+/// [RuleMap] assigns [elementary&#32;rules](AutomatonRule) to some of the
+/// columns of an [automaton](Automaton), so that different stretches of the
+/// automaton evolve under different dynamics. A mapped cell evolves according
+/// to its own rule, applied to its usual neighborhood, so neighboring
+/// stretches interact across their interface. Unmapped cells evolve according
+/// to whatever [rule](RuleKind) governs the automaton as a whole, so an empty
+/// map changes nothing. Columns advance from left to right, as they appear on
+/// screen, which is _against_ the natural order of an automaton. `K` is the
+/// length of the automaton, in cells.
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct RuleMap<const K: usize = AUTOMATON_LENGTH>(
+	[Option<AutomatonRule>; K]
+);
 
-#[cfg(test)]
-mod test
+impl<const K: usize> RuleMap<K>
 {
-	use crate::automata::Automaton;
-	#[cfg(doc)]
-	use crate::automata::AutomatonRule;
+	/// Construct an empty [RuleMap], which assigns no rules.
+	pub const fn new() -> Self
+	{
+		Self([None; K])
+	}
 
-	/// Use a well-known [cellular&32;automaton][Automaton] to verify correct
-	/// construction of the second generation under
-	/// [Rule&#32;#30](AutomatonRule).
-	//noinspection SpellCheckingInspection
-	#[test]
-	fn rule_30()
+	/// Determine whether the receiver assigns no rules at all.
+	pub fn is_empty(&self) -> bool
 	{
-		//     XX•X••••X••X•••X•••••X••••••XX
-		// 0b00110100001001000100000100000011
+		self.0.iter().all(Option::is_none)
+	}
+
+	/// Answer the [rule](AutomatonRule) assigned to the specified column, if
+	/// any.
+	pub fn get(&self, column: usize) -> Option<AutomatonRule>
+	{
+		self.0[column]
+	}
+
+	/// Assign the specified [rule](AutomatonRule) to the specified column, or
+	/// unassign the column if `rule` is [None].
+	pub fn set(&mut self, column: usize, rule: Option<AutomatonRule>)
+	{
+		self.0[column] = rule;
+	}
+
+	/// Answer the maximal runs of adjacent columns that share a
+	/// [rule](AutomatonRule), from left to right, each with its rule.
+	/// Unassigned columns belong to no run.
+	pub fn spans(&self) -> Vec<(RangeInclusive<usize>, AutomatonRule)>
+	{
+		let mut spans = Vec::<(RangeInclusive<usize>, AutomatonRule)>::new();
+		for (column, rule) in self.0.iter().enumerate()
+		{
+			let Some(rule) = *rule else { continue };
+			match spans.last_mut()
+			{
+				Some((span, last))
+					if *last == rule && *span.end() + 1 == column =>
+						*span = *span.start() ..= column,
+				_ => spans.push((column ..= column, rule))
+			}
+		}
+		spans
+	}
+
+	/// Compute the successor of the specified [automaton](Automaton): every
+	/// mapped cell according to its own [rule](AutomatonRule), and every other
+	/// cell in accordance with `rule`.
+	pub fn next(
+		&self,
+		rule: &RuleKind,
+		automaton: &Automaton<K>
+	) -> Automaton<K>
+	{
+		let mut next = rule.next(automaton);
+		for (column, mapped) in self.0.iter().enumerate()
+		{
+			let Some(mapped) = mapped else { continue };
+			let i = K - column - 1;
+			next[i] = mapped.next_cell(compute_ordinal(
+				automaton[(i + 1) % K],
+				automaton[i],
+				automaton[(i + K - 1) % K]
+			));
+		}
+		next
+	}
+}
+
+/// Note that we cannot auto-derive [Default] because of the generic parameter,
+/// so we manually implement it here.
+impl<const K: usize> Default for RuleMap<K>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const K: usize> FromStr for RuleMap<K>
+{
+	type Err = String;
+
+	/// Parse a comma-separated list of assignments, each a column or an
+	/// inclusive range of columns, a colon, and a [rule](AutomatonRule), e.g.,
+	/// `0-31:90,32-63:sierpinski`. Later assignments take precedence over
+	/// earlier ones. An empty string denotes the empty map.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let mut map = Self::new();
+		for assignment in s.split(',').map(str::trim).filter(|a| !a.is_empty())
+		{
+			let (columns, rule) = assignment.split_once(':')
+				.ok_or_else(|| format!(
+					"expected columns:rule, not '{}'",
+					assignment
+				))?;
+			let rule = rule.trim().parse::<AutomatonRule>()?;
+			let column = |column: &str| column.trim().parse::<usize>()
+				.ok()
+				.filter(|&column| column < K)
+				.ok_or_else(|| format!(
+					"bad column '{}': expected 0 to {}",
+					column.trim(),
+					K - 1
+				));
+			let (first, last) = match columns.split_once('-')
+			{
+				Some((first, last)) => (column(first)?, column(last)?),
+				None => (column(columns)?, column(columns)?)
+			};
+			if first > last
+			{
+				return Err(format!("empty range of columns '{}'", columns))
+			}
+			for column in first ..= last
+			{
+				map.set(column, Some(rule));
+			}
+		}
+		Ok(map)
+	}
+}
+
+impl<const K: usize> Display for RuleMap<K>
+{
+	/// Render the [spans](RuleMap::spans) of the map just as
+	/// [FromStr](RuleMap::from_str) would parse them.
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		for (i, (span, rule)) in self.spans().into_iter().enumerate()
+		{
+			if i > 0
+			{
+				write!(f, ",")?;
+			}
+			match span.start() == span.end()
+			{
+				true => write!(f, "{}:{}", span.start(), rule.0)?,
+				false =>
+					write!(f, "{}-{}:{}", span.start(), span.end(), rule.0)?
+			}
+		}
+		Ok(())
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Rule timelines.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [RuleTimeline] assigns [elementary&#32;rules](AutomatonRule) to ranges of
+/// generations, so that an evolution switches rules by itself as it proceeds.
+/// The rule assigned to a generation governs the evolution _from_ that
+/// generation, counting from 0 at the first generation. Each switch replaces
+/// the entire [schedule](RuleSchedule), but only when the evolution enters a
+/// range, so rules chosen during a range survive until the next switch.
+/// Between ranges, and beyond the last, the schedule keeps whatever rules it
+/// has.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Component)]
+pub struct RuleTimeline(Vec<(RangeInclusive<usize>, AutomatonRule)>);
+
+impl RuleTimeline
+{
+	/// Determine whether the receiver assigns no rules at all.
+	pub fn is_empty(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+
+	/// Answer the [rule](AutomatonRule) assigned to the specified generation,
+	/// if any. Later assignments take precedence over earlier ones.
+	pub fn rule_at(&self, generation: usize) -> Option<AutomatonRule>
+	{
+		self.0.iter()
+			.rev()
+			.find(|(range, _)| range.contains(&generation))
+			.map(|(_, rule)| *rule)
+	}
+
+	/// Answer the [rule](AutomatonRule) to switch to upon reaching the
+	/// specified generation, if the evolution enters a new range there.
+	pub fn switch_at(&self, generation: usize) -> Option<AutomatonRule>
+	{
+		let rule = self.rule_at(generation)?;
+		match generation.checked_sub(1).and_then(|g| self.rule_at(g))
+		{
+			Some(previous) if previous == rule => None,
+			_ => Some(rule)
+		}
+	}
+
+	/// Answer the first generation after the specified one at which the
+	/// evolution [switches](Self::switch_at) rules, if any, along with the
+	/// incoming [rule](AutomatonRule).
+	pub fn next_switch(
+		&self,
+		generation: usize
+	) -> Option<(usize, AutomatonRule)>
+	{
+		// Switches happen only where ranges begin or end.
+		let mut candidates = self.0.iter()
+			.flat_map(|(range, _)| {
+				[*range.start(), range.end().wrapping_add(1)]
+			})
+			.filter(|&candidate| candidate > generation)
+			.collect::<Vec<_>>();
+		candidates.sort_unstable();
+		candidates.into_iter()
+			.find_map(|candidate| {
+				self.switch_at(candidate).map(|rule| (candidate, rule))
+			})
+	}
+
+	/// If the evolution switches rules upon reaching the specified generation,
+	/// then replace the specified [schedule](RuleSchedule) with the incoming
+	/// [rule](AutomatonRule). Answer the outgoing schedule, if the schedule
+	/// changed.
+	pub fn follow(
+		&self,
+		generation: usize,
+		schedule: &mut RuleSchedule
+	) -> Option<RuleSchedule>
+	{
+		let incoming = RuleSchedule::from(self.switch_at(generation)?);
+		match *schedule == incoming
+		{
+			true => None,
+			false => Some(std::mem::replace(schedule, incoming))
+		}
+	}
+}
+
+impl FromStr for RuleTimeline
+{
+	type Err = String;
+
+	/// Parse a list of assignments, separated by commas or newlines, each a
+	/// generation or an inclusive range of generations, a colon, and a
+	/// [rule](AutomatonRule), e.g., `0-99: 30, 100-199: 110`. A range without
+	/// an end, e.g., `200-`, never ends. Later assignments take precedence
+	/// over earlier ones. A `#` comments out the rest of its line.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let mut timeline = Vec::new();
+		let assignments = s.lines()
+			.map(|line| line.split_once('#').map_or(line, |(line, _)| line))
+			.flat_map(|line| line.split(','))
+			.map(str::trim)
+			.filter(|assignment| !assignment.is_empty());
+		for assignment in assignments
+		{
+			let (generations, rule) = assignment.split_once(':')
+				.ok_or_else(|| format!(
+					"expected generations:rule, not '{}'",
+					assignment
+				))?;
+			let rule = rule.trim().parse::<AutomatonRule>()?;
+			let generation = |generation: &str| generation.trim()
+				.parse::<usize>()
+				.map_err(|_| format!("bad generation '{}'", generation.trim()));
+			let (first, last) = match generations.split_once('-')
+			{
+				Some((first, last)) if last.trim().is_empty() =>
+					(generation(first)?, usize::MAX),
+				Some((first, last)) => (generation(first)?, generation(last)?),
+				None => (generation(generations)?, generation(generations)?)
+			};
+			if first > last
+			{
+				return Err(format!(
+					"empty range of generations '{}'",
+					generations.trim()
+				))
+			}
+			timeline.push((first ..= last, rule));
+		}
+		Ok(Self(timeline))
+	}
+}
+
+impl Display for RuleTimeline
+{
+	/// Render the assignments just as [FromStr](RuleTimeline::from_str) would
+	/// parse them.
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		for (i, (range, rule)) in self.0.iter().enumerate()
+		{
+			if i > 0
+			{
+				write!(f, ", ")?;
+			}
+			match (*range.start(), *range.end())
+			{
+				(first, last) if first == last =>
+					write!(f, "{}: {}", first, rule.0)?,
+				(first, usize::MAX) => write!(f, "{}-: {}", first, rule.0)?,
+				(first, last) => write!(f, "{}-{}: {}", first, last, rule.0)?
+			}
+		}
+		Ok(())
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Automata.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [Automaton] represents a [1-dimensional&#32;cellular&#32;automaton]. The
+/// automaton itself is a sequence of cells, each represented by a `bool`, which
+/// may be occupied (`true`) or vacant (`false`). The rightmost cell has the
+/// index `0`, and the leftmost cell has the index `K-1`. A
+/// [rule](AutomatonRule) may be applied to an automaton to produce the next
+/// generation. `K` is the length of the automaton, in cells, and must be ≥3,
+/// which sadly is unenforceable on the `stable` channel. Note that the two ends
+/// of the automaton are considered adjacent for the purpose of computing the
+/// next generation.
+///
+/// N.B.: Rust does not guarantee a packed representation for a `bool` array; in
+/// fact, LLVM does not pack arrays of `u1` at this time, so the representation
+/// will not be maximally efficient on space. It will still have relatively good
+/// spatial and temporal performance, however, and this approach obviates the
+/// need for any external crates, e.g.,
+/// [`bitvec`](https://crates.io/crates/bitvec), and permits derivation of
+/// [Copy].
+///
+/// [1-dimensional&#32;cellular&#32;automaton]: https://en.wikipedia.org/wiki/Elementary_cellular_automaton
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Automaton<const K: usize = AUTOMATON_LENGTH>([bool; K]);
+
+impl<const K: usize> Automaton<K>
+{
+	/// Construct a new [Automaton] that is completely vacant, i.e., each cell
+	/// is unoccupied.
+	pub const fn new() -> Self
+	{
+		Self([false; K])
+	}
+
+	/// Construct a new [Automaton] from a bit vector of arbitrary width, packed
+	/// into `u64` words from least to most significant, such that bit `i` of
+	/// word `w` corresponds to cell `64 * w + i`, counting from the right. This
+	/// generalizes the [conversion](Automaton::from) from `u64` to automata
+	/// wider than 64 cells. Missing words are vacant, and bits beyond index `K`
+	/// are ignored.
+	pub fn from_bits(words: &[u64]) -> Self
+	{
+		let mut automaton = Self::new();
+		for index in 0 .. K.min(words.len() * u64::BITS as usize)
+		{
+			let word = words[index / u64::BITS as usize];
+			automaton[index] = word & (1 << (index % u64::BITS as usize)) != 0;
+		}
+		automaton
+	}
+
+	/// Parse an [Automaton] from a string of digits of arbitrary length, in
+	/// hexadecimal if prefixed by `0x`, or in binary if prefixed by `0b`,
+	/// ignoring the case of the prefix. The prefix is mandatory, since a
+	/// string of hexadecimal digits may itself begin with `0b`; use
+	/// [from_hex](Self::from_hex) for bare hexadecimal digits. As with
+	/// [from_bits](Self::from_bits), the last digit supplies the rightmost
+	/// cells. Underscores and whitespace are ignored, so that long strings can
+	/// be grouped for legibility. Leading zeroes are permitted, but a live cell
+	/// beyond index `K` is an error.
+	pub fn from_digits(s: &str) -> Result<Self, String>
+	{
+		match strip_radix(s.trim())
+		{
+			Some((digits, radix)) => Self::from_radix(s, digits, radix),
+			None => Err(format!(
+				"unknown radix of seed '{}': expected a prefix of `0x` or `0b`",
+				s
+			))
+		}
+	}
+
+	/// Parse an [Automaton] from a string of hexadecimal digits of arbitrary
+	/// length, optionally prefixed by `0x`, ignoring case. Unlike
+	/// [from_digits](Self::from_digits), a string that begins with `0b` is
+	/// hexadecimal too. Otherwise the two agree.
+	pub fn from_hex(s: &str) -> Result<Self, String>
+	{
+		let trimmed = s.trim();
+		let digits = match strip_radix(trimmed)
+		{
+			Some((digits, 16)) => digits,
+			_ => trimmed
+		};
+		Self::from_radix(s, digits, 16)
+	}
+
+	/// Answer the receiver as a string of hexadecimal digits, prefixed by
+	/// `0x`, that [from_digits](Self::from_digits) accepts. There is one digit
+	/// for every four cells, including any leading zeroes, so that every cell
+	/// is represented, however wide the automaton.
+	pub fn to_hex(&self) -> String
+	{
+		let mut hex = String::from("0x");
+		for position in (0 .. K.div_ceil(4)).rev()
+		{
+			let digit = (0 .. 4)
+				.map(|bit| position * 4 + bit)
+				.filter(|&index| index < K && self[index])
+				.fold(0, |digit, index| digit | 1 << (index % 4));
+			hex.push(char::from_digit(digit, 16).unwrap());
+		}
+		hex
+	}
+
+	/// Parse an [Automaton] from the specified digits, which are in the
+	/// specified radix, either `2` or `16`. `s` is the complete string, for
+	/// error messages.
+	fn from_radix(s: &str, digits: &str, radix: u32) -> Result<Self, String>
+	{
+		let width = match radix
+		{
+			2 => 1,
+			_ => 4
+		};
+		let digits = digits.chars()
+			.filter(|c| *c != '_' && !c.is_whitespace())
+			.map(|c| c.to_digit(radix).ok_or_else(|| format!(
+				"unknown digit '{}' in seed '{}'",
+				c,
+				s
+			)))
+			.collect::<Result<Vec<_>, _>>()?;
+		if digits.is_empty()
+		{
+			return Err(format!("empty seed '{}'", s))
+		}
+		let mut automaton = Self::new();
+		// The last digit supplies the rightmost cells, whose indices are 0.
+		for (position, digit) in digits.into_iter().rev().enumerate()
+		{
+			for bit in 0 .. width
+			{
+				if digit & (1 << bit) == 0
+				{
+					continue
+				}
+				let index = position * width + bit;
+				if index >= K
+				{
+					return Err(format!(
+						"unusable seed '{}': expected at most {} cells",
+						s,
+						K
+					))
+				}
+				automaton[index] = true;
+			}
+		}
+		Ok(automaton)
+	}
+
+	/// Compute the successor [automaton][Automaton] in accordance with the
+	/// specified [rule](AutomatonRule). Automata of at least
+	/// [PARALLEL_EVOLUTION_THRESHOLD] cells are evolved
+	/// [in&#32;parallel](Self::par_next). With the `simd` feature, automata
+	/// of at most 64 cells are evolved [bitwise](Self::next_bitwise).
+	pub fn next(&self, rule: AutomatonRule) -> Self
+	{
+		#[cfg(feature = "simd")]
+		if K <= u64::BITS as usize
+		{
+			return self.next_bitwise(rule)
+		}
+		if K >= PARALLEL_EVOLUTION_THRESHOLD
+		{
+			return self.par_next(rule)
+		}
+		let mut next = [false; K];
+		self.evolve_span(rule, 0, &mut next);
+		Automaton(next)
+	}
+
+	/// Compute the `n`-th successor [automaton][Automaton] in accordance with
+	/// the specified [rule](AutomatonRule), without retaining the generations
+	/// in between. With the `simd` feature, automata of at most 64 cells stay
+	/// packed into a `u64` throughout.
+	pub fn next_n(&self, rule: AutomatonRule, n: usize) -> Self
+	{
+		#[cfg(feature = "simd")]
+		if K <= u64::BITS as usize
+		{
+			let packed = (0 .. n).fold(
+				u64::from(*self),
+				|packed, _| Self::next_packed(packed, rule)
+			);
+			return Automaton::from(packed)
+		}
+		(0 .. n).fold(*self, |automaton, _| automaton.next(rule))
+	}
+
+	/// Compute the successor [automaton][Automaton] in accordance with the
+	/// specified [rule](AutomatonRule), splitting the cells into chunks of
+	/// [PARALLEL_CHUNK_LENGTH] and evolving the chunks concurrently on the
+	/// [compute&#32;task&#32;pool](ComputeTaskPool). Each chunk reads its
+	/// boundary neighborhoods from the (shared, immutable) receiver, so the
+	/// chunks need not coordinate. Where threads are unavailable, e.g., in the
+	/// browser, the chunks are evolved one after another.
+	pub fn par_next(&self, rule: AutomatonRule) -> Self
+	{
+		let mut next = [false; K];
+		let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+		pool.scope(|scope| {
+			for (chunk, cells) in next.chunks_mut(PARALLEL_CHUNK_LENGTH)
+				.enumerate()
+			{
+				let start = chunk * PARALLEL_CHUNK_LENGTH;
+				scope.spawn(async move {
+					self.evolve_span(rule, start, cells);
+				});
+			}
+		});
+		Automaton(next)
+	}
+
+	/// Compute the successor [automaton][Automaton] in accordance with the
+	/// specified [rule](AutomatonRule), all 64 cells at once. The cells are
+	/// packed into a `u64`, whose rotations line up the left and right
+	/// neighbors of every cell with the cell itself; the rule then becomes a
+	/// boolean function of three words, the disjunction of the minterms of the
+	/// neighborhoods that it brings to life.
+	#[cfg(feature = "simd")]
+	pub fn next_bitwise(&self, rule: AutomatonRule) -> Self
+	{
+		assert!(K <= u64::BITS as usize);
+		Automaton::from(Self::next_packed(u64::from(*self), rule))
+	}
+
+	/// Compute the successor of the automaton packed into the low `K` bits of
+	/// `middle`, [bitwise](Self::next_bitwise), in accordance with the
+	/// specified [rule](AutomatonRule). Answer the successor, packed likewise.
+	#[cfg(feature = "simd")]
+	fn next_packed(middle: u64, rule: AutomatonRule) -> u64
+	{
+		let mask = u64::MAX >> (u64::BITS as usize - K);
+		// The left neighbor of each cell has the next higher index, and the
+		// right neighbor the next lower, wrapping around the ends.
+		let left = (middle >> 1 | middle << (K - 1)) & mask;
+		let right = (middle << 1 | middle >> (K - 1)) & mask;
+		let mut next = 0u64;
+		for ordinal in 0 ..= 7
+		{
+			if rule.next_cell(ordinal)
+			{
+				let left = if ordinal & 4 != 0 { left } else { !left };
+				let middle = if ordinal & 2 != 0 { middle } else { !middle };
+				let right = if ordinal & 1 != 0 { right } else { !right };
+				next |= left & middle & right;
+			}
+		}
+		next & mask
+	}
+
+	/// Compute the successors of the span of cells that begins at the
+	/// specified index, in accordance with the specified
+	/// [rule](AutomatonRule), writing them into `cells`, which the span
+	/// exactly fills.
+	fn evolve_span(&self, rule: AutomatonRule, start: usize, cells: &mut [bool])
+	{
+		let len = cells.len();
+		// Compute the edge cells of the span, whose neighbors may lie in the
+		// adjacent spans, or across the ends of the automaton.
+		let wrapped = |i: usize| compute_ordinal(
+			self[(i + 1) % K],
+			self[i],
+			self[(i + K - 1) % K]
+		);
+		cells[0] = rule.next_cell(wrapped(start));
+		if len > 1
+		{
+			cells[len - 1] = rule.next_cell(wrapped(start + len - 1));
+		}
+		// Computing the medial cells is trivial.
+		let medial = cells[.. len.saturating_sub(1)].iter_mut().enumerate();
+		for (offset, cell) in medial.skip(1)
+		{
+			let i = start + offset;
+			let ordinal = compute_ordinal(
+				self[i + 1],
+				self[i],
+				self[i - 1]
+			);
+			*cell = rule.next_cell(ordinal);
+		}
+	}
+
+	/// Answer the [automaton](Automaton) whose cells are occupied exactly where
+	/// the receiver and the specified automaton disagree.
+	pub fn difference(&self, other: &Self) -> Self
+	{
+		self.xor(other)
+	}
+
+	/// Answer the [automaton](Automaton) whose cells have moved the specified
+	/// number of columns to the left, as displayed. Cells that pass the left
+	/// edge reenter at the right.
+	pub fn rotate_left(&self, columns: usize) -> Self
+	{
+		let columns = columns % K;
+		Automaton(std::array::from_fn(|i| self[(i + K - columns) % K]))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells have moved the specified
+	/// number of columns to the right, as displayed. Cells that pass the right
+	/// edge reenter at the left.
+	pub fn rotate_right(&self, columns: usize) -> Self
+	{
+		let columns = columns % K;
+		Automaton(std::array::from_fn(|i| self[(i + columns) % K]))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells have moved the specified
+	/// number of columns to the left, as displayed, or to the right if
+	/// `columns` is negative, just like a shift of the corresponding bit
+	/// vector. Cells that pass either edge are lost, and vacant cells fill in
+	/// behind.
+	pub fn shift(&self, columns: isize) -> Self
+	{
+		Automaton(std::array::from_fn(|i| {
+			let source = i as isize - columns;
+			(0 .. K as isize).contains(&source) && self[source as usize]
+		}))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells are occupied exactly where
+	/// the receiver's are vacant.
+	pub fn invert(&self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| !self[i]))
+	}
+
+	/// Answer the mirror image of the receiver, i.e., the
+	/// [automaton](Automaton) whose cells run in the opposite order.
+	pub fn mirror(&self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| self[K - i - 1]))
+	}
+
+	/// Answer the mirror-symmetric [automaton](Automaton) whose left half, as
+	/// displayed, is the left half of the receiver, and whose right half is
+	/// the mirror image of that. For odd `K`, the middle cell is kept. Under
+	/// any rule that is its own mirror image, e.g., rule 90, a symmetric first
+	/// generation evolves symmetrically forever.
+	pub fn symmetrize(&self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| self[i.max(K - i - 1)]))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells are occupied exactly where
+	/// the receiver and the specified automaton are both occupied.
+	pub fn and(&self, other: &Self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| self[i] && other[i]))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells are occupied exactly where
+	/// the receiver or the specified automaton is occupied.
+	pub fn or(&self, other: &Self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| self[i] || other[i]))
+	}
+
+	/// Answer the [automaton](Automaton) whose cells are occupied exactly where
+	/// either the receiver or the specified automaton is occupied, but not
+	/// both.
+	pub fn xor(&self, other: &Self) -> Self
+	{
+		Automaton(std::array::from_fn(|i| self[i] != other[i]))
+	}
+
+	/// Answer the number of occupied cells.
+	pub fn population_count(&self) -> usize
+	{
+		self.0.iter().filter(|cell| **cell).count()
+	}
+
+	/// Answer the Shannon entropy of the neighborhoods of the cells, relative
+	/// to its maximum of 3 bits, wrapping around at the edges. Uniform
+	/// automata have no entropy.
+	pub fn entropy(&self) -> f32
+	{
+		let mut counts = [0usize; 8];
+		for index in 0 .. K
+		{
+			let ordinal = compute_ordinal(
+				self[(index + 1) % K],
+				self[index],
+				self[(index + K - 1) % K]
+			);
+			counts[ordinal as usize] += 1;
+		}
+		let entropy = counts.iter()
+			.filter(|&&count| count > 0)
+			.map(|&count| {
+				let p = count as f32 / K as f32;
+				-p * p.log2()
+			})
+			.sum::<f32>();
+		entropy / 3.0
+	}
+
+	/// Answer the power spectrum of the cells, wrapping around at the edges,
+	/// by way of a discrete Fourier transform. The `k`-th power belongs to the
+	/// spatial frequency of `k` cycles around the automaton, i.e., to the
+	/// wavelength of `K / k` cells, for `k` in `[0, K/2]`. The mean is removed
+	/// beforehand, so the power of frequency `0` is always `0`, and uniform
+	/// automata have no power at all.
+	pub fn power_spectrum(&self) -> Vec<f32>
+	{
+		let mean = self.population_count() as f32 / K as f32;
+		(0 ..= K / 2)
+			.map(|frequency| {
+				let (mut re, mut im) = (0.0f32, 0.0f32);
+				for index in 0 .. K
+				{
+					let value = self[index] as u8 as f32 - mean;
+					let angle = TAU * (frequency * index % K) as f32 / K as f32;
+					re += value * angle.cos();
+					im -= value * angle.sin();
+				}
+				(re * re + im * im) / K as f32
+			})
+			.collect()
+	}
+
+	/// Construct an [automaton](Automaton) from a row of brightness values,
+	/// given from left to right, by resampling the row to `K` cells and then
+	/// thresholding each cell: a cell is occupied if its average brightness
+	/// falls below `threshold`, i.e., dark pixels come alive. Wider rows are
+	/// averaged down, and narrower rows are stretched.
+	pub fn from_brightness(row: &[u8], threshold: u8) -> Self
+	{
+		assert!(!row.is_empty());
+		let width = row.len();
+		let mut cells = [false; K];
+		for column in 0 .. K
+		{
+			let start = column * width / K;
+			let end = ((column + 1) * width / K).max(start + 1);
+			let span = &row[start .. end];
+			let sum = span.iter().map(|&pixel| pixel as usize).sum::<usize>();
+			cells[K - column - 1] = sum < threshold as usize * span.len();
+		}
+		Automaton(cells)
+	}
+
+	/// Determine whether every cell is vacant.
+	pub fn is_vacant(&self) -> bool
+	{
+		self.0.iter().all(|cell| !cell)
+	}
+
+	/// Answer an [iterator](Iterator) that traverse the cells of the
+	/// [automaton](Automaton) in right-to-left order.
+	pub fn iter(&self) -> impl Iterator<Item=&bool>
+	{
+		self.0.iter()
+	}
+}
+
+/// Note that we cannot auto-derive [Default] because of the generic parameter,
+/// so we manually implement it here.
+impl<const K: usize> Default for Automaton<K>
+{
+	/// Construct a new [Automaton] that is completely vacant, i.e., each cell
+	/// is unoccupied.
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const K: usize> From<[bool; K]> for Automaton<K>
+{
+	/// Initialize an [automaton](Automaton) directly from its cells, given in
+	/// right-to-left order.
+	fn from(value: [bool; K]) -> Self
+	{
+		Automaton(value)
+	}
+}
+
+impl<const K: usize> From<u64> for Automaton<K>
+{
+	/// Initialize an [automaton](Automaton) by treating the specified `u64` as
+	/// a bit vector of up to 64 bits. Ignore high bits beyond index `K`. Wider
+	/// automata can be initialized with [from_bits](Automaton::from_bits).
+	fn from(value: u64) -> Self
+	{
+		assert!(K <= 0u64.count_zeros() as usize);
+		let mut next = [false; K];
+		for i in 0 ..= K - 1
+		{
+			next[i] = value & (1 << i) != 0;
+		}
+		Automaton(next)
+	}
+}
+
+impl<const K: usize> From<Automaton<K>> for u64
+{
+	/// Pack the specified [automaton](Automaton) into a bit vector of up to 64
+	/// bits, such that lower numbered bits correspond to cells on the right.
+	/// This is the inverse of the [conversion](Automaton::from) from `u64`.
+	fn from(value: Automaton<K>) -> Self
+	{
+		assert!(K <= 0u64.count_zeros() as usize);
+		value.iter()
+			.enumerate()
+			.filter(|(_, cell)| **cell)
+			.fold(0, |bits, (i, _)| bits | (1 << i))
+	}
+}
+
+impl<const K: usize> Display for Automaton<K>
+{
+	/// Render an automaton with a prefix that specifies its length followed by
+	/// a densely-packed series of `X` and `•` that represent occupancy and
+	/// vacancy, respectively, from left to right as displayed.
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "Automaton[{}]: ", K)?;
+		for i in (0 ..= K - 1).rev()
+		{
+			write!(f, "{}", if self[i] { "X" } else { "•" })?;
+		}
+		Ok(())
+	}
+}
+
+impl<const K: usize> FromStr for Automaton<K>
+{
+	type Err = String;
+
+	/// Parse an automaton from a series of glyphs, one per cell, from left to
+	/// right as displayed: `X`, `1`, or `#` for an occupied cell, and `•`, `0`,
+	/// or `.` for a vacant one. Whitespace is ignored, as is the prefix that
+	/// [Display] emits, so rendered automata round-trip. Patterns shorter than
+	/// `K` cells are padded on the left with vacant cells, just like the
+	/// [conversion](Automaton::from) from `u64`.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let glyphs = match s.trim().strip_prefix("Automaton[")
+		{
+			Some(rest) =>
+			{
+				let (len, glyphs) = rest.split_once("]:")
+					.ok_or_else(|| format!("malformed automaton '{}'", s))?;
+				if len.parse::<usize>() != Ok(K)
+				{
+					return Err(format!(
+						"mismatched automaton '{}': expected {} cells",
+						s,
+						K
+					))
+				}
+				glyphs
+			},
+			None => s
+		};
+		let cells = glyphs.chars()
+			.filter(|glyph| !glyph.is_whitespace())
+			.map(|glyph| match glyph
+			{
+				'X' | '1' | '#' => Ok(true),
+				'•' | '0' | '.' => Ok(false),
+				_ => Err(format!(
+					"unknown glyph '{}': expected X, 1, or # for a live cell, \
+						or •, 0, or . for a dead cell",
+					glyph
+				))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		if cells.is_empty() || cells.len() > K
+		{
+			return Err(format!(
+				"unusable pattern '{}': expected between 1 and {} cells",
+				s,
+				K
+			))
+		}
+		let mut automaton = Automaton::new();
+		// The last glyph is the rightmost cell, whose index is 0.
+		for (index, live) in cells.into_iter().rev().enumerate()
+		{
+			automaton[index] = live;
+		}
+		Ok(automaton)
+	}
+}
+
+impl<const K: usize> Index<usize> for Automaton<K>
+{
+	type Output = bool;
+
+	#[inline]
+	fn index(&self, index: usize) -> &Self::Output
+	{
+		&self.0[index]
+	}
+}
+
+impl<const K: usize> IndexMut<usize> for Automaton<K>
+{
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	{
+		&mut self.0[index]
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Histories.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The last `N` generations of a [cellular&#32;automaton](Automaton). Each
+/// automaton comprises `K` cells.
+#[derive(Clone, Debug, Component)]
+pub struct History<
+	const K: usize = AUTOMATON_LENGTH,
+	const N: usize = AUTOMATON_HISTORY
+>(
+	ConstGenericRingBuffer<Automaton<K>, N>
+);
+
+impl<const K: usize, const N: usize> History<K, N>
+{
+	/// Construct an empty [History].
+	pub fn new() -> Self
+	{
+		let mut ring = ConstGenericRingBuffer::new();
+		for _ in 0 .. N
+		{
+			ring.push(Automaton::default());
+		}
+		assert!(ring.is_full());
+		Self(ring)
+	}
+
+	/// Answer a reference to the [automaton](Automaton) that represents the
+	/// newest generation.
+	/// [default](Default::default)&#32;[automaton](Automaton).
+	pub fn newest(&self) -> &Automaton<K>
+	{
+		self.0.back().unwrap()
+	}
+
+	/// Answer a reference to the [automaton](Automaton) that represents the
+	/// oldest generation.
+	#[allow(dead_code)]
+	pub fn oldest(&self) -> &Automaton<K>
+	{
+		self.0.front().unwrap()
+	}
+
+	/// Replace the [newest](Self::newest)&#32;[automaton](Automaton) with the
+	/// one provided. This is provided to support user customization of the
+	/// seed.
+	pub fn replace(&mut self, replacement: Automaton<K>)
+	{
+		match self.0.back_mut()
+		{
+			Some(newest) => *newest = replacement,
+			None => self.0.push(replacement)
+		}
+	}
+
+	/// Evolve the [newest](Self::newest)&#32;[automaton](Automaton) according
+	/// to the specified [rule](RuleKind). Append the result to the
+	/// [history](History). If the [history](History) is full, then the
+	/// [oldest](Self::oldest)&#32;[automaton](Automaton) will be forgotten.
+	pub fn evolve(&mut self, rule: &RuleKind)
+	{
+		self.0.push(rule.next(self.newest()));
+	}
+
+	/// [Evolve](Self::evolve) the [history](History) according to the
+	/// specified [rule](RuleKind), except for the cells to which the specified
+	/// [map](RuleMap) assigns rules of their own.
+	pub fn evolve_mapped(&mut self, rule: &RuleKind, map: &RuleMap<K>)
+	{
+		self.0.push(map.next(rule, self.newest()));
+	}
+
+	/// [Evolve](Self::evolve) the [history](History) `n` times according to
+	/// the specified [rule](RuleKind). Generations that the history would
+	/// forget straight away are never appended, so only the last `N`
+	/// evolutions cost more than the [bulk&#32;evolution](RuleKind::next_n)
+	/// of the [newest](Self::newest)&#32;[automaton](Automaton).
+	pub fn evolve_n(&mut self, rule: &RuleKind, n: usize)
+	{
+		let skipped = n.saturating_sub(N);
+		if skipped > 0
+		{
+			// The replaced generation is forgotten by the evolutions below.
+			self.replace(rule.next_n(self.newest(), skipped));
+		}
+		for _ in 0 .. n - skipped
+		{
+			self.evolve(rule);
+		}
+	}
+
+	/// Answer an iterator that traverses the [history](History) from
+	/// [oldest](Self::oldest) to [newest](Self::newest).
+	pub fn iter(&self) -> impl Iterator<Item=&Automaton<K>>
+	{
+		self.0.iter()
+	}
+
+	/// Determine whether the [history](History) has settled into a
+	/// [steady&#32;state](SteadyState), i.e., whether each of the last `period`
+	/// evolutions left the [newest](Self::newest) generation unchanged. When
+	/// the evolutions follow a [schedule](RuleSchedule), `period` should be its
+	/// length, so that every scheduled rule is known to preserve the
+	/// generation. A period that the history is too short to witness never
+	/// settles.
+	pub fn steady_state(&self, period: usize) -> Option<SteadyState>
+	{
+		assert!(period > 0);
+		if period >= N
+		{
+			return None
+		}
+		let newest = self.newest();
+		let settled = self.0.iter()
+			.rev()
+			.skip(1)
+			.take(period)
+			.all(|generation| generation == newest);
+		match settled
+		{
+			false => None,
+			true if newest.is_vacant() => Some(SteadyState::Extinct),
+			true => Some(SteadyState::FixedPoint)
+		}
+	}
+
+	/// Answer the [history](History) whose generations are the
+	/// [differences](Automaton::difference) between the corresponding
+	/// generations of the receiver and the specified history.
+	pub fn difference(&self, other: &Self) -> Self
+	{
+		let mut ring = ConstGenericRingBuffer::new();
+		for (mine, theirs) in self.iter().zip(other.iter())
+		{
+			ring.push(mine.difference(theirs));
+		}
+		assert!(ring.is_full());
+		Self(ring)
+	}
+}
+
+impl<const K: usize, const N: usize> Default for History<K, N>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const K: usize, const N: usize> From<Automaton<K>> for History<K, N>
+{
+	/// Given a single [automaton](Automaton), start a new (history)[History]
+	/// that uses the automaton as its first generation.
+	fn from(value: Automaton<K>) -> Self
+	{
+		let mut history = Self::default();
+		history.replace(value);
+		history
+	}
+}
+
+impl<const K: usize, const N: usize> Index<usize> for History<K, N>
+{
+	type Output = Automaton<K>;
+
+	/// Borrow the `index`-th cell. `index` is zero-based.
+	#[inline]
+	fn index(&self, index: usize) -> &Self::Output
+	{
+		&self.0[index]
+	}
+}
+
+impl<const K: usize, const N: usize> IndexMut<usize> for History<K, N>
+{
+	/// Mutably borrow the `index`-th cell. `index` is zero-based.
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	{
+		&mut self.0[index]
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Decay.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The decay of the cells of the last `N` generations of a
+/// [cellular&#32;automaton](Automaton), after the fashion of "Generations"
+/// rules like Brian's Brain. Rather than vanishing outright, a dying cell
+/// passes through several decay levels, one per generation, before it is
+/// finally dead. While decaying, a cell counts as vacant to its neighbors, but
+/// cannot be reborn. Each row of levels corresponds to the generation of the
+/// accompanying [History] at the same index. A level of `0` means that the
+/// cell is live or dead; otherwise, the level counts the generations since
+/// the cell died, from `1` up to the number of decay generations.
+#[derive(Clone, Debug, Component)]
+pub struct Decay<
+	const K: usize = AUTOMATON_LENGTH,
+	const N: usize = AUTOMATON_HISTORY
+>
+{
+	/// The number of generations that a dying cell takes to vanish.
+	generations: u8,
+
+	/// The decay levels of the cells of each generation, each automaton in
+	/// right-to-left order.
+	levels: ConstGenericRingBuffer<[u8; K], N>
+}
+
+impl<const K: usize, const N: usize> Decay<K, N>
+{
+	/// Construct a new [Decay] in which no cell is decaying, and in which each
+	/// dying cell takes the specified number of generations to vanish.
+	pub fn new(generations: u8) -> Self
+	{
+		let mut decay = Self {
+			generations,
+			levels: ConstGenericRingBuffer::new()
+		};
+		decay.restart();
+		decay
+	}
+
+	/// Answer the number of generations that a dying cell takes to vanish.
+	pub fn generations(&self) -> u8
+	{
+		self.generations
+	}
+
+	/// Forget every decaying cell, e.g., because the accompanying
+	/// [history](History) started over.
+	pub fn restart(&mut self)
+	{
+		for _ in 0 .. N
+		{
+			self.levels.push([0; K]);
+		}
+		assert!(self.levels.is_full());
+	}
+
+	/// [Evolve](History::evolve_mapped) the specified [history](History)
+	/// according to the specified [rule](RuleKind) and [map](RuleMap), but
+	/// prevent decaying cells from being reborn. Then record the decay levels
+	/// of the new generation: cells that just died begin to decay, and
+	/// decaying cells decay further until they vanish.
+	pub fn evolve(
+		&mut self,
+		history: &mut History<K, N>,
+		rule: &RuleKind,
+		map: &RuleMap<K>
+	) {
+		let previous = *history.newest();
+		let levels = *self.levels.back().unwrap();
+		history.evolve_mapped(rule, map);
+		let mut next = *history.newest();
+		let mut decayed = [0; K];
+		for i in 0 .. K
+		{
+			if levels[i] > 0
+			{
+				next[i] = false;
+			}
+			decayed[i] = match (previous[i], next[i])
+			{
+				(true, false) if self.generations > 0 => 1,
+				(false, _) if levels[i] > 0 && levels[i] < self.generations =>
+					levels[i] + 1,
+				_ => 0
+			};
+		}
+		history.replace(next);
+		self.levels.push(decayed);
+	}
+}
+
+impl<const K: usize, const N: usize> Index<usize> for Decay<K, N>
+{
+	type Output = [u8; K];
+
+	/// Borrow the decay levels of the `index`-th generation. `index` is
+	/// zero-based.
+	#[inline]
+	fn index(&self, index: usize) -> &Self::Output
+	{
+		&self.levels[index]
+	}
+}
+
+impl<const K: usize, const N: usize> IndexMut<usize> for Decay<K, N>
+{
+	/// Mutably borrow the decay levels of the `index`-th generation. `index`
+	/// is zero-based.
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	{
+		&mut self.levels[index]
+	}
+}
+
+/// The ways in which a [history](History) can settle, such that further
+/// evolution is pointless.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SteadyState
+{
+	/// Every cell is vacant, and remains so.
+	Extinct,
+
+	/// Some cells are occupied, but the generation no longer changes.
+	FixedPoint
+}
+
+impl Display for SteadyState
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			SteadyState::Extinct => write!(f, "the automaton died out"),
+			SteadyState::FixedPoint =>
+				write!(f, "the automaton reached a fixed point")
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Scrollback.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Every generation of a [cellular&#32;automaton](Automaton) since its first,
+/// not just the last few kept by its [History], optionally capped at some
+/// number of generations. Each generation is retained along with the
+/// [rule](RuleKind) that produced it. Each automaton comprises `K` cells.
+#[derive(Clone, Debug, Component)]
+pub struct Scrollback<const K: usize = AUTOMATON_LENGTH>
+{
+	/// The generations, from oldest to newest, each with the rule that
+	/// produced it. The first generation was produced by no rule.
+	generations: VecDeque<(Automaton<K>, Option<RuleKind>)>,
+
+	/// The index of the oldest retained generation, counting from the first
+	/// generation.
+	first: usize,
+
+	/// The maximum number of generations to keep, if any. Once the cap is
+	/// reached, the oldest generation is forgotten to make room for each new
+	/// one.
+	cap: Option<usize>
+}
+
+impl<const K: usize> Scrollback<K>
+{
+	/// Construct a new [Scrollback] whose first generation is the specified
+	/// [automaton](Automaton), optionally capped at the specified number of
+	/// generations.
+	pub fn new(first: Automaton<K>, cap: Option<usize>) -> Self
+	{
+		assert!(cap != Some(0));
+		Self { generations: VecDeque::from([(first, None)]), first: 0, cap }
+	}
+
+	/// Answer the number of generations retained, of which there is always at
+	/// least one.
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize
+	{
+		self.generations.len()
+	}
+
+	/// Append a new generation, produced by the specified [rule](RuleKind),
+	/// forgetting the oldest if the cap has been reached.
+	pub fn push(&mut self, automaton: Automaton<K>, rule: RuleKind)
+	{
+		if Some(self.generations.len()) == self.cap
+		{
+			self.generations.pop_front();
+			self.first += 1;
+		}
+		self.generations.push_back((automaton, Some(rule)));
+	}
+
+	/// Replace the newest generation with the one provided, e.g., because the
+	/// user edited it.
+	pub fn replace_newest(&mut self, automaton: Automaton<K>)
+	{
+		self.generations.back_mut().unwrap().0 = automaton;
+	}
+
+	/// Forget every generation, and start over from the specified
+	/// [automaton](Automaton).
+	pub fn restart(&mut self, first: Automaton<K>)
+	{
+		self.generations.clear();
+		self.generations.push_back((first, None));
+		self.first = 0;
+	}
+
+	/// Rewind to the generation at the specified index, counting from the
+	/// first generation, e.g., to restore a checkpoint, and forget every newer
+	/// generation. If that generation is no longer retained, or differs from
+	/// the specified [automaton](Automaton), e.g., because the evolution has
+	/// since started over, then start over from the automaton instead, at the
+	/// specified index.
+	pub fn rewind(&mut self, index: usize, automaton: Automaton<K>)
+	{
+		let retained = index.checked_sub(self.first)
+			.filter(|&offset| {
+				self.generations.get(offset)
+					.is_some_and(|(generation, _)| *generation == automaton)
+			});
+		match retained
+		{
+			Some(offset) => self.generations.truncate(offset + 1),
+			None =>
+			{
+				self.restart(automaton);
+				self.first = index;
+			}
+		}
+	}
+
+	/// Answer the generation that lies `back` generations before the newest,
+	/// if it has been retained.
+	pub fn back(&self, back: usize) -> Option<&Automaton<K>>
+	{
+		let len = self.generations.len();
+		if back < len { self.generations.get(len - back - 1).map(|g| &g.0) }
+		else { None }
+	}
+
+	/// Answer the generation that lies `back` generations before the newest,
+	/// if it has been retained, along with its index, counting from the first
+	/// generation, and the [rule](RuleKind) that produced it.
+	pub fn generation(
+		&self,
+		back: usize
+	) -> Option<(usize, &Automaton<K>, Option<&RuleKind>)>
+	{
+		let len = self.generations.len();
+		if back >= len
+		{
+			return None
+		}
+		let (automaton, rule) = &self.generations[len - back - 1];
+		Some((self.first + len - back - 1, automaton, rule.as_ref()))
+	}
+
+	/// Answer the [rule](RuleKind) that produced the newest generation, or
+	/// [None] if the newest generation is the first.
+	#[allow(dead_code)]
+	pub fn newest_rule(&self) -> Option<&RuleKind>
+	{
+		self.generations.back().and_then(|(_, rule)| rule.as_ref())
+	}
+
+	/// Trace the ancestry of the cell at the specified index of the generation
+	/// that lies `back` generations before the newest, through at most `depth`
+	/// earlier generations. Answer the ancestors in each earlier generation,
+	/// nearest first, as a set of indices, in ascending order. The ancestors in
+	/// each generation are the [determinants](RuleKind::determinants) of the
+	/// ancestors in the next, under the [rule](RuleKind) that produced the
+	/// next, beginning with the traced cell itself. The trace ends early at the
+	/// oldest retained generation, and at any generation without ancestors.
+	pub fn ancestry(
+		&self,
+		back: usize,
+		index: usize,
+		depth: usize
+	) -> Vec<Vec<usize>>
+	{
+		let len = self.generations.len();
+		let mut ancestry = Vec::new();
+		let mut descendants = vec![index];
+		for step in back .. back + depth
+		{
+			if step + 1 >= len
+			{
+				break
+			}
+			let (child, rule) = &self.generations[len - step - 1];
+			let (parent, _) = &self.generations[len - step - 2];
+			let Some(rule) = rule else
+			{
+				break
+			};
+			// The parent must have produced the child, which fails only if the
+			// child was edited afterward; an edited child has no ancestors.
+			if rule.next(parent) != *child
+			{
+				break
+			}
+			let mut ancestors = [false; K];
+			for &descendant in &descendants
+			{
+				for ancestor in rule.determinants(parent, descendant)
+				{
+					ancestors[ancestor] = true;
+				}
+			}
+			descendants = (0 .. K).filter(|&i| ancestors[i]).collect();
+			if descendants.is_empty()
+			{
+				break
+			}
+			ancestry.push(descendants.clone());
+		}
+		ancestry
+	}
+
+	/// Answer an iterator that traverses the retained generations from oldest
+	/// to newest, giving the index of each generation and the
+	/// [rule](RuleKind) that produced it.
+	pub fn iter(
+		&self
+	) -> impl Iterator<Item=(usize, &Automaton<K>, Option<&RuleKind>)>
+	{
+		self.generations.iter()
+			.enumerate()
+			.map(|(i, (automaton, rule))| {
+				(self.first + i, automaton, rule.as_ref())
+			})
+	}
+
+	/// Write the retained generations as CSV, one row per generation: the
+	/// index of the generation, the [rule](RuleKind) that produced it (empty
+	/// for the first generation), and then the cells as `0` or `1`, from left
+	/// to right. The first row names the columns.
+	pub fn write_csv(&self, mut out: impl Write) -> io::Result<()>
+	{
+		write!(out, "generation,rule")?;
+		for column in 0 .. K
+		{
+			write!(out, ",c{}", column)?;
+		}
+		writeln!(out)?;
+		for (index, automaton, rule) in self.iter()
+		{
+			let rule = rule.map(|rule| rule.abbreviation()).unwrap_or_default();
+			// Script and module names might contain anything, so quote them
+			// as necessary.
+			if rule.contains(&[',', '"', '\n'][..])
+			{
+				write!(out, "{},\"{}\"", index, rule.replace('"', "\"\""))?;
+			}
+			else
+			{
+				write!(out, "{},{}", index, rule)?;
+			}
+			for column in 0 .. K
+			{
+				write!(out, ",{}", automaton[K - column - 1] as u8)?;
+			}
+			writeln!(out)?;
+		}
+		Ok(())
+	}
+
+	/// Write the retained generations as a Wolfram Language expression, for
+	/// cross-checking in Mathematica: a list whose first element evolves the
+	/// oldest retained generation afresh, by
+	/// `CellularAutomaton[rule, init, t]`, and whose second element holds the retained generations themselves, as
+	/// lists of `0` and `1`, with cells from left to right. `CellularAutomaton`
+	/// treats the first generation as cyclic, just as [Automaton] does, so the
+	/// two elements agree, unless cells were edited along the way. If a single
+	/// elementary rule did not produce every generation, then the first
+	/// element is `Missing["NotApplicable"]`.
+	pub fn write_wolfram(&self, mut out: impl Write) -> io::Result<()>
+	{
+		let row = |automaton: &Automaton<K>| {
+			let cells = (0 .. K)
+				.map(|column| (automaton[K - column - 1] as u8).to_string())
+				.collect::<Vec<_>>();
+			format!("{{{}}}", cells.join(", "))
+		};
+		let (first, oldest, _) = self.generation(self.len() - 1).unwrap();
+		let (last, ..) = self.generation(0).unwrap();
+		let mut rules = self.iter()
+			.skip(1)
+			.map(|(_, _, rule)| match rule
+			{
+				Some(RuleKind::Wolfram(rule)) => Some(*rule),
+				_ => None
+			});
+		let rule = match rules.next()
+		{
+			Some(Some(rule)) if rules.all(|other| other == Some(rule)) =>
+				Some(rule),
+			_ => None
+		};
+		writeln!(
+			out,
+			"(* Generations {} through {}, with cells from left to right. *)",
+			first,
+			last
+		)?;
+		writeln!(out, "{{")?;
+		match rule
+		{
+			Some(rule) => writeln!(
+				out,
+				"\tCellularAutomaton[{}, {}, {}],",
+				rule.0,
+				row(oldest),
+				last - first
+			)?,
+			None => writeln!(out, "\tMissing[\"NotApplicable\"],")?
+		}
+		writeln!(out, "\t{{")?;
+		let rows = self.iter()
+			.map(|(_, automaton, _)| format!("\t\t{}", row(automaton)))
+			.collect::<Vec<_>>();
+		writeln!(out, "{}", rows.join(",\n"))?;
+		writeln!(out, "\t}}")?;
+		writeln!(out, "}}")
+	}
+}
+
+/// The formats in which the retained generations of a
+/// [scrollback](Scrollback) can be exported.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum ExportFormat
+{
+	/// [CSV](Scrollback::write_csv), for spreadsheets and data analysis.
+	#[default]
+	Csv,
+
+	/// A [Wolfram&#32;Language](Scrollback::write_wolfram) expression, for
+	/// cross-checking in Mathematica.
+	Wolfram
+}
+
+impl ExportFormat
+{
+	/// Answer the conventional file extension of the format.
+	pub fn extension(self) -> &'static str
+	{
+		match self
+		{
+			ExportFormat::Csv => "csv",
+			ExportFormat::Wolfram => "wl"
+		}
+	}
+
+	/// Answer the format conventionally indicated by the specified file
+	/// extension, if any.
+	pub fn from_extension(extension: &str) -> Option<Self>
+	{
+		match extension.to_ascii_lowercase().as_str()
+		{
+			"csv" => Some(ExportFormat::Csv),
+			"wl" | "m" => Some(ExportFormat::Wolfram),
+			_ => None
+		}
+	}
+
+	/// Write the retained generations of the specified
+	/// [scrollback](Scrollback) in the format.
+	pub fn write<const K: usize>(
+		self,
+		scrollback: &Scrollback<K>,
+		out: impl Write
+	) -> io::Result<()>
+	{
+		match self
+		{
+			ExportFormat::Csv => scrollback.write_csv(out),
+			ExportFormat::Wolfram => scrollback.write_wolfram(out)
+		}
+	}
+}
+
+impl FromStr for ExportFormat
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"csv" => Ok(ExportFormat::Csv),
+			"wolfram" => Ok(ExportFormat::Wolfram),
+			_ => Err(format!(
+				"unknown export format '{}': expected csv or wolfram",
+				s
+			))
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Column densities.                             //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A tally of how often each column of a [cellular&#32;automaton](Automaton)
+/// has been live, gathered one generation at a time, so that spatial structure
+/// shows up as soon as it forms, e.g., the asymmetry of Rule 30. Each
+/// automaton comprises `K` cells.
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct ColumnDensity<const K: usize = AUTOMATON_LENGTH>
+{
+	/// The number of tallied generations in which each cell was live, by
+	/// index, i.e., from right to left.
+	live: [u64; K],
+
+	/// The number of tallied generations.
+	generations: u64
+}
+
+impl<const K: usize> ColumnDensity<K>
+{
+	/// Construct an empty [ColumnDensity].
+	pub const fn new() -> Self
+	{
+		Self { live: [0; K], generations: 0 }
+	}
+
+	/// Tally the specified generation.
+	pub fn push(&mut self, automaton: &Automaton<K>)
+	{
+		for (live, &cell) in self.live.iter_mut().zip(automaton.0.iter())
+		{
+			*live += cell as u64;
+		}
+		self.generations += 1;
+	}
+
+	/// Withdraw the specified generation, which must have been tallied before,
+	/// e.g., because it was edited.
+	pub fn remove(&mut self, automaton: &Automaton<K>)
+	{
+		for (live, &cell) in self.live.iter_mut().zip(automaton.0.iter())
+		{
+			*live -= cell as u64;
+		}
+		self.generations -= 1;
+	}
+
+	/// Answer the number of tallied generations.
+	pub fn generations(&self) -> u64
+	{
+		self.generations
+	}
+
+	/// Answer the fraction of tallied generations in which the specified
+	/// column, counting from the left, was live, or `0` if none have been
+	/// tallied.
+	pub fn density(&self, column: usize) -> f32
+	{
+		match self.generations
+		{
+			0 => 0.0,
+			generations => self.live[K - column - 1] as f32 / generations as f32
+		}
+	}
+}
+
+impl<const K: usize> Default for ColumnDensity<K>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const K: usize> From<&Scrollback<K>> for ColumnDensity<K>
+{
+	/// Tally every generation retained by the [scrollback](Scrollback).
+	fn from(scrollback: &Scrollback<K>) -> Self
+	{
+		let mut density = Self::new();
+		for (_, automaton, _) in scrollback.iter()
+		{
+			density.push(automaton);
+		}
+		density
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Spatial spectra.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [power&#32;spectrum](Automaton::power_spectrum) of several
+/// [cellular&#32;automata](Automaton), averaged over all of them, e.g., over
+/// the generations of a [history](History), which quantifies the periodic
+/// spatial structure of the rule that produced them. Each automaton comprises
+/// `K` cells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpatialSpectrum<const K: usize = AUTOMATON_LENGTH>
+{
+	/// The mean power of each spatial frequency, in `[0, K/2]`.
+	power: Vec<f32>
+}
+
+impl<const K: usize> SpatialSpectrum<K>
+{
+	/// Average the power spectra of the specified automata. The spectrum of
+	/// no automata at all has no power.
+	pub fn new<'a>(rows: impl IntoIterator<Item=&'a Automaton<K>>) -> Self
+	{
+		let mut power = vec![0.0; K / 2 + 1];
+		let mut count = 0;
+		for automaton in rows
+		{
+			for (sum, row) in power.iter_mut().zip(automaton.power_spectrum())
+			{
+				*sum += row;
+			}
+			count += 1;
+		}
+		if count > 0
+		{
+			power.iter_mut().for_each(|power| *power /= count as f32);
+		}
+		Self { power }
+	}
+
+	/// Answer the mean power of the specified spatial frequency, in
+	/// `[0, K/2]`.
+	pub fn power(&self, frequency: usize) -> f32
+	{
+		self.power[frequency]
+	}
+
+	/// Answer the dominant spatial frequency, i.e., the nonzero frequency of
+	/// greatest power, preferring the lowest on a tie, or [None] if there is
+	/// no power at all.
+	pub fn dominant_frequency(&self) -> Option<usize>
+	{
+		(1 ..= K / 2)
+			.filter(|&frequency| self.power[frequency] > SPECTRUM_EPSILON)
+			.fold(None, |best: Option<usize>, frequency| match best
+			{
+				Some(best) if self.power[best] + SPECTRUM_EPSILON
+					>= self.power[frequency] => Some(best),
+				_ => Some(frequency)
+			})
+	}
+
+	/// Answer the characteristic wavelength, in cells, i.e., that of the
+	/// [dominant&#32;frequency](Self::dominant_frequency), or [None] if there
+	/// is no power at all.
+	pub fn wavelength(&self) -> Option<f32>
+	{
+		self.dominant_frequency().map(|frequency| K as f32 / frequency as f32)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Complexity.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Estimate the complexity of the specified generations, e.g., the newest
+/// [COMPLEXITY_WINDOW] generations of a [scrollback](Scrollback), by counting
+/// the phrases of the Lempel-Ziv (1976) parsing of their cells, concatenated
+/// in order. The count is normalized by that expected of a random sequence of
+/// the same length, so that the complexity is near `0` for uniform or
+/// periodic generations and near `1` for chaotic ones. No generations at all
+/// have no complexity.
+pub fn complexity<'a, const K: usize>(
+	generations: impl IntoIterator<Item=&'a Automaton<K>>
+) -> f32
+{
+	let cells = generations.into_iter()
+		.flat_map(|automaton| automaton.iter().copied())
+		.collect::<Vec<_>>();
+	match cells.len()
+	{
+		0 | 1 => 0.0,
+		len => lempel_ziv_phrases(&cells) as f32 * (len as f32).log2()
+			/ len as f32
+	}
+}
+
+/// Answer the number of phrases of the Lempel-Ziv (1976) parsing of the
+/// specified cells, i.e., the number of times that the parsing must extend
+/// its vocabulary because the rest of the cells cannot be copied from
+/// anything earlier. This is the algorithm of Kaspar and Schuster (1987).
+fn lempel_ziv_phrases(cells: &[bool]) -> usize
+{
+	let len = cells.len();
+	if len < 2
+	{
+		return len
+	}
+	// The phrase being built starts at `start`, and is compared, `length`
+	// cells at a time, against the earlier copy that starts at `source`.
+	let (mut source, mut start, mut length) = (0, 1, 1);
+	let (mut longest, mut phrases) = (1, 1);
+	loop
+	{
+		if cells[source + length - 1] == cells[start + length - 1]
+		{
+			length += 1;
+			if start + length > len
+			{
+				return phrases + 1
+			}
+		}
+		else
+		{
+			longest = longest.max(length);
+			source += 1;
+			if source == start
+			{
+				phrases += 1;
+				start += longest;
+				if start + 1 > len
+				{
+					return phrases
+				}
+				(source, length, longest) = (0, 1, 1);
+			}
+			else
+			{
+				length = 1;
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Tapes.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [Tape] represents a [1-dimensional&#32;cellular&#32;automaton](Automaton)
+/// of unbounded length, after the fashion of the pictures in Wolfram's _A New
+/// Kind of Science_: a finite stretch of interesting cells set against an
+/// infinite background, in which every cell shares the same state. The tape
+/// grows at both ends as activity approaches them, so its two ends are never
+/// adjacent, unlike those of an [Automaton]. Note that the background itself
+/// evolves: under a rule that births a cell from a vacant neighborhood, the
+/// background comes alive.
+///
+/// Cells are addressed by position relative to the seed from which the tape
+/// grew, so that positions remain stable as the tape grows. As with an
+/// [Automaton], the rightmost cell of the seed has position `0`, and positions
+/// increase leftward. Cells grown to the right of the seed have negative
+/// positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tape
+{
+	/// The cells of the tape, in right-to-left order. Never empty.
+	cells: Vec<bool>,
+
+	/// The number of cells grown to the right of the seed, i.e., the index of
+	/// the cell at position `0`.
+	origin: usize,
+
+	/// The state of every cell beyond either end of the tape.
+	background: bool
+}
+
+impl Tape
+{
+	/// Answer the number of cells on the tape, not counting the background.
+	pub fn len(&self) -> usize
+	{
+		self.cells.len()
+	}
+
+	/// Answer the positions of the cells on the tape, from right to left.
+	pub fn positions(&self) -> Range<isize>
+	{
+		let start = -(self.origin as isize);
+		start .. start + self.cells.len() as isize
+	}
+
+	/// Answer the state of every cell beyond either end of the tape.
+	pub fn background(&self) -> bool
+	{
+		self.background
+	}
+
+	/// Answer the state of the cell at the specified position. Positions
+	/// beyond either end of the tape answer the [background](Self::background).
+	pub fn cell(&self, position: isize) -> bool
+	{
+		let index = position.checked_add(self.origin as isize)
+			.and_then(|index| usize::try_from(index).ok());
+		match index.and_then(|index| self.cells.get(index))
+		{
+			Some(&cell) => cell,
+			None => self.background
+		}
+	}
+
+	/// Compute the successor [tape](Tape) in accordance with the specified
+	/// [rule](AutomatonRule), growing it as necessary. Only elementary rules
+	/// can evolve a tape, since scripts and modules expect automata of a
+	/// fixed length.
+	pub fn next(&self, rule: AutomatonRule) -> Self
+	{
+		let background = rule.next_cell(compute_ordinal(
+			self.background,
+			self.background,
+			self.background
+		));
+		// Every cell just beyond either end of the tape sits in a neighborhood
+		// of pure background, because the ends are kept clear of activity, so
+		// the tape need not grow before evolving.
+		let cells = self.positions()
+			.map(|position| {
+				let ordinal = compute_ordinal(
+					self.cell(position + 1),
+					self.cell(position),
+					self.cell(position - 1)
+				);
+				rule.next_cell(ordinal)
+			})
+			.collect();
+		let mut next = Self { cells, origin: self.origin, background };
+		next.grow();
+		next
+	}
+
+	/// Grow the tape by [TAPE_GROWTH] cells at each end where activity lies
+	/// within [TAPE_MARGIN] cells of it, i.e., where some cell differs from
+	/// the [background](Self::background).
+	fn grow(&mut self)
+	{
+		let len = self.cells.len();
+		let margin = TAPE_MARGIN.min(len);
+		let active = |cells: &[bool]| {
+			cells.iter().any(|&cell| cell != self.background)
+		};
+		let right = active(&self.cells[.. margin]);
+		let left = active(&self.cells[len - margin ..]);
+		if right
+		{
+			let mut cells = vec![self.background; TAPE_GROWTH];
+			cells.append(&mut self.cells);
+			self.cells = cells;
+			self.origin += TAPE_GROWTH;
+		}
+		if left
+		{
+			self.cells.resize(self.cells.len() + TAPE_GROWTH, self.background);
+		}
+	}
+}
+
+impl<const K: usize> From<Automaton<K>> for Tape
+{
+	/// Initialize a [tape](Tape) from the specified [automaton](Automaton),
+	/// which becomes its seed, set against a vacant background.
+	fn from(value: Automaton<K>) -> Self
+	{
+		let mut tape = Self {
+			cells: value.iter().copied().collect(),
+			origin: 0,
+			background: false
+		};
+		tape.grow();
+		tape
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Ants.                                    //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A turn that an [ant](Ant) makes upon visiting a cell, given by its letter
+/// in the conventional notation for generalized [Langton's&#32;ants].
+///
+/// [Langton's&#32;ants]: https://en.wikipedia.org/wiki/Langton%27s_ant
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Turn
+{
+	/// `L`: Turn 90° to the left.
+	Left,
+
+	/// `R`: Turn 90° to the right.
+	Right,
+
+	/// `N`: Carry straight on.
+	Straight,
+
+	/// `U`: Turn around.
+	Reverse
+}
+
+/// The [turns](Turn) that [ants](Ant) make, indexed by the state of the cell
+/// that they visit. The number of turns is the number of states that a cell
+/// may take, so there must be at least two. Each visit advances the state of
+/// the cell to the next, wrapping around after the last. Langton's original ant
+/// follows `RL`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Turns(Vec<Turn>);
+
+impl Turns
+{
+	/// Answer the number of states that a cell may take.
+	pub fn states(&self) -> usize
+	{
+		self.0.len()
+	}
+}
+
+impl Default for Turns
+{
+	/// Answer the turns of Langton's original ant, `RL`.
+	fn default() -> Self
+	{
+		Self(vec![Turn::Right, Turn::Left])
+	}
+}
+
+impl FromStr for Turns
+{
+	type Err = String;
+
+	/// Parse the turns from a string of letters, one per state, e.g., `LLRR`,
+	/// ignoring case.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let turns = s.chars()
+			.map(|letter| match letter.to_ascii_uppercase()
+			{
+				'L' => Ok(Turn::Left),
+				'R' => Ok(Turn::Right),
+				'N' => Ok(Turn::Straight),
+				'U' => Ok(Turn::Reverse),
+				_ => Err(format!(
+					"unknown turn '{}': expected L, R, N, or U",
+					letter
+				))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		match turns.len()
+		{
+			2 ..= MAX_TURNS => Ok(Self(turns)),
+			_ => Err(format!(
+				"unusable turns '{}': expected between 2 and {} turns",
+				s,
+				MAX_TURNS
+			))
+		}
+	}
+}
+
+impl Display for Turns
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		for turn in &self.0
+		{
+			let letter = match turn
+			{
+				Turn::Left => "L",
+				Turn::Right => "R",
+				Turn::Straight => "N",
+				Turn::Reverse => "U"
+			};
+			write!(f, "{}", letter)?;
+		}
+		Ok(())
+	}
+}
+
+/// The direction in which an [ant](Ant) faces. North is toward the top of the
+/// grid, i.e., toward lower rows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Heading
+{
+	/// Toward the top of the grid.
+	North,
+
+	/// Toward the right of the grid.
+	East,
+
+	/// Toward the bottom of the grid.
+	South,
+
+	/// Toward the left of the grid.
+	West
+}
+
+impl Heading
+{
+	/// Answer the heading that results from making the specified
+	/// [turn](Turn).
+	pub const fn turn(self, turn: Turn) -> Self
+	{
+		// Count the quarter turns clockwise.
+		let quarters = match turn
+		{
+			Turn::Straight => 0,
+			Turn::Right => 1,
+			Turn::Reverse => 2,
+			Turn::Left => 3
+		};
+		let headings =
+			[Heading::North, Heading::East, Heading::South, Heading::West];
+		headings[(self as usize + quarters) % headings.len()]
+	}
+}
+
+/// An ant that wanders a grid of cells, turning according to the state of each
+/// cell that it visits, and then advancing that state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ant
+{
+	/// The row of the cell beneath the ant, counting down from the top.
+	pub row: usize,
+
+	/// The column of the cell beneath the ant, counting from the left.
+	pub column: usize,
+
+	/// The direction in which the ant faces.
+	pub heading: Heading
+}
+
+/// A colony of [ants](Ant) that wander a grid of `N` rows of `K` cells each,
+/// all following the same [turns](Turns). Cells begin in state `0`. The grid
+/// wraps around at its edges, in both directions, so the ants never fall off.
+#[derive(Clone, Debug, Resource)]
+pub struct Colony<
+	const K: usize = AUTOMATON_LENGTH,
+	const N: usize = AUTOMATON_HISTORY
+>
+{
+	/// The turns that every ant follows.
+	turns: Turns,
+
+	/// The ants, which move in order.
+	ants: Vec<Ant>,
+
+	/// The states of the cells, row by row.
+	states: Vec<u8>
+}
+
+impl<const K: usize, const N: usize> Colony<K, N>
+{
+	/// Construct a new [Colony] of the specified number of [ants](Ant), at
+	/// least one, following the specified [turns](Turns). The ants start out
+	/// evenly spaced across the middle row, facing north.
+	pub fn new(turns: Turns, count: usize) -> Self
+	{
+		assert!(count > 0);
+		let ants = (1 ..= count)
+			.map(|i| Ant {
+				row: N / 2,
+				column: i * K / (count + 1),
+				heading: Heading::North
+			})
+			.collect();
+		Self { turns, ants, states: vec![0; K * N] }
+	}
+
+	/// Answer the [turns](Turns) that every ant follows.
+	pub fn turns(&self) -> &Turns
+	{
+		&self.turns
+	}
+
+	/// Answer the [ants](Ant).
+	pub fn ants(&self) -> &[Ant]
+	{
+		&self.ants
+	}
+
+	/// Answer the state of the cell at the specified row and column.
+	pub fn state(&self, row: usize, column: usize) -> u8
+	{
+		self.states[row * K + column]
+	}
+
+	/// Move every [ant](Ant) one step, in order: turn according to the state
+	/// of the cell beneath, advance the state of that cell, and then move
+	/// forward one cell.
+	pub fn step(&mut self)
+	{
+		let states = self.turns.states();
+		for ant in &mut self.ants
+		{
+			let state = &mut self.states[ant.row * K + ant.column];
+			ant.heading = ant.heading.turn(self.turns.0[*state as usize]);
+			*state = ((*state as usize + 1) % states) as u8;
+			match ant.heading
+			{
+				Heading::North => ant.row = (ant.row + N - 1) % N,
+				Heading::East => ant.column = (ant.column + 1) % K,
+				Heading::South => ant.row = (ant.row + 1) % N,
+				Heading::West => ant.column = (ant.column + K - 1) % K
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Particles.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The known gliders of Rule 110, which drift through the ether, the rule's
+/// periodic background, and whose collisions carry out the computations that
+/// make the rule [universal]. The names follow Matthew Cook. Gliders that share
+/// a displacement and a period, e.g., `C1`, `C2`, and `C3`, cannot be told
+/// apart by their motion, so each such family goes by the name of its first
+/// member.
+///
+/// [universal]: https://en.wikipedia.org/wiki/Rule_110
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Glider
+{
+	/// `A`: Moves 2 cells right every 3 generations.
+	A,
+
+	/// `B`: Moves 2 cells left every 4 generations.
+	B,
+
+	/// `C`: Stands still, repeating every 7 generations.
+	C,
+
+	/// `D`: Moves 2 cells right every 10 generations.
+	D,
+
+	/// `B̂`: Moves 6 cells left every 12 generations.
+	BHat,
+
+	/// `E`: Moves 8 cells left every 30 generations.
+	E,
+
+	/// `F`: Moves 4 cells left every 36 generations.
+	F,
+
+	/// `G`: Moves 14 cells left every 42 generations.
+	G
+}
+
+impl Glider
+{
+	/// Every glider, in order of increasing period. A glider that repeats
+	/// every `p` generations also repeats every multiple of `p` generations,
+	/// so recognizing gliders in this order favors the most specific one.
+	pub const ALL: [Glider; 8] = [
+		Glider::A,
+		Glider::B,
+		Glider::C,
+		Glider::D,
+		Glider::BHat,
+		Glider::E,
+		Glider::F,
+		Glider::G
+	];
+
+	/// Answer the number of cells that the glider moves to the right, as
+	/// displayed, during each of its [periods](Self::period). Gliders that
+	/// move to the left have negative displacements.
+	pub const fn displacement(self) -> isize
+	{
+		match self
+		{
+			Glider::A => 2,
+			Glider::B => -2,
+			Glider::C => 0,
+			Glider::D => 2,
+			Glider::BHat => -6,
+			Glider::E => -8,
+			Glider::F => -4,
+			Glider::G => -14
+		}
+	}
+
+	/// Answer the number of generations after which the glider resumes its
+	/// shape, [displaced](Self::displacement).
+	pub const fn period(self) -> usize
+	{
+		match self
+		{
+			Glider::A => 3,
+			Glider::B => 4,
+			Glider::C => 7,
+			Glider::D => 10,
+			Glider::BHat => 12,
+			Glider::E => 30,
+			Glider::F => 36,
+			Glider::G => 42
+		}
+	}
+}
+
+impl Display for Glider
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			Glider::A => "A",
+			Glider::B => "B",
+			Glider::C => "C",
+			Glider::D => "D",
+			Glider::BHat => "B̂",
+			Glider::E => "E",
+			Glider::F => "F",
+			Glider::G => "G"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+/// A particle that [History::particles] found: a connected region of cells,
+/// across one or more generations, that departs from the ether of Rule 110.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Particle
+{
+	/// The generations that the particle spans, by their indices within the
+	/// [history](History), oldest first.
+	pub rows: RangeInclusive<usize>,
+
+	/// The lowest index of the cells that the particle spans. A particle may
+	/// straddle the edge of its automaton, so the indices that it spans run
+	/// upward from here, wrapping around after the last cell.
+	pub start: usize,
+
+	/// The number of indices that the particle spans.
+	pub width: usize,
+
+	/// The [glider](Glider) that the particle moves like, if any. Particles
+	/// that span too few generations to reveal their motion, and particles in
+	/// the midst of collisions, resemble no glider.
+	pub glider: Option<Glider>
+}
+
+impl<const K: usize, const N: usize> History<K, N>
+{
+	/// Find the particles of Rule 110 in the [history](History), i.e., the
+	/// connected regions of cells that depart from the ether. Neighboring
+	/// cells, including diagonal ones, belong to the same particle, and the
+	/// automaton wraps around at its edges. Recognize each particle as the
+	/// first [glider](Glider) whose displacement and period carry every cell
+	/// of the particle onto a cell of the same state. Only histories that
+	/// evolved under Rule 110 contain meaningful particles.
+	pub fn particles(&self) -> Vec<Particle>
+	{
+		let ether = self.ether();
+		let mut seen = [[false; K]; N];
+		let mut particles = Vec::new();
+		for row in 0 .. N
+		{
+			for index in 0 .. K
+			{
+				if ether[row][index] || seen[row][index]
+				{
+					continue
+				}
+				seen[row][index] = true;
+				let mut pending = vec![(row, index)];
+				let mut cells = Vec::new();
+				while let Some((row, index)) = pending.pop()
+				{
+					cells.push((row, index));
+					let above = row.saturating_sub(1);
+					let below = (row + 1).min(N - 1);
+					let left = (index + 1) % K;
+					let right = (index + K - 1) % K;
+					for neighbor in above ..= below
+					{
+						for other in [right, index, left]
+						{
+							if !ether[neighbor][other] && !seen[neighbor][other]
+							{
+								seen[neighbor][other] = true;
+								pending.push((neighbor, other));
+							}
+						}
+					}
+				}
+				particles.push(self.particle(&cells));
+			}
+		}
+		particles
+	}
+
+	/// Answer which cells belong to the ether, i.e., which cells lie within
+	/// some run of cells that matches a rotation of the [tile](ETHER). Runs
+	/// wrap around at the edges of the automaton. Automata shorter than a
+	/// tile contain no ether.
+	fn ether(&self) -> [[bool; K]; N]
+	{
+		self.covered(&ETHER, ETHER.len())
+	}
+
+	/// Answer which cells lie within some run of `run` cells that matches the
+	/// specified tile, repeated as often as necessary, from some rotation
+	/// onward. Runs wrap around at the edges of the automaton. Automata shorter
+	/// than a run contain no matches.
+	fn covered(&self, tile: &[bool], run: usize) -> [[bool; K]; N]
+	{
+		let mut covered = [[false; K]; N];
+		if K < run
+		{
+			return covered
+		}
+		for (row, automaton) in self.iter().enumerate()
+		{
+			for start in 0 .. K
+			{
+				let matches = (0 .. tile.len()).any(|phase| {
+					(0 .. run).all(|offset| {
+						automaton[(start + offset) % K]
+							== tile[(phase + offset) % tile.len()]
+					})
+				});
+				if matches
+				{
+					for offset in 0 .. run
+					{
+						covered[row][(start + offset) % K] = true;
+					}
+				}
+			}
+		}
+		covered
+	}
+
+	/// Answer the [particle](Particle) that comprises the specified cells,
+	/// given as pairs of row and index. There must be at least one cell.
+	fn particle(&self, cells: &[(usize, usize)]) -> Particle
+	{
+		let oldest = cells.iter().map(|&(row, _)| row).min().unwrap();
+		let newest = cells.iter().map(|&(row, _)| row).max().unwrap();
+		// The particle spans every index but those of the longest run of
+		// indices that it never occupies, which may wrap around.
+		let mut occupied = [false; K];
+		for &(_, index) in cells
+		{
+			occupied[index] = true;
+		}
+		let (mut gap, mut end, mut run) = (0, 0, 0);
+		for i in 0 .. 2 * K
+		{
+			match occupied[i % K]
+			{
+				true => run = 0,
+				false =>
+				{
+					run += 1;
+					if run > gap
+					{
+						(gap, end) = (run, i % K);
+					}
+				}
+			}
+		}
+		let start = if gap == 0 { 0 } else { (end + 1) % K };
+		let glider = Glider::ALL.into_iter().find(|glider| {
+			let period = glider.period();
+			newest - oldest >= period && cells.iter()
+				.filter(|&&(row, _)| row + period < N)
+				.all(|&(row, index)| {
+					// Rightward motion, as displayed, lowers the index.
+					let shifted = (index as isize - glider.displacement())
+						.rem_euclid(K as isize) as usize;
+					self[row + period][shifted] == self[row][index]
+				})
+		});
+		Particle {
+			rows: oldest ..= newest,
+			start,
+			width: K - gap.min(K),
+			glider
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Backgrounds.                                //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The periodic background that [History::background] detected: a tile of
+/// cells, some rotation of which repeats across the background of every
+/// generation, like the [ether](ETHER) of Rule 110. Gliders and their
+/// collisions are whatever departs from the background.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Background
+{
+	/// The tile, in index order, i.e., from right to left as displayed.
+	tile: Vec<bool>
+}
+
+impl Background
+{
+	/// Answer the spatial period of the background, i.e., the length of its
+	/// tile, in cells.
+	pub fn period(&self) -> usize
+	{
+		self.tile.len()
+	}
+
+	/// Answer the temporal period of the background under the specified
+	/// [rule](AutomatonRule), i.e., the number of generations after which the
+	/// background recurs in place, or [None] if it doesn't recur within
+	/// [MAX_BACKGROUND_RECURRENCE] generations. A background may drift in the
+	/// meantime, e.g., every generation of the ether is a rotation of the
+	/// last. A periodic background evolves exactly like a ring of cells that
+	/// holds one tile.
+	pub fn recurrence(&self, rule: AutomatonRule) -> Option<usize>
+	{
+		let len = self.tile.len();
+		let mut ring = self.tile.clone();
+		for generation in 1 ..= MAX_BACKGROUND_RECURRENCE
+		{
+			ring = (0 .. len)
+				.map(|index| rule.next_cell(compute_ordinal(
+					ring[(index + 1) % len],
+					ring[index],
+					ring[(index + len - 1) % len]
+				)))
+				.collect();
+			if ring == self.tile
+			{
+				return Some(generation)
+			}
+		}
+		None
+	}
+}
+
+impl<const K: usize, const N: usize> History<K, N>
+{
+	/// Detect the periodic [background](Background) of the history, i.e., the
+	/// tile of at most [MAX_BACKGROUND_PERIOD] cells that, repeated, accounts
+	/// for the most runs of cells across every generation, preferring the
+	/// shortest tile on a tie. Each run must repeat the tile at least twice,
+	/// and must span at least [MIN_BACKGROUND_RUN] cells. Answer [None] if the
+	/// background so found covers less than [MIN_BACKGROUND_COVERAGE] of the
+	/// cells, e.g., because the history is chaotic.
+	pub fn background(&self) -> Option<Background>
+	{
+		let mut counts = HashMap::<(usize, u32), usize>::new();
+		for automaton in self.iter()
+		{
+			for period in 1 ..= MAX_BACKGROUND_PERIOD.min(K / 2)
+			{
+				let run = (2 * period).max(MIN_BACKGROUND_RUN).min(K);
+				for start in 0 .. K
+				{
+					let periodic = (0 .. run - period).all(|offset| {
+						automaton[(start + offset) % K]
+							== automaton[(start + offset + period) % K]
+					});
+					if !periodic
+					{
+						continue
+					}
+					let tile = (0 .. period)
+						.map(|offset| automaton[(start + offset) % K]);
+					if let Some(canonical) = canonical_tile(tile, period)
+					{
+						*counts.entry((period, canonical)).or_default() += 1;
+					}
+				}
+			}
+		}
+		let (period, canonical) = counts.into_iter()
+			.max_by(|((p, a), x), ((q, b), y)| {
+				x.cmp(y).then(q.cmp(p)).then(b.cmp(a))
+			})
+			.map(|(key, _)| key)?;
+		let background = Background {
+			tile: (0 .. period)
+				.map(|offset| canonical & (1 << offset) != 0)
+				.collect()
+		};
+		let covered = self.background_cells(&background)
+			.iter()
+			.flatten()
+			.filter(|&&cell| cell)
+			.count();
+		let coverage = covered as f32 / (K * N) as f32;
+		(coverage >= MIN_BACKGROUND_COVERAGE).then_some(background)
+	}
+
+	/// Answer which cells belong to the specified [background](Background),
+	/// i.e., which cells lie within some run of at least [MIN_BACKGROUND_RUN]
+	/// cells, and of at least one tile, that matches a rotation of the tile.
+	pub fn background_cells(&self, background: &Background) -> [[bool; K]; N]
+	{
+		let run = background.period().max(MIN_BACKGROUND_RUN).min(K);
+		self.covered(&background.tile, run)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Light cones.                                //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The parts of the light cone of a cell, its apex. Each cell sees only its
+/// immediate neighbors, so influence spreads by at most one cell per
+/// generation in either direction. The past cone comprises the cells of
+/// earlier generations that could have influenced the apex, and the future
+/// cone comprises the cells of later generations that the apex could
+/// influence in turn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cone
+{
+	/// The apex itself.
+	Apex,
+
+	/// A cell that could have influenced the apex.
+	Past,
+
+	/// A cell that the apex could influence.
+	Future
+}
+
+impl Cone
+{
+	/// Answer the part of the light cone of the cell at `apex` that contains
+	/// the cell at `cell`, or [None] if the cell lies outside the cone. Each
+	/// cell is given as a pair of row and index, where the rows count
+	/// generations, and the indices run across an [automaton](Automaton) of `K`
+	/// cells. The automaton wraps around at its edges, and so does influence.
+	pub fn locate<const K: usize>(
+		apex: (usize, usize),
+		cell: (usize, usize)
+	) -> Option<Cone>
+	{
+		let (apex_row, apex_index) = apex;
+		let (row, index) = cell;
+		let distance = apex_index.abs_diff(index);
+		let distance = distance.min(K - distance);
+		let within = distance <= apex_row.abs_diff(row);
+		match row.cmp(&apex_row)
+		{
+			Ordering::Equal if distance == 0 => Some(Cone::Apex),
+			Ordering::Less if within => Some(Cone::Past),
+			Ordering::Greater if within => Some(Cone::Future),
+			_ => None
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Interestingness.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How interesting the evolution of an [elementary&#32;rule](AutomatonRule)
+/// looks, by three measures taken over a stretch of its evolution from some
+/// first generation, each normalized to `[0,1]`. Dull rules die out, freeze,
+/// or cycle almost at once; interesting rules stay busy for a long time, yet
+/// still show some structure, like the particles of Rule 110.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Interestingness
+{
+	/// The mean [entropy](Automaton::entropy) of the neighborhoods of the
+	/// cells of each generation. Uniform generations score 0.
+	pub entropy: f32,
+
+	/// How much better the spacetime diagram compresses than a scramble of
+	/// the same cells, judged by the number of LZ78 phrases. Chaos and
+	/// uniformity alike score near 0, since scrambling changes neither.
+	pub compressibility: f32,
+
+	/// The number of generations before the first that repeats an earlier
+	/// generation, relative to the number of generations measured. An
+	/// evolution that never repeats scores 1.
+	pub transient: f32
+}
+
+impl Interestingness
+{
+	/// Measure the evolution of the specified first generation under the
+	/// specified [rule](AutomatonRule), through the specified number of
+	/// generations beyond the first.
+	pub fn measure<const K: usize>(
+		seed: Automaton<K>,
+		rule: AutomatonRule,
+		generations: usize
+	) -> Self
+	{
+		assert!(generations > 0);
+		let mut automaton = seed;
+		let mut seen = HashMap::from([(automaton, 0)]);
+		let mut transient = None;
+		let mut entropy = automaton.entropy();
+		let mut cells = Vec::with_capacity(K * (generations + 1));
+		cells.extend(automaton.iter());
+		for index in 1 ..= generations
+		{
+			automaton = automaton.next(rule);
+			entropy += automaton.entropy();
+			cells.extend(automaton.iter());
+			if transient.is_none()
+			{
+				transient = seen.insert(automaton, index);
+			}
+		}
+		let scrambled = scramble(&cells);
+		let compressibility =
+			1.0 - lz78_phrases(&cells) as f32 / lz78_phrases(&scrambled) as f32;
+		Self {
+			entropy: entropy / (generations + 1) as f32,
+			compressibility: compressibility.clamp(0.0, 1.0),
+			transient: transient
+				.map(|transient| transient as f32 / generations as f32)
+				.unwrap_or(1.0)
+		}
+	}
+
+	/// [Measure](Self::measure) the evolution of each of the specified first
+	/// generations under the specified [rule](AutomatonRule), and answer the
+	/// mean of the measures.
+	pub fn mean<const K: usize>(
+		seeds: &[Automaton<K>],
+		rule: AutomatonRule,
+		generations: usize
+	) -> Self
+	{
+		assert!(!seeds.is_empty());
+		let count = seeds.len() as f32;
+		seeds.iter()
+			.map(|&seed| Self::measure(seed, rule, generations))
+			.fold(Self::default(), |mean, measure| Self {
+				entropy: mean.entropy + measure.entropy / count,
+				compressibility:
+					mean.compressibility + measure.compressibility / count,
+				transient: mean.transient + measure.transient / count
+			})
+	}
+
+	/// Answer the overall score, in `[0,1]`: the mean of the entropy, the
+	/// transient, and a parabola of the compressibility that peaks halfway
+	/// between chaos and order.
+	pub fn score(&self) -> f32
+	{
+		let structure =
+			4.0 * self.compressibility * (1.0 - self.compressibility);
+		(self.entropy + structure + self.transient) / 3.0
+	}
+}
+
+impl Display for Interestingness
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(
+			f,
+			"{:.2} (entropy {:.2}, compressibility {:.2}, transient {:.2})",
+			self.score(),
+			self.entropy,
+			self.compressibility,
+			self.transient
+		)
+	}
+}
+
+/// Examine the elementary [rules](AutomatonRule) in random order, each
+/// [measured](Interestingness::mean) from the specified first generations, and
+/// answer the first whose score meets the specified threshold. If none does,
+/// then answer the highest scoring rule instead.
+pub fn surprising_rule<const K: usize>(
+	seeds: &[Automaton<K>],
+	generations: usize,
+	threshold: f32
+) -> (AutomatonRule, Interestingness)
+{
+	let mut codes = (0 ..= u8::MAX).collect::<Vec<_>>();
+	codes.shuffle(&mut thread_rng());
+	let mut best = (AutomatonRule::default(), Interestingness::default());
+	for code in codes
+	{
+		let rule = AutomatonRule::from(code);
+		let measure = Interestingness::mean(seeds, rule, generations);
+		if measure.score() >= threshold
+		{
+			return (rule, measure)
+		}
+		if measure.score() > best.1.score()
+		{
+			best = (rule, measure);
+		}
+	}
+	best
+}
+
+/// Answer the number of phrases into which LZ78 parses the specified bits.
+/// The fewer the phrases, the more compressible the bits.
+fn lz78_phrases(bits: &[bool]) -> usize
+{
+	// A binary trie of the phrases so far; 0 marks a missing child, since the
+	// root is never anyone's child.
+	let mut trie = vec![[0usize; 2]];
+	let mut node = 0;
+	let mut phrases = 0;
+	for &bit in bits
+	{
+		match trie[node][bit as usize]
+		{
+			0 =>
+			{
+				trie.push([0; 2]);
+				trie[node][bit as usize] = trie.len() - 1;
+				phrases += 1;
+				node = 0;
+			},
+			child => node = child
+		}
+	}
+	// Count the unfinished last phrase, if any.
+	phrases + (node != 0) as usize
+}
+
+/// Answer a scramble of the specified bits, with the same density but none of
+/// the structure, by striding through them with a step coprime to their
+/// number.
+fn scramble(bits: &[bool]) -> Vec<bool>
+{
+	let len = bits.len();
+	let mut stride = SCRAMBLE_STRIDE;
+	while gcd(stride, len) != 1
+	{
+		stride += 1;
+	}
+	(0 .. len).map(|index| bits[index * stride % len]).collect()
+}
+
+/// Answer the greatest common divisor of the specified numbers.
+fn gcd(a: usize, b: usize) -> usize
+{
+	match b
+	{
+		0 => a,
+		_ => gcd(b, a % b)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Transients.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How the evolution of a first generation settles down. Every finite
+/// [automaton](Automaton) must eventually repeat a generation, after which it
+/// cycles forever. The transient precedes the cycle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Orbit
+{
+	/// The number of generations before the first generation of the cycle.
+	pub transient: usize,
+
+	/// The number of generations in the cycle. A fixed point has period 1.
+	pub period: usize
+}
+
+impl Display for Orbit
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(
+			f,
+			"transient {}, period {}",
+			self.transient,
+			self.period
+		)
+	}
+}
+
+impl<const K: usize> Automaton<K>
+{
+	/// Answer the [orbit](Orbit) of the receiver under the specified
+	/// [rule](AutomatonRule), using Brent's algorithm, which needs no memory
+	/// of earlier generations. Give up, answering [None], if the cycle hasn't
+	/// closed after `limit` evolutions.
+	pub fn orbit(&self, rule: AutomatonRule, limit: usize) -> Option<Orbit>
+	{
+		// Find the period, by racing a hare ahead of a tortoise that teleports
+		// to the hare at every power of two.
+		let mut power = 1;
+		let mut period = 1;
+		let mut tortoise = *self;
+		let mut hare = self.next(rule);
+		let mut steps = 1;
+		while tortoise != hare
+		{
+			if steps >= limit
+			{
+				return None
+			}
+			if power == period
+			{
+				tortoise = hare;
+				power *= 2;
+				period = 0;
+			}
+			hare = hare.next(rule);
+			period += 1;
+			steps += 1;
+		}
+		// Find the transient, by starting the hare one period ahead of the
+		// tortoise, then advancing both until they meet at the cycle.
+		let mut tortoise = *self;
+		let mut hare = *self;
+		for _ in 0 .. period
+		{
+			hare = hare.next(rule);
+		}
+		let mut transient = 0;
+		while tortoise != hare
+		{
+			tortoise = tortoise.next(rule);
+			hare = hare.next(rule);
+			transient += 1;
+		}
+		Some(Orbit { transient, period })
+	}
+}
+
+/// Among the specified first generations, answer the one with the longest
+/// transient under the specified [rule](AutomatonRule), along with its
+/// [orbit](Orbit). First generations whose cycles don't close within `limit`
+/// evolutions are passed over. Answer [None] if every first generation is
+/// passed over.
+pub fn longest_transient<const K: usize>(
+	rule: AutomatonRule,
+	seeds: impl IntoIterator<Item=Automaton<K>>,
+	limit: usize
+) -> Option<(Automaton<K>, Orbit)>
+{
+	seeds.into_iter()
+		.filter_map(|seed| seed.orbit(rule, limit).map(|orbit| (seed, orbit)))
+		.max_by_key(|(_, orbit)| orbit.transient)
+}
+
+/// Answer the first generation with the longest transient under the specified
+/// [rule](AutomatonRule), along with its [orbit](Orbit), by examining every
+/// first generation of `K` cells. Rather than evolving each first generation
+/// anew, remember the transient of every generation ever reached, so that
+/// every evolution stops as soon as it joins a known one. `K` must not exceed
+/// [MAX_EXHAUSTIVE_LENGTH].
+pub fn longest_transient_exhaustive<const K: usize>(
+	rule: AutomatonRule
+) -> (Automaton<K>, Orbit)
+{
+	assert!(K <= MAX_EXHAUSTIVE_LENGTH);
+	let mut transients = vec![UNKNOWN_TRANSIENT; 1 << K];
+	let mut path = Vec::new();
+	for start in 0 .. 1u64 << K
+	{
+		// Follow the evolution until it reaches a generation whose transient
+		// is known, or revisits a generation along the current path, which
+		// closes a new cycle.
+		let mut state = start;
+		while transients[state as usize] == UNKNOWN_TRANSIENT
+		{
+			transients[state as usize] = PENDING_TRANSIENT;
+			path.push(state);
+			state = u64::from(Automaton::<K>::from(state).next(rule));
+		}
+		let mut next = match transients[state as usize]
+		{
+			PENDING_TRANSIENT =>
+			{
+				// Every generation of the new cycle has no transient.
+				let cycle = path.iter().position(|&s| s == state).unwrap();
+				for &member in &path[cycle ..]
+				{
+					transients[member as usize] = 0;
+				}
+				path.truncate(cycle);
+				0
+			},
+			known => known
+		};
+		// Every generation that led here precedes the cycle by one more.
+		while let Some(state) = path.pop()
+		{
+			next += 1;
+			transients[state as usize] = next;
+		}
+	}
+	let (champion, _) = transients.iter()
+		.enumerate()
+		.max_by_key(|&(_, &transient)| transient)
+		.unwrap();
+	let champion = Automaton::from(champion as u64);
+	let orbit = champion.orbit(rule, usize::MAX).unwrap();
+	(champion, orbit)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                               Serialization.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "serde")]
+impl<const K: usize> Serialize for Automaton<K>
+{
+	/// Serialize the [automaton](Automaton) as a sequence of `K` booleans, in
+	/// right-to-left order, just like the [conversion](Automaton::from) from
+	/// an array.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		serializer.collect_seq(self.iter())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const K: usize> Deserialize<'de> for Automaton<K>
+{
+	/// Deserialize an [automaton](Automaton) from a sequence of exactly `K`
+	/// booleans, in right-to-left order.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let cells = Vec::<bool>::deserialize(deserializer)?;
+		let len = cells.len();
+		<[bool; K]>::try_from(cells)
+			.map(Automaton)
+			.map_err(|_| D::Error::invalid_length(
+				len,
+				&format!("{} cells", K).as_str()
+			))
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<const K: usize, const N: usize> Serialize for History<K, N>
+{
+	/// Serialize the [history](History) as a sequence of `N`
+	/// [automata](Automaton), from [oldest](History::oldest) to
+	/// [newest](History::newest).
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		serializer.collect_seq(self.iter())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const K: usize, const N: usize> Deserialize<'de> for History<K, N>
+{
+	/// Deserialize a [history](History) from a sequence of at most `N`
+	/// [automata](Automaton), from oldest to newest. A shorter sequence is
+	/// preceded by vacant generations, just as a [new](History::new) history
+	/// is.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let generations = Vec::<Automaton<K>>::deserialize(deserializer)?;
+		if generations.len() > N
+		{
+			return Err(D::Error::invalid_length(
+				generations.len(),
+				&format!("at most {} generations", N).as_str()
+			))
+		}
+		let mut history = History::new();
+		for automaton in generations
+		{
+			history.0.push(automaton);
+		}
+		Ok(history)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Split a `0b` or `0x` prefix, in either case, from the specified string of
+/// digits. Answer the remaining digits and the radix that the prefix
+/// denotes, either `2` or `16`, or [None] if there is no such prefix.
+fn strip_radix(s: &str) -> Option<(&str, u32)>
+{
+	match s.get(.. 2)
+	{
+		Some("0b" | "0B") => Some((&s[2 ..], 2)),
+		Some("0x" | "0X") => Some((&s[2 ..], 16)),
+		_ => None
+	}
+}
+
+/// Answer the canonical form of the specified tile of `period` cells, i.e.,
+/// the least of its rotations, read as a binary number whose least significant
+/// bit is the first cell, or [None] if the tile repeats within fewer cells,
+/// and thus belongs to a shorter period.
+fn canonical_tile(tile: impl Iterator<Item=bool>, period: usize) -> Option<u32>
+{
+	let mask = (1u32 << period) - 1;
+	let bits = tile.enumerate()
+		.fold(0u32, |bits, (offset, cell)| bits | (cell as u32) << offset);
+	let rotations = (1 .. period)
+		.map(|shift| (bits >> shift | bits << (period - shift)) & mask)
+		.collect::<Vec<_>>();
+	match rotations.contains(&bits)
+	{
+		true => None,
+		false => Some(rotations.into_iter().fold(bits, u32::min))
+	}
+}
+
+/// Compute the population ordinal for some unspecified [rule](AutomatonRule)
+/// based on the occupancy of the left, middle, and right cells of some
+/// unspecified [automaton](Automaton). The result will be value in `[0,7]`.
+#[inline]
+const fn compute_ordinal(left: bool, middle: bool, right: bool) -> u8
+{
+	let left = if left { 4u8 } else { 0 };
+	let middle = if middle { 2u8 } else { 0 };
+	let right = if right { 1u8 } else { 0 };
+	let ordinal = left | middle | right;
+	// Note that we cannot test range containment directly here because
+	// `contains` is not a `const fn`.
+	assert!(ordinal <= 7);
+	ordinal
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// One tile of the ether, the periodic background of Rule 110, in index order,
+/// i.e., from right to left as displayed. Every generation of the ether
+/// repeats some rotation of this tile.
+const ETHER: [bool; 14] = [
+	false, true, true, false, false, true, false,
+	false, false, true, true, true, true, true
+];
+
+/// The length of all [cellular&#32;automata](Automaton) in this application.
+pub const AUTOMATON_LENGTH: usize = 64;
+
+/// The number of generations to preserve during the evolution of a
+/// [cellular&#32;automaton](Automaton). This serves as the size of the
+/// [RingBuffer] that supports each [History].
+pub const AUTOMATON_HISTORY: usize = 50;
+
+/// The number of generations beyond the first through which to
+/// [measure](Interestingness::measure) the evolution of a rule when searching
+/// for interesting rules.
+pub const INTERESTINGNESS_GENERATIONS: usize = 256;
+
+/// The number of random first generations from which to
+/// [measure](Interestingness::mean) each rule when searching for interesting
+/// rules.
+pub const INTERESTINGNESS_TRIALS: usize = 4;
+
+/// The least [score](Interestingness::score) of an interesting rule. Rule 110,
+/// the chaotic rules, and a few dozen others clear it.
+pub const INTERESTINGNESS_THRESHOLD: f32 = 0.65;
+
+/// The greatest length of [automata](Automaton) whose first generations may be
+/// [searched&#32;exhaustively](longest_transient_exhaustive) for the longest
+/// transient. The search remembers a transient for every generation, which
+/// takes 4 MiB at this length, and 16 times as much for every 2 cells more.
+pub const MAX_EXHAUSTIVE_LENGTH: usize = 20;
+
+/// The number of evolutions after which the search for the
+/// [longest&#32;transient](longest_transient) gives up on an orbit.
+pub const TRANSIENT_LIMIT: usize = 100_000;
+
+/// The number of random first generations to examine when searching for the
+/// [longest&#32;transient](longest_transient) of an automaton too long to
+/// search exhaustively.
+pub const TRANSIENT_SAMPLES: usize = 1_000;
+
+/// The mark of a generation whose transient is not yet known, during an
+/// [exhaustive&#32;search](longest_transient_exhaustive).
+const UNKNOWN_TRANSIENT: u32 = u32::MAX;
+
+/// The mark of a generation along the evolution currently being followed,
+/// during an [exhaustive&#32;search](longest_transient_exhaustive).
+const PENDING_TRANSIENT: u32 = u32::MAX - 1;
+
+/// The least stride with which to [scramble] the cells of a spacetime diagram.
+/// A large prime spreads neighbors far apart.
+const SCRAMBLE_STRIDE: usize = 7_919;
+
+/// The length at and beyond which [automata](Automaton) are
+/// [evolved&#32;in&#32;parallel](Automaton::par_next). Shorter automata evolve
+/// faster than the tasks can be scheduled.
+pub const PARALLEL_EVOLUTION_THRESHOLD: usize = 16_384;
+
+/// The number of cells in each chunk of a
+/// [parallel&#32;evolution](Automaton::par_next).
+pub const PARALLEL_CHUNK_LENGTH: usize = 4_096;
+
+/// The number of equivalence classes into which mirroring and complementation
+/// partition the 256 elementary [rules](AutomatonRule).
+pub const EQUIVALENCE_CLASSES: usize = 88;
+
+/// Friendly names for well-known [rules](AutomatonRule), each with its
+/// Wolfram code and a brief description.
+pub const RULE_ALIASES: &[(&str, u8, &str)] = &[
+	("chaos", 30, "Chaotic, and random enough to generate random numbers"),
+	("complement", 51, "Every cell flips its state"),
+	("identity", 204, "Every cell keeps its state"),
+	("majority", 232, "Every cell joins the majority of its neighborhood"),
+	("parity", 150, "Every cell becomes the parity of its neighborhood"),
+	("shift-left", 170, "Every cell copies its right neighbor"),
+	("shift-right", 240, "Every cell copies its left neighbor"),
+	("sierpinski", 90, "Nests Sierpiński triangles from a single live cell"),
+	("traffic", 184, "Cars advance into empty space, like traffic"),
+	("turing-complete", 110, "Supports universal computation")
+];
+
+/// The greatest number of [turns](Turns) that [ants](Ant) may follow, which is
+/// also the greatest number of states that a cell may take.
+pub const MAX_TURNS: usize = 256;
+
+/// How close activity may approach either end of a [tape](Tape), in cells,
+/// before the tape grows at that end. Activity spreads at most one cell per
+/// generation, so the cells just beyond either end are always background.
+pub const TAPE_MARGIN: usize = 2;
+
+/// The number of cells by which a [tape](Tape) grows at an end. Growing in
+/// chunks, rather than cell by cell, spares the renderer from rebuilding its
+/// picture at every generation.
+pub const TAPE_GROWTH: usize = 16;
+
+/// The power beneath which a [spatial&#32;spectrum](SpatialSpectrum) treats a
+/// frequency as absent, absorbing the rounding errors of the transform.
+const SPECTRUM_EPSILON: f32 = 1e-4;
+
+/// The longest tile of a periodic [background](Background), in cells.
+pub const MAX_BACKGROUND_PERIOD: usize = 16;
+
+/// The shortest run of cells that may belong to a [background](Background).
+/// Shorter runs of a short tile, e.g., a few dead cells in a row, arise by
+/// chance even in chaos.
+pub const MIN_BACKGROUND_RUN: usize = 8;
+
+/// The least fraction of the cells of a [history](History) that a
+/// [background](Background) must cover to be detected at all.
+pub const MIN_BACKGROUND_COVERAGE: f32 = 0.5;
+
+/// The most generations that a [background](Background) is evolved in search
+/// of its [recurrence](Background::recurrence).
+pub const MAX_BACKGROUND_RECURRENCE: usize = 256;
+
+/// The number of newest generations whose [complexity] is estimated, both for
+/// the statistics panel and for batch reports.
+pub const COMPLEXITY_WINDOW: usize = 64;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Tests.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test
+{
+	use std::time::Instant;
+
+	use crate::automata::{
+		Ant, Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule,
+		Colony, complexity, COMPLEXITY_WINDOW,
+		canonical_tile,
+		Cone,
+		ColumnDensity,
+		Decay,
+		EQUIVALENCE_CLASSES, ETHER,
+		Glider,
+		Heading,
+		History,
+		Interestingness, INTERESTINGNESS_THRESHOLD,
+		lempel_ziv_phrases,
+		longest_transient, longest_transient_exhaustive,
+		Orbit,
+		PARALLEL_CHUNK_LENGTH, PARALLEL_EVOLUTION_THRESHOLD, Particle,
+		RuleKind, RuleMap, RuleSchedule, RuleTimeline,
+		Scrollback, SpatialSpectrum, SteadyState, surprising_rule,
+		Tape, TAPE_GROWTH, Turn, Turns
+	};
+
+	/// Use a well-known [cellular&32;automaton][Automaton] to verify correct
+	/// construction of the second generation under
+	/// [Rule&#32;#30](AutomatonRule).
+	//noinspection SpellCheckingInspection
+	#[test]
+	fn rule_30()
+	{
+		//     XX•X••••X••X•••X•••••X••••••XX
+		// 0b00110100001001000100000100000011
 		// 0x   3   4   2   4   4   1   0   3
 		let automaton = Automaton::<30>::from(0x34244103);
 		//     •••XX••XXXXXX•XXX•••XXX••••XX•
@@ -406,4 +3926,1090 @@ mod test
 		let actual = automaton.next(110.into());
 		assert_eq!(expected, actual);
 	}
+
+	/// Ensure that [automata](Automaton) parse from every family of glyphs,
+	/// that rendered automata round-trip, and that malformed patterns are
+	/// rejected.
+	//noinspection SpellCheckingInspection
+	#[test]
+	fn parse_automaton()
+	{
+		let automaton = Automaton::<30>::from(0x34244103);
+		assert_eq!(Ok(automaton), "XX•X••••X••X•••X•••••X••••••XX".parse());
+		assert_eq!(Ok(automaton), automaton.to_string().parse());
+		let expected = Automaton::<8>::from(0b1001_0110);
+		assert_eq!(Ok(expected), "1001 0110".parse());
+		assert_eq!(Ok(expected), "#..#.##.".parse());
+		assert_eq!(Ok(expected), "X••X•XX•".parse());
+		assert_eq!(Ok(Automaton::<8>::from(0b11)), "XX".parse());
+		assert!("X?X".parse::<Automaton<8>>().is_err());
+		assert!("".parse::<Automaton<8>>().is_err());
+		assert!("XXXXXXXXX".parse::<Automaton<8>>().is_err());
+		assert!("Automaton[7]: XXXXXXX".parse::<Automaton<8>>().is_err());
+	}
+
+	/// Ensure that the 256 [rules](AutomatonRule) fall into 88 equivalence
+	/// classes, that every rule shares its
+	/// [representative](AutomatonRule::representative) with its equivalents,
+	/// and that random representatives are always representatives.
+	#[test]
+	fn equivalence_classes()
+	{
+		let representatives = AutomatonRule::representatives();
+		assert_eq!(EQUIVALENCE_CLASSES, representatives.len());
+		for code in 0 ..= u8::MAX
+		{
+			let rule = AutomatonRule::from(code);
+			let representative = rule.representative();
+			assert!(representatives.contains(&representative));
+			assert!(representative <= rule);
+			assert_eq!(representative, rule.mirror().representative());
+			assert_eq!(representative, rule.complement().representative());
+		}
+		for code in [110, 124, 137, 193]
+		{
+			assert_eq!(
+				AutomatonRule::from(110),
+				AutomatonRule::from(code).representative()
+			);
+		}
+		assert_eq!(
+			[124, 137, 193].map(AutomatonRule::from),
+			AutomatonRule::from(110).equivalents()
+		);
+		assert_eq!(
+			[90, 165, 165].map(AutomatonRule::from),
+			AutomatonRule::from(90).equivalents()
+		);
+		let rule = AutomatonRule::random_representative();
+		assert_eq!(rule, rule.representative());
+	}
+
+	/// Ensure that mirroring reverses [automata](Automaton), and that
+	/// symmetrizing keeps the left half and mirrors it onto the right half,
+	/// for both even and odd widths.
+	#[test]
+	fn symmetrize()
+	{
+		let automaton = Automaton::<8>::from(0b1101_0011);
+		assert_eq!(Automaton::from(0b1100_1011), automaton.mirror());
+		assert_eq!(automaton, automaton.mirror().mirror());
+		let symmetric = automaton.symmetrize();
+		assert_eq!(Automaton::from(0b1101_1011), symmetric);
+		assert_eq!(symmetric, symmetric.mirror());
+		assert_eq!(symmetric, symmetric.symmetrize());
+		let automaton = Automaton::<5>::from(0b10110);
+		assert_eq!(Automaton::from(0b10101), automaton.symmetrize());
+	}
+
+	/// Ensure that [automata](Automaton) wider than 64 cells can be seeded
+	/// from several words and from strings of hexadecimal or binary digits,
+	/// that narrow seeds agree with the conversion from `u64`, and that seeds
+	/// too wide for the automaton are rejected.
+	#[test]
+	fn wide_seeds()
+	{
+		let automaton =
+			Automaton::<100>::from_bits(&[0x8000_0000_0000_0001, 0b101]);
+		assert!(automaton[0]);
+		assert!(automaton[63]);
+		assert!(automaton[64]);
+		assert!(!automaton[65]);
+		assert!(automaton[66]);
+		assert_eq!(4, automaton.iter().filter(|cell| **cell).count());
+		assert_eq!(
+			Ok(automaton),
+			Automaton::<100>::from_digits("0x5_8000_0000_0000_0001")
+		);
+		assert_eq!(
+			Automaton::<30>::from(0x34244103),
+			Automaton::<30>::from_bits(&[0x34244103])
+		);
+		assert_eq!(
+			Ok(Automaton::<30>::from(0x34244103)),
+			Automaton::<30>::from_hex("34244103")
+		);
+		assert!(Automaton::<30>::from_digits("34244103").is_err());
+		assert_eq!(
+			Ok(Automaton::<8>::from(0b1001_0110)),
+			Automaton::<8>::from_digits("0b1001_0110")
+		);
+		assert_eq!(
+			Ok(Automaton::<8>::from(0x96)),
+			Automaton::<8>::from_digits("0x0096")
+		);
+		assert_eq!(
+			Ok(Automaton::<8>::from(0x96)),
+			Automaton::<8>::from_digits("0X96")
+		);
+		assert_eq!(
+			Ok(Automaton::<8>::from(0b1001_0110)),
+			Automaton::<8>::from_digits("0B1001_0110")
+		);
+		// Bare hexadecimal digits may begin with `0b` without becoming binary.
+		assert_eq!(
+			Ok(Automaton::<16>::from(0x0b12)),
+			Automaton::<16>::from_hex("0b12")
+		);
+		assert_eq!(
+			Ok(Automaton::<16>::from(0x0b12)),
+			Automaton::<16>::from_hex("0X0B12")
+		);
+		assert!(Automaton::<16>::from_digits("0b12").is_err());
+		assert_eq!("0x0000000058000000000000001", automaton.to_hex());
+		assert_eq!(Ok(automaton), Automaton::from_digits(&automaton.to_hex()));
+		assert_eq!("0x096", Automaton::<10>::from(0x96).to_hex());
+		assert!(Automaton::<8>::from_digits("0x196").is_err());
+		assert!(Automaton::<8>::from_digits("0b2").is_err());
+		assert!(Automaton::<8>::from_digits("0x").is_err());
+		assert_eq!(Automaton::<8>::new(), Automaton::<8>::from_bits(&[]));
+	}
+
+	/// Ensure that [rules](AutomatonRule), [automata](Automaton), and
+	/// [histories](History) round-trip through serialization, and that a
+	/// short history is preceded by vacant generations.
+	#[cfg(all(feature = "serde", not(target_family = "wasm")))]
+	#[test]
+	fn serde()
+	{
+		#[derive(Debug, serde::Serialize, serde::Deserialize)]
+		struct State
+		{
+			rule: AutomatonRule,
+			automaton: Automaton<4>,
+			history: History<4, 3>
+		}
+		let mut history = History::<4, 3>::from(Automaton::from(0b0110));
+		history.evolve(&RuleKind::from(AutomatonRule::from(90)));
+		let state = State {
+			rule: AutomatonRule::from(110),
+			automaton: Automaton::from(0b1101),
+			history
+		};
+		let text = toml::to_string(&state).unwrap();
+		let actual = toml::from_str::<State>(&text).unwrap();
+		assert_eq!(state.rule, actual.rule);
+		assert_eq!(state.automaton, actual.automaton);
+		assert!(state.history.iter().eq(actual.history.iter()));
+		let short = "rule = 30\n\
+			automaton = [true, false, false, false]\n\
+			history = [[false, true, true, false]]\n";
+		let state = toml::from_str::<State>(short).unwrap();
+		assert_eq!(Automaton::from(0b0001), state.automaton);
+		assert_eq!(Automaton::from(0b0110), *state.history.newest());
+		assert_eq!(Automaton::new(), *state.history.oldest());
+		let bad = "rule = 30\nautomaton = [true]\nhistory = []\n";
+		assert!(toml::from_str::<State>(bad).is_err());
+	}
+
+	/// Ensure that the bitwise operations on [automata](Automaton) agree with
+	/// the corresponding operations on their bit vectors.
+	#[test]
+	fn bitwise()
+	{
+		let automaton = Automaton::<8>::from(0b1100_1010);
+		let other = Automaton::<8>::from(0b1010_1010);
+		assert_eq!(Automaton::from(0b0101_0110), automaton.rotate_left(3));
+		assert_eq!(Automaton::from(0b0101_1001), automaton.rotate_right(3));
+		assert_eq!(automaton, automaton.rotate_left(8));
+		assert_eq!(automaton.rotate_left(5), automaton.rotate_right(3));
+		assert_eq!(Automaton::from(0b0010_1000), automaton.shift(2));
+		assert_eq!(Automaton::from(0b0001_1001), automaton.shift(-3));
+		assert_eq!(Automaton::from(0), automaton.shift(8));
+		assert_eq!(Automaton::from(0b0011_0101), automaton.invert());
+		assert_eq!(Automaton::from(0b1000_1010), automaton.and(&other));
+		assert_eq!(Automaton::from(0b1110_1010), automaton.or(&other));
+		assert_eq!(Automaton::from(0b0110_0000), automaton.xor(&other));
+		assert_eq!(4, automaton.population_count());
+		assert_eq!(8, automaton.or(&automaton.invert()).population_count());
+	}
+
+	/// Ensure that [parallel&#32;evolution](Automaton::par_next) agrees with
+	/// sequential evolution, across the boundaries between chunks and the ends
+	/// of the automaton alike, even when the length is not a multiple of the
+	/// chunk length.
+	#[test]
+	fn par_next()
+	{
+		const K: usize = 3 * PARALLEL_CHUNK_LENGTH + 5;
+		let cells = std::array::from_fn(|i| (i * i + i / 7) % 3 == 0);
+		let automaton = Automaton::<K>::from(cells);
+		for rule in [30, 90, 110, 184]
+		{
+			let rule = AutomatonRule::from(rule);
+			let mut expected = [false; K];
+			automaton.evolve_span(rule, 0, &mut expected);
+			let expected = Automaton::from(expected);
+			assert_eq!(expected, automaton.next(rule));
+			assert_eq!(expected, automaton.par_next(rule));
+		}
+	}
+
+	/// Ensure that [bitwise&#32;evolution](Automaton::next_bitwise) agrees
+	/// with scalar evolution under every rule, for the full width of a `u64`
+	/// and for narrower automata alike.
+	#[cfg(feature = "simd")]
+	#[test]
+	fn next_bitwise()
+	{
+		fn check<const K: usize>(seed: u64)
+		{
+			let automaton = Automaton::<K>::from(seed);
+			for rule in 0 ..= 255
+			{
+				let rule = AutomatonRule::from(rule);
+				let mut expected = [false; K];
+				automaton.evolve_span(rule, 0, &mut expected);
+				let expected = Automaton::from(expected);
+				assert_eq!(expected, automaton.next_bitwise(rule), "{}", rule);
+			}
+		}
+		for seed in [0, 1, 0x34244103, 0xf89be26f89be26, u64::MAX]
+		{
+			check::<64>(seed);
+			check::<30>(seed);
+			check::<3>(seed);
+		}
+	}
+
+	/// Ensure that [bulk&#32;evolution](Automaton::next_n) agrees with
+	/// repeated evolution, both for [automata](Automaton) and for
+	/// [histories](History) that evolve fewer and more times than they can
+	/// remember.
+	#[test]
+	fn next_n()
+	{
+		let rule = AutomatonRule::from(110);
+		let seed = Automaton::<AUTOMATON_LENGTH>::from(0x34244103);
+		let mut expected = seed;
+		for n in 0 .. 200
+		{
+			assert_eq!(expected, seed.next_n(rule, n));
+			assert_eq!(expected, RuleKind::from(rule).next_n(&seed, n));
+			expected = expected.next(rule);
+		}
+		for n in [0, 1, AUTOMATON_HISTORY - 1, AUTOMATON_HISTORY, 175]
+		{
+			let mut expected = History::<AUTOMATON_LENGTH>::from(seed);
+			for _ in 0 .. n
+			{
+				expected.evolve(&rule.into());
+			}
+			let mut history = History::<AUTOMATON_LENGTH>::from(seed);
+			history.evolve_n(&rule.into(), n);
+			assert!(history.iter().eq(expected.iter()), "{}", n);
+		}
+	}
+
+	/// Measure the speedup of [parallel&#32;evolution](Automaton::par_next)
+	/// for a wide automaton. Run it in release mode:
+	///
+	/// ```text
+	/// cargo test --release par_next_speedup -- --ignored --nocapture
+	/// ```
+	#[test]
+	#[ignore = "benchmark; run in release mode"]
+	fn par_next_speedup()
+	{
+		const K: usize = 4 * PARALLEL_EVOLUTION_THRESHOLD;
+		const GENERATIONS: usize = 200;
+		let rule = AutomatonRule::from(30);
+		let seed = Automaton::<K>::from(std::array::from_fn(|i| i % 5 == 0));
+		let start = Instant::now();
+		let mut sequential = seed;
+		for _ in 0 .. GENERATIONS
+		{
+			let mut next = [false; K];
+			sequential.evolve_span(rule, 0, &mut next);
+			sequential = Automaton::from(next);
+		}
+		let sequential_time = start.elapsed();
+		let start = Instant::now();
+		let mut parallel = seed;
+		for _ in 0 .. GENERATIONS
+		{
+			parallel = parallel.par_next(rule);
+		}
+		let parallel_time = start.elapsed();
+		println!(
+			"{} generations of {} cells: sequential {:?}, parallel {:?}",
+			GENERATIONS,
+			K,
+			sequential_time,
+			parallel_time
+		);
+		assert_eq!(sequential, parallel);
+		let cores = std::thread::available_parallelism()
+			.map(|cores| cores.get())
+			.unwrap_or(1);
+		if cores > 1
+		{
+			assert!(parallel_time < sequential_time);
+		}
+	}
+
+	/// Ensure that the symmetries of [rules](AutomatonRule) agree with the
+	/// well-known equivalence classes, and that each symmetry is an
+	/// involution.
+	#[test]
+	fn symmetries()
+	{
+		let rule = AutomatonRule::from(110);
+		assert_eq!(AutomatonRule::from(124), rule.mirror());
+		assert_eq!(AutomatonRule::from(137), rule.complement());
+		assert_eq!(AutomatonRule::from(193), rule.mirrored_complement());
+		assert_eq!(AutomatonRule::from(86), AutomatonRule::from(30).mirror());
+		assert_eq!(AutomatonRule::from(90), AutomatonRule::from(90).mirror());
+		for code in 0 ..= 255u8
+		{
+			let rule = AutomatonRule::from(code);
+			assert_eq!(rule, rule.mirror().mirror());
+			assert_eq!(rule, rule.complement().complement());
+			assert_eq!(
+				rule.mirror().complement(),
+				rule.complement().mirror()
+			);
+		}
+	}
+
+	/// Ensure that [rules](AutomatonRule) parse from Wolfram codes, in decimal,
+	/// binary, or hexadecimal, and from
+	/// [aliases](crate::automata::RULE_ALIASES), regardless of case.
+	#[test]
+	fn parse_rule()
+	{
+		assert_eq!(Ok(AutomatonRule::from(30)), "30".parse());
+		assert_eq!(Ok(AutomatonRule::from(90)), "sierpinski".parse());
+		assert_eq!(Ok(AutomatonRule::from(110)), "Turing-Complete".parse());
+		assert_eq!(Ok(AutomatonRule::from(110)), "0b01101110".parse());
+		assert_eq!(Ok(AutomatonRule::from(110)), "0x6E".parse());
+		assert_eq!(Ok(AutomatonRule::from(110)), "0x6e".parse());
+		assert!("256".parse::<AutomatonRule>().is_err());
+		assert!("0b100000000".parse::<AutomatonRule>().is_err());
+		assert!("0x".parse::<AutomatonRule>().is_err());
+		assert!("nonesuch".parse::<AutomatonRule>().is_err());
+	}
+
+	/// Ensure that [mutation](AutomatonRule::mutate) flips exactly the
+	/// requested bit, and that mutating the same bit twice restores the
+	/// original [rule](AutomatonRule).
+	#[test]
+	fn mutate()
+	{
+		let rule = AutomatonRule::from(110);
+		assert_eq!(AutomatonRule::from(111), rule.mutate(0));
+		assert_eq!(AutomatonRule::from(238), rule.mutate(7));
+		for bit in 0 ..= 7
+		{
+			assert_eq!(rule, rule.mutate(bit).mutate(bit));
+		}
+	}
+
+	/// Ensure that a [schedule](RuleSchedule) applies its
+	/// [rules](RuleKind) in rotation, and that editing the schedule keeps
+	/// the [active](RuleSchedule::active) rule in range.
+	#[test]
+	fn schedule()
+	{
+		let mut schedule = RuleSchedule::from(
+			vec![AutomatonRule::from(90), AutomatonRule::from(150)]
+		);
+		assert_eq!("Rules #90, #150", schedule.to_string());
+		assert_eq!(RuleKind::from(AutomatonRule::from(90)), schedule.advance());
+		assert_eq!(RuleKind::from(AutomatonRule::from(150)), schedule.advance());
+		assert_eq!(RuleKind::from(AutomatonRule::from(90)), schedule.advance());
+		assert_eq!(
+			Some(RuleKind::from(AutomatonRule::from(150))),
+			schedule.pop()
+		);
+		assert_eq!(0, schedule.active_index());
+		assert_eq!(None, schedule.pop());
+		assert_eq!("Rule #90", schedule.to_string());
+	}
+
+	/// Ensure that a [rule&#32;map](RuleMap) governs exactly its mapped
+	/// columns, that mapped cells see their neighbors across the interface,
+	/// and that maps survive a round trip through their textual form.
+	#[test]
+	fn rule_map()
+	{
+		let death = RuleKind::from(AutomatonRule::from(0));
+		let identity = RuleKind::from(AutomatonRule::from(204));
+		let full = Automaton::<8>::from(0b1111_1111);
+		let empty = RuleMap::<8>::new();
+		assert!(empty.is_empty());
+		assert_eq!(death.next(&full), empty.next(&death, &full));
+		// The left half keeps its cells, and the right half dies out.
+		let map = "0-3:204".parse::<RuleMap<8>>().unwrap();
+		assert_eq!(Automaton::from(0b1111_0000), map.next(&death, &full));
+		// The right half shifts rightward, drawing on the left half.
+		let map = "4-7:shift-right".parse::<RuleMap<8>>().unwrap();
+		let lone = Automaton::<8>::from(0b0001_0000);
+		assert_eq!(Automaton::from(0b0001_1000), map.next(&identity, &lone));
+		// Later assignments win, and adjacent columns merge into spans.
+		let map = "0-5:90, 6-7:90, 2-3:30, 7:110".parse::<RuleMap<8>>()
+			.unwrap();
+		assert_eq!(Some(AutomatonRule::from(30)), map.get(2));
+		assert_eq!("0-1:90,2-3:30,4-6:90,7:110", map.to_string());
+		assert_eq!(map, map.to_string().parse().unwrap());
+		assert_eq!(empty, "".parse().unwrap());
+		assert!("8:30".parse::<RuleMap<8>>().is_err());
+		assert!("3-1:30".parse::<RuleMap<8>>().is_err());
+		assert!("5".parse::<RuleMap<8>>().is_err());
+		assert!("5:256".parse::<RuleMap<8>>().is_err());
+	}
+
+	/// Ensure that a [timeline](RuleTimeline) switches rules only upon
+	/// entering its ranges, that it predicts the next switch, and that
+	/// timelines survive a round trip through their textual form.
+	#[test]
+	fn rule_timeline()
+	{
+		let timeline = "0-99: 30, 100-199: 110 # Comment.\n300-: 90"
+			.parse::<RuleTimeline>()
+			.unwrap();
+		assert_eq!(Some(AutomatonRule::from(30)), timeline.switch_at(0));
+		assert_eq!(None, timeline.switch_at(50));
+		assert_eq!(Some(AutomatonRule::from(110)), timeline.switch_at(100));
+		assert_eq!(None, timeline.rule_at(250));
+		assert_eq!(Some(AutomatonRule::from(90)), timeline.rule_at(1_000_000));
+		assert_eq!(
+			Some((100, AutomatonRule::from(110))),
+			timeline.next_switch(0)
+		);
+		assert_eq!(
+			Some((300, AutomatonRule::from(90))),
+			timeline.next_switch(100)
+		);
+		assert_eq!(None, timeline.next_switch(300));
+		// A rule chosen midway through a range survives until the next switch.
+		let mut schedule = RuleSchedule::from(AutomatonRule::from(30));
+		assert_eq!(None, timeline.follow(0, &mut schedule));
+		schedule = AutomatonRule::from(45).into();
+		assert_eq!(None, timeline.follow(50, &mut schedule));
+		assert_eq!(
+			Some(RuleSchedule::from(AutomatonRule::from(45))),
+			timeline.follow(100, &mut schedule)
+		);
+		assert_eq!(RuleSchedule::from(AutomatonRule::from(110)), schedule);
+		// Adjacent ranges with the same rule never switch between them.
+		let timeline = "0-9: 30, 10-19: 30, 5: 90".parse::<RuleTimeline>()
+			.unwrap();
+		assert_eq!(Some((5, AutomatonRule::from(90))), timeline.next_switch(0));
+		assert_eq!(Some((6, AutomatonRule::from(30))), timeline.next_switch(5));
+		assert_eq!(None, timeline.next_switch(6));
+		assert_eq!("0-9: 30, 10-19: 30, 5: 90", timeline.to_string());
+		assert_eq!(timeline, timeline.to_string().parse().unwrap());
+		assert!("".parse::<RuleTimeline>().unwrap().is_empty());
+		assert!("9-0: 30".parse::<RuleTimeline>().is_err());
+		assert!("x: 30".parse::<RuleTimeline>().is_err());
+		assert!("5".parse::<RuleTimeline>().is_err());
+	}
+
+	/// Ensure that the [difference](History::difference) between two
+	/// [histories](History) marks exactly the cells where they disagree.
+	#[test]
+	fn difference()
+	{
+		let left = History::<8, 4>::from(Automaton::from(0b1100_1010));
+		let right = History::<8, 4>::from(Automaton::from(0b1010_1010));
+		let expected = Automaton::<8>::from(0b0110_0000);
+		let actual = left.difference(&right);
+		assert_eq!(&expected, actual.newest());
+		assert_eq!(&Automaton::new(), actual.oldest());
+		assert_eq!(&Automaton::new(), left.difference(&left).newest());
+	}
+
+	/// Ensure that [steady&#32;states](SteadyState) are detected only once
+	/// every rule in the period has preserved the newest generation.
+	#[test]
+	fn steady_state()
+	{
+		let seed = Automaton::<8>::from(0b0110_1001);
+		// Rule #0 kills every cell.
+		let mut history = History::<8, 4>::from(seed);
+		history.evolve(&AutomatonRule::from(0).into());
+		assert_eq!(None, history.steady_state(1));
+		history.evolve(&AutomatonRule::from(0).into());
+		assert_eq!(Some(SteadyState::Extinct), history.steady_state(1));
+		// Rule #204 preserves every cell.
+		let mut history = History::<8, 4>::from(seed);
+		history.evolve(&AutomatonRule::from(204).into());
+		assert_eq!(Some(SteadyState::FixedPoint), history.steady_state(1));
+		assert_eq!(None, history.steady_state(2));
+		// Rule #30 keeps going.
+		let mut history = History::<8, 4>::from(seed);
+		history.evolve(&AutomatonRule::from(30).into());
+		assert_eq!(None, history.steady_state(1));
+	}
+
+	/// Ensure that packing an [automaton](Automaton) into a `u64` inverts
+	/// unpacking it.
+	#[test]
+	fn round_trip_u64()
+	{
+		let seed = 0x34244103u64;
+		assert_eq!(seed, u64::from(Automaton::<30>::from(seed)));
+		assert_eq!(u64::MAX, u64::from(Automaton::<64>::from(u64::MAX)));
+		assert_eq!(0, u64::from(Automaton::<64>::new()));
+	}
+
+	/// Ensure that a [scrollback](Scrollback) retains every generation up to
+	/// its cap, and then forgets the oldest.
+	#[test]
+	fn scrollback()
+	{
+		let rule = RuleKind::from(AutomatonRule::from(30));
+		let mut scrollback = Scrollback::<8>::new(Automaton::from(1), Some(3));
+		scrollback.push(Automaton::from(2), rule.clone());
+		scrollback.push(Automaton::from(3), rule.clone());
+		assert_eq!(3, scrollback.len());
+		assert_eq!(Some(&Automaton::from(1)), scrollback.back(2));
+		scrollback.push(Automaton::from(4), rule.clone());
+		assert_eq!(3, scrollback.len());
+		assert_eq!(Some(&Automaton::from(2)), scrollback.back(2));
+		assert_eq!(None, scrollback.back(3));
+		assert_eq!(
+			vec![1, 2, 3],
+			scrollback.iter().map(|(i, _, _)| i).collect::<Vec<_>>()
+		);
+		assert_eq!(
+			Some((2, &Automaton::from(3), Some(&rule))),
+			scrollback.generation(1)
+		);
+		assert_eq!(None, scrollback.generation(3));
+		scrollback.replace_newest(Automaton::from(5));
+		assert_eq!(Some(&Automaton::from(5)), scrollback.back(0));
+		scrollback.restart(Automaton::from(6));
+		assert_eq!(1, scrollback.len());
+		scrollback.push(Automaton::from(7), rule.clone());
+		scrollback.push(Automaton::from(8), rule.clone());
+		scrollback.rewind(1, Automaton::from(7));
+		assert_eq!(
+			Some((1, &Automaton::from(7), Some(&rule))),
+			scrollback.generation(0)
+		);
+		assert_eq!(2, scrollback.len());
+		scrollback.rewind(40, Automaton::from(9));
+		assert_eq!(
+			Some((40, &Automaton::from(9), None)),
+			scrollback.generation(0)
+		);
+		assert_eq!(1, scrollback.len());
+		let mut unbounded = Scrollback::<8>::new(Automaton::new(), None);
+		for i in 0 .. 1000
+		{
+			unbounded.push(Automaton::from(i), rule.clone());
+		}
+		assert_eq!(1001, unbounded.len());
+	}
+
+	/// Ensure that the [Lempel-Ziv&#32;parsing](lempel_ziv_phrases) agrees with
+	/// the example of Kaspar and Schuster, and that the [complexity] of
+	/// chaotic generations far exceeds that of periodic ones.
+	#[test]
+	fn complexity_estimate()
+	{
+		let cells = "0001101001000101".chars()
+			.map(|c| c == '1')
+			.collect::<Vec<_>>();
+		assert_eq!(6, lempel_ziv_phrases(&cells));
+		assert_eq!(0, lempel_ziv_phrases(&[]));
+		assert_eq!(1, lempel_ziv_phrases(&[true]));
+		assert_eq!(0.0, complexity::<64>([]));
+		let rule_30 = AutomatonRule::from(30);
+		let rule_51 = AutomatonRule::from(51);
+		let mut chaotic = vec![Automaton::<64>::from(1 << 32)];
+		let mut periodic = vec![Automaton::<64>::from(0x5555_5555_5555_5555)];
+		for _ in 1 .. COMPLEXITY_WINDOW
+		{
+			chaotic.push(chaotic.last().unwrap().next(rule_30));
+			periodic.push(periodic.last().unwrap().next(rule_51));
+		}
+		let chaotic = complexity(&chaotic);
+		let periodic = complexity(&periodic);
+		assert!(chaotic > 0.5, "{}", chaotic);
+		assert!(periodic < 0.1, "{}", periodic);
+	}
+
+	/// Ensure that the [power&#32;spectrum](Automaton::power_spectrum) of a
+	/// periodic automaton peaks at its period, and that a
+	/// [spatial&#32;spectrum](SpatialSpectrum) finds the characteristic
+	/// wavelength of several automata, but none for uniform automata.
+	#[test]
+	fn spatial_spectrum()
+	{
+		let stripes = Automaton::<8>::from(0b0011_0011);
+		let power = stripes.power_spectrum();
+		assert_eq!(5, power.len());
+		assert!(power[0].abs() < 1e-4);
+		assert!((power[2] - 1.0).abs() < 1e-4);
+		assert!(power[4].abs() < 1e-4);
+		let shifted = Automaton::<8>::from(0b0110_0110);
+		let spectrum = SpatialSpectrum::new([&stripes, &shifted]);
+		assert_eq!(Some(2), spectrum.dominant_frequency());
+		assert_eq!(Some(4.0), spectrum.wavelength());
+		let uniform = SpatialSpectrum::new([&Automaton::<8>::from(0xFF)]);
+		assert_eq!(None, uniform.wavelength());
+		assert_eq!(0.0, uniform.power(1));
+		assert_eq!(None, SpatialSpectrum::<8>::new([]).wavelength());
+	}
+
+	/// Ensure that a [column&#32;density](ColumnDensity) tallies the
+	/// generations of a [scrollback](Scrollback) by column, from left to right,
+	/// and that withdrawn generations no longer count.
+	#[test]
+	fn column_density()
+	{
+		let rule = RuleKind::from(AutomatonRule::from(30));
+		let first = Automaton::from(0b1000);
+		let mut scrollback = Scrollback::<4>::new(first, None);
+		scrollback.push(Automaton::from(0b1001), rule.clone());
+		scrollback.push(Automaton::from(0b1100), rule);
+		let mut density = ColumnDensity::from(&scrollback);
+		assert_eq!(3, density.generations());
+		assert_eq!(
+			vec![1.0, 1.0 / 3.0, 0.0, 1.0 / 3.0],
+			(0 .. 4).map(|column| density.density(column)).collect::<Vec<_>>()
+		);
+		density.remove(&Automaton::from(0b1100));
+		density.push(&Automaton::from(0b0010));
+		assert_eq!(
+			vec![2.0 / 3.0, 0.0, 1.0 / 3.0, 1.0 / 3.0],
+			(0 .. 4).map(|column| density.density(column)).collect::<Vec<_>>()
+		);
+		assert_eq!(0.0, ColumnDensity::<4>::new().density(0));
+	}
+
+	/// Ensure that a [scrollback](Scrollback) exports as CSV, with cells from
+	/// left to right.
+	#[test]
+	fn csv()
+	{
+		let mut scrollback = Scrollback::<4>::new(Automaton::from(0b0001), None);
+		let rule = AutomatonRule::from(30).into();
+		scrollback.push(Automaton::from(0b0110), rule);
+		let mut out = Vec::new();
+		scrollback.write_csv(&mut out).unwrap();
+		assert_eq!(
+			"generation,rule,c0,c1,c2,c3\n\
+				0,,0,0,0,1\n\
+				1,30,0,1,1,0\n",
+			String::from_utf8(out).unwrap()
+		);
+	}
+
+	/// Ensure that a [scrollback](Scrollback) exports as a Wolfram Language
+	/// expression, with cells from left to right, and that the expression only
+	/// evolves afresh when a single elementary rule produced every generation.
+	#[test]
+	fn wolfram()
+	{
+		let mut scrollback = Scrollback::<4>::new(Automaton::from(0b0001), None);
+		let rule = RuleKind::from(AutomatonRule::from(30));
+		scrollback.push(Automaton::from(0b0011), rule.clone());
+		scrollback.push(Automaton::from(0b1110), rule);
+		let mut out = Vec::new();
+		scrollback.write_wolfram(&mut out).unwrap();
+		assert_eq!(
+			"(* Generations 0 through 2, with cells from left to right. *)\n\
+				{\n\
+				\tCellularAutomaton[30, {0, 0, 0, 1}, 2],\n\
+				\t{\n\
+				\t\t{0, 0, 0, 1},\n\
+				\t\t{0, 0, 1, 1},\n\
+				\t\t{1, 1, 1, 0}\n\
+				\t}\n\
+				}\n",
+			String::from_utf8(out).unwrap()
+		);
+		let rule = AutomatonRule::from(90).into();
+		scrollback.push(Automaton::from(0b0001), rule);
+		let mut out = Vec::new();
+		scrollback.write_wolfram(&mut out).unwrap();
+		assert!(
+			String::from_utf8(out).unwrap()
+				.contains("\tMissing[\"NotApplicable\"],\n")
+		);
+	}
+
+	/// Ensure that a row of brightness values is resampled to the width of the
+	/// [automaton](Automaton) and thresholded, with dark pixels coming alive.
+	#[test]
+	fn from_brightness()
+	{
+		// Exact fit.
+		let row = [0, 255, 255, 0];
+		assert_eq!(
+			Automaton::<4>::from(0b1001),
+			Automaton::from_brightness(&row, 128)
+		);
+		// Averaged down: each cell covers two pixels.
+		let row = [0, 0, 255, 255, 255, 255, 100, 100];
+		assert_eq!(
+			Automaton::<4>::from(0b1001),
+			Automaton::from_brightness(&row, 128)
+		);
+		// Stretched: each pixel covers two cells.
+		let row = [0, 255];
+		assert_eq!(
+			Automaton::<4>::from(0b1100),
+			Automaton::from_brightness(&row, 128)
+		);
+	}
+
+	/// Ensure that a [tape](Tape) agrees with a wide enough
+	/// [automaton](Automaton) for as long as the activity of the automaton
+	/// stays clear of its ends, and that the tape grows to keep its own ends
+	/// clear.
+	#[test]
+	fn tape()
+	{
+		let seed = Automaton::<64>::from(1 << 31);
+		let mut automaton = seed;
+		let mut tape = Tape::from(Automaton::<8>::from(1 << 3));
+		assert_eq!(8, tape.len());
+		for _ in 0 .. 30
+		{
+			automaton = automaton.next(30.into());
+			tape = tape.next(30.into());
+			for position in -28 .. 36
+			{
+				assert_eq!(
+					automaton[(position + 28) as usize],
+					tape.cell(position)
+				);
+			}
+			let positions = tape.positions();
+			for position in positions.start .. positions.start + 2
+			{
+				assert!(!tape.cell(position));
+			}
+			for position in positions.end - 2 .. positions.end
+			{
+				assert!(!tape.cell(position));
+			}
+		}
+		assert_eq!(0, (tape.len() - 8) % TAPE_GROWTH);
+		assert!(tape.len() >= 8 + 2 * 30);
+		assert!(!tape.cell(isize::MIN));
+		assert!(!tape.cell(isize::MAX));
+	}
+
+	/// Ensure that the background of a [tape](Tape) evolves along with its
+	/// cells, and that a tape set against a uniform background does not grow.
+	#[test]
+	fn tape_background()
+	{
+		let tape = Tape::from(Automaton::<8>::new());
+		assert_eq!(8, tape.len());
+		// Rule #1 births a cell only from a vacant neighborhood, so the
+		// background blinks.
+		let next = tape.next(1.into());
+		assert!(next.background());
+		assert!(next.positions().all(|position| next.cell(position)));
+		assert_eq!(8, next.len());
+		let next = next.next(1.into());
+		assert!(!next.background());
+		assert_eq!(8, next.len());
+		// A live cell within the margin makes the tape grow at that end only.
+		let tape = Tape::from(Automaton::<8>::from(1));
+		assert_eq!(8 + TAPE_GROWTH, tape.len());
+		assert_eq!(-(TAPE_GROWTH as isize) .. 8, tape.positions());
+	}
+
+	/// Ensure that [turns](Turns) parse from their letters, ignoring case, and
+	/// that too few turns or unknown letters are rejected.
+	#[test]
+	fn parse_turns()
+	{
+		assert_eq!(Ok(Turns::default()), "RL".parse());
+		let turns = "llrN".parse::<Turns>().unwrap();
+		assert_eq!(4, turns.states());
+		assert_eq!("LLRN", turns.to_string());
+		assert!("R".parse::<Turns>().is_err());
+		assert!("".parse::<Turns>().is_err());
+		assert!("RLX".parse::<Turns>().is_err());
+		assert_eq!(Heading::West, Heading::North.turn(Turn::Left));
+		assert_eq!(Heading::South, Heading::North.turn(Turn::Reverse));
+	}
+
+	/// Ensure that Langton's original [ant](Ant) traces its well-known path:
+	/// it flips each cell that it visits, turns right onto vacant cells and
+	/// left onto occupied ones, and wraps around the edges of the grid.
+	#[test]
+	fn langtons_ant()
+	{
+		let mut colony = Colony::<4, 4>::new(Turns::default(), 1);
+		assert_eq!(
+			&[Ant { row: 2, column: 2, heading: Heading::North }],
+			colony.ants()
+		);
+		// Four right turns bring the ant around a square, flipping each cell.
+		for _ in 0 .. 4
+		{
+			colony.step();
+		}
+		assert_eq!(
+			&[Ant { row: 2, column: 2, heading: Heading::North }],
+			colony.ants()
+		);
+		for (row, column) in [(2, 2), (2, 3), (3, 3), (3, 2)]
+		{
+			assert_eq!(1, colony.state(row, column));
+		}
+		// The ant now turns left off a flipped cell, restoring it.
+		colony.step();
+		assert_eq!(0, colony.state(2, 2));
+		assert_eq!(
+			&[Ant { row: 2, column: 1, heading: Heading::West }],
+			colony.ants()
+		);
+		// Headed north from the top row, the ant wraps to the bottom.
+		let mut colony = Colony::<4, 4>::new("NN".parse().unwrap(), 1);
+		for _ in 0 .. 3
+		{
+			colony.step();
+		}
+		assert_eq!(
+			&[Ant { row: 3, column: 2, heading: Heading::North }],
+			colony.ants()
+		);
+	}
+
+	/// Ensure that a dying cell [decays](Decay) for the specified number of
+	/// generations before vanishing, and cannot be reborn while decaying.
+	#[test]
+	fn decay()
+	{
+		let death = RuleKind::from(AutomatonRule::from(0));
+		let birth = RuleKind::from(AutomatonRule::from(255));
+		let map = RuleMap::new();
+		let mut history = History::<8, 4>::from(Automaton::from(0b00011000));
+		let mut decay = Decay::<8, 4>::new(2);
+		decay.evolve(&mut history, &death, &map);
+		assert_eq!(&Automaton::default(), history.newest());
+		assert_eq!([0, 0, 0, 1, 1, 0, 0, 0], decay[3]);
+		decay.evolve(&mut history, &death, &map);
+		assert_eq!([0, 0, 0, 2, 2, 0, 0, 0], decay[3]);
+		decay.evolve(&mut history, &death, &map);
+		assert_eq!([0; 8], decay[3]);
+		// Decaying cells cannot be reborn, even when every cell would be.
+		let mut history = History::<8, 4>::from(Automaton::from(0b00000001));
+		let mut decay = Decay::<8, 4>::new(1);
+		decay.evolve(&mut history, &death, &map);
+		assert_eq!([1, 0, 0, 0, 0, 0, 0, 0], decay[3]);
+		decay.evolve(&mut history, &birth, &map);
+		assert_eq!(&Automaton::from(0b11111110), history.newest());
+		assert_eq!([0; 8], decay[3]);
+		decay.evolve(&mut history, &birth, &map);
+		assert_eq!(&Automaton::from(0b11111111), history.newest());
+		// Without decay generations, cells die outright.
+		let mut history = History::<8, 4>::from(Automaton::from(0b00000001));
+		let mut decay = Decay::<8, 4>::new(0);
+		decay.evolve(&mut history, &death, &map);
+		assert_eq!([0; 8], decay[3]);
+		decay.restart();
+		assert_eq!(0, decay.generations());
+	}
+
+	/// Ensure that [particles](Particle) are found against the ether of
+	/// [Rule&#32;#110](AutomatonRule), and recognized as the right
+	/// [gliders](Glider). Each seed lies in a ring of four ether tiles.
+	#[test]
+	fn particles()
+	{
+		let rule = RuleKind::from(AutomatonRule::from(110));
+		let history = |seed: u64| {
+			let mut history = History::<56, 50>::from(Automaton::from(seed));
+			for _ in 1 .. 50
+			{
+				history.evolve(&rule);
+			}
+			history
+		};
+		// Pure ether.
+		let ether = history(0xf89be26f89be26);
+		assert_eq!(Vec::<Particle>::new(), ether.particles());
+		assert_eq!(
+			vec![Particle {
+				rows: 0 ..= 49,
+				start: 5,
+				width: 38,
+				glider: Some(Glider::A)
+			}],
+			history(0xe26ee9be26f89b).particles()
+		);
+		let particles = history(0xe26f8981daf89b).particles();
+		assert_eq!(1, particles.len());
+		assert_eq!(Some(Glider::C), particles[0].glider);
+		let particles = history(0xe26f9c1df2f89b).particles();
+		assert_eq!(1, particles.len());
+		assert_eq!(Some(Glider::E), particles[0].glider);
+		assert_eq!("B̂", Glider::BHat.to_string());
+	}
+
+	/// Ensure that the [background](Background) of Rule 110 is found to be its
+	/// ether, even around a glider, that uniform histories have a background
+	/// of one cell, and that chaotic histories have none.
+	#[test]
+	fn background()
+	{
+		let history = |seed: u64, rule: u8| {
+			let rule = RuleKind::from(AutomatonRule::from(rule));
+			let mut history = History::<56, 50>::from(Automaton::from(seed));
+			for _ in 1 .. 50
+			{
+				history.evolve(&rule);
+			}
+			history
+		};
+		let ether = history(0xf89be26f89be26, 110).background().unwrap();
+		assert_eq!(14, ether.period());
+		assert_eq!(
+			canonical_tile(ETHER.into_iter(), 14),
+			canonical_tile(ether.tile.iter().copied(), 14)
+		);
+		assert_eq!(Some(7), ether.recurrence(AutomatonRule::from(110)));
+		let glider = history(0xe26ee9be26f89b, 110);
+		assert_eq!(Some(ether.clone()), glider.background());
+		let cells = glider.background_cells(&ether);
+		assert!(cells.iter().flatten().any(|&cell| !cell));
+		let uniform = history(0, 0).background().unwrap();
+		assert_eq!(1, uniform.period());
+		assert_eq!(Some(1), uniform.recurrence(AutomatonRule::from(0)));
+		assert_eq!(None, history(1 << 28, 30).background());
+		let stripes = [true, false, true, false];
+		assert_eq!(None, canonical_tile(stripes.into_iter(), 4));
+	}
+
+	/// Ensure that [light&#32;cones](Cone) widen by one cell per generation in
+	/// each direction, and wrap around at the edges of the automaton.
+	#[test]
+	fn light_cone()
+	{
+		let locate = |row, index| Cone::locate::<8>((4, 2), (row, index));
+		assert_eq!(Some(Cone::Apex), locate(4, 2));
+		assert_eq!(None, locate(4, 3));
+		assert_eq!(Some(Cone::Past), locate(3, 1));
+		assert_eq!(Some(Cone::Past), locate(3, 3));
+		assert_eq!(None, locate(3, 4));
+		assert_eq!(Some(Cone::Past), locate(2, 0));
+		assert_eq!(Some(Cone::Past), locate(1, 7));
+		assert_eq!(None, locate(1, 6));
+		assert_eq!(Some(Cone::Future), locate(5, 3));
+		assert_eq!(None, locate(5, 5));
+		assert_eq!(Some(Cone::Future), locate(8, 6));
+	}
+
+	/// Ensure that only the neighbors that actually determined the fate of a
+	/// cell are credited with it.
+	#[test]
+	fn determinants()
+	{
+		let rule = |code: u8| RuleKind::from(AutomatonRule::from(code));
+		let automaton = Automaton::<8>::from(0b00010100);
+		// Rule #90 is the XOR of the outer neighbors.
+		assert_eq!(vec![4, 2], rule(90).determinants(&automaton, 3));
+		// Rule #204 is the identity.
+		assert_eq!(vec![3], rule(204).determinants(&automaton, 3));
+		// Rule #0 kills every cell, whatever its neighbors.
+		assert_eq!(Vec::<usize>::new(), rule(0).determinants(&automaton, 3));
+		// Rule #254 is the OR of all three neighbors: any live neighbor
+		// suffices, but only all three dead neighbors together kill the cell.
+		assert_eq!(vec![4, 2], rule(254).determinants(&automaton, 3));
+		assert_eq!(vec![4], rule(254).determinants(&automaton, 5));
+		assert_eq!(vec![1, 0, 7], rule(254).determinants(&automaton, 0));
+	}
+
+	/// Ensure that the ancestry of a cell follows its determinants back through
+	/// the [scrollback](Scrollback), under the rule that produced each
+	/// generation.
+	#[test]
+	fn ancestry()
+	{
+		let xor = RuleKind::from(AutomatonRule::from(90));
+		let identity = RuleKind::from(AutomatonRule::from(204));
+		let mut scrollback = Scrollback::<8>::new(Automaton::from(0b1), None);
+		for rule in [&identity, &xor, &xor]
+		{
+			let next = rule.next(scrollback.back(0).unwrap());
+			scrollback.push(next, rule.clone());
+		}
+		assert_eq!(
+			vec![vec![2, 4], vec![1, 3, 5], vec![1, 3, 5]],
+			scrollback.ancestry(0, 3, 10)
+		);
+		assert_eq!(vec![vec![2, 4]], scrollback.ancestry(0, 3, 1));
+		assert_eq!(vec![vec![2, 4], vec![2, 4]], scrollback.ancestry(1, 3, 10));
+		// An edited generation has no ancestors.
+		scrollback.replace_newest(Automaton::from(0b11));
+		assert_eq!(Vec::<Vec<usize>>::new(), scrollback.ancestry(0, 3, 10));
+	}
+
+	/// Ensure that LZ78 parses bits into the expected number of phrases.
+	#[test]
+	fn lz78()
+	{
+		use crate::automata::lz78_phrases;
+		// 0 | 1 | 00 | 01 | 1
+		let bits = [false, true, false, false, false, true, true];
+		assert_eq!(5, lz78_phrases(&bits));
+		assert_eq!(0, lz78_phrases(&[]));
+		// 0 | 00 | 000 | 0000
+		assert_eq!(4, lz78_phrases(&[false; 10]));
+	}
+
+	/// Ensure that [interestingness](Interestingness) separates Rule 110 from
+	/// rules that die out or freeze at once.
+	#[test]
+	fn interestingness()
+	{
+		let seeds = [0x9e3779b97f4a7c15, 0xbf58476d1ce4e5b9, 0x94d049bb133111eb]
+			.map(Automaton::<64>::from);
+		let measure = |code: u8| {
+			Interestingness::mean(&seeds, AutomatonRule::from(code), 256)
+		};
+		let rule_110 = measure(110);
+		// Rule #110 never repeats a generation in so short a stretch.
+		assert!(rule_110.transient > 0.99);
+		assert!(rule_110.score() >= INTERESTINGNESS_THRESHOLD);
+		// Rule #0 kills every cell at once.
+		let rule_0 = measure(0);
+		assert!(rule_0.entropy < 0.01);
+		assert!(rule_0.transient < 0.01);
+		assert!(rule_0.score() < INTERESTINGNESS_THRESHOLD);
+		// Rule #204 is the identity, so the first generation repeats at once.
+		let rule_204 = measure(204);
+		assert_eq!(0.0, rule_204.transient);
+		assert!(rule_204.score() < INTERESTINGNESS_THRESHOLD);
+		let (rule, measure) = surprising_rule(&seeds, 256, 0.0);
+		assert_eq!(Interestingness::mean(&seeds, rule, 256), measure);
+	}
+
+	/// Ensure that [orbits](Orbit) measure their transients and periods
+	/// correctly, and that the
+	/// [exhaustive&#32;search](longest_transient_exhaustive) agrees with an
+	/// ordinary [search](longest_transient) of every first generation.
+	#[test]
+	fn orbits()
+	{
+		let automaton = Automaton::<8>::from(0b1);
+		let orbit = |code: u8, limit| {
+			automaton.orbit(AutomatonRule::from(code), limit)
+		};
+		// Rule #204 is the identity.
+		assert_eq!(Some(Orbit { transient: 0, period: 1 }), orbit(204, 100));
+		// Rule #0 kills every cell at once.
+		assert_eq!(Some(Orbit { transient: 1, period: 1 }), orbit(0, 100));
+		// Rule #170 rotates every cell by one column.
+		assert_eq!(Some(Orbit { transient: 0, period: 8 }), orbit(170, 100));
+		assert_eq!(None, orbit(170, 4));
+		let rule = AutomatonRule::from(30);
+		let (champion, orbit) = longest_transient_exhaustive::<10>(rule);
+		let every = (0 .. 1u64 << 10).map(Automaton::<10>::from);
+		let (_, expected) = longest_transient(rule, every, usize::MAX).unwrap();
+		assert_eq!(expected.transient, orbit.transient);
+		assert_eq!(Some(orbit), champion.orbit(rule, usize::MAX));
+	}
 }
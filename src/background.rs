@@ -0,0 +1,357 @@
+use bevy::prelude::{
+	App, BackgroundColor, BuildChildren, Color, Commands, Component, default,
+	DespawnRecursiveExt, DetectChanges, Entity, EventWriter, Input,
+	IntoSystemConfigs, KeyCode, NodeBundle, Plugin, PositionType, Query, Ref,
+	Res, ResMut, Resource, Style, Text, TextBundle, TextStyle, UiRect, Update,
+	Val, With
+};
+
+use crate::automata::{
+	AUTOMATON_HISTORY, AUTOMATON_LENGTH, Background, Decay, History, RuleKind,
+	RuleSchedule, Scrollback
+};
+use crate::ecs::{
+	Announcement, apply_actions, CellPosition, evolve, Focused, Palette,
+	repaint, ScrollOffset, update_palette, update_scan, update_scroll
+};
+use crate::effects::blend;
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the background filter, which detects
+/// the periodic [background](Background) of every [pane](crate::ecs::Pane),
+/// e.g., the ether of Rule 110, and dims the cells that belong to it, so that
+/// gliders and their collisions stand out. A label beneath the generation
+/// counter gives the periods of the background of the
+/// [focused](Focused) pane. The [BackgroundFilter] resource governs whether
+/// the filter is active.
+pub struct BackgroundPlugin;
+
+impl Plugin for BackgroundPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<BackgroundFilter>()
+			.add_systems(Update, maybe_toggle_background)
+			.add_systems(
+				Update,
+				detect_background
+					.after(maybe_toggle_background)
+					.after(apply_actions)
+					.after(evolve)
+			)
+			.add_systems(
+				Update,
+				(dim_background, update_background_label)
+					.after(detect_background)
+					.after(update_scroll)
+					.after(update_palette)
+					.after(update_scan)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the periodic [background](Background) is dimmed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct BackgroundFilter(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [background](Background) detected in the [history](History) of a
+/// [pane](crate::ecs::Pane), if any, along with the cells that belong to it,
+/// by row and index.
+#[derive(Component)]
+struct DetectedBackground
+{
+	/// The background, if any.
+	background: Option<Background>,
+
+	/// Which cells belong to the background.
+	cells: [[bool; AUTOMATON_LENGTH]; AUTOMATON_HISTORY]
+}
+
+impl From<&History> for DetectedBackground
+{
+	fn from(history: &History) -> Self
+	{
+		let background = history.background();
+		let cells = match background
+		{
+			Some(ref background) => history.background_cells(background),
+			None => [[false; AUTOMATON_LENGTH]; AUTOMATON_HISTORY]
+		};
+		Self { background, cells }
+	}
+}
+
+/// The banner that holds the [label](BackgroundLabel).
+#[derive(Component)]
+struct BackgroundBanner;
+
+/// The label that gives the periods of the [background](Background) of the
+/// [focused](Focused) pane. It resides within a simple overlay, marked by
+/// [BackgroundBanner].
+#[derive(Component)]
+struct BackgroundLabel;
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F6`, toggle the [background&#32;filter](BackgroundFilter), and announce
+/// the new setting.
+fn maybe_toggle_background(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut filter: ResMut<BackgroundFilter>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.background)
+	{
+		filter.0 = !filter.0;
+		let message = match filter.0
+		{
+			true => Message::BackgroundOn,
+			false => Message::BackgroundOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the [background&#32;filter](BackgroundFilter) is active, detect the
+/// [background](DetectedBackground) of every [pane](crate::ecs::Pane) anew
+/// whenever its [history](History) changes, and of every pane at once when the
+/// filter is activated. Difference panes have no
+/// [scrollback](Scrollback), and no background of their own.
+fn detect_background(
+	filter: Res<BackgroundFilter>,
+	mut panes: Query<
+		(Entity, Ref<History>, Option<&mut DetectedBackground>),
+		With<Scrollback>
+	>,
+	mut commands: Commands
+) {
+	if !filter.0
+	{
+		return
+	}
+	for (entity, history, detected) in &mut panes
+	{
+		match detected
+		{
+			None =>
+			{
+				commands.entity(entity)
+					.insert(DetectedBackground::from(&*history));
+			},
+			Some(mut detected)
+				if history.is_changed() || filter.is_changed() =>
+			{
+				*detected = DetectedBackground::from(&*history);
+			},
+			Some(_) => {}
+		}
+	}
+}
+
+/// While the [background&#32;filter](BackgroundFilter) is active, blend every
+/// cell of the [background](DetectedBackground) toward the dead color, and
+/// once it is deactivated, restore every cell. While the view is
+/// [scrolled](ScrollOffset) back, the cells show the scrollback rather than
+/// the history, so they are left alone.
+pub(crate) fn dim_background(
+	filter: Res<BackgroundFilter>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(&History, Option<&Decay>, Ref<DetectedBackground>)>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>
+) {
+	let changed = filter.is_changed()
+		|| scroll.is_changed()
+		|| palette.is_changed()
+		|| panes.iter().any(|(_, _, detected)| detected.is_changed());
+	if !changed || scroll.0 != 0 || (!filter.0 && !filter.is_changed())
+	{
+		return
+	}
+	for (position, color) in &mut cells
+	{
+		let Ok((history, decay, detected)) = panes.get(position.pane) else
+		{
+			continue
+		};
+		let index = AUTOMATON_LENGTH - position.column - 1;
+		let mut new = palette.cell_color(history, decay, *position).0;
+		if filter.0 && detected.cells[position.row][index]
+		{
+			new = blend(new, palette.dead, BACKGROUND_DIMMING);
+		}
+		repaint(color, BackgroundColor(new));
+	}
+}
+
+/// While the [background&#32;filter](BackgroundFilter) is active, show a
+/// [label](BackgroundLabel) beneath the generation counter that gives the
+/// spatial period of the [background](Background) of the [focused](Focused)
+/// pane, along with its temporal period, if the
+/// [active](RuleSchedule::active) rule is elementary. Rewrite the label
+/// whenever the background, the schedule, the focus, or the
+/// [language](Language) changes. Otherwise, despawn the label.
+fn update_background_label(
+	filter: Res<BackgroundFilter>,
+	language: Res<Language>,
+	pane: Query<(Ref<DetectedBackground>, Ref<RuleSchedule>, Ref<Focused>)>,
+	banners: Query<Entity, With<BackgroundBanner>>,
+	mut labels: Query<&mut Text, With<BackgroundLabel>>,
+	mut commands: Commands
+) {
+	if !filter.0
+	{
+		if filter.is_changed()
+		{
+			for entity in &banners
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+		return
+	}
+	let Ok((detected, schedule, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let text = || label(detected.background.as_ref(), &schedule, *language);
+	match labels.get_single_mut()
+	{
+		Ok(mut label) =>
+		{
+			let changed = detected.is_changed()
+				|| schedule.is_changed()
+				|| focused.is_added()
+				|| language.is_changed();
+			if changed
+			{
+				label.sections[0].value = text();
+			}
+		},
+		Err(_) =>
+		{
+			if banners.is_empty()
+			{
+				build_label(text(), &mut commands);
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [banner](BackgroundBanner) beneath the generation counter, in
+/// the upper left corner, holding a [label](BackgroundLabel) with the
+/// specified text.
+fn build_label(text: String, commands: &mut Commands)
+{
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						padding: UiRect::all(Val::Px(8.0)),
+						top: Val::Px(BACKGROUND_LABEL_TOP),
+						left: Val::Px(8.0),
+						..default()
+					},
+					background_color: BackgroundColor(
+						BACKGROUND_LABEL_BACKGROUND
+					),
+					..default()
+				},
+				BackgroundBanner
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					TextBundle::from_section(
+						text,
+						TextStyle {
+							font_size: BACKGROUND_FONT_SIZE,
+							color: BACKGROUND_TEXT_COLOR,
+							..default()
+						}
+					),
+					BackgroundLabel
+				)
+			);
+		});
+}
+
+/// Answer the text of the [label](BackgroundLabel) for the specified
+/// [background](Background), if any, in the specified [language](Language).
+/// The temporal period is given only if the [active](RuleSchedule::active)
+/// rule of the specified [schedule](RuleSchedule) is elementary, and the
+/// background recurs under it.
+fn label(
+	background: Option<&Background>,
+	schedule: &RuleSchedule,
+	language: Language
+) -> String
+{
+	let Some(background) = background else
+	{
+		return language.text(Message::NoBackground).to_string()
+	};
+	let recurrence = match schedule.active()
+	{
+		RuleKind::Wolfram(rule) => background.recurrence(*rule),
+		#[allow(unreachable_patterns)]
+		_ => None
+	};
+	match recurrence
+	{
+		Some(recurrence) => language.format(
+			Message::BackgroundPeriods,
+			&[&background.period(), &recurrence]
+		),
+		None => language.format(
+			Message::BackgroundPeriod,
+			&[&background.period()]
+		)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How far the cells of the [background](Background) are blended toward the
+/// dead color, from `0` for not at all to `1` for entirely.
+const BACKGROUND_DIMMING: f32 = 0.75;
+
+/// The distance between the [label](BackgroundLabel) and the top edge of the
+/// window, in pixels, which keeps it clear of the generation counter.
+const BACKGROUND_LABEL_TOP: f32 = 64.0;
+
+/// The font size of the [label](BackgroundLabel).
+const BACKGROUND_FONT_SIZE: f32 = 18.0;
+
+/// The [color](Color) of the [label](BackgroundLabel).
+const BACKGROUND_TEXT_COLOR: Color = Color::YELLOW;
+
+/// The [color](Color) behind the [label](BackgroundLabel).
+const BACKGROUND_LABEL_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
@@ -0,0 +1,279 @@
+use std::time::Duration;
+
+use bevy::prelude::{
+	App, Entity, Event, EventReader, EventWriter, Input, IntoSystemConfigs,
+	KeyCode, Local, Plugin, Query, Res, ResMut, Resource, Time, Update, With
+};
+
+use crate::automata::{Decay, History, RuleSchedule, Scrollback};
+use crate::ecs::{
+	accept_digit, Announcement, AutomatonRuleBuilder, evolve, Focused, Pane,
+	RuleChanged, ScrollOffset
+};
+use crate::i18n::{Language, Message};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for bookmarks, which capture the complete
+/// state of the [focused](Focused)&#32;[pane](Pane), i.e., its
+/// [schedule](RuleSchedule), [history](History), [scrollback](Scrollback), and
+/// [decay](Decay), so that several prepared scenarios can be visited in turn
+/// during a live presentation, without reloading any files. There are nine
+/// slots, one per digit. Bookmarks last only for the session, and restoring
+/// one is not an [action](crate::ecs::Action), so it is not recorded. Any
+/// plugin can restore a bookmark by sending [RestoreBookmark].
+pub struct BookmarksPlugin;
+
+impl Plugin for BookmarksPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Bookmarks>()
+			.add_event::<RestoreBookmark>()
+			.add_systems(Update, maybe_bookmark.after(accept_digit))
+			.add_systems(
+				Update,
+				restore_bookmarks.after(maybe_bookmark).before(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The bookmark slots, numbered from `1` to `9`, each of which may hold a
+/// [bookmark](Bookmark).
+#[derive(Default, Resource)]
+pub struct Bookmarks([Option<Bookmark>; 9]);
+
+impl Bookmarks
+{
+	/// Answer the [bookmark](Bookmark) in the specified slot, if any.
+	pub(crate) fn get(&self, slot: usize) -> Option<&Bookmark>
+	{
+		self.0.get(slot.checked_sub(1)?)?.as_ref()
+	}
+
+	/// Empty the specified slot, answering the [bookmark](Bookmark) that it
+	/// held, if any.
+	pub(crate) fn remove(&mut self, slot: usize) -> Option<Bookmark>
+	{
+		self.0.get_mut(slot.checked_sub(1)?)?.take()
+	}
+
+	/// Answer an [iterator](Iterator) over the occupied slots, in order, each
+	/// with its [bookmark](Bookmark).
+	pub(crate) fn iter(&self) -> impl Iterator<Item=(usize, &Bookmark)>
+	{
+		self.0.iter()
+			.enumerate()
+			.filter_map(|(index, bookmark)| {
+				Some((index + 1, bookmark.as_ref()?))
+			})
+	}
+}
+
+/// A snapshot of the complete state of a [pane](Pane).
+#[derive(Clone, Debug)]
+pub(crate) struct Bookmark
+{
+	/// The [schedule](RuleSchedule) of the pane.
+	schedule: RuleSchedule,
+
+	/// The [history](History) of the pane.
+	history: History,
+
+	/// The [scrollback](Scrollback) of the pane.
+	scrollback: Scrollback,
+
+	/// The [decay](Decay) of the pane, if it has any.
+	decay: Option<Decay>
+}
+
+impl Bookmark
+{
+	/// Answer the [history](History) of the bookmarked pane.
+	pub(crate) fn history(&self) -> &History
+	{
+		&self.history
+	}
+
+	/// Answer the [scrollback](Scrollback) of the bookmarked pane.
+	pub(crate) fn scrollback(&self) -> &Scrollback
+	{
+		&self.scrollback
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Events.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A request to restore the [focused](Focused)&#32;[pane](Pane) from the
+/// [bookmark](Bookmark) in the specified slot.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Event)]
+pub(crate) struct RestoreBookmark(pub(crate) usize);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Save and restore [bookmarks](Bookmarks).
+///
+/// * On control and a digit from `1` to `9`, save the
+///   [focused](Focused)&#32;[pane](Pane) to the corresponding slot, replacing
+///   whatever it held, and announce the save in the toast.
+/// * On holding a digit from `1` to `9` for [BOOKMARK_HOLD], request that the
+///   focused pane be [restored](RestoreBookmark) from the corresponding slot,
+///   and discard the digit from the [rule&#32;entry](AutomatonRuleBuilder)
+///   that it began. A digit that continues a rule entry already under way is
+///   only a digit, however long it is held, so that the entry survives.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn maybe_bookmark(
+	time: Res<Time>,
+	keys: Res<Input<KeyCode>>,
+	language: Res<Language>,
+	mut bookmarks: ResMut<Bookmarks>,
+	mut builder: ResMut<AutomatonRuleBuilder>,
+	pane: Query<
+		(&RuleSchedule, &History, &Scrollback, Option<&Decay>),
+		(With<Pane>, With<Focused>)
+	>,
+	mut restorations: EventWriter<RestoreBookmark>,
+	mut announcements: EventWriter<Announcement>,
+	mut held: Local<[Option<Duration>; 9]>
+) {
+	let control =
+		keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+	for (index, &key) in BOOKMARK_KEYS.iter().enumerate()
+	{
+		let slot = index + 1;
+		if keys.just_pressed(key) && control
+		{
+			let Ok((schedule, history, scrollback, decay)) = pane.get_single()
+			else
+			{
+				continue
+			};
+			bookmarks.0[index] = Some(Bookmark {
+				schedule: schedule.clone(),
+				history: history.clone(),
+				scrollback: scrollback.clone(),
+				decay: decay.cloned()
+			});
+			announcements.send(Announcement(
+				language.format(Message::BookmarkSaved, &[&slot])
+			));
+			continue
+		}
+		// Time the hold of the digit, but only while the digit is the whole of
+		// the rule entry.
+		let digit = slot.to_string();
+		let alone = builder.buffered_input() == Some(digit.as_str());
+		held[index] = match (keys.just_pressed(key), keys.pressed(key))
+		{
+			_ if !alone => None,
+			(true, _) => Some(Duration::ZERO),
+			(false, true) => held[index].map(|hold| hold + time.delta()),
+			(false, false) => None
+		};
+		if held[index].is_some_and(|hold| hold >= BOOKMARK_HOLD)
+		{
+			// Restore only once per hold.
+			held[index] = None;
+			builder.cancel();
+			restorations.send(RestoreBookmark(slot));
+		}
+	}
+}
+
+/// Restore the [focused](Focused)&#32;[pane](Pane) from each requested
+/// [bookmark](RestoreBookmark), and announce the outcome in the toast. The
+/// restored schedule is announced as a [rule&#32;change](RuleChanged), and the
+/// view returns to the [newest](History::newest) generation.
+pub(crate) fn restore_bookmarks(
+	mut restorations: EventReader<RestoreBookmark>,
+	language: Res<Language>,
+	bookmarks: Res<Bookmarks>,
+	mut scroll: ResMut<ScrollOffset>,
+	mut pane: Query<
+		(
+			Entity,
+			&mut RuleSchedule,
+			&mut History,
+			&mut Scrollback,
+			Option<&mut Decay>
+		),
+		(With<Pane>, With<Focused>)
+	>,
+	mut rule_changes: EventWriter<RuleChanged>,
+	mut announcements: EventWriter<Announcement>
+) {
+	for &RestoreBookmark(slot) in restorations.read()
+	{
+		let Some(bookmark) = bookmarks.get(slot)
+		else
+		{
+			announcements.send(Announcement(
+				language.format(Message::NoBookmark, &[&slot])
+			));
+			continue
+		};
+		let Ok((entity, mut schedule, mut history, mut scrollback, decay)) =
+			pane.get_single_mut()
+		else
+		{
+			continue
+		};
+		let old = schedule.clone();
+		*schedule = bookmark.schedule.clone();
+		*history = bookmark.history.clone();
+		*scrollback = bookmark.scrollback.clone();
+		match (decay, &bookmark.decay)
+		{
+			(Some(mut decay), Some(saved)) => *decay = saved.clone(),
+			(Some(mut decay), None) => decay.restart(),
+			(None, _) => {}
+		}
+		// paint_cells repaints the cells from the restored history.
+		scroll.set_if_neq(ScrollOffset(0));
+		if *schedule != old
+		{
+			rule_changes.send(RuleChanged {
+				pane: entity,
+				old,
+				new: schedule.clone()
+			});
+		}
+		announcements.send(Announcement(
+			language.format(Message::BookmarkRestored, &[&slot])
+		));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [keys](KeyCode) of the bookmark slots, in order. These are the digits
+/// of the number row, which also enter rules, so they cannot be rebound.
+const BOOKMARK_KEYS: [KeyCode; 9] = [
+	KeyCode::Key1,
+	KeyCode::Key2,
+	KeyCode::Key3,
+	KeyCode::Key4,
+	KeyCode::Key5,
+	KeyCode::Key6,
+	KeyCode::Key7,
+	KeyCode::Key8,
+	KeyCode::Key9
+];
+
+/// How long a digit must be held to restore a bookmark. This must be shorter
+/// than the grace period for rule entry, so that the restoration can discard
+/// the digit before it becomes a rule.
+const BOOKMARK_HOLD: Duration = Duration::from_millis(450);
@@ -0,0 +1,312 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::str::FromStr;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventReader, IntoSystemConfigs, Local, Plugin, Query, Res, Resource,
+	Update
+};
+use serde_json::json;
+use tungstenite::{accept, Message, WebSocket};
+
+use crate::automata::{Automaton, AUTOMATON_LENGTH, RuleSchedule, Scrollback};
+use crate::ecs::{apply_actions, evolve, GenerationEvolved, Pane, RuleChanged};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for publishing the simulation over
+/// WebSocket, so that dashboards or a second display can mirror it live. Every
+/// [new&#32;generation](GenerationEvolved) and every
+/// [rule&#32;change](RuleChanged) of every [pane](Pane) is sent to every
+/// connected client. A client that connects midway first receives the latest
+/// generation and rules of every pane. Insert a [Broadcaster] before adding
+/// the plugin. Available only with the `broadcast` feature.
+pub struct BroadcastPlugin;
+
+impl Plugin for BroadcastPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(Update, publish.after(apply_actions).after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The encoding of each new generation on the wire. Rule changes are always
+/// sent as JSON.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BroadcastFormat
+{
+	/// A text frame that holds a JSON object, e.g.,
+	/// `{"type":"generation","pane":0,"index":12,"cells":"0x2c…"}`, where the
+	/// cells are [hexadecimal&#32;digits](Automaton::to_hex).
+	#[default]
+	Json,
+
+	/// A binary frame that holds the pane as a little-endian `u32`, the index
+	/// of the generation as a little-endian `u64`, then one byte per cell,
+	/// `0` or `1`, from left to right.
+	Binary
+}
+
+impl FromStr for BroadcastFormat
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"json" => Ok(BroadcastFormat::Json),
+			"binary" => Ok(BroadcastFormat::Binary),
+			_ => Err(format!(
+				"unknown broadcast format '{}': expected json or binary",
+				s
+			))
+		}
+	}
+}
+
+/// The publisher of the simulation. Background threads accept clients and
+/// send them messages, so that neither a quiet listener nor a slow client
+/// ever stalls the frame.
+#[derive(Resource)]
+pub struct Broadcaster
+{
+	/// The queue of messages awaiting delivery to every client.
+	outbox: Mutex<Sender<Dispatch>>,
+
+	/// The encoding of each new generation.
+	format: BroadcastFormat
+}
+
+impl Broadcaster
+{
+	/// Listen for WebSocket clients at the specified URL, e.g.,
+	/// `ws://0.0.0.0:9000`, and begin delivering messages to them in the
+	/// specified [format](BroadcastFormat). The scheme may be omitted. Secure
+	/// WebSockets are not supported.
+	pub fn bind(url: &str, format: BroadcastFormat) -> io::Result<Self>
+	{
+		if url.starts_with("wss://")
+		{
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				"secure WebSockets are not supported"
+			))
+		}
+		let address = url.strip_prefix("ws://").unwrap_or(url);
+		let listener = TcpListener::bind(address.trim_end_matches('/'))?;
+		let audience = Arc::new(Mutex::new(Audience::default()));
+		let admissions = audience.clone();
+		thread::spawn(move || {
+			for stream in listener.incoming()
+			{
+				match stream.map_err(|e| e.to_string()).and_then(handshake)
+				{
+					Ok(client) => admissions.lock().unwrap().admit(client),
+					Err(e) => warn!("Cannot accept broadcast client: {}", e)
+				}
+			}
+		});
+		let (outbox, inbox) = channel::<Dispatch>();
+		thread::spawn(move || {
+			for dispatch in inbox
+			{
+				audience.lock().unwrap().deliver(dispatch);
+			}
+		});
+		Ok(Self { outbox: Mutex::new(outbox), format })
+	}
+
+	/// Queue the specified message for delivery to every client.
+	fn send(&self, pane: usize, topic: Topic, message: Message)
+	{
+		// The delivery thread never finishes, so the queue never closes.
+		let _ = self.outbox.lock().unwrap().send(Dispatch {
+			pane,
+			topic,
+			message
+		});
+	}
+
+	/// Queue the specified generation of the specified pane for delivery to
+	/// every client.
+	fn send_generation(&self, pane: usize, index: usize, automaton: &Automaton)
+	{
+		let message = match self.format
+		{
+			BroadcastFormat::Json => Message::Text(json!({
+				"type": "generation",
+				"pane": pane,
+				"index": index,
+				"cells": automaton.to_hex()
+			}).to_string()),
+			BroadcastFormat::Binary =>
+			{
+				let mut frame = Vec::with_capacity(12 + AUTOMATON_LENGTH);
+				frame.extend_from_slice(&(pane as u32).to_le_bytes());
+				frame.extend_from_slice(&(index as u64).to_le_bytes());
+				frame.extend(
+					(0 .. AUTOMATON_LENGTH)
+						.rev()
+						.map(|i| automaton[i] as u8)
+				);
+				Message::Binary(frame)
+			}
+		};
+		self.send(pane, Topic::Generation, message);
+	}
+
+	/// Queue the specified [schedule](RuleSchedule) of the specified pane for
+	/// delivery to every client, as a JSON object, e.g.,
+	/// `{"type":"rules","pane":0,"rules":["30","110"],"active":1}`.
+	fn send_rules(&self, pane: usize, schedule: &RuleSchedule)
+	{
+		let rules = schedule.iter()
+			.map(|rule| rule.abbreviation())
+			.collect::<Vec<_>>();
+		let message = Message::Text(json!({
+			"type": "rules",
+			"pane": pane,
+			"rules": rules,
+			"active": schedule.active_index()
+		}).to_string());
+		self.send(pane, Topic::Rules, message);
+	}
+}
+
+/// The connected clients, and the latest message on every
+/// [topic](Topic) of every pane, with which to greet newcomers.
+#[derive(Default)]
+struct Audience
+{
+	/// The connected clients.
+	clients: Vec<WebSocket<TcpStream>>,
+
+	/// The latest message on every topic of every pane.
+	latest: BTreeMap<(usize, Topic), Message>
+}
+
+impl Audience
+{
+	/// Greet the specified client with the latest messages, then add it to
+	/// the audience, unless it has already gone away.
+	fn admit(&mut self, mut client: WebSocket<TcpStream>)
+	{
+		let greeted = self.latest.values()
+			.all(|message| client.send(message.clone()).is_ok());
+		if greeted
+		{
+			self.clients.push(client);
+		}
+	}
+
+	/// Send the specified [dispatch](Dispatch) to every client, dropping the
+	/// clients that have gone away or fallen too far behind.
+	fn deliver(&mut self, dispatch: Dispatch)
+	{
+		self.clients.retain_mut(|client| {
+			client.send(dispatch.message.clone()).is_ok()
+		});
+		self.latest.insert((dispatch.pane, dispatch.topic), dispatch.message);
+	}
+}
+
+/// A message for every client, concerning a particular pane.
+struct Dispatch
+{
+	/// The ordinal of the [pane](Pane) that the message concerns.
+	pane: usize,
+
+	/// The subject of the message.
+	topic: Topic,
+
+	/// The message itself.
+	message: Message
+}
+
+/// The subject of a [dispatch](Dispatch). Each new message on a topic
+/// supersedes the last for the purpose of greeting newcomers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Topic
+{
+	/// The rules of the pane.
+	Rules,
+
+	/// The newest generation of the pane.
+	Generation
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Publish every [new&#32;generation](GenerationEvolved) and every
+/// [rule&#32;change](RuleChanged) through the [Broadcaster]. The first time
+/// through, publish the newest generation and the rules of every
+/// [pane](Pane), so that clients can mirror the simulation from the start.
+fn publish(
+	broadcaster: Res<Broadcaster>,
+	panes: Query<(&Pane, &Scrollback, &RuleSchedule)>,
+	mut evolved: EventReader<GenerationEvolved>,
+	mut rule_changes: EventReader<RuleChanged>,
+	mut started: Local<bool>
+) {
+	if !*started
+	{
+		*started = true;
+		for (pane, scrollback, schedule) in &panes
+		{
+			let (index, automaton, _) = scrollback.generation(0).unwrap();
+			broadcaster.send_rules(pane.0, schedule);
+			broadcaster.send_generation(pane.0, index, automaton);
+		}
+	}
+	for change in rule_changes.read()
+	{
+		if let Ok((pane, ..)) = panes.get(change.pane)
+		{
+			broadcaster.send_rules(pane.0, &change.new);
+		}
+	}
+	for event in evolved.read()
+	{
+		if let Ok((pane, ..)) = panes.get(event.pane)
+		{
+			broadcaster.send_generation(pane.0, event.index, &event.automaton);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Complete the WebSocket handshake with a newly connected client. Writes
+/// that stall for longer than [WRITE_TIMEOUT] fail, so that a client that
+/// stops reading is dropped rather than stalling everyone else.
+fn handshake(stream: TcpStream) -> Result<WebSocket<TcpStream>, String>
+{
+	stream.set_write_timeout(Some(WRITE_TIMEOUT))
+		.map_err(|e| e.to_string())?;
+	accept(stream).map_err(|e| e.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How long to wait for a client to accept a message before dropping it.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
@@ -0,0 +1,50 @@
+// Renders a single cell according to its style. The style numbers match the
+// discriminants of CellStyle in cell_style.rs.
+
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct Cell
+{
+	color: vec4<f32>,
+	background: vec4<f32>,
+	style: u32,
+};
+
+@group(1) @binding(0) var<uniform> cell: Cell;
+
+const GLOW: u32 = 1u;
+const ROUNDED: u32 = 2u;
+const SCANLINES: u32 = 3u;
+
+// The radius of a rounded corner, as a fraction of half the cell.
+const CORNER_RADIUS: f32 = 0.4;
+
+// The brightness of every other row of pixels under scanlines.
+const SCANLINE_BRIGHTNESS: f32 = 0.7;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32>
+{
+	// The offset from the center of the cell, in [-1,1] along each axis.
+	let offset = in.uv * 2.0 - 1.0;
+	if cell.style == GLOW
+	{
+		// Brightest at the center, dissolving into the background at the
+		// edges.
+		let falloff = 1.0 - smoothstep(0.2, 1.2, length(offset));
+		return mix(cell.background, cell.color, falloff);
+	}
+	if cell.style == ROUNDED
+	{
+		let corner =
+			max(abs(offset) - vec2<f32>(1.0 - CORNER_RADIUS), vec2<f32>(0.0));
+		let edge = length(corner) - CORNER_RADIUS;
+		let inside = 1.0 - smoothstep(-0.05, 0.05, edge);
+		return mix(cell.background, cell.color, inside);
+	}
+	if cell.style == SCANLINES && (u32(in.position.y) & 1u) == 1u
+	{
+		return vec4<f32>(cell.color.rgb * SCANLINE_BRIGHTNESS, cell.color.a);
+	}
+	return cell.color;
+}
@@ -0,0 +1,248 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::str::FromStr;
+
+use bevy::asset::{Asset, load_internal_asset};
+use bevy::math::Vec4;
+use bevy::prelude::{
+	Added, App, Assets, BackgroundColor, BuildChildren, Children, Color,
+	Commands, default, DetectChanges, Entity, Handle, MaterialNodeBundle,
+	Plugin, PositionType, PostUpdate, Query, Ref, Res, ResMut, Resource,
+	Shader, Style, Update, Val, With
+};
+use bevy::reflect::TypePath;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+use bevy::ui::{UiMaterial, UiMaterialPlugin};
+
+use crate::ecs::{CellPosition, Palette};
+use crate::geometry::CellShape;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for rendering square cells through a
+/// [custom&#32;shader](CellMaterial), so that effects like glows, rounded
+/// corners, or scanlines cost no more than a flat color. The [CellStyle]
+/// resource chooses the effect. The [BackgroundColor] of each cell remains the
+/// authority on its color, so every other system paints cells exactly as it
+/// would without a shader; this plugin merely mirrors those colors into the
+/// [materials](CellMaterial).
+pub struct CellStylePlugin;
+
+impl Plugin for CellStylePlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		load_internal_asset!(
+			app,
+			CELL_SHADER_HANDLE,
+			"cell.wgsl",
+			Shader::from_wgsl
+		);
+		app
+			.add_plugins(UiMaterialPlugin::<CellMaterial>::default())
+			.init_resource::<CellStyle>()
+			.add_systems(Update, attach_materials)
+			.add_systems(PostUpdate, sync_materials);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The built-in styles in which the [shader](CellMaterial) can render square
+/// cells. Other [shapes](CellShape) are always rendered flat.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum CellStyle
+{
+	/// Plain color, rendered without a custom shader.
+	#[default]
+	Flat = 0,
+
+	/// Soft dots that are brightest at their centers and dissolve into the
+	/// dead color at their edges.
+	Glow = 1,
+
+	/// Squares with rounded corners.
+	Rounded = 2,
+
+	/// Plain color, darkened on every other row of pixels, like the display of
+	/// an old monitor.
+	Scanlines = 3
+}
+
+impl FromStr for CellStyle
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"flat" => Ok(CellStyle::Flat),
+			"glow" => Ok(CellStyle::Glow),
+			"rounded" => Ok(CellStyle::Rounded),
+			"scanlines" => Ok(CellStyle::Scanlines),
+			_ => Err(format!(
+				"unknown style '{}': expected flat, glow, rounded, or \
+				scanlines",
+				s
+			))
+		}
+	}
+}
+
+impl Display for CellStyle
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			CellStyle::Flat => "flat",
+			CellStyle::Glow => "glow",
+			CellStyle::Rounded => "rounded",
+			CellStyle::Scanlines => "scanlines"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Materials.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [material](UiMaterial) that renders a single cell in some
+/// [style](CellStyle). Every cell has its own material, because every cell
+/// has its own color.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub(crate) struct CellMaterial
+{
+	/// The parameters of the [shader](CELL_SHADER_HANDLE).
+	#[uniform(0)]
+	cell: CellUniform
+}
+
+impl UiMaterial for CellMaterial
+{
+	fn fragment_shader() -> ShaderRef
+	{
+		CELL_SHADER_HANDLE.into()
+	}
+}
+
+/// The uniform parameters of the [shader](CELL_SHADER_HANDLE). The layout must
+/// match the `Cell` structure in `cell.wgsl`.
+#[derive(Copy, Clone, Debug, ShaderType)]
+struct CellUniform
+{
+	/// The color of the cell, in linear RGBA.
+	color: Vec4,
+
+	/// The dead color, in linear RGBA, against which the cell is shaped.
+	background: Vec4,
+
+	/// The [style](CellStyle) of the cell, as its discriminant.
+	style: u32
+}
+
+impl CellUniform
+{
+	/// Construct the parameters for a cell of the specified color and
+	/// [style](CellStyle), shaped against the dead color of the specified
+	/// [palette](Palette).
+	fn new(color: Color, palette: &Palette, style: CellStyle) -> Self
+	{
+		Self {
+			color: Vec4::from(color.as_linear_rgba_f32()),
+			background: Vec4::from(palette.dead.as_linear_rgba_f32()),
+			style: style as u32
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Systems.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Unless the [style](CellStyle) is [flat](CellStyle::Flat), give each newly
+/// built square cell a [material](CellMaterial) of its own, rendered by a node
+/// that covers the cell. The node becomes the first child of the cell, so
+/// that the [overlay](crate::ecs::CellOverlay) still draws atop it.
+fn attach_materials(
+	style: Res<CellStyle>,
+	shape: Res<CellShape>,
+	palette: Res<Palette>,
+	mut materials: ResMut<Assets<CellMaterial>>,
+	mut commands: Commands,
+	cells: Query<(Entity, &BackgroundColor), Added<CellPosition>>
+) {
+	if *style == CellStyle::Flat || *shape != CellShape::Square
+	{
+		return
+	}
+	for (entity, color) in &cells
+	{
+		let material = materials.add(CellMaterial {
+			cell: CellUniform::new(color.0, &palette, *style)
+		});
+		let node = commands
+			.spawn(MaterialNodeBundle {
+				style: Style {
+					position_type: PositionType::Absolute,
+					left: Val::Px(0.0),
+					right: Val::Px(0.0),
+					top: Val::Px(0.0),
+					bottom: Val::Px(0.0),
+					..default()
+				},
+				material,
+				..default()
+			})
+			.id();
+		commands.entity(entity).insert_children(0, &[node]);
+	}
+}
+
+/// Mirror the [color](BackgroundColor) of every styled cell into its
+/// [material](CellMaterial) whenever either the color or the
+/// [palette](Palette) changes. This runs after every system that paints
+/// cells, so that no change is missed.
+fn sync_materials(
+	style: Res<CellStyle>,
+	palette: Res<Palette>,
+	mut materials: ResMut<Assets<CellMaterial>>,
+	cells: Query<(Ref<BackgroundColor>, &Children), With<CellPosition>>,
+	handles: Query<&Handle<CellMaterial>>
+) {
+	if *style == CellStyle::Flat
+	{
+		return
+	}
+	for (color, children) in &cells
+	{
+		if !color.is_changed() && !palette.is_changed()
+		{
+			continue
+		}
+		let Some(material) = children.first()
+			.and_then(|&child| handles.get(child).ok())
+			.and_then(|handle| materials.get_mut(handle))
+		else
+		{
+			continue
+		};
+		material.cell = CellUniform::new(color.0, &palette, *style);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [handle](Handle) of the [shader](Shader) that renders each
+/// [cell](CellMaterial), which is embedded in the executable so that it needs
+/// no asset directory.
+const CELL_SHADER_HANDLE: Handle<Shader> =
+	Handle::weak_from_u128(0x5f3c_2a8e_91d4_4b7a_b6e0_7c1f_9d83_2e45);
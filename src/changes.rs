@@ -0,0 +1,165 @@
+use bevy::prelude::{
+	App, BackgroundColor, Color, DetectChanges, EventWriter, Input,
+	IntoSystemConfigs, KeyCode, Plugin, Query, Ref, Res, ResMut, Resource,
+	Update, With
+};
+
+use crate::automata::{Decay, History, Scrollback};
+use crate::background::dim_background;
+use crate::ecs::{
+	Announcement, apply_actions, CellPosition, evolve, Palette, repaint,
+	ScrollOffset, update_palette, update_scan, update_scroll
+};
+use crate::effects::blend;
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for change highlighting, which tints every
+/// cell that changed relative to the previous generation, i.e., the row above
+/// it: births in one color, and deaths in another. Cells that did not change
+/// are muted, so that the activity fronts of a rule stand out against its
+/// static regions. The [HighlightChanges] resource governs whether the
+/// highlighting is active.
+pub struct ChangesPlugin;
+
+impl Plugin for ChangesPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<HighlightChanges>()
+			.add_systems(Update, maybe_toggle_changes)
+			.add_systems(
+				Update,
+				highlight_changes
+					.after(maybe_toggle_changes)
+					.after(apply_actions)
+					.after(evolve)
+					.after(update_scroll)
+					.after(update_palette)
+					.after(update_scan)
+					.after(dim_background)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the cells that changed since the previous generation are tinted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct HighlightChanges(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F7`, toggle [change&#32;highlighting](HighlightChanges), and announce
+/// the new setting.
+fn maybe_toggle_changes(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut highlight: ResMut<HighlightChanges>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.changes)
+	{
+		highlight.0 = !highlight.0;
+		let message = match highlight.0
+		{
+			true => Message::ChangesOn,
+			false => Message::ChangesOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While [change&#32;highlighting](HighlightChanges) is active, recolor every
+/// cell of every [pane](crate::ecs::Pane) whose [history](History) changed:
+/// a birth in the pressed color, a death in the difference color, and any
+/// other cell blended toward the dead color. Once highlighting is deactivated,
+/// restore every cell. The oldest row has no previous generation, so it is
+/// muted throughout. Highlighting takes precedence over the
+/// [background&#32;filter](crate::background::BackgroundFilter). While the
+/// view is [scrolled](ScrollOffset) back, the cells show the scrollback rather
+/// than the history, so they are left alone. Difference panes have no
+/// [scrollback](Scrollback), so they are left alone too.
+fn highlight_changes(
+	highlight: Res<HighlightChanges>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(Ref<History>, Option<&Decay>), With<Scrollback>>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>
+) {
+	if scroll.0 != 0 || (!highlight.0 && !highlight.is_changed())
+	{
+		return
+	}
+	let all = highlight.is_changed()
+		|| scroll.is_changed()
+		|| palette.is_changed();
+	if !all && !panes.iter().any(|(history, _)| history.is_changed())
+	{
+		return
+	}
+	for (position, color) in &mut cells
+	{
+		let Ok((history, decay)) = panes.get(position.pane) else
+		{
+			continue
+		};
+		if !all && !history.is_changed()
+		{
+			continue
+		}
+		let mut new = palette.cell_color(&history, decay, *position).0;
+		if highlight.0
+		{
+			new = change_color(&history, *position, new, &palette);
+		}
+		repaint(color, BackgroundColor(new));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the highlighted [color](Color) of the specified cell of the
+/// specified [history](History), whose ordinary color is `base`: the pressed
+/// color if the cell was born since the previous generation, the difference
+/// color if it died, and otherwise the ordinary color, muted.
+fn change_color(
+	history: &History,
+	position: CellPosition,
+	base: Color,
+	palette: &Palette
+) -> Color
+{
+	let live = history[position];
+	let previous = match position.row
+	{
+		0 => live,
+		row => history[CellPosition { row: row - 1, ..position }]
+	};
+	match (previous, live)
+	{
+		(false, true) => palette.pressed,
+		(true, false) => palette.difference,
+		_ => blend(base, palette.dead, CHANGES_MUTING)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How far the cells that did not change are blended toward the dead color,
+/// from `0` for not at all to `1` for entirely.
+const CHANGES_MUTING: f32 = 0.6;
@@ -0,0 +1,378 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, DetectChangesMut, Entity, EventWriter, Input, IntoSystemConfigs,
+	KeyCode, Plugin, Query, Res, ResMut, Resource, Update, With
+};
+
+use crate::automata::{
+	Automaton, AUTOMATON_HISTORY, Decay, History, RuleSchedule, Scrollback
+};
+use crate::ecs::{
+	Announcement, AutomatonRuleBuilder, evolve, Focused, Pane, RuleChanged,
+	ScrollOffset
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for checkpoints, which let very long runs
+/// jump back in time. Every so many generations, the state of every
+/// [pane](Pane), i.e., its [schedule](RuleSchedule), [history](History), and
+/// [decay](Decay), is captured automatically. On `Home`, the
+/// [focused](Focused) pane jumps back to its previous checkpoint, and its
+/// [scrollback](Scrollback) rewinds to match. Unlike the scrollback, which may
+/// be capped, checkpoints reach back to the beginning of the run: the newest
+/// [MAX_CHECKPOINTS_IN_MEMORY] of each pane stay in memory, and older ones are
+/// spilled to disk, if a directory was given, or forgotten otherwise. Like
+/// [bookmarks](crate::bookmarks), restoring a checkpoint is not an
+/// [action](crate::ecs::Action), so it is not recorded.
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Checkpoints>()
+			.add_systems(Update, maybe_restore_checkpoint.before(evolve))
+			.add_systems(Update, take_checkpoints.after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [checkpoints](Checkpoint) of every [pane](Pane), along with how often
+/// to take them and where to spill them.
+#[derive(Resource)]
+pub struct Checkpoints
+{
+	/// The number of generations between checkpoints, or `0` to take none.
+	interval: usize,
+
+	/// The directory into which checkpoints are spilled once they no longer
+	/// fit in memory, if any.
+	spill: Option<PathBuf>,
+
+	/// The checkpoints of each pane, given as an entity.
+	panes: HashMap<Entity, PaneCheckpoints>,
+
+	/// The number of checkpoints spilled so far, which names the next file.
+	spilled: usize
+}
+
+impl Checkpoints
+{
+	/// Construct an empty [Checkpoints] that checkpoints every pane every
+	/// `interval` generations, or never, if `interval` is `0`. If a directory
+	/// is specified, then spill checkpoints into it once they no longer fit in
+	/// memory; the directory must already exist.
+	pub fn new(interval: usize, spill: Option<PathBuf>) -> Self
+	{
+		Self { interval, spill, panes: HashMap::new(), spilled: 0 }
+	}
+
+	/// Determine whether the specified pane is due for a checkpoint at the
+	/// generation with the specified index: either it has none yet, or the
+	/// evolution has entered another interval since its newest checkpoint. A
+	/// pane whose evolution started over is therefore checkpointed at once.
+	fn is_due(&self, pane: Entity, index: usize) -> bool
+	{
+		if self.interval == 0
+		{
+			return false
+		}
+		match self.panes.get(&pane).and_then(|pane| pane.memory.back())
+		{
+			Some(newest) =>
+				newest.index / self.interval != index / self.interval,
+			None => true
+		}
+	}
+
+	/// Keep the specified [checkpoint](Checkpoint) of the specified pane. If
+	/// the pane then holds too many checkpoints in memory, then spill the
+	/// oldest, or forget it if there is nowhere to spill it.
+	fn take(&mut self, pane: Entity, checkpoint: Checkpoint)
+	{
+		let checkpoints = self.panes.entry(pane).or_default();
+		checkpoints.memory.push_back(checkpoint);
+		if checkpoints.memory.len() <= MAX_CHECKPOINTS_IN_MEMORY
+		{
+			return
+		}
+		let oldest = checkpoints.memory.pop_front().unwrap();
+		let Some(ref directory) = self.spill else { return };
+		let path = directory.join(format!("checkpoint-{}.bin", self.spilled));
+		self.spilled += 1;
+		match fs::write(&path, encode_history(&oldest.history))
+		{
+			Ok(()) => checkpoints.disk.push(SpilledCheckpoint {
+				index: oldest.index,
+				schedule: oldest.schedule,
+				path
+			}),
+			Err(e) =>
+				warn!("Cannot spill checkpoint to {}: {}", path.display(), e)
+		}
+	}
+
+	/// Answer the previous [checkpoint](Checkpoint) of the specified pane,
+	/// whose newest generation is the specified [automaton](Automaton), at
+	/// the specified index. If the pane already stands at its newest
+	/// checkpoint, e.g., because it was just restored, then forget that
+	/// checkpoint in favor of the one before, so that repeated restorations
+	/// walk ever further back.
+	fn previous(
+		&mut self,
+		pane: Entity,
+		index: usize,
+		newest: &Automaton
+	) -> Option<Checkpoint>
+	{
+		let checkpoints = self.panes.get_mut(&pane)?;
+		checkpoints.reload();
+		let current = checkpoints.memory.back()?;
+		if current.index == index
+			&& current.history.newest() == newest
+			&& (checkpoints.memory.len() > 1 || !checkpoints.disk.is_empty())
+		{
+			checkpoints.memory.pop_back();
+			checkpoints.reload();
+		}
+		checkpoints.memory.back().cloned()
+	}
+}
+
+impl Default for Checkpoints
+{
+	fn default() -> Self
+	{
+		Self::new(DEFAULT_CHECKPOINT_INTERVAL, None)
+	}
+}
+
+/// The [checkpoints](Checkpoint) of a single [pane](Pane).
+#[derive(Default)]
+struct PaneCheckpoints
+{
+	/// The newest checkpoints, from oldest to newest.
+	memory: VecDeque<Checkpoint>,
+
+	/// The older checkpoints, spilled to disk, from oldest to newest.
+	disk: Vec<SpilledCheckpoint>
+}
+
+impl PaneCheckpoints
+{
+	/// If no checkpoints remain in memory, then reload the newest
+	/// [spilled](SpilledCheckpoint) checkpoint, deleting its file. Skip any
+	/// file that cannot be read.
+	fn reload(&mut self)
+	{
+		while self.memory.is_empty()
+		{
+			let Some(spilled) = self.disk.pop() else { return };
+			let history = fs::read(&spilled.path)
+				.map_err(|e| e.to_string())
+				.and_then(|bytes| decode_history(&bytes));
+			let _ = fs::remove_file(&spilled.path);
+			match history
+			{
+				Ok(history) => self.memory.push_back(Checkpoint {
+					index: spilled.index,
+					schedule: spilled.schedule,
+					history,
+					decay: None
+				}),
+				Err(e) => warn!(
+					"Cannot reload checkpoint from {}: {}",
+					spilled.path.display(),
+					e
+				)
+			}
+		}
+	}
+}
+
+/// A snapshot of the state of a [pane](Pane) at some generation.
+#[derive(Clone, Debug)]
+struct Checkpoint
+{
+	/// The index of the generation, counting from the first generation.
+	index: usize,
+
+	/// The [schedule](RuleSchedule) of the pane.
+	schedule: RuleSchedule,
+
+	/// The [history](History) of the pane.
+	history: History,
+
+	/// The [decay](Decay) of the pane, if it has any. Decay isn't spilled, so
+	/// it restarts upon restoring a checkpoint that was.
+	decay: Option<Decay>
+}
+
+/// A [checkpoint](Checkpoint) whose [history](History) was spilled to disk.
+/// The [schedule](RuleSchedule) stays in memory, since rules defined by
+/// scripts or modules cannot be written out.
+struct SpilledCheckpoint
+{
+	/// The index of the generation, counting from the first generation.
+	index: usize,
+
+	/// The [schedule](RuleSchedule) of the pane.
+	schedule: RuleSchedule,
+
+	/// The file that holds the [history](History) of the pane.
+	path: PathBuf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Take a [checkpoint](Checkpoint) of every [pane](Pane) that is
+/// [due](Checkpoints::is_due) for one. Difference panes have no scrollback,
+/// and thus no checkpoints.
+fn take_checkpoints(
+	mut checkpoints: ResMut<Checkpoints>,
+	panes: Query<
+		(Entity, &RuleSchedule, &History, &Scrollback, Option<&Decay>),
+		With<Pane>
+	>
+) {
+	for (entity, schedule, history, scrollback, decay) in &panes
+	{
+		let (index, ..) = scrollback.generation(0).unwrap();
+		if checkpoints.is_due(entity, index)
+		{
+			checkpoints.take(entity, Checkpoint {
+				index,
+				schedule: schedule.clone(),
+				history: history.clone(),
+				decay: decay.cloned()
+			});
+		}
+	}
+}
+
+/// On `Home`, restore the [focused](Focused)&#32;[pane](Pane) from its
+/// [previous](Checkpoints::previous)&#32;[checkpoint](Checkpoint), and
+/// announce the outcome in the toast. The restored schedule is announced as a
+/// [rule&#32;change](RuleChanged), and the view returns to the
+/// [newest](History::newest) generation.
+#[allow(clippy::too_many_arguments)]
+fn maybe_restore_checkpoint(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
+	mut checkpoints: ResMut<Checkpoints>,
+	mut scroll: ResMut<ScrollOffset>,
+	mut pane: Query<
+		(
+			Entity,
+			&mut RuleSchedule,
+			&mut History,
+			&mut Scrollback,
+			Option<&mut Decay>
+		),
+		(With<Pane>, With<Focused>)
+	>,
+	mut rule_changes: EventWriter<RuleChanged>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.checkpoint)
+		|| builder.claims(bindings.checkpoint)
+	{
+		return
+	}
+	let Ok((entity, mut schedule, mut history, mut scrollback, decay)) =
+		pane.get_single_mut()
+	else
+	{
+		return
+	};
+	let (index, ..) = scrollback.generation(0).unwrap();
+	let Some(checkpoint) =
+		checkpoints.previous(entity, index, history.newest())
+	else
+	{
+		announcements.send(Announcement(
+			language.text(Message::NoCheckpoint).to_string()
+		));
+		return
+	};
+	let old = schedule.clone();
+	*schedule = checkpoint.schedule;
+	*history = checkpoint.history;
+	scrollback.rewind(checkpoint.index, *history.newest());
+	match (decay, checkpoint.decay)
+	{
+		(Some(mut decay), Some(saved)) => *decay = saved,
+		(Some(mut decay), None) => decay.restart(),
+		(None, _) => {}
+	}
+	// paint_cells repaints the cells from the restored history.
+	scroll.set_if_neq(ScrollOffset(0));
+	if *schedule != old
+	{
+		rule_changes.send(RuleChanged {
+			pane: entity,
+			old,
+			new: schedule.clone()
+		});
+	}
+	announcements.send(Announcement(
+		language.format(Message::RestoredCheckpoint, &[&checkpoint.index])
+	));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Encode the specified [history](History) for spilling: its generations,
+/// from oldest to newest, each as eight big-endian bytes.
+fn encode_history(history: &History) -> Vec<u8>
+{
+	history.iter()
+		.flat_map(|automaton| u64::from(*automaton).to_be_bytes())
+		.collect()
+}
+
+/// Decode a [history](History) [encoded](encode_history) for spilling.
+fn decode_history(bytes: &[u8]) -> Result<History, String>
+{
+	if bytes.len() != AUTOMATON_HISTORY * 8
+	{
+		return Err(format!("expected {} bytes", AUTOMATON_HISTORY * 8))
+	}
+	let mut history = History::new();
+	for (row, word) in bytes.chunks_exact(8).enumerate()
+	{
+		history[row] =
+			Automaton::from(u64::from_be_bytes(word.try_into().unwrap()));
+	}
+	Ok(history)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of generations between checkpoints, unless otherwise specified.
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 1000;
+
+/// The number of checkpoints of each [pane](Pane) to keep in memory. Older
+/// checkpoints are spilled to disk, if possible, or forgotten otherwise.
+const MAX_CHECKPOINTS_IN_MEMORY: usize = 64;
@@ -0,0 +1,463 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::thread;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, Commands, DetectChanges, DetectChangesMut, EventReader, EventWriter,
+	IntoSystemConfigs, Plugin, Query, Res, ResMut, Resource, Update
+};
+
+use crate::automata::{History, RuleMap, RuleSchedule};
+use crate::ecs::{
+	Action, Announcement, apply_actions, evolve, EvolutionTimer, Generation,
+	GenerationCeiling, Pane, Relay
+};
+use crate::i18n::{Language, Message};
+use crate::replay::initial_actions;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for collaborative sessions, in which
+/// several running instances share one simulation. One instance is the
+/// [host](Host), and the others are its [guests](Guest). The host owns the
+/// simulation: every [action](Action) that a guest takes is relayed to the
+/// host, which approves it or denies it, and every action that the host
+/// applies, whether its own or a guest's, is rebroadcast to every guest,
+/// stamped with the [generation](Generation) that it preceded. Guests apply
+/// the approved actions at the same generations, and never evolve past the
+/// host, so every participant sees the same evolution. Anyone may pause or
+/// resume, but the host denies every other action from a guest while the
+/// evolution runs. Insert a [Host] or a [Guest] before adding the plugin.
+/// Available for native builds only.
+///
+/// The protocol is line-oriented text over TCP. Guests send actions in the
+/// [recorded](crate::replay) form, e.g., `toggle 0 12`. The host sends:
+///
+/// * `hello <generation> <running|paused>` to greet a guest, followed by the
+///   actions that reproduce the state of every pane.
+/// * `<generation> <action>` for every action that it applies.
+/// * `tick <generation>` whenever the evolution advances.
+/// * `denied <action>` to refuse a guest's action.
+pub struct CollaborationPlugin;
+
+impl Plugin for CollaborationPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Update, admit_guests.before(apply_actions))
+			.add_systems(Update, accept_requests.before(apply_actions))
+			.add_systems(
+				Update,
+				broadcast_actions.after(apply_actions).before(evolve)
+			)
+			.add_systems(Update, broadcast_ticks.after(evolve))
+			.add_systems(Update, receive_from_host.before(apply_actions))
+			.add_systems(Update, send_to_host.after(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The host of a collaborative session, which owns the simulation. Background
+/// threads accept guests, read their requests, and write to them, so that no
+/// guest ever stalls the frame.
+#[derive(Resource)]
+pub struct Host
+{
+	/// The guests that have connected, but have not yet been greeted.
+	arrivals: Mutex<Receiver<Attendee>>,
+
+	/// The requests of the guests, each tagged with the guest's identifier.
+	/// [None] means that the guest departed.
+	requests: Mutex<Receiver<(usize, Option<String>)>>,
+
+	/// The guests that have been greeted.
+	guests: Vec<Attendee>
+}
+
+impl Host
+{
+	/// Listen for guests at the specified address, e.g., `0.0.0.0:7000`.
+	pub fn bind(address: &str) -> io::Result<Self>
+	{
+		let listener = TcpListener::bind(address)?;
+		let (arrive, arrivals) = channel();
+		let (request, requests) = channel();
+		thread::spawn(move || {
+			for (id, stream) in listener.incoming().enumerate()
+			{
+				let request = request.clone();
+				let attendee = stream.and_then(|stream| {
+					let address = stream.peer_addr()?;
+					let outbox = converse(stream, move |line| {
+						request.send((id, line)).is_ok()
+					})?;
+					Ok(Attendee { id, address, outbox })
+				});
+				match attendee
+				{
+					Ok(attendee) =>
+					{
+						if arrive.send(attendee).is_err()
+						{
+							break
+						}
+					},
+					Err(e) => warn!("Cannot admit guest: {}", e)
+				}
+			}
+		});
+		Ok(Self {
+			arrivals: Mutex::new(arrivals),
+			requests: Mutex::new(requests),
+			guests: Vec::new()
+		})
+	}
+
+	/// Send the specified line to every guest.
+	fn broadcast(&self, line: &str)
+	{
+		for guest in &self.guests
+		{
+			guest.send(line);
+		}
+	}
+}
+
+/// A guest of a collaborative session, which shares the simulation of its
+/// [host](Host). Background threads read from and write to the host.
+#[derive(Resource)]
+pub struct Guest
+{
+	/// The lines to send to the host.
+	outbox: Mutex<Sender<String>>,
+
+	/// The lines received from the host. [None] means that the host departed.
+	inbox: Mutex<Receiver<Option<String>>>,
+
+	/// The approved [actions](Action) that remain to be applied, in order,
+	/// each with the [generation](Generation) that it precedes.
+	pending: VecDeque<(u64, Action)>,
+
+	/// The latest [generation](Generation) reached by the host. The guest
+	/// never evolves past it.
+	horizon: u64
+}
+
+impl Guest
+{
+	/// Join the session hosted at the specified address, e.g.,
+	/// `192.168.1.20:7000`.
+	pub fn join(address: &str) -> io::Result<Self>
+	{
+		let (receive, inbox) = channel();
+		let outbox = converse(TcpStream::connect(address)?, move |line| {
+			receive.send(line).is_ok()
+		})?;
+		Ok(Self {
+			outbox: Mutex::new(outbox),
+			inbox: Mutex::new(inbox),
+			pending: VecDeque::new(),
+			horizon: 0
+		})
+	}
+}
+
+/// A guest, as the [host](Host) knows it.
+struct Attendee
+{
+	/// The identifier of the guest, unique within the session.
+	id: usize,
+
+	/// The address of the guest.
+	address: SocketAddr,
+
+	/// The lines to send to the guest.
+	outbox: Sender<String>
+}
+
+impl Attendee
+{
+	/// Send the specified line to the guest. The writer thread notices if the
+	/// guest has departed, so a failure here means nothing new.
+	fn send(&self, line: &str)
+	{
+		let _ = self.outbox.send(line.to_string());
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Greet every guest that has just connected to the [host](Host) with the
+/// [generation](Generation), the run state, and the
+/// [actions](initial_actions) that reproduce the state of every
+/// [pane](Pane), then include the guest in every broadcast.
+fn admit_guests(
+	host: Option<ResMut<Host>>,
+	timer: Res<EvolutionTimer>,
+	generation: Res<Generation>,
+	language: Res<Language>,
+	panes: Query<(&Pane, &History, &RuleSchedule, &RuleMap)>,
+	mut announcements: EventWriter<Announcement>
+) {
+	let Some(mut host) = host else { return };
+	let arrivals = host.arrivals.lock().unwrap().try_iter().collect::<Vec<_>>();
+	if arrivals.is_empty()
+	{
+		return
+	}
+	let generation = generation.get();
+	let mut greeting = vec![format!(
+		"hello {} {}",
+		generation,
+		match timer.is_running()
+		{
+			true => "running",
+			false => "paused"
+		}
+	)];
+	let mut panes = panes.iter().collect::<Vec<_>>();
+	panes.sort_by_key(|(pane, ..)| **pane);
+	for (pane, history, schedule, map) in panes
+	{
+		greeting.extend(
+			initial_actions(pane.0, history, schedule, map)
+				.into_iter()
+				.map(|action| format!("{} {}", generation, action))
+		);
+	}
+	for guest in arrivals
+	{
+		for line in &greeting
+		{
+			guest.send(line);
+		}
+		announcements.send(Announcement(
+			language.format(Message::GuestJoined, &[&guest.address])
+		));
+		host.guests.push(guest);
+	}
+}
+
+/// Approve or deny every request from the guests of the [host](Host). Pausing
+/// and resuming are always approved, but every other [action](Action) only
+/// while the evolution is paused. Approved actions are sent like any other,
+/// and so are [broadcast](broadcast_actions) once applied. Announce the
+/// departure of any guest.
+fn accept_requests(
+	host: Option<ResMut<Host>>,
+	timer: Res<EvolutionTimer>,
+	language: Res<Language>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	let Some(mut host) = host else { return };
+	let requests = host.requests.lock().unwrap().try_iter().collect::<Vec<_>>();
+	for (id, request) in requests
+	{
+		let index = host.guests.iter().position(|guest| guest.id == id);
+		let Some(index) = index else { continue };
+		let Some(line) = request else
+		{
+			let guest = host.guests.remove(index);
+			announcements.send(Announcement(
+				language.format(Message::GuestLeft, &[&guest.address])
+			));
+			continue
+		};
+		match line.parse::<Action>()
+		{
+			Ok(action)
+				if action == Action::TogglePause || !timer.is_running() =>
+					actions.send(action),
+			Ok(action) =>
+				host.guests[index].send(&format!("denied {}", action)),
+			Err(e) => warn!("Ignoring request from guest: {}", e)
+		}
+	}
+}
+
+/// Broadcast every [action](Action) just [applied](apply_actions) by the
+/// [host](Host) to every guest, stamped with the [generation](Generation) that
+/// it preceded.
+fn broadcast_actions(
+	host: Option<Res<Host>>,
+	generation: Res<Generation>,
+	mut actions: EventReader<Action>
+) {
+	let Some(host) = host else { return };
+	for action in actions.read()
+	{
+		host.broadcast(&format!("{} {}", generation.get(), action));
+	}
+}
+
+/// Whenever the [generation](Generation) of the [host](Host) advances, tell
+/// every guest, so that the guests may follow.
+fn broadcast_ticks(host: Option<Res<Host>>, generation: Res<Generation>)
+{
+	let Some(host) = host else { return };
+	if generation.is_changed()
+	{
+		host.broadcast(&format!("tick {}", generation.get()));
+	}
+}
+
+/// Read everything that the host has sent to the [guest](Guest), then pass
+/// every approved [action](Action) that has fallen due to the [relay](Relay).
+/// Forbid the evolution to pass the host, or the generation of the next
+/// pending action. If the host has departed, then carry on alone.
+#[allow(clippy::too_many_arguments)]
+fn receive_from_host(
+	guest: Option<ResMut<Guest>>,
+	relay: Option<ResMut<Relay>>,
+	timer: Res<EvolutionTimer>,
+	language: Res<Language>,
+	mut generation: ResMut<Generation>,
+	mut ceiling: ResMut<GenerationCeiling>,
+	mut announcements: EventWriter<Announcement>,
+	mut commands: Commands
+) {
+	let (Some(mut guest), Some(mut relay)) = (guest, relay) else { return };
+	let guest = &mut *guest;
+	let mut departed = false;
+	loop
+	{
+		let line = match guest.inbox.lock().unwrap().try_recv()
+		{
+			Ok(Some(line)) => line,
+			Ok(None) | Err(TryRecvError::Disconnected) =>
+			{
+				departed = true;
+				break
+			},
+			Err(TryRecvError::Empty) => break
+		};
+		let (head, rest) = line.split_once(' ').unwrap_or((&line, ""));
+		match head
+		{
+			"hello" =>
+			{
+				let mut words = rest.split_whitespace();
+				let start = words.next().and_then(|word| word.parse().ok());
+				let Some(start) = start else
+				{
+					warn!("Ignoring greeting from host: {}", line);
+					continue
+				};
+				// Start over from the generation of the host, with the
+				// evolution running just when the host's is.
+				generation.0 = start;
+				guest.horizon = start;
+				guest.pending.clear();
+				if (words.next() == Some("running")) != timer.is_running()
+				{
+					guest.pending.push_back((start, Action::TogglePause));
+				}
+			},
+			"tick" => match rest.parse()
+			{
+				Ok(tick) => guest.horizon = tick,
+				Err(_) => warn!("Ignoring tick from host: {}", line)
+			},
+			"denied" => announcements.send(Announcement(
+				language.text(Message::EditDenied).to_string()
+			)),
+			stamp => match (stamp.parse::<u64>(), rest.parse::<Action>())
+			{
+				(Ok(stamp), Ok(action)) =>
+					guest.pending.push_back((stamp, action)),
+				_ => warn!("Ignoring message from host: {}", line)
+			}
+		}
+	}
+	while let Some(&(stamp, _)) = guest.pending.front()
+	{
+		if stamp > generation.0
+		{
+			break
+		}
+		relay.incoming.push(guest.pending.pop_front().unwrap().1);
+	}
+	let limit = guest.pending.front()
+		.map_or(guest.horizon, |&(stamp, _)| stamp.min(guest.horizon));
+	ceiling.set_if_neq(GenerationCeiling(Some(limit)));
+	if departed
+	{
+		announcements.send(Announcement(
+			language.text(Message::HostLeft).to_string()
+		));
+		ceiling.set_if_neq(GenerationCeiling(None));
+		commands.remove_resource::<Guest>();
+		commands.remove_resource::<Relay>();
+	}
+}
+
+/// Send every [action](Action) that the [guest](Guest) took locally to the
+/// host, for approval.
+fn send_to_host(guest: Option<Res<Guest>>, relay: Option<ResMut<Relay>>)
+{
+	let (Some(guest), Some(mut relay)) = (guest, relay) else { return };
+	if relay.outgoing.is_empty()
+	{
+		return
+	}
+	let outbox = guest.outbox.lock().unwrap();
+	for action in relay.outgoing.drain(..)
+	{
+		let _ = outbox.send(action.to_string());
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Begin a conversation over the specified stream. A reader thread passes
+/// each line received to `receive`, then [None] once the stream ends, and
+/// stops early if `receive` answers `false`. A writer thread sends each line
+/// that arrives through the answered [sender](Sender), and closes its half of
+/// the stream once the sender is dropped.
+fn converse(
+	stream: TcpStream,
+	receive: impl Fn(Option<String>) -> bool + Send + 'static
+) -> io::Result<Sender<String>>
+{
+	stream.set_nodelay(true)?;
+	let mut writer = stream.try_clone()?;
+	thread::spawn(move || {
+		for line in BufReader::new(stream).lines()
+		{
+			let Ok(line) = line else { break };
+			if !receive(Some(line.trim().to_string()))
+			{
+				return
+			}
+		}
+		receive(None);
+	});
+	let (outbox, lines) = channel::<String>();
+	thread::spawn(move || {
+		for line in lines
+		{
+			if writeln!(writer, "{}", line).is_err()
+			{
+				break
+			}
+		}
+		// Nobody will send anything else, so let the other end know.
+		let _ = writer.shutdown(Shutdown::Write);
+	});
+	Ok(outbox)
+}
@@ -0,0 +1,1036 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::hint::black_box;
+use std::io::{BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use std::{fmt, io};
+
+use bevy::prelude::Color;
+use clap::Args;
+use image::{Delay, Frame, ImageError, Rgba, RgbaImage};
+use image::codecs::gif::{GifEncoder, Repeat};
+use rand::random;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::automata::{
+	Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule, complexity,
+	COMPLEXITY_WINDOW, ExportFormat, INTERESTINGNESS_GENERATIONS,
+	INTERESTINGNESS_THRESHOLD, INTERESTINGNESS_TRIALS, Interestingness,
+	longest_transient, longest_transient_exhaustive,
+	Orbit,
+	Scrollback, surprising_rule, TRANSIENT_LIMIT, TRANSIENT_SAMPLES
+};
+use crate::ecs::{Palette, PaletteOverrides, PaletteScheme};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Simulation.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The automaton that a headless command simulates, without a window.
+#[derive(Debug, Args)]
+pub(crate) struct Simulation
+{
+	/// The rule, specified as a Wolfram code between 0 and 255, inclusive, in
+	/// decimal, binary (`0b01101110`), or hex (`0x6E`), or by the name of a
+	/// well-known rule, e.g., `sierpinski`. If unspecified, the rule will be
+	/// chosen randomly.
+	#[arg(short, long)]
+	rule: Option<AutomatonRule>,
+
+	/// The first generation, specified as a 64-bit integer that represents the
+	/// complete population. Lower numbered bits correspond to cells on the
+	/// right. If unspecified, the first generation will be chosen randomly.
+	#[arg(short, long)]
+	seed: Option<u64>
+}
+
+impl Simulation
+{
+	/// Answer the rule, choosing one randomly if unspecified.
+	fn rule(&self) -> AutomatonRule
+	{
+		self.rule.unwrap_or_else(|| random::<u8>().into())
+	}
+
+	/// Answer the first generation, choosing one randomly if unspecified.
+	fn seed(&self) -> Automaton
+	{
+		self.seed.map(Automaton::from).unwrap_or_else(|| random::<u64>().into())
+	}
+
+	/// Evolve the first generation the specified number of times, and answer
+	/// every generation, first included, as a [scrollback](Scrollback).
+	fn evolve(&self, generations: usize) -> Scrollback
+	{
+		let rule = self.rule();
+		let mut automaton = self.seed();
+		let mut scrollback = Scrollback::new(automaton, None);
+		for _ in 0 .. generations
+		{
+			automaton = automaton.next(rule);
+			scrollback.push(automaton, rule.into());
+		}
+		scrollback
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Render.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Render the evolution of an automaton to an image file, offline.
+#[derive(Debug, Args)]
+pub(crate) struct RenderArguments
+{
+	#[command(flatten)]
+	simulation: Simulation,
+
+	/// The number of generations to evolve beyond the first. Defaults to 100.
+	#[arg(short, long, value_name = "COUNT")]
+	generations: Option<usize>,
+
+	/// The image to write. A `.gif` file receives an animation with one frame
+	/// per generation, showing the last 50 generations as the evolver does.
+	/// An `.svg` file receives a still spacetime diagram as vector graphics,
+	/// which scale cleanly in papers and slides. Any other image file, e.g.,
+	/// `.png`, receives a still spacetime diagram as pixels. Spacetime
+	/// diagrams show the first generation on top.
+	#[arg(short, long, value_name = "FILE")]
+	output: PathBuf,
+
+	/// The width and height of each cell, in pixels. Defaults to 4.
+	#[arg(long, value_name = "PIXELS")]
+	cell_size: Option<u32>,
+
+	/// The gap between adjacent cells, in pixels, through which the dead color
+	/// shows. Defaults to 0.
+	#[arg(long, value_name = "PIXELS")]
+	cell_spacing: Option<u32>,
+
+	/// The interval between the frames of an animation, in milliseconds.
+	/// Defaults to 250.
+	#[arg(long, value_name = "MILLIS")]
+	heartbeat: Option<u32>,
+
+	/// The palette: `classic`, `deuteranopia`, `protanopia`, `high-contrast`,
+	/// or `muted`. Defaults to `classic`.
+	#[arg(long, value_name = "SCHEME")]
+	palette: Option<PaletteScheme>,
+
+	/// The color of live cells, as a hex string, e.g., `#ff8800`. Overrides
+	/// the palette.
+	#[arg(long, value_name = "COLOR", value_parser = parse_color)]
+	live_color: Option<Color>,
+
+	/// The color of dead cells, and of the gaps between cells, as a hex
+	/// string, e.g., `#ffffff`. Overrides the palette.
+	#[arg(long, value_name = "COLOR", value_parser = parse_color)]
+	dead_color: Option<Color>,
+
+	/// In an `.svg` file, merge each run of adjacent live cells within a
+	/// generation into a single rectangle, spanning the gaps between them,
+	/// rather than drawing a rectangle per live cell. This makes much smaller
+	/// files.
+	#[arg(long)]
+	run_length: bool
+}
+
+impl RenderArguments
+{
+	/// Render the evolution, as either an animation or a still, according to
+	/// the extension of the output file.
+	pub(crate) fn execute(&self) -> Result<(), CommandError>
+	{
+		let scrollback = self.simulation.evolve(
+			self.generations.unwrap_or(DEFAULT_GENERATIONS)
+		);
+		let generations = scrollback.iter()
+			.map(|(_, automaton, _)| *automaton)
+			.collect::<Vec<_>>();
+		let palette = Palette::new(
+			self.palette.unwrap_or_default(),
+			&PaletteOverrides {
+				live: self.live_color,
+				dead: self.dead_color,
+				..PaletteOverrides::default()
+			}
+		);
+		let grid = Grid {
+			cell_size: self.cell_size.unwrap_or(DEFAULT_CELL_SIZE).max(1),
+			spacing: self.cell_spacing.unwrap_or_default()
+		};
+		let extension = self.output.extension()
+			.and_then(|extension| extension.to_str())
+			.map(|extension| extension.to_ascii_lowercase());
+		if extension.as_deref() == Some("svg")
+		{
+			return write_file(&self.output, |file| {
+				write_svg(&generations, &palette, grid, self.run_length, file)
+			}).map_err(|e| CommandError::Write(self.output.clone(), e))
+		}
+		let written = match extension.as_deref()
+		{
+			Some("gif") => self.write_animation(&generations, &palette, grid),
+			_ => draw(&generations, generations.len(), &palette, grid)
+				.save(&self.output)
+		};
+		written.map_err(|e| CommandError::Image(self.output.clone(), e))
+	}
+
+	/// Write an animated GIF with one frame per generation. Each frame shows
+	/// the last [AUTOMATON_HISTORY] generations, newest at the bottom.
+	fn write_animation(
+		&self,
+		generations: &[Automaton],
+		palette: &Palette,
+		grid: Grid
+	) -> Result<(), ImageError>
+	{
+		let file = File::create(&self.output)?;
+		let mut encoder = GifEncoder::new(BufWriter::new(file));
+		encoder.set_repeat(Repeat::Infinite)?;
+		let delay = Delay::from_numer_denom_ms(
+			self.heartbeat.unwrap_or(DEFAULT_HEARTBEAT),
+			1
+		);
+		let frames = (0 .. generations.len()).map(|newest| {
+			let oldest = (newest + 1).saturating_sub(AUTOMATON_HISTORY);
+			let image = draw(
+				&generations[oldest ..= newest],
+				AUTOMATON_HISTORY,
+				palette,
+				grid
+			);
+			Frame::from_parts(image, 0, 0, delay)
+		});
+		encoder.encode_frames(frames)
+	}
+}
+
+/// The layout of the cells of a spacetime diagram.
+#[derive(Copy, Clone, Debug)]
+struct Grid
+{
+	/// The width and height of each cell, in pixels.
+	cell_size: u32,
+
+	/// The gap between adjacent cells, in pixels.
+	spacing: u32
+}
+
+impl Grid
+{
+	/// Answer the distance between the origins of adjacent cells, in pixels.
+	fn pitch(&self) -> u32
+	{
+		self.cell_size + self.spacing
+	}
+
+	/// Answer the extent, in pixels, of the specified number of adjacent
+	/// cells, including the gaps between them, but not around them.
+	fn extent(&self, cells: usize) -> u32
+	{
+		(cells as u32 * self.pitch()).saturating_sub(self.spacing)
+	}
+}
+
+/// Draw the specified generations, one per row of cells, oldest on top, into
+/// an image tall enough for the specified number of rows. Rows beyond the
+/// generations are drawn at the top, dead.
+fn draw(
+	generations: &[Automaton],
+	rows: usize,
+	palette: &Palette,
+	grid: Grid
+) -> RgbaImage
+{
+	let live = Rgba(palette.live.as_rgba_u8());
+	let dead = Rgba(palette.dead.as_rgba_u8());
+	let vacant = rows.saturating_sub(generations.len());
+	let width = grid.extent(AUTOMATON_LENGTH);
+	let height = grid.extent(rows);
+	RgbaImage::from_fn(width, height, |x, y| {
+		if x % grid.pitch() >= grid.cell_size
+			|| y % grid.pitch() >= grid.cell_size
+		{
+			return dead
+		}
+		let row = (y / grid.pitch()) as usize;
+		let column = (x / grid.pitch()) as usize;
+		match row.checked_sub(vacant)
+		{
+			Some(row) if generations[row][AUTOMATON_LENGTH - column - 1] =>
+				live,
+			_ => dead
+		}
+	})
+}
+
+/// Write the specified generations as an SVG spacetime diagram, one per row of
+/// cells, oldest on top. The background takes the dead color, and each live
+/// cell is a rectangle of the live color, unless `run_length` is set, in which
+/// case each run of adjacent live cells within a row is a single rectangle.
+fn write_svg(
+	generations: &[Automaton],
+	palette: &Palette,
+	grid: Grid,
+	run_length: bool,
+	mut out: impl Write
+) -> io::Result<()>
+{
+	let width = grid.extent(AUTOMATON_LENGTH);
+	let height = grid.extent(generations.len());
+	writeln!(
+		out,
+		"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" \
+			height=\"{1}\" viewBox=\"0 0 {0} {1}\" \
+			shape-rendering=\"crispEdges\">",
+		width,
+		height
+	)?;
+	writeln!(
+		out,
+		"<rect width=\"{}\" height=\"{}\" {}/>",
+		width,
+		height,
+		svg_fill(palette.dead)
+	)?;
+	writeln!(out, "<g {}>", svg_fill(palette.live))?;
+	for (row, automaton) in generations.iter().enumerate()
+	{
+		let y = row as u32 * grid.pitch();
+		let mut column = 0;
+		while column < AUTOMATON_LENGTH
+		{
+			let live = |column| automaton[AUTOMATON_LENGTH - column - 1];
+			if !live(column)
+			{
+				column += 1;
+				continue
+			}
+			let run = match run_length
+			{
+				true => (column .. AUTOMATON_LENGTH)
+					.take_while(|&column| live(column))
+					.count(),
+				false => 1
+			};
+			writeln!(
+				out,
+				"<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+				column as u32 * grid.pitch(),
+				y,
+				grid.extent(run),
+				grid.cell_size
+			)?;
+			column += run;
+		}
+	}
+	writeln!(out, "</g>")?;
+	writeln!(out, "</svg>")
+}
+
+/// Answer the SVG attributes that fill a shape with the specified color,
+/// including its opacity, if translucent.
+fn svg_fill(color: Color) -> String
+{
+	let [r, g, b, a] = color.as_rgba_u8();
+	match a
+	{
+		u8::MAX => format!("fill=\"#{:02x}{:02x}{:02x}\"", r, g, b),
+		_ => format!(
+			"fill=\"#{:02x}{:02x}{:02x}\" fill-opacity=\"{:.3}\"",
+			r,
+			g,
+			b,
+			a as f32 / u8::MAX as f32
+		)
+	}
+}
+
+/// Parse a [color](Color) from a hex string, with or without a leading `#`,
+/// in any of the forms accepted by [Color::hex].
+fn parse_color(s: &str) -> Result<Color, String>
+{
+	Color::hex(s).map_err(|e| format!("bad color '{}': {}", s, e))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Search.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Explore the elementary rules, by [measuring](Interestingness) how
+/// interesting each looks from random first generations, and list the rules
+/// whose scores clear a threshold, or pick one of them at random.
+#[derive(Debug, Args)]
+pub(crate) struct SearchArguments
+{
+	/// The first generation, specified as a 64-bit integer that represents the
+	/// complete population. Lower numbered bits correspond to cells on the
+	/// right. If unspecified, each rule is measured from several random first
+	/// generations, the same for every rule.
+	#[arg(short, long)]
+	seed: Option<u64>,
+
+	/// The number of random first generations from which to measure each
+	/// rule, when `seed` is unspecified. Defaults to 4.
+	#[arg(long, value_name = "COUNT", conflicts_with = "seed")]
+	trials: Option<usize>,
+
+	/// The number of generations to evolve beyond the first. Defaults to 256.
+	#[arg(short, long, value_name = "COUNT")]
+	generations: Option<usize>,
+
+	/// The least score, between 0 and 1, inclusive, of an interesting rule.
+	/// Defaults to 0.65.
+	#[arg(long, value_name = "SCORE")]
+	threshold: Option<f32>,
+
+	/// Print a single interesting rule, chosen randomly, rather than listing
+	/// all of them.
+	#[arg(long)]
+	pick: bool
+}
+
+impl SearchArguments
+{
+	/// Measure the elementary rules, then print the interesting ones, one per
+	/// line, from highest to lowest score, along with their measures. When
+	/// picking, print only a random interesting rule, or the highest scoring
+	/// rule if none is interesting.
+	pub(crate) fn execute(&self) -> Result<(), CommandError>
+	{
+		let seeds: Vec<Automaton> = match self.seed
+		{
+			Some(seed) => vec![Automaton::from(seed)],
+			None => (0 .. self.trials.unwrap_or(INTERESTINGNESS_TRIALS).max(1))
+				.map(|_| Automaton::from(random::<u64>()))
+				.collect()
+		};
+		let generations = self.generations
+			.unwrap_or(INTERESTINGNESS_GENERATIONS)
+			.max(1);
+		let threshold = self.threshold.unwrap_or(INTERESTINGNESS_THRESHOLD);
+		let mut out = io::stdout().lock();
+		if self.pick
+		{
+			let (rule, measure) =
+				surprising_rule(&seeds, generations, threshold);
+			return writeln!(out, "rule {:>3}  {}", u8::from(rule), measure)
+				.map_err(CommandError::Stdout)
+		}
+		let progress = Progress::new("search", 1 << u8::BITS);
+		let mut measures = (0 ..= u8::MAX).into_par_iter()
+			.map(|code| {
+				let rule = AutomatonRule::from(code);
+				let measure =
+					Interestingness::mean(&seeds, rule, generations);
+				progress.advance();
+				(rule, measure)
+			})
+			.filter(|(_, measure)| measure.score() >= threshold)
+			.collect::<Vec<_>>();
+		progress.finish();
+		measures.sort_by(|(_, a), (_, b)| b.score().total_cmp(&a.score()));
+		for (rule, measure) in measures
+		{
+			writeln!(out, "rule {:>3}  {}", u8::from(rule), measure)
+				.map_err(CommandError::Stdout)?;
+		}
+		Ok(())
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Transients.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Search first generations for the one that runs longest under a rule before
+/// falling into a cycle.
+#[derive(Debug, Args)]
+pub(crate) struct TransientArguments
+{
+	/// The rule, specified as a Wolfram code between 0 and 255, inclusive, in
+	/// decimal, binary (`0b01101110`), or hex (`0x6E`), or by the name of a
+	/// well-known rule, e.g., `sierpinski`. If unspecified, the rule will be
+	/// chosen randomly.
+	#[arg(short, long)]
+	rule: Option<AutomatonRule>,
+
+	/// The number of random first generations to examine. Defaults to 1000.
+	#[arg(long, value_name = "COUNT")]
+	samples: Option<usize>,
+
+	/// The number of evolutions after which to give up on a first generation
+	/// whose cycle hasn't closed. Defaults to 100000.
+	#[arg(long, value_name = "COUNT")]
+	limit: Option<usize>,
+
+	/// Examine every first generation of a narrower automaton instead, of
+	/// `8`, `12`, `16`, or `20` cells.
+	#[arg(
+		long,
+		value_name = "CELLS",
+		conflicts_with_all = ["samples", "limit", "install"]
+	)]
+	exhaustive: Option<usize>,
+
+	/// Open the evolver afterward, with the rule and the champion as the
+	/// first generation.
+	#[arg(long)]
+	install: bool
+}
+
+impl TransientArguments
+{
+	/// Search for the first generation with the longest transient, and print
+	/// it along with its [orbit](Orbit). Answer the rule and the champion if
+	/// they should be installed in the evolver.
+	pub(crate) fn execute(
+		&self
+	) -> Result<Option<(AutomatonRule, u64)>, CommandError>
+	{
+		let rule = self.rule.unwrap_or_else(|| random::<u8>().into());
+		let champion = match self.exhaustive
+		{
+			Some(8) => Some(exhaustive::<8>(rule)),
+			Some(12) => Some(exhaustive::<12>(rule)),
+			Some(16) => Some(exhaustive::<16>(rule)),
+			Some(20) => Some(exhaustive::<20>(rule)),
+			Some(width) => return Err(CommandError::Width(width)),
+			None =>
+			{
+				let samples = self.samples.unwrap_or(TRANSIENT_SAMPLES);
+				let seeds = (0 .. samples).map(|_| {
+					Automaton::<AUTOMATON_LENGTH>::from(random::<u64>())
+				});
+				longest_transient(
+					rule,
+					seeds,
+					self.limit.unwrap_or(TRANSIENT_LIMIT)
+				)
+				.map(|(seed, orbit)| (u64::from(seed), AUTOMATON_LENGTH, orbit))
+			}
+		};
+		let mut out = io::stdout().lock();
+		let Some((seed, width, orbit)) = champion else
+		{
+			writeln!(
+				out,
+				"rule {}: no cycle closed within {} generations",
+				rule,
+				self.limit.unwrap_or(TRANSIENT_LIMIT)
+			).map_err(CommandError::Stdout)?;
+			return Ok(None)
+		};
+		writeln!(
+			out,
+			"rule {}: seed {} ({} cells), {}",
+			rule,
+			seed,
+			width,
+			orbit
+		).map_err(CommandError::Stdout)?;
+		Ok(self.install.then_some((rule, seed)))
+	}
+}
+
+/// Search every first generation of `K` cells for the one with the longest
+/// transient under the specified [rule](AutomatonRule), and answer it, its
+/// width, and its [orbit](Orbit).
+fn exhaustive<const K: usize>(rule: AutomatonRule) -> (u64, usize, Orbit)
+{
+	let (seed, orbit) = longest_transient_exhaustive::<K>(rule);
+	(u64::from(seed), K, orbit)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Batch.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Simulate many rules from many random first generations, and report
+/// statistics for each rule, for offline analysis.
+#[derive(Debug, Args)]
+pub(crate) struct BatchArguments
+{
+	/// A JSON file that lists the rules to simulate, as an array of Wolfram
+	/// codes or names, e.g., `[30, "sierpinski", 110]`. If unspecified, every
+	/// elementary rule is simulated.
+	#[arg(long, value_name = "FILE")]
+	rules: Option<PathBuf>,
+
+	/// The number of random first generations from which to simulate each
+	/// rule. Defaults to 16.
+	#[arg(long, value_name = "COUNT")]
+	seeds: Option<usize>,
+
+	/// The number of generations to evolve beyond the first. Defaults to 1000.
+	#[arg(short, long, value_name = "COUNT")]
+	generations: Option<usize>,
+
+	/// The report to write, as JSON, or as CSV if the file name ends with
+	/// `.csv`. If unspecified, write JSON to standard output.
+	#[arg(short, long, value_name = "FILE")]
+	output: Option<PathBuf>
+}
+
+impl BatchArguments
+{
+	/// Simulate every rule from the same random first generations, then write
+	/// a [report](RuleReport) for each rule.
+	pub(crate) fn execute(&self) -> Result<(), CommandError>
+	{
+		let rules = match self.rules
+		{
+			Some(ref path) => read_rules(path)?,
+			None => (0 ..= u8::MAX).map(AutomatonRule::from).collect()
+		};
+		let seeds = (0 .. self.seeds.unwrap_or(DEFAULT_BATCH_SEEDS).max(1))
+			.map(|_| Automaton::from(random::<u64>()))
+			.collect::<Vec<Automaton>>();
+		let generations = self.generations
+			.unwrap_or(DEFAULT_BATCH_GENERATIONS)
+			.max(1);
+		let progress = Progress::new("batch", rules.len() * seeds.len());
+		let reports = rules.par_iter()
+			.map(|&rule| {
+				RuleReport::simulate(rule, &seeds, generations, &progress)
+			})
+			.collect::<Vec<_>>();
+		progress.finish();
+		let csv = self.output.as_ref()
+			.and_then(|path| path.extension())
+			.is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+		let write = |out: &mut dyn Write| match csv
+		{
+			true => write_reports_csv(&reports, out),
+			false => serde_json::to_writer_pretty(&mut *out, &reports)
+				.map_err(io::Error::from)
+				.and_then(|()| writeln!(out))
+		};
+		match self.output
+		{
+			Some(ref path) => write_file(path, |file| write(file))
+				.map_err(|e| CommandError::Write(path.clone(), e)),
+			None => write(&mut io::stdout().lock())
+				.map_err(CommandError::Stdout)
+		}
+	}
+}
+
+/// The statistics of one rule, simulated from several first generations.
+#[derive(Clone, Debug, Serialize)]
+struct RuleReport
+{
+	/// The Wolfram code of the rule.
+	rule: u8,
+
+	/// The number of first generations simulated.
+	runs: usize,
+
+	/// The mean fraction of live cells, over every generation of every run.
+	mean_density: f32,
+
+	/// The mean [entropy](Automaton::entropy), over every generation of every
+	/// run.
+	mean_entropy: f32,
+
+	/// The mean [complexity] of the last [COMPLEXITY_WINDOW] generations of
+	/// each run.
+	mean_complexity: f32,
+
+	/// The number of runs that fell into a cycle within the simulation.
+	cycled: usize,
+
+	/// The mean transient of the runs that fell into a cycle, if any did.
+	mean_transient: Option<f32>,
+
+	/// The mean period of the runs that fell into a cycle, if any did.
+	mean_period: Option<f32>
+}
+
+impl RuleReport
+{
+	/// Simulate the specified rule from each of the specified first
+	/// generations, in parallel, through the specified number of generations
+	/// beyond the first, and gather the statistics. Advance the
+	/// [progress](Progress) once per first generation.
+	fn simulate(
+		rule: AutomatonRule,
+		seeds: &[Automaton],
+		generations: usize,
+		progress: &Progress
+	) -> Self
+	{
+		let runs = seeds.par_iter()
+			.map(|&seed| {
+				let mut density = 0.0;
+				let mut entropy = 0.0;
+				let mut recent = VecDeque::with_capacity(COMPLEXITY_WINDOW);
+				let mut automaton = seed;
+				for generation in 0 ..= generations
+				{
+					if generation > 0
+					{
+						automaton = automaton.next(rule);
+					}
+					density += automaton.population_count() as f32
+						/ AUTOMATON_LENGTH as f32;
+					entropy += automaton.entropy();
+					if recent.len() == COMPLEXITY_WINDOW
+					{
+						recent.pop_front();
+					}
+					recent.push_back(automaton);
+				}
+				let orbit = seed.orbit(rule, generations);
+				progress.advance();
+				(density, entropy, complexity(&recent), orbit)
+			})
+			.collect::<Vec<_>>();
+		// Sum in order, so that the report doesn't depend on the scheduling.
+		let density = runs.iter().map(|run| run.0).sum::<f32>();
+		let entropy = runs.iter().map(|run| run.1).sum::<f32>();
+		let complexity = runs.iter().map(|run| run.2).sum::<f32>();
+		let orbits = runs.iter().filter_map(|run| run.3).collect::<Vec<_>>();
+		let samples = (seeds.len() * (generations + 1)) as f32;
+		let mean = |sum: usize| match orbits.is_empty()
+		{
+			true => None,
+			false => Some(sum as f32 / orbits.len() as f32)
+		};
+		Self {
+			rule: rule.into(),
+			runs: seeds.len(),
+			mean_density: density / samples,
+			mean_entropy: entropy / samples,
+			mean_complexity: complexity / seeds.len() as f32,
+			cycled: orbits.len(),
+			mean_transient:
+				mean(orbits.iter().map(|orbit| orbit.transient).sum()),
+			mean_period: mean(orbits.iter().map(|orbit| orbit.period).sum())
+		}
+	}
+}
+
+/// Write the specified [reports](RuleReport) as CSV, one row per rule. The
+/// first row names the columns. Missing means are left empty.
+fn write_reports_csv(reports: &[RuleReport], out: &mut dyn Write)
+	-> io::Result<()>
+{
+	writeln!(
+		out,
+		"rule,runs,mean_density,mean_entropy,mean_complexity,cycled,\
+			mean_transient,mean_period"
+	)?;
+	let optional = |value: Option<f32>| {
+		value.map(|value| value.to_string()).unwrap_or_default()
+	};
+	for report in reports
+	{
+		writeln!(
+			out,
+			"{},{},{},{},{},{},{},{}",
+			report.rule,
+			report.runs,
+			report.mean_density,
+			report.mean_entropy,
+			report.mean_complexity,
+			report.cycled,
+			optional(report.mean_transient),
+			optional(report.mean_period)
+		)?;
+	}
+	Ok(())
+}
+
+/// Read a JSON array of rules, each given as a Wolfram code or a name, from
+/// the file at the specified path.
+fn read_rules(path: &Path) -> Result<Vec<AutomatonRule>, CommandError>
+{
+	/// A rule, as it appears in the file.
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum RuleName
+	{
+		Code(u8),
+		Name(String)
+	}
+	let text = std::fs::read_to_string(path)
+		.map_err(|e| CommandError::Read(path.to_path_buf(), e))?;
+	let names: Vec<RuleName> = serde_json::from_str(&text)
+		.map_err(|e| CommandError::Json(path.to_path_buf(), e))?;
+	names.into_iter()
+		.map(|name| match name
+		{
+			RuleName::Code(code) => Ok(AutomatonRule::from(code)),
+			RuleName::Name(name) => name.parse()
+				.map_err(|e| CommandError::Rule(path.to_path_buf(), e))
+		})
+		.collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Benchmark.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Measure how quickly an automaton evolves, without a window.
+#[derive(Debug, Args)]
+pub(crate) struct BenchArguments
+{
+	#[command(flatten)]
+	simulation: Simulation,
+
+	/// The number of generations to evolve beyond the first. Defaults to
+	/// 1000000.
+	#[arg(short, long, value_name = "COUNT")]
+	generations: Option<usize>
+}
+
+impl BenchArguments
+{
+	/// Evolve the automaton [in&#32;bulk](Automaton::next_n), retaining only
+	/// the newest generation, then print the elapsed time and the rate of
+	/// evolution.
+	pub(crate) fn execute(&self) -> Result<(), CommandError>
+	{
+		let rule = self.simulation.rule();
+		let automaton = self.simulation.seed();
+		let generations =
+			self.generations.unwrap_or(DEFAULT_BENCH_GENERATIONS);
+		let start = Instant::now();
+		black_box(black_box(automaton).next_n(rule, generations));
+		let elapsed = start.elapsed();
+		let rate =
+			generations as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+		writeln!(
+			io::stdout().lock(),
+			"rule {}: {} generations of {} cells in {:.3?} ({:.0}/s)",
+			u8::from(rule),
+			generations,
+			AUTOMATON_LENGTH,
+			elapsed,
+			rate
+		).map_err(CommandError::Stdout)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Export.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Export the evolution of an automaton as CSV or as a Wolfram Language
+/// expression, without a window.
+#[derive(Debug, Args)]
+pub(crate) struct ExportArguments
+{
+	#[command(flatten)]
+	simulation: Simulation,
+
+	/// The number of generations to evolve beyond the first. Defaults to 100.
+	#[arg(short, long, value_name = "COUNT")]
+	generations: Option<usize>,
+
+	/// The format to write: csv or wolfram. If unspecified, infer the format
+	/// from the extension of the output file, i.e., .wl or .m for wolfram, or
+	/// else csv.
+	#[arg(short, long, value_name = "FORMAT")]
+	format: Option<ExportFormat>,
+
+	/// The file to write. If unspecified, write to standard output.
+	#[arg(short, long, value_name = "FILE")]
+	output: Option<PathBuf>
+}
+
+impl ExportArguments
+{
+	/// Write every generation in the requested format, just as the evolver's
+	/// own export does.
+	pub(crate) fn execute(&self) -> Result<(), CommandError>
+	{
+		let scrollback = self.simulation.evolve(
+			self.generations.unwrap_or(DEFAULT_GENERATIONS)
+		);
+		let format = self.format.unwrap_or_else(|| {
+			self.output.as_ref()
+				.and_then(|path| path.extension())
+				.and_then(|extension| extension.to_str())
+				.and_then(ExportFormat::from_extension)
+				.unwrap_or_default()
+		});
+		match self.output
+		{
+			Some(ref path) =>
+				write_file(path, |file| format.write(&scrollback, file))
+					.map_err(|e| CommandError::Write(path.clone(), e)),
+			None => format.write(&scrollback, io::stdout().lock())
+				.map_err(CommandError::Stdout)
+		}
+	}
+}
+
+/// Create the file at the specified path, and fill it using the specified
+/// writer.
+fn write_file(
+	path: &Path,
+	write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>
+) -> io::Result<()>
+{
+	let mut file = BufWriter::new(File::create(path)?);
+	write(&mut file)?;
+	file.flush()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Progress.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A progress indicator for long headless commands, shared by the threads
+/// that do the work. It redraws a single line on standard error, so it stays
+/// out of any report written to standard output, and it stays silent when
+/// standard error isn't a terminal.
+struct Progress
+{
+	/// The label that precedes the count.
+	label: &'static str,
+
+	/// The number of units of work in total.
+	total: usize,
+
+	/// The number of units of work finished so far.
+	done: AtomicUsize,
+
+	/// Whether to draw anything at all.
+	visible: bool
+}
+
+impl Progress
+{
+	/// Construct a progress indicator for the specified total units of work.
+	fn new(label: &'static str, total: usize) -> Self
+	{
+		Self {
+			label,
+			total,
+			done: AtomicUsize::new(0),
+			visible: io::stderr().is_terminal()
+		}
+	}
+
+	/// Record one more finished unit of work. Redraw only when the whole
+	/// percentage changes, so that the terminal doesn't become the bottleneck.
+	fn advance(&self)
+	{
+		let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+		let percent = |done: usize| done * 100 / self.total.max(1);
+		if self.visible && (done == 1 || percent(done) != percent(done - 1))
+		{
+			eprint!(
+				"\r{}: {}/{} ({}%)",
+				self.label,
+				done,
+				self.total,
+				percent(done)
+			);
+		}
+	}
+
+	/// Erase the progress indicator, leaving the cursor at the start of the
+	/// line.
+	fn finish(&self)
+	{
+		if self.visible
+		{
+			eprint!("\r{:width$}\r", "", width = PROGRESS_WIDTH);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why a headless command may fail.
+#[derive(Debug)]
+pub(crate) enum CommandError
+{
+	/// The specified file could not be written.
+	Write(PathBuf, io::Error),
+
+	/// The specified image could not be encoded or written.
+	Image(PathBuf, ImageError),
+
+	/// Standard output could not be written.
+	Stdout(io::Error),
+
+	/// The specified file could not be read.
+	Read(PathBuf, io::Error),
+
+	/// The specified file is not valid JSON of the expected shape.
+	Json(PathBuf, serde_json::Error),
+
+	/// The specified file names an unknown rule.
+	Rule(PathBuf, String),
+
+	/// Exhaustive search is unavailable for automata of the specified width.
+	Width(usize)
+}
+
+impl Display for CommandError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			CommandError::Write(path, e) =>
+				write!(f, "{}: cannot write: {}", path.display(), e),
+			CommandError::Image(path, e) =>
+				write!(f, "{}: cannot render: {}", path.display(), e),
+			CommandError::Stdout(e) =>
+				write!(f, "cannot write to standard output: {}", e),
+			CommandError::Read(path, e) =>
+				write!(f, "{}: cannot read: {}", path.display(), e),
+			CommandError::Json(path, e) =>
+				write!(f, "{}: {}", path.display(), e),
+			CommandError::Rule(path, e) =>
+				write!(f, "{}: {}", path.display(), e),
+			CommandError::Width(width) => write!(
+				f,
+				"cannot search {} cells exhaustively; try 8, 12, 16, or 20",
+				width
+			)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The default number of generations to render or export.
+const DEFAULT_GENERATIONS: usize = 100;
+
+/// The default number of random first generations from which a batch
+/// simulates each rule.
+const DEFAULT_BATCH_SEEDS: usize = 16;
+
+/// The default number of generations to evolve during a batch.
+const DEFAULT_BATCH_GENERATIONS: usize = 1_000;
+
+/// The default number of generations to evolve during a benchmark.
+const DEFAULT_BENCH_GENERATIONS: usize = 1_000_000;
+
+/// The default width and height of each rendered cell, in pixels.
+const DEFAULT_CELL_SIZE: u32 = 4;
+
+/// The default interval between the frames of a rendered animation, in
+/// milliseconds.
+const DEFAULT_HEARTBEAT: u32 = 250;
+
+/// The number of columns to blank when erasing a [progress](Progress)
+/// indicator.
+const PROGRESS_WIDTH: usize = 60;
@@ -0,0 +1,557 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{fmt, fs, io};
+
+use bevy::prelude::{Color, KeyCode};
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+use crate::ecs::{PaletteOverrides, PaletteScheme};
+use crate::keys::{key_from_name, key_name, KeyBindings};
+
+////////////////////////////////////////////////////////////////////////////////
+//                               Configuration.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The contents of a configuration file, which supplies defaults for the
+/// command line arguments. Every setting is optional, and the command line
+/// takes precedence over the configuration file. For example:
+///
+/// ```toml
+/// rule = 110
+/// seed = 4096
+/// heartbeat = 100
+/// scrollback = 10000
+/// favorites = [30, 90, 110]
+///
+/// [palette]
+/// scheme = "deuteranopia"
+/// pressed = "#ff8800"
+///
+/// [keys]
+/// pause = "f8"
+/// show_fps = "f2"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config
+{
+	/// The rule, specified as a Wolfram code.
+	pub rule: Option<u8>,
+
+	/// Several rules, specified as Wolfram codes, that the evolver applies in
+	/// rotation, one per generation. Supersedes `rule`.
+	pub rules: Vec<u8>,
+
+	/// The first generation, specified as a 64-bit integer.
+	pub seed: Option<u64>,
+
+	/// The interval between successive generations, in milliseconds.
+	pub heartbeat: Option<u64>,
+
+	/// The maximum number of generations to keep for scrolling back through
+	/// the evolution.
+	pub scrollback: Option<usize>,
+
+	/// The favorite rules, specified as Wolfram codes, in the order in which
+	/// they were marked. Marking or unmarking a favorite rewrites this
+	/// setting.
+	pub favorites: Vec<u8>,
+
+	/// The colors in which cells are painted.
+	pub palette: PaletteConfig,
+
+	/// The keys bound to the hotkey actions.
+	pub keys: KeysConfig
+}
+
+impl Config
+{
+	/// Load the configuration file at the specified path.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError>
+	{
+		Ok(toml::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	/// Load the configuration file at the [default&#32;path](DEFAULT_PATH),
+	/// if it exists. Answer the default configuration otherwise.
+	pub fn load_default() -> Result<Self, ConfigError>
+	{
+		match Path::new(DEFAULT_PATH).exists()
+		{
+			true => Self::load(DEFAULT_PATH),
+			false => Ok(Self::default())
+		}
+	}
+
+	/// Answer the path of the configuration file that is loaded when no other
+	/// is specified.
+	pub fn default_path() -> &'static Path
+	{
+		Path::new(DEFAULT_PATH)
+	}
+
+	/// Rewrite the `favorites` setting of the configuration file at the
+	/// specified path, creating the file if necessary. Every other line of the
+	/// file, including comments, is preserved verbatim. The setting is placed
+	/// just before the first table, since top-level settings cannot follow
+	/// tables.
+	pub fn save_favorites(
+		path: impl AsRef<Path>,
+		favorites: &[u8]
+	) -> io::Result<()>
+	{
+		let path = path.as_ref();
+		let text = match path.exists()
+		{
+			true => fs::read_to_string(path)?,
+			false => String::new()
+		};
+		let codes = favorites.iter()
+			.map(u8::to_string)
+			.collect::<Vec<_>>()
+			.join(", ");
+		let setting = format!("favorites = [{}]", codes);
+		let mut lines = Vec::new();
+		let mut placed = false;
+		// The number of unclosed brackets of an old setting being dropped.
+		let mut depth = 0;
+		for line in text.lines()
+		{
+			if depth > 0
+			{
+				depth += brackets(line);
+				continue
+			}
+			let trimmed = line.trim_start();
+			if !placed && trimmed.starts_with('[')
+			{
+				lines.push(setting.as_str());
+				placed = true;
+			}
+			let old = !placed
+				&& trimmed.strip_prefix("favorites")
+					.is_some_and(|rest| rest.trim_start().starts_with('='));
+			match old
+			{
+				true => depth = brackets(line),
+				false => lines.push(line)
+			}
+		}
+		if !placed
+		{
+			lines.push(setting.as_str());
+		}
+		let mut text = lines.join("\n");
+		text.push('\n');
+		fs::write(path, text)
+	}
+
+	/// Answer the [palette&#32;scheme](PaletteScheme) named by the
+	/// configuration, if any.
+	pub fn palette_scheme(&self) -> Option<PaletteScheme>
+	{
+		self.palette.scheme.map(|scheme| scheme.0)
+	}
+
+	/// Answer the [colors](PaletteOverrides) specified by the configuration,
+	/// which replace those of every [palette&#32;scheme](PaletteScheme).
+	pub fn palette_overrides(&self) -> PaletteOverrides
+	{
+		let color = |color: Option<HexColor>| color.map(|color| color.0);
+		PaletteOverrides {
+			live: color(self.palette.live),
+			dead: color(self.palette.dead),
+			difference: color(self.palette.difference),
+			pressed: color(self.palette.pressed),
+			chrome: None
+		}
+	}
+
+	/// Answer the [key&#32;bindings](KeyBindings) described by the
+	/// configuration, using the default keys for any that are unspecified.
+	/// Fail if two actions end up bound to the same key, whether both were
+	/// specified or one kept its default, since both would fire on a single
+	/// press.
+	pub fn key_bindings(&self) -> Result<KeyBindings, ConfigError>
+	{
+		let default = KeyBindings::default();
+		let keys = &self.keys;
+		let key = |key: Option<KeyName>, default| {
+			key.map(|key| key.0).unwrap_or(default)
+		};
+		let bindings = KeyBindings {
+			pause: key(keys.pause, default.pause),
+			show_fps: key(keys.show_fps, default.show_fps),
+			next_pane: key(keys.next_pane, default.next_pane),
+			append_rule: key(keys.append_rule, default.append_rule),
+			drop_rule: key(keys.drop_rule, default.drop_rule),
+			mutate: key(keys.mutate, default.mutate),
+			surprise: key(keys.surprise, default.surprise),
+			walk_back: key(keys.walk_back, default.walk_back),
+			favorite: key(keys.favorite, default.favorite),
+			previous_favorite: key(
+				keys.previous_favorite,
+				default.previous_favorite
+			),
+			next_favorite: key(keys.next_favorite, default.next_favorite),
+			mirror: key(keys.mirror, default.mirror),
+			complement: key(keys.complement, default.complement),
+			mirrored_complement: key(
+				keys.mirrored_complement,
+				default.mirrored_complement
+			),
+			mute: key(keys.mute, default.mute),
+			export: key(keys.export, default.export),
+			share_card: key(keys.share_card, default.share_card),
+			poster: key(keys.poster, default.poster),
+			checkpoint: key(keys.checkpoint, default.checkpoint),
+			gridlines: key(keys.gridlines, default.gridlines),
+			vsync: key(keys.vsync, default.vsync),
+			palette: key(keys.palette, default.palette),
+			effects: key(keys.effects, default.effects),
+			neon: key(keys.neon, default.neon),
+			extrusion: key(keys.extrusion, default.extrusion),
+			auto_rotate: key(keys.auto_rotate, default.auto_rotate),
+			tape: key(keys.tape, default.tape),
+			particles: key(keys.particles, default.particles),
+			ancestry: key(keys.ancestry, default.ancestry),
+			minimap: key(keys.minimap, default.minimap),
+			density: key(keys.density, default.density),
+			statistics: key(keys.statistics, default.statistics),
+			analysis: key(keys.analysis, default.analysis),
+			background: key(keys.background, default.background),
+			changes: key(keys.changes, default.changes),
+			gallery: key(keys.gallery, default.gallery),
+			paint_rule: key(keys.paint_rule, default.paint_rule),
+			cursor_left: key(keys.cursor_left, default.cursor_left),
+			cursor_right: key(keys.cursor_right, default.cursor_right),
+			toggle_cell: key(keys.toggle_cell, default.toggle_cell),
+			rotate_left: key(keys.rotate_left, default.rotate_left),
+			rotate_right: key(keys.rotate_right, default.rotate_right),
+			invert: key(keys.invert, default.invert),
+			symmetrize: key(keys.symmetrize, default.symmetrize),
+			longest_transient: key(
+				keys.longest_transient,
+				default.longest_transient
+			),
+			fast_forward: key(keys.fast_forward, default.fast_forward),
+			turbo: key(keys.turbo, default.turbo),
+			slow_motion: key(keys.slow_motion, default.slow_motion),
+			page_back: key(keys.page_back, default.page_back),
+			page_forward: key(keys.page_forward, default.page_forward)
+		};
+		match bindings.conflict()
+		{
+			Some((first, second, key)) => Err(ConfigError::Conflict {
+				first,
+				second,
+				key: key_name(key)
+			}),
+			None => Ok(bindings)
+		}
+	}
+}
+
+/// The `[palette]` table of a [configuration&#32;file](Config). The scheme is
+/// specified by [name](PaletteScheme), e.g., `"high-contrast"`. Each color is
+/// specified as a hex string, e.g., `"#ff8800"`, and overrides the scheme.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaletteConfig
+{
+	/// The initial palette scheme.
+	scheme: Option<SchemeName>,
+
+	/// The color of live cells.
+	live: Option<HexColor>,
+
+	/// The color of dead cells.
+	dead: Option<HexColor>,
+
+	/// The color of cells of a difference pane where the compared histories
+	/// disagree.
+	difference: Option<HexColor>,
+
+	/// The color of a hovered cell of the newest generation.
+	pressed: Option<HexColor>
+}
+
+/// The `[keys]` table of a [configuration&#32;file](Config), which binds each
+/// hotkey action to a key, specified by [name](key_from_name), e.g.,
+/// `"space"`, `"right shift"`, or `"f"`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeysConfig
+{
+	/// Resume or pause the evolution.
+	pause: Option<KeyName>,
+
+	/// Show the frames per second (FPS), while held.
+	show_fps: Option<KeyName>,
+
+	/// Move the keyboard focus to the next pane.
+	next_pane: Option<KeyName>,
+
+	/// Append the rule being typed to the schedule.
+	append_rule: Option<KeyName>,
+
+	/// Drop the last rule from the schedule.
+	drop_rule: Option<KeyName>,
+
+	/// Mutate the active rule.
+	mutate: Option<KeyName>,
+
+	/// Replace the active rule with a surprising one.
+	surprise: Option<KeyName>,
+
+	/// Walk back along the breadcrumb trail.
+	walk_back: Option<KeyName>,
+
+	/// Mark or unmark the active rule as a favorite.
+	favorite: Option<KeyName>,
+
+	/// Switch to the previous favorite rule.
+	previous_favorite: Option<KeyName>,
+
+	/// Switch to the next favorite rule.
+	next_favorite: Option<KeyName>,
+
+	/// Replace the active rule with its mirror image.
+	mirror: Option<KeyName>,
+
+	/// Replace the active rule with its complement.
+	complement: Option<KeyName>,
+
+	/// Replace the active rule with its mirrored complement.
+	mirrored_complement: Option<KeyName>,
+
+	/// Mute or unmute the sonification.
+	mute: Option<KeyName>,
+
+	/// Export the generations of the focused pane, by default as CSV.
+	export: Option<KeyName>,
+
+	/// Export a share card of the focused pane.
+	share_card: Option<KeyName>,
+
+	/// Render a poster of the focused pane.
+	poster: Option<KeyName>,
+
+	/// Jump back to the previous checkpoint of the focused pane.
+	checkpoint: Option<KeyName>,
+
+	/// Change the gridlines.
+	gridlines: Option<KeyName>,
+
+	/// Toggle vsync.
+	vsync: Option<KeyName>,
+
+	/// Switch to the next palette scheme.
+	palette: Option<KeyName>,
+
+	/// Toggle the birth and death effects.
+	effects: Option<KeyName>,
+
+	/// Toggle the neon presentation mode.
+	neon: Option<KeyName>,
+
+	/// Toggle the 3D view.
+	extrusion: Option<KeyName>,
+
+	/// Toggle the automatic rotation of the 3D view.
+	auto_rotate: Option<KeyName>,
+
+	/// Toggle the growing tape.
+	tape: Option<KeyName>,
+
+	/// Toggle the outlines of the particles of Rule 110.
+	particles: Option<KeyName>,
+
+	/// Toggle the tracing of the ancestry of the selected cell.
+	ancestry: Option<KeyName>,
+
+	/// Toggle the minimap of the scrollback.
+	minimap: Option<KeyName>,
+
+	/// Toggle the density histogram beneath each grid.
+	density: Option<KeyName>,
+
+	/// Toggle the statistics panel.
+	statistics: Option<KeyName>,
+
+	/// Toggle the analysis view of spatial frequencies.
+	analysis: Option<KeyName>,
+
+	/// Toggle the dimming of the periodic background.
+	background: Option<KeyName>,
+
+	/// Toggle the highlighting of births and deaths.
+	changes: Option<KeyName>,
+
+	/// Toggle the gallery of bookmarks.
+	gallery: Option<KeyName>,
+
+	/// Toggle the painting of rules onto columns.
+	paint_rule: Option<KeyName>,
+
+	/// Move the cell cursor to the left.
+	cursor_left: Option<KeyName>,
+
+	/// Move the cell cursor to the right.
+	cursor_right: Option<KeyName>,
+
+	/// Toggle the cell under the cell cursor.
+	toggle_cell: Option<KeyName>,
+
+	/// Rotate the newest generation one column to the left.
+	rotate_left: Option<KeyName>,
+
+	/// Rotate the newest generation one column to the right.
+	rotate_right: Option<KeyName>,
+
+	/// Invert the newest generation.
+	invert: Option<KeyName>,
+
+	/// Mirror the left half of the newest generation onto its right half.
+	symmetrize: Option<KeyName>,
+
+	/// Reseed with the first generation that has the longest transient.
+	longest_transient: Option<KeyName>,
+
+	/// Fast-forward through many generations at once.
+	fast_forward: Option<KeyName>,
+
+	/// Evolve once per frame, while held.
+	turbo: Option<KeyName>,
+
+	/// Evolve in slow motion, while held.
+	slow_motion: Option<KeyName>,
+
+	/// Scroll back by a page of generations.
+	page_back: Option<KeyName>,
+
+	/// Scroll forward by a page of generations.
+	page_forward: Option<KeyName>
+}
+
+/// A [key](KeyCode) deserialized from its [name](key_from_name).
+#[derive(Copy, Clone, Debug)]
+struct KeyName(KeyCode);
+
+impl<'de> Deserialize<'de> for KeyName
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let name = String::deserialize(deserializer)?;
+		key_from_name(&name)
+			.map(KeyName)
+			.ok_or_else(|| D::Error::custom(format!("unknown key '{}'", name)))
+	}
+}
+
+/// A [palette&#32;scheme](PaletteScheme) deserialized from its name.
+#[derive(Copy, Clone, Debug)]
+struct SchemeName(PaletteScheme);
+
+impl<'de> Deserialize<'de> for SchemeName
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let name = String::deserialize(deserializer)?;
+		name.parse().map(SchemeName).map_err(D::Error::custom)
+	}
+}
+
+/// A [color](Color) deserialized from a hex string, with or without a leading
+/// `#`, in any of the forms accepted by [Color::hex].
+#[derive(Copy, Clone, Debug)]
+struct HexColor(Color);
+
+impl<'de> Deserialize<'de> for HexColor
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let hex = String::deserialize(deserializer)?;
+		Color::hex(&hex)
+			.map(HexColor)
+			.map_err(|e| {
+				D::Error::custom(format!("bad color '{}': {}", hex, e))
+			})
+	}
+}
+
+/// Answer the number of opening brackets in the specified line of TOML, less
+/// the number of closing brackets, ignoring any comment.
+fn brackets(line: &str) -> i32
+{
+	let code = line.split('#').next().unwrap_or_default();
+	code.matches('[').count() as i32 - code.matches(']').count() as i32
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why a [configuration&#32;file](Config) may fail to load.
+#[derive(Debug)]
+pub enum ConfigError
+{
+	/// The configuration file could not be read.
+	Io(io::Error),
+
+	/// The configuration file is not valid.
+	Parse(toml::de::Error),
+
+	/// Two actions, given by name, are bound to the same key.
+	Conflict { first: &'static str, second: &'static str, key: &'static str }
+}
+
+impl From<io::Error> for ConfigError
+{
+	fn from(value: io::Error) -> Self
+	{
+		ConfigError::Io(value)
+	}
+}
+
+impl From<toml::de::Error> for ConfigError
+{
+	fn from(value: toml::de::Error) -> Self
+	{
+		ConfigError::Parse(value)
+	}
+}
+
+impl Display for ConfigError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			ConfigError::Io(e) => write!(f, "cannot read configuration: {}", e),
+			ConfigError::Parse(e) => write!(f, "invalid configuration: {}", e),
+			ConfigError::Conflict { first, second, key } => write!(
+				f,
+				"invalid configuration: `{}` and `{}` are both bound to '{}'",
+				first,
+				second,
+				key
+			)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The path of the configuration file that is loaded when no other is
+/// specified, relative to the working directory.
+const DEFAULT_PATH: &str = "cellular-automata.toml";
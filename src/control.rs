@@ -0,0 +1,259 @@
+use std::io::{self, BufRead};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventWriter, IntoSystemConfigs, Plugin, Query, Res, ResMut, Resource,
+	Update, With
+};
+
+use crate::automata::{Automaton, AutomatonRule};
+use crate::ecs::{Action, apply_actions, EvolutionTimer, Focused, Pane};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for external control, through which other
+/// programs and scripts can drive the simulation by issuing simple textual
+/// [commands](Command), one per line:
+///
+/// * `rule 110` replaces the schedule of the focused pane with a single rule.
+/// * `pause` pauses the evolution, and `resume` resumes it.
+/// * `seed 0xdeadbeef` reseeds the focused pane.
+/// * `speed 50` evolves 50 generations per second.
+///
+/// Insert a [Control] before adding the plugin. Commands are carried out as
+/// [actions](Action) at the start of the next frame, just like keystrokes, so
+/// they can be recorded. Available for native builds only.
+pub struct ControlPlugin;
+
+impl Plugin for ControlPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(Update, accept_commands.before(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The place from which external [commands](Command) arrive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ControlSource
+{
+	/// Standard input, one command per line.
+	Stdin
+}
+
+impl FromStr for ControlSource
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"stdin" => Ok(ControlSource::Stdin),
+			_ => Err(format!("unknown control source '{}': expected stdin", s))
+		}
+	}
+}
+
+/// The lines of text received from the [source](ControlSource), awaiting the
+/// next frame. A background thread reads the source, so that a quiet source
+/// never stalls the frame.
+#[derive(Resource)]
+pub struct Control(Mutex<Receiver<String>>);
+
+impl Control
+{
+	/// Begin reading lines from the specified [source](ControlSource).
+	pub fn open(source: ControlSource) -> Self
+	{
+		let (sender, receiver) = channel();
+		match source
+		{
+			ControlSource::Stdin =>
+			{
+				thread::spawn(move || {
+					// Stop at the end of the input, or once nobody listens.
+					for line in io::stdin().lock().lines()
+					{
+						let Ok(line) = line else { break };
+						if sender.send(line).is_err()
+						{
+							break
+						}
+					}
+				});
+			}
+		}
+		Self(Mutex::new(receiver))
+	}
+
+	/// Answer every line received since the last call.
+	fn drain(&self) -> Vec<String>
+	{
+		self.0.lock().unwrap().try_iter().collect()
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Commands.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A request from the [source](ControlSource), parsed from one line of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Command
+{
+	/// Replace the schedule of the focused pane with a single rule.
+	Rule(AutomatonRule),
+
+	/// Pause the evolution, if it is running.
+	Pause,
+
+	/// Resume the evolution, if it is paused.
+	Resume,
+
+	/// Reseed the focused pane.
+	Seed(Automaton),
+
+	/// Evolve the specified number of generations per second.
+	Speed(f64)
+}
+
+impl FromStr for Command
+{
+	type Err = String;
+
+	/// Parse a command name, followed by its argument, if any, e.g.,
+	/// `rule 110`, `pause`, `resume`, `seed 0xdeadbeef`, or `speed 50`. A rule
+	/// may be given by code or name, as on the command line. A seed may be
+	/// given as a decimal integer, or as [digits](Automaton::from_digits) of
+	/// any length with a `0x` or `0b` prefix. A speed, in generations per
+	/// second, must be positive.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let mut words = s.split_whitespace();
+		let name = words.next().unwrap_or_default();
+		let argument = words.next();
+		if words.next().is_some()
+		{
+			return Err(format!("too many arguments: '{}'", s.trim()))
+		}
+		let required = || argument
+			.ok_or_else(|| format!("'{}' requires an argument", name));
+		match (name, argument)
+		{
+			("rule", _) => Ok(Command::Rule(required()?.parse()?)),
+			("pause", None) => Ok(Command::Pause),
+			("resume", None) => Ok(Command::Resume),
+			("seed", _) =>
+			{
+				let seed = required()?;
+				match seed.parse::<u64>()
+				{
+					Ok(seed) => Ok(Command::Seed(Automaton::from(seed))),
+					Err(_) => Ok(Command::Seed(Automaton::from_digits(seed)?))
+				}
+			},
+			("speed", _) => required()?.parse::<f64>()
+				.ok()
+				.filter(|speed| speed.is_finite() && *speed > 0.0)
+				.map(Command::Speed)
+				.ok_or_else(|| format!(
+					"bad speed '{}': expected generations per second",
+					argument.unwrap_or_default()
+				)),
+			("pause" | "resume", Some(_)) =>
+				Err(format!("'{}' takes no argument", name)),
+			_ => Err(format!(
+				"unknown command '{}': expected rule, pause, resume, seed, or \
+				speed",
+				name
+			))
+		}
+	}
+}
+
+impl Command
+{
+	/// Carry out the command upon the specified [pane](Pane), as an
+	/// [action](Action), except that a change of speed takes effect directly
+	/// upon the [timer](EvolutionTimer). `running` tracks the run state across
+	/// a batch of commands, so that `pause` and `resume` in the same frame
+	/// pair up correctly, and neither toggles the run state needlessly.
+	pub(crate) fn carry_out(
+		self,
+		pane: usize,
+		running: &mut bool,
+		timer: &mut EvolutionTimer,
+		actions: &mut EventWriter<Action>
+	) {
+		match self
+		{
+			Command::Rule(rule) =>
+				actions.send(Action::ReplaceRule { pane, rule }),
+			Command::Pause | Command::Resume =>
+			{
+				if *running != (self == Command::Resume)
+				{
+					*running = !*running;
+					actions.send(Action::TogglePause);
+				}
+			},
+			Command::Seed(seed) => actions.send(Action::Seed { pane, seed }),
+			Command::Speed(speed) =>
+			{
+				let millis = (1000.0 / speed).round().max(1.0) as u64;
+				timer.set_heartbeat(Duration::from_millis(millis));
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Carry out every [command](Command) received from the
+/// [source](ControlSource), as [actions](Action) upon the
+/// [focused](Focused)&#32;[pane](Pane). Changes of speed take effect directly
+/// upon the [timer](EvolutionTimer). Blank lines are ignored, and malformed
+/// commands are logged and skipped.
+fn accept_commands(
+	control: Res<Control>,
+	focused: Query<&Pane, With<Focused>>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut actions: EventWriter<Action>
+) {
+	let Ok(pane) = focused.get_single() else
+	{
+		return
+	};
+	let mut running = timer.is_running();
+	for line in control.drain()
+	{
+		if line.trim().is_empty()
+		{
+			continue
+		}
+		match line.parse::<Command>()
+		{
+			Ok(command) => command.carry_out(
+				pane.0,
+				&mut running,
+				&mut timer,
+				&mut actions
+			),
+			Err(e) => warn!("Ignoring control command: {}", e)
+		}
+	}
+}
@@ -0,0 +1,316 @@
+use std::collections::HashSet;
+
+use bevy::prelude::{
+	App, BackgroundColor, BuildChildren, Commands, Component, default,
+	DespawnRecursiveExt, DetectChanges, Entity, EventWriter, FlexDirection,
+	Input, IntoSystemConfigs, KeyCode, NodeBundle, Parent, Plugin, PositionType,
+	Query, Ref, Res, ResMut, Resource, Style, UiRect, Update, Val
+};
+use bevy::ui::{AlignItems, GridPlacement};
+
+use crate::automata::{
+	Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, ColumnDensity, Scrollback
+};
+use crate::ecs::{Announcement, apply_actions, evolve, Palette, PaneView};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the density histogram, which draws
+/// beneath the grid of every [pane](crate::ecs::Pane) a bar chart of how often
+/// each column has been live over the [scrollback](Scrollback), revealing
+/// spatial structure such as the left-right asymmetry of Rule 30. The
+/// [tallies](DensityTally) are kept up to date one generation at a time,
+/// whether or not the histogram is shown. The [DensityHistogram] resource
+/// governs whether the histogram is shown.
+pub struct DensityPlugin;
+
+impl Plugin for DensityPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<DensityHistogram>()
+			.add_systems(Update, maybe_toggle_density)
+			.add_systems(
+				Update,
+				tally_density.after(apply_actions).after(evolve)
+			)
+			.add_systems(
+				Update,
+				update_density
+					.after(maybe_toggle_density)
+					.after(tally_density)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the density histogram is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct DensityHistogram(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [column&#32;density](ColumnDensity) of a [pane](crate::ecs::Pane),
+/// along with the index and the cells of the newest generation tallied, so
+/// that only the generations since can be tallied.
+#[derive(Component)]
+struct DensityTally
+{
+	/// The column density.
+	density: ColumnDensity,
+
+	/// The index of the newest generation tallied.
+	counted: usize,
+
+	/// The cells of the newest generation tallied.
+	newest: Automaton
+}
+
+impl From<&Scrollback> for DensityTally
+{
+	fn from(scrollback: &Scrollback) -> Self
+	{
+		let (counted, &newest, _) = scrollback.generation(0).unwrap();
+		Self { density: ColumnDensity::from(scrollback), counted, newest }
+	}
+}
+
+/// The strip beneath the grid of a [view](PaneView), given as an
+/// [entity](Entity), that holds its [bars](DensityBar).
+#[derive(Component)]
+struct DensityStrip(Entity);
+
+/// A bar of the density histogram, which pictures the density of the
+/// specified column.
+#[derive(Component)]
+struct DensityBar(usize);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F3`, toggle the [density&#32;histogram](DensityHistogram), and announce
+/// the new setting.
+fn maybe_toggle_density(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut histogram: ResMut<DensityHistogram>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.density)
+	{
+		histogram.0 = !histogram.0;
+		let message = match histogram.0
+		{
+			true => Message::DensityOn,
+			false => Message::DensityOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// Bring the [tally](DensityTally) of every [pane](crate::ecs::Pane) up to
+/// date with its [scrollback](Scrollback), tallying only the generations
+/// evolved since the last frame. An edited newest generation replaces its
+/// earlier tally. If the evolution started over or was rewound, or the
+/// scrollback no longer retains the generations since the last frame, then
+/// tally the scrollback afresh. Difference panes have no scrollback, and thus
+/// no tally.
+fn tally_density(
+	mut panes: Query<(Entity, Ref<Scrollback>, Option<&mut DensityTally>)>,
+	mut commands: Commands
+) {
+	for (entity, scrollback, tally) in &mut panes
+	{
+		let Some(mut tally) = tally else
+		{
+			commands.entity(entity).insert(DensityTally::from(&*scrollback));
+			continue
+		};
+		if !scrollback.is_changed()
+		{
+			continue
+		}
+		let (index, &newest, _) = scrollback.generation(0).unwrap();
+		if index == tally.counted
+		{
+			if newest != tally.newest
+			{
+				let old = tally.newest;
+				tally.density.remove(&old);
+				tally.density.push(&newest);
+				tally.newest = newest;
+			}
+			continue
+		}
+		let backlog = index.saturating_sub(tally.counted);
+		let continues = backlog > 0 && scrollback.generation(backlog)
+			.is_some_and(|(_, automaton, _)| *automaton == tally.newest);
+		match continues
+		{
+			true =>
+			{
+				for back in (0 .. backlog).rev()
+				{
+					let (_, automaton, _) =
+						scrollback.generation(back).unwrap();
+					tally.density.push(automaton);
+				}
+				tally.counted = index;
+				tally.newest = newest;
+			},
+			false => *tally = DensityTally::from(&*scrollback)
+		}
+	}
+}
+
+/// While the [density&#32;histogram](DensityHistogram) is shown, give every
+/// [view](PaneView) of a tallied pane a [strip](DensityStrip) of
+/// [bars](DensityBar), and keep the height of each bar in step with the
+/// [tally](DensityTally) and its color in step with the [palette](Palette).
+/// Otherwise, despawn every strip. Views are rebuilt from time to time, e.g.,
+/// whenever the layout changes, and their strips go with them, so new views
+/// receive new strips.
+fn update_density(
+	histogram: Res<DensityHistogram>,
+	palette: Res<Palette>,
+	views: Query<(Entity, &PaneView)>,
+	tallies: Query<Ref<DensityTally>>,
+	strips: Query<(Entity, &DensityStrip)>,
+	mut bars: Query<(&DensityBar, &Parent, &mut Style, &mut BackgroundColor)>,
+	mut commands: Commands
+) {
+	if !histogram.0
+	{
+		for (strip, _) in &strips
+		{
+			commands.entity(strip).despawn_recursive();
+		}
+		return
+	}
+	let striped = strips.iter()
+		.map(|(_, strip)| strip.0)
+		.collect::<HashSet<_>>();
+	for (view, pane) in &views
+	{
+		if striped.contains(&view)
+		{
+			continue
+		}
+		if let Ok(tally) = tallies.get(pane.0)
+		{
+			strip(view, &tally.density, &palette, &mut commands);
+		}
+	}
+	let changed = palette.is_changed()
+		|| tallies.iter().any(|tally| tally.is_changed());
+	if !changed
+	{
+		return
+	}
+	for (bar, parent, mut style, mut color) in &mut bars
+	{
+		let tally = strips.get(parent.get())
+			.and_then(|(_, strip)| views.get(strip.0))
+			.and_then(|(_, pane)| tallies.get(pane.0));
+		if let Ok(tally) = tally
+		{
+			style.height = Val::Percent(tally.density.density(bar.0) * 100.0);
+			color.0 = palette.live;
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Draw the specified [column&#32;density](ColumnDensity) beneath the grid of
+/// the specified [view](PaneView), as a [strip](DensityStrip) of one
+/// [bar](DensityBar) per column. The strip is an absolutely positioned child
+/// of the grid that spans every column of the last row, but is pushed down
+/// into the padding below it, so the grid itself lines up each bar with its
+/// column.
+fn strip(
+	view: Entity,
+	density: &ColumnDensity,
+	palette: &Palette,
+	commands: &mut Commands
+) {
+	commands.entity(view).with_children(|builder| {
+		builder
+			.spawn(
+				(
+					NodeBundle {
+						style: Style {
+							position_type: PositionType::Absolute,
+							grid_row: GridPlacement::start(
+								AUTOMATON_HISTORY as i16
+							),
+							grid_column: GridPlacement::start_span(
+								1,
+								AUTOMATON_LENGTH as u16
+							),
+							left: Val::Px(0.0),
+							right: Val::Px(0.0),
+							top: Val::Percent(100.0),
+							height: Val::Px(STRIP_HEIGHT),
+							margin: UiRect::top(Val::Px(STRIP_MARGIN)),
+							flex_direction: FlexDirection::Row,
+							align_items: AlignItems::FlexEnd,
+							..default()
+						},
+						background_color: BackgroundColor(palette.dead),
+						..default()
+					},
+					DensityStrip(view)
+				)
+			)
+			.with_children(|builder| {
+				for column in 0 .. AUTOMATON_LENGTH
+				{
+					builder.spawn(
+						(
+							NodeBundle {
+								style: Style {
+									flex_grow: 1.0,
+									flex_basis: Val::Px(0.0),
+									height: Val::Percent(
+										density.density(column) * 100.0
+									),
+									..default()
+								},
+								background_color: BackgroundColor(
+									palette.live
+								),
+								..default()
+							},
+							DensityBar(column)
+						)
+					);
+				}
+			});
+	});
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The height of the [strip](DensityStrip) beneath each grid, in pixels, which
+/// must fit within the padding of the grid.
+const STRIP_HEIGHT: f32 = 18.0;
+
+/// The gap between the grid and its [strip](DensityStrip), in pixels.
+const STRIP_MARGIN: f32 = 3.0;
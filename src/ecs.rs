@@ -1,78 +1,440 @@
 use std::fmt;
 use std::fmt::Formatter;
 use std::ops::{Index, IndexMut, RangeInclusive};
+use std::str::FromStr;
 use std::time::Duration;
 
-use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::diagnostic::{
+	Diagnostic, DiagnosticId, Diagnostics, DiagnosticsStore,
+	FrameTimeDiagnosticsPlugin, RegisterDiagnostic
+};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::input::touch::Touches;
+use bevy::log::warn;
 use bevy::prelude::{
-	AlignSelf, App,
-	BackgroundColor, BuildChildren, Button, ButtonBundle,
-	Camera2dBundle, Changed, ChildBuilder, Color, Commands, Component,
-	default, DefaultPlugins, Display,
-	Input, Interaction,
+	Added, AlignSelf, App, Assets,
+	BackgroundColor, BorderColor, BuildChildren, Bundle, Button, ButtonBundle,
+	Camera2dBundle, Changed, ChildBuilder, ClearColor, Color, Commands,
+	Component,
+	default, DefaultPlugins, DetectChanges, DetectChangesMut, Display,
+	Entity, Event, EventReader, EventWriter,
+	FlexDirection,
+	Image, Input, Interaction, IntoSystemConfigs,
 	KeyCode,
+	Last, Local,
+	Mut,
 	NodeBundle,
-	Plugin, PluginGroup, PositionType,
+	Overflow,
+	ParamSet, Plugin, PluginGroup, PositionType,
 	Query,
-	Res, ResMut, Resource,
+	Ref, Res, ResMut, Resource,
 	Startup, Style,
 	Text, TextBundle, TextSection, TextStyle, Time, Timer,
-	UiRect, Update,
+	UiImage, UiRect, Update,
 	Val,
-	Window, WindowPlugin, With
+	Window, WindowPlugin, With, Without,
+	ZIndex
 };
 use bevy::time::TimerMode;
+#[cfg(not(target_family = "wasm"))]
+use bevy::utils::Instant;
+#[cfg(target_os = "macos")]
+use bevy::window::CompositeAlphaMode;
+use bevy::window::{PresentMode, PrimaryWindow, WindowLevel, WindowMode};
 use bevy::ui::{JustifyContent, RepeatedGridTrack};
+use rand::random;
 
+use crate::analysis::AnalysisPlugin;
+use crate::background::BackgroundPlugin;
+use crate::changes::ChangesPlugin;
+use crate::ancestry::AncestryPlugin;
 use crate::automata::{
-	AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule,
-	History
+	Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule,
+	Decay,
+	ExportFormat,
+	History,
+	INTERESTINGNESS_GENERATIONS, INTERESTINGNESS_THRESHOLD,
+	INTERESTINGNESS_TRIALS,
+	longest_transient,
+	RuleKind, RuleMap, RuleSchedule, RuleTimeline,
+	Scrollback, SteadyState, surprising_rule
 };
 #[cfg(doc)]
-use crate::automata::Automaton;
+use crate::automata::Interestingness;
+use crate::bookmarks::BookmarksPlugin;
+use crate::checkpoint::CheckpointPlugin;
+use crate::density::DensityPlugin;
+use crate::cell_style::CellStylePlugin;
+use crate::effects::{blend, EffectsPlugin};
+use crate::extrusion::{Extrusion, ExtrusionPlugin};
+use crate::favorites::FavoritesPlugin;
+use crate::gallery::GalleryPlugin;
+use crate::geometry::{CellShape, Gridlines};
+use crate::gestures::GesturePlugin;
+use crate::i18n::{Language, Message};
+use crate::keys::{key_name, KeyBindings};
+use crate::light_cone::LightConePlugin;
+use crate::minimap::MinimapPlugin;
+use crate::neon::NeonPlugin;
+use crate::particles::ParticlePlugin;
+use crate::phosphor::PhosphorPlugin;
+use crate::poster::PosterPlugin;
+use crate::rule_icon::RuleIconPlugin;
+use crate::rule_map::{Painting, RuleMapPlugin};
+use crate::share_card::ShareCardPlugin;
+use crate::sonification::SonificationPlugin;
+use crate::statistics::StatisticsPlugin;
+use crate::tape::TapePlugin;
+use crate::tooltip::TooltipPlugin;
+use crate::transitions::TransitionPlugin;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                  Plugins.                                  //
 ////////////////////////////////////////////////////////////////////////////////
 
 /// The [plugin](Plugin) responsible for managing our
-/// [evolutionary&#32;system](evolve).
-pub struct AutomataPlugin;
+/// [evolutionary&#32;system](evolve). Construct it from an [AutomataConfig] to
+/// embed the evolver in another Bevy application.
+#[derive(Clone, Debug, Default)]
+pub struct AutomataPlugin
+{
+	/// The configuration of the evolver.
+	config: AutomataConfig
+}
+
+impl AutomataPlugin
+{
+	/// Construct a new [AutomataPlugin] with the specified
+	/// [configuration](AutomataConfig).
+	pub fn new(config: AutomataConfig) -> Self
+	{
+		Self { config }
+	}
+}
 
 impl Plugin for AutomataPlugin
 {
-	/// The initial [seed](Automaton) and [rule](AutomatonRule) must already
-	/// have been set.
+	/// The initial [panes](AutomatonBundle), each with its own
+	/// [seed](Automaton) and [schedule](RuleSchedule), should already have
+	/// been spawned; if none have, then a single pane is spawned with a random
+	/// seed and the default schedule. The leftmost pane receives the input
+	/// [focus](Focused). Resources inserted beforehand, e.g., [FramePacing],
+	/// [PaletteScheme], or [PaletteOverrides], take precedence over the
+	/// [configuration](AutomataConfig).
 	fn build(&self, app: &mut App)
 	{
-		let _seed = app.world.get_resource::<History>()
-			.expect("History resource to be inserted already");
-		let rule = app.world.get_resource::<AutomatonRule>()
-			.expect("AutomatonRule resource to be inserted already");
-		let mut window = Window {
-			resolution: [1024.0, 768.0].into(),
-			title: rule.to_string(),
-			..default()
+		let config = &self.config;
+		let mut panes = app.world.query::<(Entity, &Pane, &RuleSchedule)>();
+		if panes.iter(&app.world).next().is_none()
+		{
+			app.world.spawn(AutomatonBundle::new(
+				0,
+				History::from(Automaton::from(random::<u64>())),
+				RuleSchedule::default(),
+				None
+			));
+		}
+		let first = panes.iter(&app.world)
+			.min_by_key(|(_, pane, _)| **pane)
+			.map(|(entity, _, _)| entity)
+			.unwrap();
+		let title = title(
+			panes.iter(&app.world).map(|(_, pane, schedule)| (pane, schedule))
+		);
+		app.world.entity_mut(first).insert(Focused);
+		let pacing = *app.world
+			.get_resource_or_insert_with(|| config.pacing);
+		let scheme = *app.world
+			.get_resource_or_insert_with(|| config.scheme);
+		let overrides = {
+			let mut overrides = app.world
+				.get_resource_or_insert_with(|| config.overrides);
+			if config.transparent
+			{
+				overrides.dead = overrides.dead.or(Some(Color::NONE));
+				overrides.chrome = overrides.chrome.or(Some(Color::NONE));
+			}
+			*overrides
 		};
-		set_title(&mut window, *rule);
-		app
-			.add_plugins(DefaultPlugins.set(WindowPlugin {
+		app.world.get_resource_or_insert_with(|| {
+			let mut timer = EvolutionTimer::new();
+			timer.set_heartbeat(config.heartbeat);
+			if !config.paused
+			{
+				timer.toggle();
+			}
+			timer
+		});
+		if config.default_plugins
+		{
+			let mut window = Window {
+				resolution: config.resolution.into(),
+				title: title.clone(),
+				present_mode: pacing.present_mode(),
+				// Track the size of the canvas's parent element, so that the
+				// page, or an embedding iframe, decides how big we are.
+				#[cfg(target_family = "wasm")]
+				fit_canvas_to_parent: true,
+				..default()
+			};
+			if config.ambient
+			{
+				window.mode = WindowMode::BorderlessFullscreen;
+				window.decorations = false;
+				window.window_level = WindowLevel::AlwaysOnTop;
+				// Let clicks fall through to whatever lies beneath.
+				window.cursor.hit_test = false;
+			}
+			if config.transparent
+			{
+				window.transparent = true;
+				// Without this, macOS composites the window as opaque.
+				#[cfg(target_os = "macos")]
+				{
+					window.composite_alpha_mode =
+						CompositeAlphaMode::PostMultiplied;
+				}
+			}
+			set_title(&mut window, &title);
+			app.add_plugins(DefaultPlugins.set(WindowPlugin {
 				primary_window: Some(window),
 				..default()
-			}))
-			.add_plugins(FrameTimeDiagnosticsPlugin)
-			.insert_resource(EvolutionTimer::default())
+			}));
+		}
+		if config.transparent
+		{
+			app.insert_resource(ClearColor(Color::NONE));
+		}
+		if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>()
+		{
+			app.add_plugins(FrameTimeDiagnosticsPlugin);
+		}
+		app
+			.register_diagnostic(
+				Diagnostic::new(
+					GENERATIONS_PER_SECOND,
+					"generations_per_second",
+					GENERATIONS_PER_SECOND_HISTORY
+				)
+					.with_suffix(" gen/s")
+			)
+			.add_plugins(SonificationPlugin)
+			.add_plugins(EffectsPlugin)
+			.add_plugins(TransitionPlugin)
+			.add_plugins(PhosphorPlugin)
+			.add_plugins(CellStylePlugin)
+			.add_plugins(NeonPlugin)
+			.add_plugins(ExtrusionPlugin)
+			.add_plugins(TapePlugin)
+			.add_plugins(ParticlePlugin)
+			.add_plugins(LightConePlugin)
+			.add_plugins(AncestryPlugin)
+			.add_plugins(TooltipPlugin)
+			.add_plugins(MinimapPlugin)
+			.add_plugins(DensityPlugin)
+			.add_plugins(StatisticsPlugin)
+			.add_plugins(AnalysisPlugin)
+			.add_plugins(BackgroundPlugin)
+			.add_plugins(ChangesPlugin)
+			.add_plugins(RuleIconPlugin)
+			.add_plugins(RuleMapPlugin)
+			.add_plugins(GesturePlugin)
+			.add_plugins(FavoritesPlugin)
+			.add_plugins(BookmarksPlugin)
+			.add_plugins(GalleryPlugin)
+			.add_plugins(ShareCardPlugin)
+			.add_plugins(PosterPlugin)
+			.add_plugins(CheckpointPlugin)
+			.init_resource::<CellShape>()
+			.init_resource::<Gridlines>()
+			.insert_resource(Palette::new(scheme, &overrides))
+			.init_resource::<KeyBindings>()
+			.init_resource::<Language>()
+			.init_resource::<Mode>()
 			.insert_resource(AutomatonRuleBuilder::default())
+			.insert_resource(Generation::default())
+			.insert_resource(ScrollOffset::default())
+			.insert_resource(Turbo::default())
+			.insert_resource(SlowMotion::default())
+			.insert_resource(GenerationCeiling::default())
+			.init_resource::<Scan>()
+			.init_resource::<SymmetricSeeds>()
+			.init_resource::<ClassSampling>()
+			.init_resource::<ExportFormat>()
+			.insert_resource(CellCursor::default())
+			.add_event::<Action>()
+			.add_event::<Announcement>()
+			.add_event::<GenerationEvolved>()
+			.add_event::<RuleChanged>()
+			.add_event::<CellEdited>()
 			.add_systems(Startup, add_camera)
 			.add_systems(Startup, build_ui)
 			.add_systems(Update, maybe_toggle_instructions)
+			.add_systems(Update, maybe_change_focus)
+			.add_systems(Update, update_focus)
 			.add_systems(Update, accept_digit)
 			.add_systems(Update, maybe_show_fps)
+			.add_systems(Update, maybe_turbo.before(accept_digit))
+			.add_systems(Update, maybe_slow_motion)
 			.add_systems(Update, maybe_toggle_cells)
+			.add_systems(Update, maybe_move_cursor)
+			.add_systems(Update, update_cursor.after(maybe_move_cursor))
 			.add_systems(Update, update_next_rule)
 			.add_systems(Update, maybe_change_rule)
-			.add_systems(Update, evolve)
+			.add_systems(Update, maybe_edit_schedule)
+			.add_systems(Update, update_schedule)
+			.add_systems(Update, update_rule_bits)
+			.add_systems(Update, maybe_mutate_rule)
+			.add_systems(Update, maybe_surprise)
+			.add_systems(Update, maybe_transform_rule.before(accept_digit))
+			.add_systems(Update, maybe_transform_seed)
+			.add_systems(Update, maybe_find_transient)
+			.add_systems(Update, maybe_fast_forward)
+			.add_systems(Update, update_mutation)
+			.add_systems(Update, update_title)
+			.add_systems(
+				Update,
+				apply_actions
+					.after(maybe_toggle_instructions)
+					.after(maybe_toggle_cells)
+					.after(maybe_change_rule)
+					.after(maybe_edit_schedule)
+					.after(maybe_mutate_rule)
+					.after(maybe_surprise)
+					.after(maybe_transform_rule)
+					.after(maybe_transform_seed)
+					.after(maybe_find_transient)
+					.after(maybe_fast_forward)
+					.after(maybe_move_cursor)
+			)
+			.add_systems(
+				Update,
+				evolve
+					.after(apply_actions)
+					.after(maybe_turbo)
+					.after(maybe_slow_motion)
+			)
+			.add_systems(Update, maybe_pause_on_steady_state.after(evolve))
+			.add_systems(
+				Update,
+				maybe_cycle_rule.after(maybe_pause_on_steady_state)
+			)
+			.add_systems(Update, update_toast)
+			.add_systems(Update, maybe_scroll)
+			.add_systems(Update, maybe_export.before(accept_digit))
+			.add_systems(
+				Update,
+				update_scroll.after(maybe_scroll).after(apply_actions)
+			)
+			.add_systems(
+				Update,
+				update_differences.after(evolve)
+			)
+			.add_systems(Update, maybe_change_gridlines)
+			.add_systems(
+				Update,
+				update_gridlines.after(maybe_change_gridlines)
+			)
+			.add_systems(Update, maybe_toggle_vsync)
+			.add_systems(Update, maybe_change_palette)
+			.add_systems(
+				Update,
+				update_palette
+					.after(maybe_change_palette)
+					.after(update_scroll)
+			)
+			.add_systems(
+				Update,
+				paint_cells
+					.after(evolve)
+					.after(apply_actions)
+					.after(update_palette)
+			)
+			.add_systems(
+				Update,
+				update_scan
+					.after(evolve)
+					.after(update_palette)
+					.after(paint_cells)
+			)
+			.add_systems(Update, update_generation.after(evolve))
+			.add_systems(
+				Update,
+				update_countdown.after(evolve).after(apply_actions)
+			)
+			.add_systems(Update, measure_generations.after(evolve))
+			.add_systems(Update, trace_events.after(evolve))
 			.add_systems(Update, update_fps);
+		#[cfg(not(target_family = "wasm"))]
+		app.add_systems(Last, limit_frame_rate);
+		#[cfg(target_family = "wasm")]
+		app
+			.add_systems(Update, sync_url.after(evolve))
+			.add_systems(Update, persist_settings.after(evolve));
+		#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+		app.add_systems(Update, crate::scripting::maybe_reload_scripts);
+		#[cfg(feature = "egui")]
+		app.add_plugins(crate::settings::SettingsPlugin);
+	}
+}
+
+/// The configuration of an [AutomataPlugin]. Any resource that the
+/// configuration would supply is left alone if it was inserted before the
+/// plugin was added.
+#[derive(Copy, Clone, Debug)]
+pub struct AutomataConfig
+{
+	/// The [scheme](PaletteScheme) that colors the cells.
+	pub scheme: PaletteScheme,
+
+	/// The [colors](PaletteOverrides) that replace those of every scheme.
+	pub overrides: PaletteOverrides,
+
+	/// The logical resolution of the primary window, if the plugin creates it.
+	/// On the web, the canvas tracks the size of its parent element instead.
+	pub resolution: [f32; 2],
+
+	/// The interval between successive generations.
+	pub heartbeat: Duration,
+
+	/// How the frames are [paced](FramePacing).
+	pub pacing: FramePacing,
+
+	/// Whether the evolution begins paused, showing the instructions.
+	pub paused: bool,
+
+	/// Whether to run as an ambient decoration, in a borderless, click-through
+	/// window that fills the screen and stays on top of every other window.
+	/// This applies only if the plugin creates the window.
+	pub ambient: bool,
+
+	/// Whether to render dead cells and the chrome around them fully
+	/// transparent, in a window with an alpha channel, so that the evolution
+	/// can be overlaid upon other content, e.g., by streaming software. Any
+	/// [overrides](PaletteOverrides) of those colors take precedence.
+	pub transparent: bool,
+
+	/// Whether to add Bevy's [DefaultPlugins], including a primary window. An
+	/// embedding application that already has a window, or that otherwise
+	/// provides these plugins itself, should turn this off.
+	pub default_plugins: bool
+}
+
+impl Default for AutomataConfig
+{
+	fn default() -> Self
+	{
+		Self {
+			scheme: PaletteScheme::default(),
+			overrides: PaletteOverrides::default(),
+			resolution: WINDOW_RESOLUTION,
+			heartbeat: HEARTBEAT,
+			pacing: FramePacing::default(),
+			paused: true,
+			ambient: false,
+			transparent: false,
+			default_plugins: true
+		}
 	}
 }
 
@@ -81,9 +443,11 @@ impl Plugin for AutomataPlugin
 ////////////////////////////////////////////////////////////////////////////////
 
 /// A repeating [timer](Timer) timer that controls the [evolution][evolve] rate
-/// of the [automaton](Automaton).
+/// of the [automaton](Automaton). Insert this resource before adding the
+/// [AutomataPlugin] to override the [configured](AutomataConfig::heartbeat)
+/// heartbeat.
 #[derive(Resource)]
-struct EvolutionTimer(Timer);
+pub(crate) struct EvolutionTimer(Timer);
 
 impl EvolutionTimer
 {
@@ -98,21 +462,36 @@ impl EvolutionTimer
 	}
 
 	/// Determine whether the [timer](Timer) is running.
-	fn is_running(&self) -> bool
+	pub(crate) fn is_running(&self) -> bool
 	{
 		!self.0.paused()
 	}
 
-	/// Update the timer by the specified [duration](Duration). If the timer has
-	/// expired, then run the specified function.
+	/// Answer how far the current heartbeat has elapsed, in `[0,1]`.
+	pub(crate) fn progress(&self) -> f32
+	{
+		self.0.percent()
+	}
+
+	/// Update the timer by the specified [duration](Duration). Answer the
+	/// number of times that the timer expired, which exceeds one whenever the
+	/// heartbeat is shorter than the duration.
 	#[inline]
-	fn tick(&mut self, delta: Duration, on_expired: impl FnOnce())
+	fn tick(&mut self, delta: Duration) -> u32
 	{
-		self.0.tick(delta);
-		if self.0.finished()
-		{
-			on_expired();
-		}
+		self.0.tick(delta).times_finished_this_tick()
+	}
+
+	/// Answer the interval between successive generations.
+	pub(crate) fn heartbeat(&self) -> Duration
+	{
+		self.0.duration()
+	}
+
+	/// Change the interval between successive generations.
+	pub(crate) fn set_heartbeat(&mut self, heartbeat: Duration)
+	{
+		self.0.set_duration(heartbeat);
 	}
 
 	/// Toggle the execution state of the [timer](Timer), between paused and
@@ -136,9 +515,467 @@ impl Default for EvolutionTimer
 	}
 }
 
+/// What the [panes](Pane) simulate. Insert this resource before adding the
+/// [AutomataPlugin] to choose a mode other than elementary automata.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum Mode
+{
+	/// Elementary cellular automata, one generation per row.
+	#[default]
+	Elementary,
+
+	/// A colony of [Langton's&#32;ants](crate::automata::Colony), which
+	/// wander the whole grid. The [evolver](evolve) keeps time for the ants,
+	/// but leaves the [histories](History) alone.
+	Ant
+}
+
+impl FromStr for Mode
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"elementary" => Ok(Mode::Elementary),
+			"ant" => Ok(Mode::Ant),
+			_ => Err(format!(
+				"unknown mode '{}': expected elementary or ant",
+				s
+			))
+		}
+	}
+}
+
+impl fmt::Display for Mode
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			Mode::Elementary => "elementary",
+			Mode::Ant => "ant"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+/// Screensaver mode: every so often, replace the [schedule](RuleSchedule) of
+/// every [pane](Pane) with a random [rule](AutomatonRule), and optionally
+/// reseed its [history](History). Cycling happens only while the evolver is
+/// running. Insert this resource before adding the [AutomataPlugin] to enable
+/// cycling.
+#[derive(Resource)]
+pub struct RuleCycle
+{
+	/// The [timer](Timer) that paces the cycle.
+	timer: Timer,
+
+	/// Whether to reseed each pane with a random first generation at each
+	/// switch.
+	reseed: bool
+}
+
+impl RuleCycle
+{
+	/// Construct a new [RuleCycle] that switches rules at the specified
+	/// interval, optionally reseeding at each switch.
+	pub fn new(period: Duration, reseed: bool) -> Self
+	{
+		Self { timer: Timer::new(period, TimerMode::Repeating), reseed }
+	}
+
+	/// Cut short the current interval, so that the next switch happens
+	/// immediately.
+	fn expire(&mut self)
+	{
+		let remaining = self.timer.remaining();
+		self.timer.tick(remaining);
+	}
+}
+
+/// How the frames are paced, independently of the [heartbeat](EvolutionTimer)
+/// of the evolution. Insert this resource before adding the [AutomataPlugin]
+/// to cap the frame rate or to disable vsync.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Resource)]
+pub struct FramePacing
+{
+	/// The shortest interval between successive frames, if any.
+	cap: Option<Duration>,
+
+	/// Whether to synchronize frames with the display's refresh rate.
+	vsync: bool
+}
+
+impl FramePacing
+{
+	/// Construct a new [FramePacing] that renders at most `fps_cap` frames per
+	/// second, if specified, and synchronizes with the display's refresh rate
+	/// if `vsync`. The cap is ignored unless it is positive and finite.
+	pub fn new(fps_cap: Option<f32>, vsync: bool) -> Self
+	{
+		let cap = fps_cap
+			.filter(|fps| fps.is_finite() && *fps > 0.0)
+			.and_then(|fps| Duration::try_from_secs_f32(1.0 / fps).ok());
+		Self { cap, vsync }
+	}
+
+	/// Answer the [present&#32;mode](PresentMode) that honors the vsync
+	/// setting.
+	fn present_mode(&self) -> PresentMode
+	{
+		match self.vsync
+		{
+			true => PresentMode::AutoVsync,
+			false => PresentMode::AutoNoVsync
+		}
+	}
+}
+
+impl Default for FramePacing
+{
+	fn default() -> Self
+	{
+		Self { cap: None, vsync: true }
+	}
+}
+
+/// The [colors](Color) in which cells are painted, as derived from the current
+/// [scheme](PaletteScheme) and any [overrides](PaletteOverrides).
+#[derive(Copy, Clone, Debug, PartialEq, Resource)]
+pub struct Palette
+{
+	/// The color of live cells, and of the borders around square cells.
+	pub(crate) live: Color,
+
+	/// The color of dead cells.
+	pub(crate) dead: Color,
+
+	/// The color of cells of a difference pane where the compared histories
+	/// disagree.
+	pub(crate) difference: Color,
+
+	/// The color of a hovered cell of the [newest](History::newest)
+	/// generation.
+	pub(crate) pressed: Color,
+
+	/// The color of the chrome around and between the cells.
+	pub(crate) chrome: Color
+}
+
+impl Palette
+{
+	/// Answer the colors of the specified [scheme](PaletteScheme), replacing
+	/// any that have [overrides](PaletteOverrides).
+	pub fn new(scheme: PaletteScheme, overrides: &PaletteOverrides) -> Self
+	{
+		let base = scheme.palette();
+		Self {
+			live: overrides.live.unwrap_or(base.live),
+			dead: overrides.dead.unwrap_or(base.dead),
+			difference: overrides.difference.unwrap_or(base.difference),
+			pressed: overrides.pressed.unwrap_or(base.pressed),
+			chrome: overrides.chrome.unwrap_or(base.chrome)
+		}
+	}
+
+	/// Answer the appropriate [BackgroundColor] for the specified cell
+	/// liveness.
+	#[inline]
+	fn liveness_color(&self, live: bool) -> BackgroundColor
+	{
+		BackgroundColor(if live { self.live } else { self.dead })
+	}
+
+	/// Answer the appropriate [BackgroundColor] for the specified cell of the
+	/// specified [history](History), whose pane may [decay](Decay). Live and
+	/// dead cells take their [liveness&#32;colors](Self::liveness_color), but
+	/// decaying cells fade from the live color toward the dead color.
+	#[inline]
+	pub(crate) fn cell_color(
+		&self,
+		history: &History,
+		decay: Option<&Decay>,
+		position: CellPosition
+	) -> BackgroundColor
+	{
+		match decay.map(|decay| (decay[position], decay.generations()))
+		{
+			Some((level, generations)) if level > 0 =>
+			{
+				let fraction = level as f32 / (generations as f32 + 1.0);
+				BackgroundColor(blend(self.live, self.dead, fraction))
+			},
+			_ => self.liveness_color(history[position])
+		}
+	}
+
+	/// Answer the appropriate [BackgroundColor] for the specified cell of a
+	/// difference pane, rendering an agreement like a dead cell.
+	#[inline]
+	fn difference_color(&self, differs: bool) -> BackgroundColor
+	{
+		BackgroundColor(if differs { self.difference } else { self.dead })
+	}
+}
+
+/// The built-in color schemes for painting cells. Besides the classic scheme,
+/// there are schemes that stay distinguishable under the common forms of
+/// red–green color blindness, a high-contrast scheme, and a muted scheme for
+/// ambient displays. Every scheme has its
+/// own pressed color, chosen to stand apart from its other colors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Resource)]
+pub enum PaletteScheme
+{
+	/// Black and white cells, with crimson differences and yellow highlights.
+	#[default]
+	Classic,
+
+	/// Black and white cells, with vermilion differences and sky blue
+	/// highlights, safe for deuteranopia.
+	Deuteranopia,
+
+	/// Black and white cells, with blue differences and orange highlights,
+	/// safe for protanopia.
+	Protanopia,
+
+	/// White cells on black, with yellow differences and magenta
+	/// highlights, for maximal contrast.
+	HighContrast,
+
+	/// Slate cells on a dusky background, with dusty rose differences and
+	/// pale gold highlights, for an unobtrusive
+	/// [ambient](AutomataConfig::ambient) display.
+	Muted
+}
+
+impl PaletteScheme
+{
+	/// Every scheme, in cycling order.
+	#[allow(dead_code)]
+	pub const ALL: [PaletteScheme; 5] = [
+		PaletteScheme::Classic,
+		PaletteScheme::Deuteranopia,
+		PaletteScheme::Protanopia,
+		PaletteScheme::HighContrast,
+		PaletteScheme::Muted
+	];
+
+	/// Answer the next scheme in cycling order, wrapping around at the end.
+	pub fn next(self) -> Self
+	{
+		match self
+		{
+			PaletteScheme::Classic => PaletteScheme::Deuteranopia,
+			PaletteScheme::Deuteranopia => PaletteScheme::Protanopia,
+			PaletteScheme::Protanopia => PaletteScheme::HighContrast,
+			PaletteScheme::HighContrast => PaletteScheme::Muted,
+			PaletteScheme::Muted => PaletteScheme::Classic
+		}
+	}
+
+	/// Answer the colors of the scheme.
+	fn palette(self) -> Palette
+	{
+		match self
+		{
+			PaletteScheme::Classic => Palette {
+				live: LIVE_COLOR,
+				dead: DEAD_COLOR,
+				difference: DIFFERENCE_COLOR,
+				pressed: PRESSED_COLOR,
+				chrome: CHROME_COLOR
+			},
+			PaletteScheme::Deuteranopia => Palette {
+				live: LIVE_COLOR,
+				dead: DEAD_COLOR,
+				difference: VERMILION,
+				pressed: SKY_BLUE,
+				chrome: CHROME_COLOR
+			},
+			PaletteScheme::Protanopia => Palette {
+				live: LIVE_COLOR,
+				dead: DEAD_COLOR,
+				difference: BLUE,
+				pressed: ORANGE,
+				chrome: CHROME_COLOR
+			},
+			PaletteScheme::HighContrast => Palette {
+				live: Color::WHITE,
+				dead: Color::BLACK,
+				difference: Color::YELLOW,
+				pressed: Color::FUCHSIA,
+				chrome: CHROME_COLOR
+			},
+			PaletteScheme::Muted => Palette {
+				live: SLATE,
+				dead: DUSK,
+				difference: DUSTY_ROSE,
+				pressed: PALE_GOLD,
+				chrome: CHROME_COLOR
+			}
+		}
+	}
+}
+
+impl FromStr for PaletteScheme
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"classic" => Ok(PaletteScheme::Classic),
+			"deuteranopia" => Ok(PaletteScheme::Deuteranopia),
+			"protanopia" => Ok(PaletteScheme::Protanopia),
+			"high-contrast" => Ok(PaletteScheme::HighContrast),
+			"muted" => Ok(PaletteScheme::Muted),
+			_ => Err(format!(
+				"unknown palette '{}': expected classic, deuteranopia, \
+					protanopia, high-contrast, or muted",
+				s
+			))
+		}
+	}
+}
+
+impl fmt::Display for PaletteScheme
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			PaletteScheme::Classic => "classic",
+			PaletteScheme::Deuteranopia => "deuteranopia",
+			PaletteScheme::Protanopia => "protanopia",
+			PaletteScheme::HighContrast => "high-contrast",
+			PaletteScheme::Muted => "muted"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+/// Colors that replace those of every [scheme](PaletteScheme), e.g., to keep
+/// the pressed color consistent across schemes. Insert this resource before
+/// adding the [AutomataPlugin] to override the colors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Resource)]
+pub struct PaletteOverrides
+{
+	/// The color of live cells.
+	pub(crate) live: Option<Color>,
+
+	/// The color of dead cells.
+	pub(crate) dead: Option<Color>,
+
+	/// The color of cells of a difference pane where the compared histories
+	/// disagree.
+	pub(crate) difference: Option<Color>,
+
+	/// The color of a hovered cell of the [newest](History::newest)
+	/// generation.
+	pub(crate) pressed: Option<Color>,
+
+	/// The color of the chrome around and between the cells.
+	pub(crate) chrome: Option<Color>
+}
+
+/// The number of generations [evolved](evolve) since the application started.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Resource)]
+pub struct Generation(pub(crate) u64);
+
+impl Generation
+{
+	/// Answer the number of generations evolved since the application started.
+	pub fn get(&self) -> u64
+	{
+		self.0
+	}
+}
+
+/// Whether the turbo key is [held](maybe_turbo). While it is, every running
+/// [pane](Pane) [evolves](evolve) once per frame, whatever its heartbeat.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub(crate) struct Turbo(pub(crate) bool);
+
+/// Whether the slow-motion key is [held](maybe_slow_motion). While it is,
+/// every heartbeat lasts [SLOW_MOTION_FACTOR] times as long.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub(crate) struct SlowMotion(pub(crate) bool);
+
+/// Whether to [reveal](update_scan) each new generation cell by cell, from
+/// left to right, while in [slow&#32;motion](SlowMotion), so that the rule can
+/// be seen scanning the previous generation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Scan(pub(crate) bool);
+
+/// Whether random first generations are
+/// [mirror-symmetric](Automaton::symmetrize), so that self-mirroring rules,
+/// e.g., rule 90, grow their iconic symmetric fractals.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct SymmetricSeeds(pub(crate) bool);
+
+/// Whether random [rules](AutomatonRule) are drawn uniformly from the
+/// [equivalence&#32;classes](AutomatonRule::representative) under mirroring
+/// and complementation, rather than from all 256 rules, so that a new rule
+/// never merely reflects or inverts one just seen. While set, the
+/// [rule&#32;bits](RuleBits) also name the class of the active rule.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct ClassSampling(pub(crate) bool);
+
+impl ClassSampling
+{
+	/// Answer a random [rule](AutomatonRule): the
+	/// [representative](AutomatonRule::random_representative) of a random
+	/// class if sampling by class, or else any rule at all.
+	pub fn random_rule(self) -> AutomatonRule
+	{
+		match self.0
+		{
+			true => AutomatonRule::random_representative(),
+			false => AutomatonRule::from(random::<u8>())
+		}
+	}
+}
+
+/// The [generation](Generation) beyond which the [evolver](evolve) must not
+/// advance, if any. A [replay](crate::replay) sets this to the generation of
+/// its next action, so that evolving several generations in a single frame
+/// never overshoots the action.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub(crate) struct GenerationCeiling(pub(crate) Option<u64>);
+
+/// While present, [apply_actions] diverts every [action](Action) sent locally
+/// into [outgoing](Self::outgoing), and instead applies the actions found in
+/// [incoming](Self::incoming). A guest of a
+/// [collaborative&#32;session](crate::collaboration) relays its actions to
+/// the host this way, and applies only those that the host approves.
+#[derive(Clone, Debug, Default, Resource)]
+pub(crate) struct Relay
+{
+	/// The actions sent locally, awaiting delivery elsewhere.
+	pub(crate) outgoing: Vec<Action>,
+
+	/// The actions approved elsewhere, awaiting application.
+	pub(crate) incoming: Vec<Action>
+}
+
+/// How many generations the view has been scrolled back through the
+/// [scrollback](Scrollback) of each [pane](Pane). At `0`, the view shows the
+/// [history](History), ending with the [newest](History::newest) generation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub(crate) struct ScrollOffset(pub(crate) usize);
+
+/// The column of the keyboard cursor within the [newest](History::newest)
+/// generation of the [focused](Focused)&#32;[pane](Pane), counting from the
+/// left, if the cursor has been summoned.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+struct CellCursor(Option<usize>);
+
 /// State management for a user-driven [rule](AutomatonRule) change.
 #[derive(Default, Resource)]
-struct AutomatonRuleBuilder
+pub(crate) struct AutomatonRuleBuilder
 {
 	/// The string buffer for constructing the next [rule](AutomatonRule) from
 	/// user input. Transitions from [None] to [Some] when the first digit is
@@ -166,38 +1003,75 @@ impl AutomatonRuleBuilder
 		}
 	}
 
-	/// Append a digit onto the [builder](AutomatonRuleBuilder). Reset the
-	/// [timer](Timer) between successive digits.
-	fn push_digit(&mut self, c: char)
+	/// Append a character onto the [builder](AutomatonRuleBuilder). Reset the
+	/// [timer](Timer) between successive characters. Entry always begins with
+	/// a decimal digit, but a leading `0` may be followed by `b` or `x` to
+	/// enter the rest of the rule in binary or hexadecimal, respectively.
+	/// Letters that cannot continue the input are ignored, so that they remain
+	/// available as hotkeys.
+	fn push(&mut self, c: char)
 	{
-		assert!(c.is_digit(10));
 		match self.builder
 		{
-			None =>
+			None if c.is_ascii_digit() =>
 			{
 				self.builder = Some(c.into());
 				self.timer = Some(
 					Timer::new(RULE_ENTRY_GRACE, TimerMode::Once)
 				);
 			},
-			Some(ref mut builder) if builder.len() < 3 =>
+			None => {},
+			Some(_) if self.accepts(c) =>
 			{
-				builder.push(c);
+				self.builder.as_mut().unwrap().push(c);
 				self.timer.as_mut().unwrap().reset();
 			},
-			Some(_) =>
+			Some(_) if c.is_ascii_digit() =>
 			{
 				// If too many digits were entered, then rule conversion will
 				// definitely fail. Bail early, to avoid buffering too much
 				// bogus input.
 				self.builder = None;
 				self.timer = None;
-			}
+			},
+			Some(_) => {}
+		}
+	}
+
+	/// Determine whether the specified [key](KeyCode) types a letter that can
+	/// continue the input supplied thus far, so that it shouldn't also act as
+	/// a hotkey.
+	pub(crate) fn claims(&self, key: KeyCode) -> bool
+	{
+		key.to_letter().is_some_and(|c| self.accepts(c))
+	}
+
+	/// Determine whether the specified character can continue the input
+	/// supplied thus far.
+	fn accepts(&self, c: char) -> bool
+	{
+		match self.builder.as_deref()
+		{
+			None => false,
+			Some("0") if c == 'b' || c == 'x' => true,
+			Some(input) if input.starts_with("0b") =>
+				(c == '0' || c == '1') && input.len() < 10,
+			Some(input) if input.starts_with("0x") =>
+				c.is_ascii_hexdigit() && input.len() < 4,
+			Some(input) => c.is_ascii_digit() && input.len() < 3
 		}
 	}
 
+	/// Discard the input supplied thus far, if any, without decoding a
+	/// [rule](AutomatonRule).
+	pub(crate) fn cancel(&mut self)
+	{
+		self.builder = None;
+		self.timer = None;
+	}
+
 	/// Answer the buffered input, if any.
-	fn buffered_input(&self) -> Option<&str>
+	pub(crate) fn buffered_input(&self) -> Option<&str>
 	{
 		self.builder.as_deref()
 	}
@@ -208,52 +1082,407 @@ impl AutomatonRuleBuilder
 	{
 		match self.timer
 		{
-			Some(ref timer) if timer.just_finished() =>
-			{
-				let rule = match self.builder.as_ref().unwrap().parse::<u8>()
-				{
-					Ok(rule) => Some(AutomatonRule::from(rule)),
-					Err(_) => None
-				};
-				self.builder = None;
-				self.timer = None;
-				rule
-			}
+			Some(ref timer) if timer.just_finished() => self.take_rule(),
 			_ => None
 		}
 	}
+
+	/// Attempt to decode a [rule](AutomatonRule) from the input supplied thus
+	/// far, without waiting for the [timer](Timer) to expire. Reset the
+	/// builder in any event.
+	fn take_rule(&mut self) -> Option<AutomatonRule>
+	{
+		let rule = self.builder.take()?.parse::<AutomatonRule>().ok();
+		self.timer = None;
+		rule
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Events.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// An action that alters the simulation. Input systems translate keystrokes
+/// and clicks into actions, and [apply_actions] carries them out, so that
+/// every change can be [recorded](crate::replay) and replayed faithfully.
+/// Panes are identified by their [ordinals](Pane). Any randomness is resolved
+/// before the action is sent, e.g., a [mutation](Action::Mutate) carries the
+/// mutated rule, not the key that requested it.
+#[derive(Clone, Debug, PartialEq, Eq, Event)]
+pub enum Action
+{
+	/// Toggle the run state, between paused and unpaused.
+	TogglePause,
+
+	/// Replace the first generation of a pane, discarding its history.
+	Seed { pane: usize, seed: Automaton },
+
+	/// Replace the entire [schedule](RuleSchedule) of a pane with a single
+	/// [rule](AutomatonRule).
+	ReplaceRule { pane: usize, rule: AutomatonRule },
+
+	/// Append a [rule](AutomatonRule) to the [schedule](RuleSchedule) of a
+	/// pane.
+	AppendRule { pane: usize, rule: AutomatonRule },
+
+	/// Drop the last [rule](AutomatonRule) from the [schedule](RuleSchedule)
+	/// of a pane, unless it is the only rule.
+	DropRule { pane: usize },
+
+	/// Replace the [active](RuleSchedule::active) rule of a pane with the
+	/// specified mutant, leaving the outgoing [schedule](RuleSchedule) on the
+	/// pane's [breadcrumb&#32;trail](RuleTrail).
+	Mutate { pane: usize, rule: AutomatonRule },
+
+	/// Restore the previous [schedule](RuleSchedule) of a pane from its
+	/// [breadcrumb&#32;trail](RuleTrail).
+	WalkBack { pane: usize },
+
+	/// Toggle a cell of the [newest](History::newest) generation of a pane.
+	/// Columns advance from left to right.
+	ToggleCell { pane: usize, column: usize },
+
+	/// Assign a [rule](AutomatonRule) to a column of a pane, overriding its
+	/// [schedule](RuleSchedule) there, or restore the schedule there if the
+	/// rule is [None]. Columns advance from left to right.
+	PaintRule { pane: usize, column: usize, rule: Option<AutomatonRule> },
+
+	/// Transform the [newest](History::newest) generation of a pane, which
+	/// seeds every generation that follows.
+	TransformSeed { pane: usize, transform: SeedTransform },
+
+	/// Evolve a pane through the specified number of generations at once,
+	/// painting only the last.
+	FastForward { pane: usize, generations: usize }
+}
+
+/// A transformation of the [newest](History::newest) generation of a pane,
+/// built from the bitwise operations of [automata](Automaton).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeedTransform
+{
+	/// [Rotate](Automaton::rotate_left) every cell one column to the left.
+	RotateLeft,
+
+	/// [Rotate](Automaton::rotate_right) every cell one column to the right.
+	RotateRight,
+
+	/// [Invert](Automaton::invert) every cell.
+	Invert,
+
+	/// [Symmetrize](Automaton::symmetrize) the cells, mirroring the left half
+	/// onto the right half.
+	Symmetrize
+}
+
+impl SeedTransform
+{
+	/// Apply the transformation to the specified [automaton](Automaton).
+	pub fn apply(self, automaton: &Automaton) -> Automaton
+	{
+		match self
+		{
+			SeedTransform::RotateLeft => automaton.rotate_left(1),
+			SeedTransform::RotateRight => automaton.rotate_right(1),
+			SeedTransform::Invert => automaton.invert(),
+			SeedTransform::Symmetrize => automaton.symmetrize()
+		}
+	}
+}
+
+/// A brief message to show to the user in the [toast](Toast).
+#[derive(Clone, Debug, Event)]
+pub(crate) struct Announcement(pub(crate) String);
+
+/// Notice that a [pane](Pane) evolved a new generation. Other plugins, e.g.,
+/// for audio, networking, or logging, can read these without reaching into the
+/// [history](History) of the pane.
+#[derive(Clone, Debug, Event)]
+pub struct GenerationEvolved
+{
+	/// The [pane](Pane) entity that evolved.
+	pub pane: Entity,
+
+	/// The index of the new generation, counting from the first generation of
+	/// the [scrollback](Scrollback).
+	pub index: usize,
+
+	/// The new generation.
+	pub automaton: Automaton
+}
+
+/// Notice that the [rule&#32;schedule](RuleSchedule) of a [pane](Pane)
+/// changed, whether by replacement, extension, mutation, or walking back.
+#[derive(Clone, Debug, Event)]
+pub struct RuleChanged
+{
+	/// The [pane](Pane) entity whose schedule changed.
+	pub pane: Entity,
+
+	/// The schedule before the change.
+	pub old: RuleSchedule,
+
+	/// The schedule after the change.
+	pub new: RuleSchedule
+}
+
+/// Notice that a cell of the [newest](History::newest) generation of a
+/// [pane](Pane) was edited in place, either directly or by a
+/// [seed&#32;transformation](SeedTransform).
+#[derive(Copy, Clone, Debug, Event)]
+pub struct CellEdited
+{
+	/// The edited cell.
+	pub position: CellPosition
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Bundles.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Everything needed to spawn an automaton pane. Each pane evolves its own
+/// [history](History) according to its own [schedule](RuleSchedule), but all
+/// panes share the same [EvolutionTimer], so several automata may be compared
+/// side by side. Panes are laid out from left to right in order of their
+/// [ordinals](Pane).
+#[derive(Bundle)]
+pub struct AutomatonBundle
+{
+	/// The ordinal of the pane.
+	pane: Pane,
+
+	/// The recent generations of the automaton.
+	history: History,
+
+	/// Every generation of the automaton, for [scrolling](maybe_scroll).
+	scrollback: Scrollback,
+
+	/// The rules that govern the evolution of the automaton.
+	schedule: RuleSchedule,
+
+	/// The rules assigned to individual columns, which override the
+	/// [schedule](RuleSchedule) there. Empty unless
+	/// [painted](crate::rule_map).
+	map: RuleMap,
+
+	/// The rules abandoned during [mutation](maybe_mutate_rule).
+	trail: RuleTrail
+}
+
+impl AutomatonBundle
+{
+	/// Construct the bundle for the `index`-th pane, whose automaton evolves
+	/// from the specified [history](History) according to the specified
+	/// [schedule](RuleSchedule). The [scrollback](Scrollback) retains at most
+	/// `scrollback_cap` generations, if specified, and every generation
+	/// otherwise. The cap never falls below the length of the history.
+	pub fn new(
+		index: usize,
+		history: History,
+		schedule: RuleSchedule,
+		scrollback_cap: Option<usize>
+	) -> Self
+	{
+		Self {
+			pane: Pane(index),
+			scrollback: Scrollback::new(
+				*history.newest(),
+				scrollback_cap.map(|cap| cap.max(AUTOMATON_HISTORY))
+			),
+			history,
+			schedule,
+			map: RuleMap::default(),
+			trail: RuleTrail::default()
+		}
+	}
+}
+
+/// Everything needed to spawn a difference pane, which highlights the cells
+/// where the [histories](History) of two other panes disagree. The difference
+/// pane owns a [history](History) of its own, but no [schedule](RuleSchedule);
+/// rather than evolving, its history is recomputed whenever either of the
+/// compared histories changes.
+#[derive(Bundle)]
+pub struct DifferenceBundle
+{
+	/// The ordinal of the pane.
+	pane: Pane,
+
+	/// The differences between the compared histories.
+	history: History,
+
+	/// The compared panes.
+	difference: Difference
+}
+
+impl DifferenceBundle
+{
+	/// Construct the bundle for the `index`-th pane, which compares the
+	/// [histories](History) of the specified [pane](AutomatonBundle) entities.
+	pub fn new(index: usize, left: Entity, right: Entity) -> Self
+	{
+		Self {
+			pane: Pane(index),
+			history: History::default(),
+			difference: Difference { left, right }
+		}
+	}
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                Components.                                 //
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The ordinal of an automaton pane, counting from left to right.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Component)]
+pub(crate) struct Pane(pub(crate) usize);
+
+/// Marks the [pane](Pane) that receives keyboard input, e.g., rule entry and
+/// [mutation](maybe_mutate_rule). Exactly one pane is focused at any time.
+#[derive(Component)]
+pub(crate) struct Focused;
+
+/// The two [panes](Pane), given as [entities](Entity), whose
+/// [histories](History) are compared by a difference pane.
+#[derive(Copy, Clone, Debug, Component)]
+struct Difference
+{
+	/// The first compared pane.
+	left: Entity,
+
+	/// The second compared pane.
+	right: Entity
+}
+
+/// The grid that renders the [history](History) of some [pane](Pane), given
+/// as an [entity](Entity).
+#[derive(Component)]
+pub(crate) struct PaneView(pub(crate) Entity);
+
+/// The breadcrumb trail left behind by [rule&#32;mutations](maybe_mutate_rule).
+/// Each mutation pushes the outgoing [schedule](RuleSchedule) onto the trail,
+/// so that the user can walk back, one step at a time, toward the
+/// [rule](AutomatonRule) from which the exploration began.
+#[derive(Default, Component)]
+struct RuleTrail(Vec<RuleSchedule>);
+
+impl RuleTrail
+{
+	/// Leave the specified [schedule](RuleSchedule) on the trail.
+	fn push(&mut self, schedule: RuleSchedule)
+	{
+		self.0.push(schedule);
+	}
+
+	/// Walk back one step along the trail, answering the
+	/// [schedule](RuleSchedule) found there, if any.
+	fn pop(&mut self) -> Option<RuleSchedule>
+	{
+		self.0.pop()
+	}
+
+	/// Answer the most recently abandoned [schedule](RuleSchedule), if any.
+	fn last(&self) -> Option<&RuleSchedule>
+	{
+		self.0.last()
+	}
+
+	/// Answer an iterator that traverses the trail from the most recently
+	/// abandoned [schedule](RuleSchedule) back to the original one.
+	fn iter(&self) -> impl Iterator<Item=&RuleSchedule>
+	{
+		self.0.iter().rev()
+	}
+}
+
+/// A [pane](Pane) that keeps its own time, evolving at its own interval rather
+/// than at the shared [heartbeat](EvolutionTimer). The shared timer still
+/// decides whether the evolution is paused.
+#[derive(Component)]
+pub struct Heartbeat(Timer);
+
+impl Heartbeat
+{
+	/// Construct a new [Heartbeat] that evolves its pane once every
+	/// `interval`.
+	pub fn new(interval: Duration) -> Self
+	{
+		Self(Timer::new(interval, TimerMode::Repeating))
+	}
+
+	/// Answer how far the current heartbeat has elapsed, in `[0,1]`.
+	pub(crate) fn progress(&self) -> f32
+	{
+		self.0.percent()
+	}
+
+	/// Answer the interval between successive generations of the pane.
+	pub(crate) fn interval(&self) -> Duration
+	{
+		self.0.duration()
+	}
+}
+
 /// The coordinates of some cell in the grid that renders the
 /// [history](History). A [CellPosition] can serve as an [index](Index) into a
 /// [history](History).
-#[derive(Copy, Clone, Debug, Component)]
-struct CellPosition
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Component)]
+pub struct CellPosition
 {
+	/// The [pane](Pane) entity whose [history](History) contains this cell.
+	pub pane: Entity,
+
 	/// The row coordinate for this cell, advancing from the
 	/// [oldest](History::oldest) generation to the [newest](History::newest)
 	/// generation.
-	row: usize,
+	pub row: usize,
 
 	/// The column coordinate for this cell, advancing from left to right. Note
 	/// that this is _against_ the natural order of an [automaton](Automaton).
-	column: usize
+	pub column: usize
 }
 
 impl CellPosition
 {
 	/// Determine whether the receiver represents the [newest](History::newest)
 	/// generation.
-	fn is_active_automaton(&self) -> bool
+	pub(crate) fn is_active_automaton(&self) -> bool
 	{
 		self.row == AUTOMATON_HISTORY - 1
 	}
 }
 
+/// The state of a cell of the [history](History) of an evolving [pane](Pane),
+/// as last [painted](paint_cells): its liveness, and its decay level, if the
+/// pane [decays](Decay). Only cells whose states [change](Changed) from one
+/// generation to the next are recolored.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Component)]
+pub(crate) struct CellState
+{
+	/// Whether the cell is live.
+	live: bool,
+
+	/// The decay level of the cell, or `0` if it is not decaying.
+	level: u8
+}
+
+impl CellState
+{
+	/// Answer the state of the specified cell of the specified
+	/// [history](History), whose pane [decays](Decay) as specified, if at all.
+	pub(crate) fn of(
+		history: &History,
+		decay: Option<&Decay>,
+		position: CellPosition
+	) -> Self
+	{
+		Self {
+			live: history[position],
+			level: decay.map_or(0, |decay| decay[position])
+		}
+	}
+}
+
 impl fmt::Display for CellPosition
 {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
@@ -284,6 +1513,40 @@ impl<const K: usize, const N: usize> IndexMut<CellPosition> for History<K, N>
 	}
 }
 
+impl<const K: usize, const N: usize> Index<CellPosition> for Decay<K, N>
+{
+	type Output = u8;
+
+	/// Visually, treat the automaton as though its `0` index occurs at the
+	/// right edge.
+	fn index(&self, index: CellPosition) -> &Self::Output
+	{
+		&self[index.row][K - index.column - 1]
+	}
+}
+
+impl<const K: usize, const N: usize> IndexMut<CellPosition> for Decay<K, N>
+{
+	/// Visually, treat the automaton as though its `0` index occurs at the
+	/// right edge.
+	fn index_mut(&mut self, index: CellPosition) -> &mut Self::Output
+	{
+		&mut self[index.row][K - index.column - 1]
+	}
+}
+
+/// The frame around a single cell, whose padding serves as the border of the
+/// cell.
+#[derive(Component)]
+pub(crate) struct CellFrame;
+
+/// The overlay atop the cell at some [position](CellPosition), which tints the
+/// cell independently of its liveness color, e.g., to show a
+/// [light&#32;cone](crate::light_cone). The overlay is transparent until
+/// tinted, and takes the [shape](CellShape) of its cell.
+#[derive(Component)]
+pub(crate) struct CellOverlay(pub(crate) CellPosition);
+
 /// The overlay that displays instructions to the user. The overlay is only
 /// displayed when the evolver is paused. Since the evolver begins
 /// paused, however, the user always has an upfront chance to review the
@@ -291,6 +1554,25 @@ impl<const K: usize, const N: usize> IndexMut<CellPosition> for History<K, N>
 #[derive(Component)]
 struct Instructions;
 
+/// The overlay that explains why the evolver paused itself, displayed only
+/// after an [automatic&#32;pause](maybe_pause_on_steady_state).
+#[derive(Component)]
+struct SteadyStateBanner;
+
+/// The label that explains why the evolver paused itself.
+#[derive(Component)]
+struct SteadyStateLabel;
+
+/// The overlay that briefly shows an [announcement](Announcement), e.g., the
+/// new [rule](AutomatonRule) chosen by a [cycle](RuleCycle). The
+/// [timer](Timer) governs how long the toast remains visible.
+#[derive(Component)]
+struct Toast(Timer);
+
+/// The label that shows the content of the [toast](Toast).
+#[derive(Component)]
+struct ToastLabel;
+
 /// The overlay that displays the partial next [rule](AutomatonRule), assuming
 /// that the user is actively entering a new rule.
 #[derive(Component)]
@@ -300,6 +1582,52 @@ struct NextRule;
 #[derive(Component)]
 struct NextRuleLabel;
 
+/// The overlay that compares the outgoing and incoming [rules](AutomatonRule)
+/// of the most recent [mutation](maybe_mutate_rule). The overlay is displayed
+/// only while the [breadcrumb&#32;trail](RuleTrail) is nonempty.
+#[derive(Component)]
+struct Mutation;
+
+/// The label that compares the outgoing and incoming [rules](AutomatonRule) of
+/// the most recent [mutation](maybe_mutate_rule).
+#[derive(Component)]
+struct MutationLabel;
+
+/// The label that shows the [breadcrumb&#32;trail](RuleTrail).
+#[derive(Component)]
+struct RuleTrailLabel;
+
+/// The overlay that shows the [schedule](RuleSchedule), highlighting the
+/// [active](RuleSchedule::active) rule. The overlay is displayed only while the
+/// schedule comprises several [rules](AutomatonRule).
+#[derive(Component)]
+struct Schedule;
+
+/// The label that shows the [schedule](RuleSchedule).
+#[derive(Component)]
+struct ScheduleLabel;
+
+/// The overlay that shows the binary expansion of the
+/// [active](RuleSchedule::active)&#32;[rule](AutomatonRule) of the
+/// [focused](Focused) pane beneath the neighborhood that each bit governs,
+/// followed by the rule's [equivalents](AutomatonRule::equivalents). The
+/// overlay is displayed only while that rule is elementary.
+#[derive(Component)]
+struct RuleBits;
+
+/// The label that shows the binary expansion of the active rule.
+#[derive(Component)]
+struct RuleBitsLabel;
+
+/// The overlay that shows the absolute [generation](Generation) number.
+#[derive(Component)]
+struct GenerationHud;
+
+/// The label that shows the absolute [generation](Generation) number. It
+/// resides within a simple overlay, marked by [GenerationHud].
+#[derive(Component)]
+struct GenerationLabel;
+
 /// The overlay that shows the instantaneous frames per second (FPS). This is a
 /// debugging feature, available when the user is holding down the right shift
 /// key.
@@ -324,26 +1652,85 @@ fn add_camera(mut commands: Commands)
 
 /// Build the complete user interface:
 ///
-/// * A grid representing the [history](History).
+/// * A grid representing the [history](History) of each [pane](Pane), from
+///   left to right. Difference panes are included.
 /// * An instructional banner, displayed when the evolver is paused.
+/// * A steady state banner, displayed when the evolver pauses itself.
+/// * A toast, displayed briefly to announce a change.
 /// * A rule buffer banner, displayed while the user is entering a new rule.
+/// * A mutation banner, displayed while the user is exploring mutations.
+/// * A schedule banner, displayed while several rules are scheduled.
 /// * An FPS banner, displayed while the user holds the right shift key.
-fn build_ui(history: Res<History>, mut commands: Commands)
-{
+#[allow(clippy::too_many_arguments)]
+fn build_ui(
+	panes: Query<(Entity, &Pane, &History, Option<&Difference>)>,
+	shape: Res<CellShape>,
+	palette: Res<Palette>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	timer: Res<EvolutionTimer>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands
+) {
+	let image = shape.texture()
+		.map(|texture| UiImage::new(images.add(texture)))
+		.unwrap_or_default();
+	let shape = *shape;
+	let mut panes = panes.iter().collect::<Vec<_>>();
+	panes.sort_by_key(|(_, pane, _, _)| **pane);
+	let width = 100.0 / panes.len() as f32;
 	commands
 		.spawn(NodeBundle {
 			style: Style {
 				height: Val::Percent(100.0),
 				width: Val::Percent(100.0),
+				// Zoomed panes overflow the window.
+				overflow: Overflow::clip(),
 				..default()
 			},
-			background_color: BackgroundColor(Color::DARK_GRAY),
+			background_color: BackgroundColor(palette.chrome),
 			..default()
 		})
 		.with_children(|builder| {
-			build_history(builder, &history);
-			build_instruction_banner(builder);
-			build_next_rule_banner(builder);
+			for (entity, _, history, difference) in panes
+			{
+				match difference
+				{
+					None => build_history(
+						builder,
+						entity,
+						history,
+						width,
+						(shape, &image),
+						&palette,
+						Palette::liveness_color,
+						true
+					),
+					Some(_) => build_history(
+						builder,
+						entity,
+						history,
+						width,
+						(shape, &image),
+						&palette,
+						Palette::difference_color,
+						false
+					)
+				}
+			}
+			build_instruction_banner(
+				builder,
+				&bindings,
+				*language,
+				!timer.is_running()
+			);
+			build_steady_state_banner(builder, *language);
+			build_toast(builder);
+			build_next_rule_banner(builder, *language);
+			build_mutation_banner(builder, &bindings, *language);
+			build_schedule_banner(builder);
+			build_rule_bits_banner(builder);
+			build_generation_banner(builder, *language);
 			build_fps_banner(builder);
 		});
 }
@@ -356,33 +1743,70 @@ fn build_ui(history: Res<History>, mut commands: Commands)
 /// overlay.
 fn maybe_toggle_instructions(
 	keys: Res<Input<KeyCode>>,
-	mut instructions: Query<&mut Style, With<Instructions>>,
-	mut timer: ResMut<EvolutionTimer>
+	bindings: Res<KeyBindings>,
+	mut actions: EventWriter<Action>
+) {
+	if keys.just_pressed(bindings.pause)
+	{
+		actions.send(Action::TogglePause);
+	}
+}
+
+/// On tab, pass the input [focus](Focused) to the next [pane](Pane), wrapping
+/// around after the last.
+fn maybe_change_focus(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	panes: Query<(Entity, &Pane, Option<&Focused>), With<RuleSchedule>>,
+	mut commands: Commands
+) {
+	if keys.just_pressed(bindings.next_pane)
+	{
+		let mut panes = panes.iter().collect::<Vec<_>>();
+		panes.sort_by_key(|(_, pane, _)| **pane);
+		let focused = panes.iter()
+			.position(|(_, _, focused)| focused.is_some())
+			.unwrap();
+		let next = (focused + 1) % panes.len();
+		commands.entity(panes[focused].0).remove::<Focused>();
+		commands.entity(panes[next].0).insert(Focused);
+	}
+}
+
+/// Outline the [focused](Focused)&#32;[pane](Pane), but only when several panes
+/// compete for focus.
+fn update_focus(
+	focused: Query<Entity, Added<Focused>>,
+	mut views: Query<(&PaneView, &mut BorderColor)>
 ) {
-	if keys.just_pressed(KeyCode::Space)
+	if let Ok(focused) = focused.get_single()
 	{
-		timer.toggle();
-		let style = &mut instructions.single_mut();
-		style.display = match style.display
+		let several = views.iter().count() > 1;
+		for (view, mut color) in &mut views
 		{
-			Display::Flex => Display::None,
-			Display::None => Display::Flex,
-			Display::Grid => unreachable!()
-		};
+			*color = BorderColor(
+				if several && view.0 == focused { FOCUS_COLOR }
+				else { Color::NONE }
+			);
+		}
 	}
 }
 
-/// On digit, append the digit to the [AutomatonRuleBuilder].
-fn accept_digit(
+/// On digit, append the digit to the [AutomatonRuleBuilder]. On letter, append
+/// the letter too, but only if it continues a binary or hexadecimal rule.
+/// While control is held, digits save [bookmarks](crate::bookmarks) instead.
+pub(crate) fn accept_digit(
 	keys: Res<Input<KeyCode>>,
 	mut builder: ResMut<AutomatonRuleBuilder>,
 	mut next_rule: Query<&mut Style, With<NextRule>>
 ) {
-	for key in keys.get_just_pressed()
+	let control =
+		keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+	for key in keys.get_just_pressed().filter(|_| !control)
 	{
-		match key.to_digit()
+		match key.to_digit().or_else(|| key.to_letter())
 		{
-			Some(digit) => builder.push_digit(digit),
+			Some(c) => builder.push(c),
 			None => {}
 		}
 	}
@@ -395,59 +1819,191 @@ fn accept_digit(
 /// When right shift is held, display the frames per second (FPS).
 fn maybe_show_fps(
 	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
 	mut fps: Query<&mut Style, With<Fps>>
 ) {
 	let style = &mut fps.single_mut();
-	style.display = match keys.pressed(KeyCode::ShiftRight)
+	style.display = match keys.pressed(bindings.show_fps)
 	{
 		true => Display::Flex,
 		false => Display::None
 	};
 }
 
-/// Handle toggling of the cells in the latest generation.
+/// While `F` is held, engage the [turbo](Turbo), so that the evolution races
+/// ahead at one generation per frame; on release, return to the heartbeat.
+fn maybe_turbo(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	mut turbo: ResMut<Turbo>
+) {
+	// While a hexadecimal rule is being entered, `F` is a digit.
+	if builder.claims(bindings.turbo)
+	{
+		return
+	}
+	turbo.set_if_neq(Turbo(keys.pressed(bindings.turbo)));
+}
+
+/// While `Z` is held, engage [slow&#32;motion](SlowMotion), so that every
+/// heartbeat lasts [SLOW_MOTION_FACTOR] times as long; on release, return to
+/// the heartbeat.
+fn maybe_slow_motion(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	mut slow: ResMut<SlowMotion>
+) {
+	slow.set_if_neq(SlowMotion(keys.pressed(bindings.slow_motion)));
+}
+
+/// Handle toggling of the cells in the latest generation, but only while the
+/// view isn't [scrolled](ScrollOffset) back.
 ///
-/// * On press of an active cell _while paused_, toggle the cell.
+/// * On press of an active cell _while paused_, toggle the cell. A touch only
+///   toggles the cell by a [long&#32;press](crate::gestures::GesturePlugin).
+///   While [painting](Painting), the press paints its column instead.
 /// * On hover of an active cell _while paused_, highlight the button to
 ///   indicate interactivity.
 /// * On un-hover of an active cell _while paused_, restore the button's
-///   original [liveness&#32;color](liveness_color).
+///   original [color](Palette::cell_color).
+#[allow(clippy::too_many_arguments)]
 fn maybe_toggle_cells(
-	timer: ResMut<EvolutionTimer>,
-	mut history: ResMut<History>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	histories: Query<(&Pane, &History, Option<&Decay>)>,
 	mut interaction: Query<
 		(&Interaction, &CellPosition, &mut BackgroundColor),
 		(Changed<Interaction>, With<Button>)
-	>
+	>,
+	touches: Res<Touches>,
+	painting: Res<Painting>,
+	mut actions: EventWriter<Action>
 ) {
-	if !timer.is_running()
+	if !timer.is_running() && scroll.0 == 0
 	{
+		// Touches toggle cells only by a long press.
+		let touching = touches.iter().next().is_some();
 		for (interaction, position, mut color) in &mut interaction
 		{
+			let (pane, history, decay) =
+				histories.get(position.pane).unwrap();
 			match *interaction
 			{
+				Interaction::Pressed if touching || painting.0 => {},
 				Interaction::Pressed =>
 				{
-					let cell = &mut history[*position];
-					*cell = !*cell;
-					*color = liveness_color(*cell);
+					actions.send(Action::ToggleCell {
+						pane: pane.0,
+						column: position.column
+					});
 				},
 				Interaction::Hovered =>
 				{
-					*color = BackgroundColor(PRESSED_COLOR);
+					*color = BackgroundColor(palette.pressed);
 				},
 				Interaction::None =>
 				{
-					*color = liveness_color(history[*position]);
+					*color = palette.cell_color(history, decay, *position);
 				}
 			}
 		}
 	}
 }
 
+/// Drive the keyboard cursor over the cells of the latest generation of the
+/// [focused](Focused)&#32;[pane](Pane), so that the first generation can be
+/// edited without a mouse. As with the mouse, this works only _while paused_
+/// and while the view isn't [scrolled](ScrollOffset) back.
+///
+/// * On left or right arrow, move the cursor to the adjacent cell, summoning it
+///   to the middle cell if necessary.
+/// * On enter, toggle the cell under the cursor, summoning it if necessary.
+fn maybe_move_cursor(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	pane: Query<&Pane, With<Focused>>,
+	mut cursor: ResMut<CellCursor>,
+	mut actions: EventWriter<Action>
+) {
+	if timer.is_running() || scroll.0 != 0
+	{
+		return
+	}
+	let last = AUTOMATON_LENGTH - 1;
+	let summoned = cursor.0.unwrap_or(AUTOMATON_LENGTH / 2);
+	if keys.just_pressed(bindings.cursor_left)
+	{
+		let column = match cursor.0
+		{
+			Some(column) => column.saturating_sub(1),
+			None => summoned
+		};
+		cursor.set_if_neq(CellCursor(Some(column)));
+	}
+	if keys.just_pressed(bindings.cursor_right)
+	{
+		let column = match cursor.0
+		{
+			Some(column) => (column + 1).min(last),
+			None => summoned
+		};
+		cursor.set_if_neq(CellCursor(Some(column)));
+	}
+	if keys.just_pressed(bindings.toggle_cell)
+	{
+		match cursor.0
+		{
+			Some(column) => actions.send(Action::ToggleCell {
+				pane: pane.single().0,
+				column
+			}),
+			None => cursor.0 = Some(summoned)
+		}
+	}
+}
+
+/// Ring the cell under the keyboard [cursor](CellCursor) with a focus border,
+/// but only while the cursor is usable, i.e., _while paused_ and while the view
+/// isn't [scrolled](ScrollOffset) back.
+fn update_cursor(
+	cursor: Res<CellCursor>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	focused: Query<Entity, With<Focused>>,
+	mut cells: Query<
+		(&CellPosition, &mut Style, &mut BorderColor),
+		With<Button>
+	>
+) {
+	let focused = focused.single();
+	let usable = !timer.is_running() && scroll.0 == 0;
+	for (position, mut style, mut color) in &mut cells
+	{
+		let ringed = usable
+			&& position.pane == focused
+			&& cursor.0 == Some(position.column);
+		let border = match ringed
+		{
+			true => UiRect::all(Val::Px(CURSOR_BORDER)),
+			false => UiRect::default()
+		};
+		// Only write on change, so that change detection remains meaningful.
+		if style.border != border
+		{
+			style.border = border;
+			*color = BorderColor(CURSOR_COLOR);
+		}
+	}
+}
+
 /// Update the next [rule](AutomatonRule) label.
 fn update_next_rule(
 	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
 	mut next_rule: Query<&mut Text, With<NextRuleLabel>>
 ) {
 	let buffered_input = builder.buffered_input();
@@ -456,60 +2012,1531 @@ fn update_next_rule(
 		let text = &mut next_rule.single_mut();
 		text.sections[1].value = match builder.buffered_input()
 		{
-			Some(rule) if rule.parse::<u8>().is_ok() => rule.to_string(),
-			_ => "Error".to_string()
+			Some(input) => match input.parse::<AutomatonRule>()
+			{
+				// Translate binary and hexadecimal input into decimal.
+				Ok(rule) if input.contains(['b', 'x']) =>
+					format!("{} = {}", input, u8::from(rule)),
+				Ok(_) => input.to_string(),
+				Err(_) => language.text(Message::Error).to_string()
+			},
+			_ => language.text(Message::Error).to_string()
 		};
 	}
 }
 
-/// Change the [rule](AutomatonRule) for future [evolutions](evolve), if another
-/// [rule](AutomatonRule) is pending. Update the window title to reflect the new
-/// [rule](AutomatonRule).
+/// Change the [rule](AutomatonRule) for future [evolutions](evolve) of the
+/// [focused](Focused)&#32;[pane](Pane), if another [rule](AutomatonRule) is
+/// pending. The new rule replaces the entire [schedule](RuleSchedule).
 fn maybe_change_rule(
 	time: Res<Time>,
-	mut rule: ResMut<AutomatonRule>,
+	pane: Query<&Pane, With<Focused>>,
 	mut builder: ResMut<AutomatonRuleBuilder>,
-	mut query: Query<&mut Window>
+	mut actions: EventWriter<Action>
 ) {
 	builder.tick(time.delta());
 	match builder.new_rule()
 	{
-		Some(new_rule) =>
-		{
-			*rule = new_rule;
-			let window = &mut query.single_mut();
-			set_title(window.as_mut(), *rule);
-		},
+		Some(rule) =>
+			actions.send(Action::ReplaceRule { pane: pane.single().0, rule }),
 		None => {}
 	}
 }
 
-/// [Evolve](History::evolve) the [automaton](Automaton), and update the visual
-/// [history](History).
-fn evolve(
-	time: Res<Time>,
-	rule: Res<AutomatonRule>,
-	mut timer: ResMut<EvolutionTimer>,
-	mut history: ResMut<History>,
-	mut cells: Query<(&CellPosition, &mut BackgroundColor)>
+/// Edit the [schedule](RuleSchedule) of the [focused](Focused)&#32;[pane](Pane)
+/// at runtime.
+///
+/// * On comma, append the [rule](AutomatonRule) currently being entered to
+///   the schedule, rather than waiting for it to replace the schedule.
+/// * On delete, remove the last rule from the schedule, unless it is the only
+///   rule.
+fn maybe_edit_schedule(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	pane: Query<&Pane, With<Focused>>,
+	mut builder: ResMut<AutomatonRuleBuilder>,
+	mut actions: EventWriter<Action>
 ) {
-	if timer.is_running()
+	let pane = pane.single().0;
+	if keys.just_pressed(bindings.append_rule)
 	{
-		timer.tick(time.delta(), || {
-			// Run the evolver one step.
-			history.evolve(*rule);
-
-			// Update each of the cells to reflect its new state in the model.
-			for (position, mut color) in &mut cells
-			{
-				*color = liveness_color(history[*position]);
-			}
-		});
+		if let Some(rule) = builder.take_rule()
+		{
+			actions.send(Action::AppendRule { pane, rule });
+		}
+	}
+	else if keys.just_pressed(bindings.drop_rule)
+	{
+		actions.send(Action::DropRule { pane });
 	}
 }
 
-/// Update the frames per second (FPS) label.
-fn update_fps(
+/// Update the schedule label to show the [schedule](RuleSchedule) of the
+/// [focused](Focused)&#32;[pane](Pane), highlighting the
+/// [active](RuleSchedule::active)&#32;[rule](AutomatonRule). Hide the overlay
+/// unless several rules are scheduled.
+fn update_schedule(
+	schedule: Query<(Ref<RuleSchedule>, Ref<Focused>)>,
+	mut overlay: Query<&mut Style, With<Schedule>>,
+	mut label: Query<&mut Text, With<ScheduleLabel>>
+) {
+	let (schedule, focused) = schedule.single();
+	if schedule.is_changed() || focused.is_added()
+	{
+		let style = &mut overlay.single_mut();
+		style.display =
+			if schedule.len() > 1 { Display::Flex }
+			else { Display::None };
+		let text = &mut label.single_mut();
+		text.sections = schedule.iter()
+			.enumerate()
+			.map(|(i, rule)| {
+				let color =
+					if i == schedule.active_index() { ACTIVE_RULE_COLOR }
+					else { LABEL_COLOR };
+				TextSection::new(
+					format!(" {} ", rule.abbreviation()),
+					TextStyle {
+						font_size: 32.0,
+						color,
+						..default()
+					}
+				)
+			})
+			.collect();
+	}
+}
+
+/// On `M`, flip a random bit of the [active](RuleSchedule::active)
+/// [elementary&#32;rule](AutomatonRule) of the [focused](Focused)
+/// [pane](Pane), leaving
+/// the outgoing [schedule](RuleSchedule) on the pane's
+/// [breadcrumb&#32;trail](RuleTrail). On backspace, walk back along the trail
+/// to the previous schedule.
+fn maybe_mutate_rule(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>
+) {
+	let (pane, schedule) = pane.single();
+	let pane = pane.0;
+	if keys.just_pressed(bindings.mutate)
+	{
+		// Only elementary rules have Wolfram codes to mutate.
+		match *schedule.active()
+		{
+			RuleKind::Wolfram(rule) =>
+			{
+				let rule = rule.mutate(random::<u8>() % 8);
+				actions.send(Action::Mutate { pane, rule });
+			},
+			#[allow(unreachable_patterns)]
+			_ => {}
+		}
+	}
+	else if keys.just_pressed(bindings.walk_back)
+	{
+		actions.send(Action::WalkBack { pane });
+	}
+}
+
+/// On `S`, replace the [active](RuleSchedule::active) rule of the
+/// [focused](Focused)&#32;[pane](Pane) with a
+/// [surprising](surprising_rule)&#32;[elementary&#32;rule](AutomatonRule),
+/// i.e., a random rule whose [interestingness](Interestingness) from random
+/// first generations clears [INTERESTINGNESS_THRESHOLD], rather than any rule
+/// at all. The outgoing [schedule](RuleSchedule) is left on the pane's
+/// [breadcrumb&#32;trail](RuleTrail), just like a mutation. Announce the new
+/// rule and its score in the [toast](Toast).
+fn maybe_surprise(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.surprise)
+	{
+		return
+	}
+	let (pane, schedule) = pane.single();
+	let seeds = (0 .. INTERESTINGNESS_TRIALS)
+		.map(|_| Automaton::<AUTOMATON_LENGTH>::from(random::<u64>()))
+		.collect::<Vec<_>>();
+	let (rule, measure) = surprising_rule(
+		&seeds,
+		INTERESTINGNESS_GENERATIONS,
+		INTERESTINGNESS_THRESHOLD
+	);
+	announcements.send(Announcement(format!(
+		"{}: {} → {} ({:.2})",
+		language.text(Message::Surprise),
+		schedule.active(),
+		rule,
+		measure.score()
+	)));
+	actions.send(Action::Mutate { pane: pane.0, rule });
+}
+
+/// Replace the [active](RuleSchedule::active)&#32;[elementary&#32;rule]
+/// (AutomatonRule) of the [focused](Focused)&#32;[pane](Pane) with one of its
+/// symmetric equivalents, leaving the outgoing [schedule](RuleSchedule) on the
+/// pane's [breadcrumb&#32;trail](RuleTrail), just like a mutation. Announce
+/// which transformation was applied in the [toast](Toast).
+///
+/// * On `H`, [mirror](AutomatonRule::mirror) the rule.
+/// * On `C`, [complement](AutomatonRule::complement) the rule.
+/// * On `B`, apply both, i.e., take the
+///   [mirrored&#32;complement](AutomatonRule::mirrored_complement).
+///
+/// While a binary or hexadecimal rule is being entered, any of these keys that
+/// the [rule&#32;builder](AutomatonRuleBuilder) claims belong to it instead.
+fn maybe_transform_rule(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	type Transform = fn(AutomatonRule) -> AutomatonRule;
+	let transforms: [(KeyCode, Message, Transform); 3] = [
+		(bindings.mirror, Message::Mirror, AutomatonRule::mirror),
+		(
+			bindings.complement,
+			Message::Complement,
+			AutomatonRule::complement
+		),
+		(
+			bindings.mirrored_complement,
+			Message::MirroredComplement,
+			AutomatonRule::mirrored_complement
+		)
+	];
+	let (pane, schedule) = pane.single();
+	for (key, name, transform) in transforms
+	{
+		if keys.just_pressed(key) && !builder.claims(key)
+		{
+			// Only elementary rules have Wolfram codes to transform.
+			match *schedule.active()
+			{
+				RuleKind::Wolfram(old) =>
+				{
+					let rule = transform(old);
+					actions.send(Action::Mutate { pane: pane.0, rule });
+					announcements.send(Announcement(
+						format!("{}: {} → {}", language.text(name), old, rule)
+					));
+				},
+				#[allow(unreachable_patterns)]
+				_ => {}
+			}
+		}
+	}
+}
+
+/// Transform the [newest](History::newest) generation of the
+/// [focused](Focused)&#32;[pane](Pane), and announce the transformation and
+/// the resulting population in the [toast](Toast).
+///
+/// * On `[`, [rotate](SeedTransform::RotateLeft) the cells to the left.
+/// * On `]`, [rotate](SeedTransform::RotateRight) the cells to the right.
+/// * On `/`, [invert](SeedTransform::Invert) the cells.
+/// * On `Y`, [symmetrize](SeedTransform::Symmetrize) the cells.
+fn maybe_transform_seed(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &History), With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	let transforms = [
+		(bindings.rotate_left, Message::RotatedLeft, SeedTransform::RotateLeft),
+		(
+			bindings.rotate_right,
+			Message::RotatedRight,
+			SeedTransform::RotateRight
+		),
+		(bindings.invert, Message::Inverted, SeedTransform::Invert),
+		(
+			bindings.symmetrize,
+			Message::Symmetrized,
+			SeedTransform::Symmetrize
+		)
+	];
+	let (pane, history) = pane.single();
+	for (key, message, transform) in transforms
+	{
+		if keys.just_pressed(key)
+		{
+			actions.send(Action::TransformSeed { pane: pane.0, transform });
+			let population = transform.apply(history.newest())
+				.population_count();
+			announcements.send(Announcement(
+				language.format(message, &[&population])
+			));
+		}
+	}
+}
+
+/// On `J`, search random first generations for the one that runs longest
+/// under the [active](RuleSchedule::active)&#32;[elementary&#32;rule]
+/// (AutomatonRule) of the [focused](Focused)&#32;[pane](Pane) before falling
+/// into a cycle, and reseed the pane with the champion. Announce the
+/// [orbit](crate::automata::Orbit) of the champion in the [toast](Toast).
+/// First generations whose cycles don't close within [TRANSIENT_SEARCH_LIMIT]
+/// generations are passed over, lest the search stall the frame.
+fn maybe_find_transient(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.longest_transient)
+	{
+		return
+	}
+	let (pane, schedule) = pane.single();
+	// Only elementary rules are fast enough to search.
+	let rule = match *schedule.active()
+	{
+		RuleKind::Wolfram(rule) => rule,
+		#[allow(unreachable_patterns)]
+		_ => return
+	};
+	let seeds = (0 .. TRANSIENT_SEARCH_SAMPLES)
+		.map(|_| Automaton::<AUTOMATON_LENGTH>::from(random::<u64>()));
+	match longest_transient(rule, seeds, TRANSIENT_SEARCH_LIMIT)
+	{
+		Some((seed, orbit)) =>
+		{
+			actions.send(Action::Seed { pane: pane.0, seed });
+			announcements.send(Announcement(language.format(
+				Message::FoundTransient,
+				&[&orbit.transient, &orbit.period]
+			)));
+		},
+		None => announcements.send(Announcement(language.format(
+			Message::NoTransient,
+			&[&TRANSIENT_SEARCH_LIMIT]
+		)))
+	}
+}
+
+/// On `.`, [fast-forward](Action::FastForward) the [focused](Focused)
+/// [pane](Pane) through [FAST_FORWARD_GENERATIONS] generations at once, and
+/// announce the skip in the [toast](Toast). This works whether or not the
+/// evolution is paused.
+fn maybe_fast_forward(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	pane: Query<&Pane, With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.fast_forward)
+	{
+		return
+	}
+	actions.send(Action::FastForward {
+		pane: pane.single().0,
+		generations: FAST_FORWARD_GENERATIONS
+	});
+	announcements.send(Announcement(language.format(
+		Message::FastForwarded,
+		&[&FAST_FORWARD_GENERATIONS]
+	)));
+}
+
+/// Update the mutation overlay to compare the outgoing and incoming
+/// [schedules](RuleSchedule) of the [focused](Focused)&#32;[pane](Pane) and to
+/// show its [breadcrumb&#32;trail](RuleTrail). Hide the overlay when the trail
+/// is empty.
+fn update_mutation(
+	pane: Query<(Ref<RuleSchedule>, Ref<RuleTrail>, Ref<Focused>)>,
+	mut mutation: Query<&mut Style, With<Mutation>>,
+	mut label: Query<&mut Text, (With<MutationLabel>, Without<RuleTrailLabel>)>,
+	mut trail_label: Query<&mut Text, With<RuleTrailLabel>>
+) {
+	let (schedule, trail, focused) = pane.single();
+	if schedule.is_changed() || trail.is_changed() || focused.is_added()
+	{
+		let style = &mut mutation.single_mut();
+		match trail.last()
+		{
+			Some(old) =>
+			{
+				style.display = Display::Flex;
+				let text = &mut label.single_mut();
+				text.sections[0].value = old.to_string();
+				text.sections[2].value = schedule.to_string();
+				let text = &mut trail_label.single_mut();
+				text.sections[1].value = trail.iter()
+					.map(|schedule| schedule.iter()
+						.map(|rule| rule.abbreviation())
+						.collect::<Vec<_>>()
+						.join(","))
+					.collect::<Vec<_>>()
+					.join(" ← ");
+			},
+			None => style.display = Display::None
+		}
+	}
+}
+
+/// Update the binary expansion of the [active](RuleSchedule::active)
+/// [rule](AutomatonRule) of the [focused](Focused)&#32;[pane](Pane), hiding
+/// the overlay unless the rule is elementary. Beneath the bits, list the
+/// rule's [equivalents](AutomatonRule::equivalents), and, while
+/// [sampling&#32;by&#32;class](ClassSampling), name the
+/// [representative](AutomatonRule::representative) of its class.
+fn update_rule_bits(
+	classes: Res<ClassSampling>,
+	language: Res<Language>,
+	schedule: Query<(Ref<RuleSchedule>, Ref<Focused>)>,
+	mut overlay: Query<&mut Style, With<RuleBits>>,
+	mut label: Query<&mut Text, With<RuleBitsLabel>>
+) {
+	let (schedule, focused) = schedule.single();
+	if schedule.is_changed() || focused.is_added()
+	{
+		let style = &mut overlay.single_mut();
+		match *schedule.active()
+		{
+			RuleKind::Wolfram(rule) =>
+			{
+				style.display = Display::Flex;
+				// Align each bit beneath the neighborhood that it governs,
+				// from 111 on the left to 000 on the right.
+				let bits = format!("{:08b}", u8::from(rule))
+					.chars()
+					.map(|bit| format!(" {} ", bit))
+					.collect::<Vec<_>>()
+					.join(" ");
+				let text = &mut label.single_mut();
+				text.sections[1].value = bits;
+				let equivalents = rule.equivalents()
+					.map(|rule| u8::from(rule).to_string())
+					.join("/");
+				let mut summary = format!(
+					"\n{}",
+					language.format(
+						Message::Equivalents,
+						&[&u8::from(rule), &equivalents]
+					)
+				);
+				if classes.0
+				{
+					summary.push_str(&format!(
+						"\n{}",
+						language.format(
+							Message::RuleClass,
+							&[&u8::from(rule.representative())]
+						)
+					));
+				}
+				text.sections[2].value = summary;
+			},
+			#[allow(unreachable_patterns)]
+			_ => style.display = Display::None
+		}
+	}
+}
+
+/// Update the window title to show the [schedule](RuleSchedule) of every
+/// [pane](Pane), but only when the title would actually change.
+fn update_title(
+	changed: Query<(), Changed<RuleSchedule>>,
+	panes: Query<(&Pane, &RuleSchedule)>,
+	mut windows: Query<&mut Window, With<PrimaryWindow>>,
+	mut last_title: Local<String>
+) {
+	if !changed.is_empty()
+	{
+		let title = title(panes.iter());
+		if *last_title != title
+		{
+			let Ok(mut window) = windows.get_single_mut() else
+			{
+				return
+			};
+			set_title(window.as_mut(), &title);
+			*last_title = title;
+		}
+	}
+}
+
+/// Keep the query string of the page in sync with the
+/// [schedule](RuleSchedule) and the first generation of the leftmost
+/// [pane](Pane), so that reloading the page, or copying its address, reproduces
+/// them. The entry in the browser's history is replaced, not pushed. Available
+/// for WASM builds only.
+#[cfg(target_family = "wasm")]
+fn sync_url(
+	panes: Query<(&Pane, Ref<RuleSchedule>, Ref<Scrollback>)>,
+	mut last_url: Local<String>
+) {
+	let Some((_, schedule, scrollback)) =
+		panes.iter().min_by_key(|(pane, ..)| **pane)
+	else
+	{
+		return
+	};
+	if !schedule.is_changed() && !scrollback.is_changed()
+	{
+		return
+	}
+	let Some(url) = synced_url(&schedule, &scrollback) else
+	{
+		return
+	};
+	if *last_url != url
+	{
+		replace_url(&url);
+		*last_url = url;
+	}
+}
+
+/// Persist the [schedule](RuleSchedule) and the first generation of the
+/// leftmost [pane](Pane), along with the [palette](PaletteScheme), the
+/// [gridlines](Gridlines), the [cell&#32;shape](CellShape), the
+/// [language](Language), and the [heartbeat](EvolutionTimer), to the browser's
+/// local storage, as a query string. When the page is next loaded without query
+/// parameters, the stored query string stands in for them. Available for WASM
+/// builds only.
+#[cfg(target_family = "wasm")]
+#[allow(clippy::too_many_arguments)]
+fn persist_settings(
+	panes: Query<(&Pane, Ref<RuleSchedule>, Ref<Scrollback>)>,
+	scheme: Res<PaletteScheme>,
+	gridlines: Res<Gridlines>,
+	shape: Res<CellShape>,
+	language: Res<Language>,
+	timer: Res<EvolutionTimer>,
+	mut last_heartbeat: Local<Duration>,
+	mut last_settings: Local<String>
+) {
+	let Some((_, schedule, scrollback)) =
+		panes.iter().min_by_key(|(pane, ..)| **pane)
+	else
+	{
+		return
+	};
+	if !schedule.is_changed()
+		&& !scrollback.is_changed()
+		&& !scheme.is_changed()
+		&& !gridlines.is_changed()
+		&& !shape.is_changed()
+		&& !language.is_changed()
+		&& timer.heartbeat() == *last_heartbeat
+	{
+		return
+	}
+	*last_heartbeat = timer.heartbeat();
+	let Ok(params) = web_sys::UrlSearchParams::new() else
+	{
+		return
+	};
+	set_rule_and_seed(&params, &schedule, &scrollback);
+	params.set("palette", &scheme.to_string());
+	params.set("grid", &gridlines.to_string());
+	params.set("cell_shape", &shape.to_string());
+	params.set("lang", &language.to_string());
+	params.set("heartbeat", &timer.heartbeat().as_millis().to_string());
+	let settings = String::from(params.to_string());
+	if *last_settings != settings
+	{
+		if let Some(storage) = web_sys::window()
+			.and_then(|window| window.local_storage().ok().flatten())
+		{
+			let _ = storage.set_item(SETTINGS_STORAGE_KEY, &settings);
+		}
+		*last_settings = settings;
+	}
+}
+
+/// Carry out every pending [action](Action), whether it originated from the
+/// user or from a [replay](crate::replay). Announce the consequences as
+/// [RuleChanged], [CellEdited], and [GenerationEvolved] events. While a
+/// [relay](Relay) is present, carry out its incoming actions instead, and
+/// leave the pending ones to the relay.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_actions(
+	mut actions: EventReader<Action>,
+	relay: Option<ResMut<Relay>>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut scroll: ResMut<ScrollOffset>,
+	mut panes: Query<(
+		Entity,
+		&Pane,
+		&mut History,
+		&mut Scrollback,
+		&mut RuleSchedule,
+		&mut RuleMap,
+		&mut RuleTrail,
+		Option<&mut Decay>,
+		Option<&RuleTimeline>
+	)>,
+	mut instructions: Query<
+		&mut Style,
+		(With<Instructions>, Without<SteadyStateBanner>)
+	>,
+	mut banner: Query<&mut Style, With<SteadyStateBanner>>,
+	mut rule_changes: EventWriter<RuleChanged>,
+	mut edits: EventWriter<CellEdited>,
+	mut evolved: EventWriter<GenerationEvolved>
+) {
+	let actions = match relay
+	{
+		Some(mut relay) =>
+		{
+			relay.outgoing.extend(actions.read().cloned());
+			std::mem::take(&mut relay.incoming)
+		},
+		None => actions.read().cloned().collect()
+	};
+	for action in &actions
+	{
+		if *action == Action::TogglePause
+		{
+			timer.toggle();
+			let style = &mut instructions.single_mut();
+			style.display = match style.display
+			{
+				Display::Flex => Display::None,
+				Display::None => Display::Flex,
+				Display::Grid => unreachable!()
+			};
+			// Dismiss any explanation of an automatic pause.
+			banner.single_mut().display = Display::None;
+			continue
+		}
+		let index = match *action
+		{
+			Action::TogglePause => unreachable!(),
+			Action::Seed { pane, .. }
+				| Action::ReplaceRule { pane, .. }
+				| Action::AppendRule { pane, .. }
+				| Action::DropRule { pane }
+				| Action::Mutate { pane, .. }
+				| Action::WalkBack { pane }
+				| Action::ToggleCell { pane, .. }
+				| Action::PaintRule { pane, .. }
+				| Action::TransformSeed { pane, .. }
+				| Action::FastForward { pane, .. } => pane
+		};
+		let Some((
+			entity,
+			_,
+			mut history,
+			mut scrollback,
+			mut schedule,
+			mut map,
+			mut trail,
+			decay,
+			timeline
+		)) = panes.iter_mut().find(|(_, pane, ..)| pane.0 == index)
+		else
+		{
+			warn!("Ignoring {:?}: no such pane", action);
+			continue
+		};
+		let old = schedule.clone();
+		match *action
+		{
+			Action::TogglePause => unreachable!(),
+			Action::Seed { seed, .. } =>
+			{
+				*history = History::from(seed);
+				scrollback.restart(*history.newest());
+				if let Some(mut decay) = decay
+				{
+					decay.restart();
+				}
+				// Edits always show the newest generation.
+				scroll.set_if_neq(ScrollOffset(0));
+			},
+			Action::ReplaceRule { rule, .. } => *schedule = rule.into(),
+			Action::AppendRule { rule, .. } => schedule.push(rule),
+			Action::DropRule { .. } =>
+			{
+				schedule.pop();
+			},
+			Action::Mutate { rule, .. } =>
+			{
+				trail.push(schedule.clone());
+				schedule.replace_active(rule);
+			},
+			Action::WalkBack { .. } =>
+			{
+				if let Some(old) = trail.pop()
+				{
+					*schedule = old;
+				}
+			},
+			Action::ToggleCell { column, .. } =>
+			{
+				let position = CellPosition {
+					pane: entity,
+					row: AUTOMATON_HISTORY - 1,
+					column
+				};
+				let cell = &mut history[position];
+				*cell = !*cell;
+				// A toggled cell is live or dead, never decaying.
+				if let Some(mut decay) = decay
+				{
+					decay[position] = 0;
+				}
+				scrollback.replace_newest(*history.newest());
+				scroll.set_if_neq(ScrollOffset(0));
+				edits.send(CellEdited { position });
+			},
+			Action::PaintRule { column, rule, .. } =>
+			{
+				map.set(column, rule);
+				// Painting changes no rules of the schedule.
+				continue
+			},
+			Action::TransformSeed { transform, .. } =>
+			{
+				let original = *history.newest();
+				let transformed = transform.apply(&original);
+				history.replace(transformed);
+				// Transformed cells are live or dead, never decaying.
+				if let Some(mut decay) = decay
+				{
+					for column in 0 .. AUTOMATON_LENGTH
+					{
+						decay[CellPosition {
+							pane: entity,
+							row: AUTOMATON_HISTORY - 1,
+							column
+						}] = 0;
+					}
+				}
+				scrollback.replace_newest(*history.newest());
+				scroll.set_if_neq(ScrollOffset(0));
+				edits.send_batch(
+					(0 .. AUTOMATON_LENGTH)
+						.filter(|&index| original[index] != transformed[index])
+						.map(|index| CellEdited {
+							position: CellPosition {
+								pane: entity,
+								row: AUTOMATON_HISTORY - 1,
+								column: AUTOMATON_LENGTH - index - 1
+							}
+						})
+				);
+			},
+			Action::FastForward { generations, .. } =>
+			{
+				if generations == 0
+				{
+					continue
+				}
+				// Evolve exactly as the heartbeat would, so the scrollback and
+				// any decay stay complete, but paint only the outcome.
+				let mut decay = decay;
+				for _ in 0 .. generations
+				{
+					follow_timeline(
+						entity,
+						timeline,
+						&scrollback,
+						&mut schedule,
+						&mut rule_changes
+					);
+					let rule = schedule.advance();
+					match decay.as_mut()
+					{
+						Some(decay) => decay.evolve(&mut history, &rule, &map),
+						None => history.evolve_mapped(&rule, &map)
+					}
+					scrollback.push(*history.newest(), rule);
+				}
+				let (index, automaton, _) = scrollback.generation(0).unwrap();
+				evolved.send(GenerationEvolved {
+					pane: entity,
+					index,
+					automaton: *automaton
+				});
+				scroll.set_if_neq(ScrollOffset(0));
+				// Advancing the schedule changes no rules, and the timeline
+				// announces its own switches.
+				continue
+			}
+		}
+		if *schedule != old
+		{
+			rule_changes.send(RuleChanged {
+				pane: entity,
+				old,
+				new: schedule.clone()
+			});
+		}
+	}
+}
+
+/// [Evolve](History::evolve) the [automaton](Automaton) of every
+/// [pane](Pane), leaving the visual [histories](History) to [paint_cells].
+/// Panes that [decay](Decay) evolve through their decay, which also fades
+/// their trails. Panes with a [heartbeat](Heartbeat) of their own evolve at
+/// their own pace. A heartbeat shorter than a frame evolves several
+/// generations per frame, up to [MAX_GENERATIONS_PER_FRAME], of which only the
+/// last is painted, so that the frame rate never caps the evolution rate. A
+/// [ceiling](GenerationCeiling) caps the generations that every pane evolves
+/// in a frame, whether it follows the shared timer or a heartbeat of its own,
+/// so that no pane overshoots the ceiling. Panes with a
+/// [timeline](RuleTimeline) switch rules as they enter its ranges, announcing
+/// each switch as a [RuleChanged] event.
+/// While the [turbo](Turbo) is engaged, every pane evolves on every frame; in
+/// [slow&#32;motion](SlowMotion), time passes [SLOW_MOTION_FACTOR] times more
+/// slowly. In any [mode](Mode) besides elementary automata, just count the
+/// [generations](Generation), and leave the panes alone. Announce each new
+/// generation as a [GenerationEvolved] event.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn evolve(
+	time: Res<Time>,
+	mode: Res<Mode>,
+	turbo: Res<Turbo>,
+	slow: Res<SlowMotion>,
+	ceiling: Res<GenerationCeiling>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut generation: ResMut<Generation>,
+	mut panes: Query<(
+		Entity,
+		&mut History,
+		&mut Scrollback,
+		&mut RuleSchedule,
+		&RuleMap,
+		Option<&RuleTimeline>,
+		Option<&mut Decay>,
+		Option<&mut Heartbeat>
+	)>,
+	mut rule_changes: EventWriter<RuleChanged>,
+	mut evolved: EventWriter<GenerationEvolved>
+) {
+	let delta = match slow.0
+	{
+		true => time.delta() / SLOW_MOTION_FACTOR,
+		false => time.delta()
+	};
+	let headroom = ceiling.0.map(|ceiling| {
+		let headroom = ceiling.saturating_sub(generation.0);
+		u32::try_from(headroom).unwrap_or(u32::MAX)
+	});
+	// Turn the number of expirations into the number of generations due.
+	let steps = |expirations: u32| {
+		let steps = match turbo.0
+		{
+			true => expirations.max(1),
+			false => expirations
+		};
+		let steps = steps.min(MAX_GENERATIONS_PER_FRAME);
+		match headroom
+		{
+			Some(headroom) => steps.min(headroom),
+			None => steps
+		}
+	};
+	if !timer.is_running()
+	{
+		return
+	}
+	let shared = steps(timer.tick(delta));
+	// Touch the generation only when it advances, since other systems watch
+	// it for changes.
+	if shared > 0
+	{
+		generation.0 += shared as u64;
+	}
+	if *mode != Mode::Elementary
+	{
+		return
+	}
+	// Run each due evolver as many steps as are due, advancing its schedule.
+	// Panes with a heartbeat of their own count the expirations of their own
+	// timers, and the rest those of the shared timer.
+	for (
+		pane,
+		mut history,
+		mut scrollback,
+		mut schedule,
+		map,
+		timeline,
+		mut decay,
+		heartbeat
+	) in &mut panes
+	{
+		let due = match heartbeat
+		{
+			Some(mut heartbeat) => steps(
+				heartbeat.0.tick(delta).times_finished_this_tick()
+			),
+			None => shared
+		};
+		for _ in 0 .. due
+		{
+			follow_timeline(
+				pane,
+				timeline,
+				&scrollback,
+				&mut schedule,
+				&mut rule_changes
+			);
+			let rule = schedule.advance();
+			match decay.as_mut()
+			{
+				Some(decay) => decay.evolve(&mut history, &rule, map),
+				None => history.evolve_mapped(&rule, map)
+			}
+			scrollback.push(*history.newest(), rule);
+			let (index, automaton, _) = scrollback.generation(0).unwrap();
+			evolved.send(GenerationEvolved {
+				pane,
+				index,
+				automaton: *automaton
+			});
+		}
+	}
+}
+
+/// Bring the [state](CellState) of every cell of every evolving [pane](Pane)
+/// whose [history](History) or [decay](Decay) changed up to date, and recolor
+/// only the cells whose states actually changed, so that a new generation
+/// leaves alone the many cells that look the same as before. Whenever the view
+/// returns from the [scrollback](ScrollOffset), or the [palette](Palette)
+/// changes, recolor every cell, since either may have painted the cells
+/// without regard to their states. While the view is scrolled back, the cells
+/// show the scrollback instead, so leave them alone. Cells of difference panes
+/// are left to [update_differences](update_differences).
+pub(crate) fn paint_cells(
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(Ref<History>, Option<Ref<Decay>>), With<Scrollback>>,
+	mut cells: ParamSet<(
+		Query<(&CellPosition, &mut CellState)>,
+		Query<(&CellPosition, &mut BackgroundColor), Changed<CellState>>
+	)>
+) {
+	if scroll.0 != 0
+	{
+		return
+	}
+	let all = scroll.is_changed() || palette.is_changed();
+	let changed = all || panes.iter().any(|(history, decay)| {
+		history.is_changed() || decay.is_some_and(|decay| decay.is_changed())
+	});
+	if !changed
+	{
+		return
+	}
+	for (position, mut state) in &mut cells.p0()
+	{
+		let Ok((history, decay)) = panes.get(position.pane) else
+		{
+			continue
+		};
+		let due = history.is_changed()
+			|| decay.as_ref().is_some_and(|decay| decay.is_changed());
+		if !all && !due
+		{
+			continue
+		}
+		let new = CellState::of(&history, decay.as_deref(), *position);
+		if all
+		{
+			// Flag every cell, whether or not its state changed.
+			*state = new;
+		}
+		else
+		{
+			state.set_if_neq(new);
+		}
+	}
+	for (position, color) in &mut cells.p1()
+	{
+		if let Ok((history, decay)) = panes.get(position.pane)
+		{
+			let decay = decay.as_deref();
+			repaint(color, palette.cell_color(&history, decay, *position));
+		}
+	}
+}
+
+/// While in [slow&#32;motion](SlowMotion) with [scanning](Scan) enabled, reveal
+/// the [newest](History::newest) generation of each [pane](Pane) cell by cell,
+/// from left to right, in step with the progress of its heartbeat, so that the
+/// rule can be seen sweeping across the previous generation. Unrevealed cells
+/// show as dead. Once the scan stops, e.g., because the slow-motion key was
+/// released, reveal every cell at once.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_scan(
+	scan: Res<Scan>,
+	slow: Res<SlowMotion>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(&History, Option<&Decay>, Option<&Heartbeat>)>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>,
+	mut hiding: Local<bool>
+) {
+	let scanning =
+		scan.0 && slow.0 && timer.is_running() && scroll.0 == 0;
+	if !scanning && !*hiding
+	{
+		return
+	}
+	*hiding = scanning;
+	for (position, color) in &mut cells
+	{
+		if position.row != AUTOMATON_HISTORY - 1
+		{
+			continue
+		}
+		// Difference panes have no history of their own.
+		let Ok((history, decay, heartbeat)) = panes.get(position.pane)
+		else
+		{
+			continue
+		};
+		let progress = match heartbeat
+		{
+			Some(heartbeat) => heartbeat.progress(),
+			None => timer.progress()
+		};
+		let revealed = (progress * AUTOMATON_LENGTH as f32) as usize;
+		let new = match scanning && position.column >= revealed
+		{
+			true => palette.liveness_color(false),
+			false => palette.cell_color(history, decay, *position)
+		};
+		repaint(color, new);
+	}
+}
+
+/// Once every [pane](Pane) has settled into a [steady&#32;state](SteadyState),
+/// pause the evolver automatically, rather than looping forever, and show the
+/// instructional overlay along with a banner that explains why. The
+/// explanation describes the [focused](Focused) pane. In screensaver mode,
+/// [cycle](RuleCycle) to the next rule immediately instead.
+fn maybe_pause_on_steady_state(
+	mut timer: ResMut<EvolutionTimer>,
+	cycle: Option<ResMut<RuleCycle>>,
+	language: Res<Language>,
+	panes: Query<(Ref<History>, &RuleSchedule, Option<&Focused>)>,
+	mut instructions: Query<
+		&mut Style,
+		(With<Instructions>, Without<SteadyStateBanner>)
+	>,
+	mut banner: Query<&mut Style, With<SteadyStateBanner>>,
+	mut label: Query<&mut Text, With<SteadyStateLabel>>
+) {
+	if !timer.is_running()
+		|| !panes.iter().any(|(history, _, _)| history.is_changed())
+	{
+		return
+	}
+	let mut reason = None;
+	for (history, schedule, focused) in &panes
+	{
+		match history.steady_state(schedule.len())
+		{
+			None => return,
+			Some(state) if focused.is_some() => reason = Some(state),
+			Some(_) => {}
+		}
+	}
+	if let Some(mut cycle) = cycle
+	{
+		cycle.expire();
+		return
+	}
+	timer.toggle();
+	instructions.single_mut().display = Display::Flex;
+	banner.single_mut().display = Display::Flex;
+	label.single_mut().sections[1].value = match reason.unwrap()
+	{
+		SteadyState::Extinct => language.text(Message::Extinct),
+		SteadyState::FixedPoint => language.text(Message::FixedPoint)
+	}.to_string();
+}
+
+/// In screensaver mode, whenever the [cycle](RuleCycle) comes around, replace
+/// the [schedule](RuleSchedule) of every [pane](Pane) with a random
+/// [rule](AutomatonRule), optionally reseeding its [history](History), and
+/// announce the new rule in a [toast](Toast). New rules are drawn
+/// [by&#32;class](ClassSampling), and new seeds are
+/// [symmetric](SymmetricSeeds), if requested. The changes are sent as
+/// [actions](Action), so they take effect on the next frame.
+#[allow(clippy::too_many_arguments)]
+fn maybe_cycle_rule(
+	time: Res<Time>,
+	timer: Res<EvolutionTimer>,
+	symmetric: Res<SymmetricSeeds>,
+	classes: Res<ClassSampling>,
+	cycle: Option<ResMut<RuleCycle>>,
+	panes: Query<&Pane, With<RuleSchedule>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	let Some(mut cycle) = cycle else { return };
+	if !timer.is_running()
+	{
+		return
+	}
+	cycle.timer.tick(time.delta());
+	if !cycle.timer.just_finished()
+	{
+		return
+	}
+	let mut panes = panes.iter().collect::<Vec<_>>();
+	panes.sort();
+	let mut rules = Vec::new();
+	for pane in panes
+	{
+		let rule = classes.random_rule();
+		actions.send(Action::ReplaceRule { pane: pane.0, rule });
+		if cycle.reseed
+		{
+			let mut seed = Automaton::<AUTOMATON_LENGTH>::from(random::<u64>());
+			if symmetric.0
+			{
+				seed = seed.symmetrize();
+			}
+			actions.send(Action::Seed { pane: pane.0, seed });
+		}
+		rules.push(rule);
+	}
+	announcements.send(Announcement(
+		rules.iter()
+			.map(|rule| rule.to_string())
+			.collect::<Vec<_>>()
+			.join(" | ")
+	));
+}
+
+/// While paused, scroll the view back and forth through the
+/// [scrollback](Scrollback) of every [pane](Pane): the mouse wheel scrolls by
+/// generations, and page up and page down scroll by whole pages. Scrolling
+/// stops at the oldest retained generation. Resuming the evolution returns the
+/// view to the newest generation. While the [3D view](Extrusion) is shown, the
+/// mouse wheel zooms its camera instead.
+fn maybe_scroll(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	mut wheel: EventReader<MouseWheel>,
+	timer: Res<EvolutionTimer>,
+	extrusion: Res<Extrusion>,
+	scrollbacks: Query<&Scrollback>,
+	mut scroll: ResMut<ScrollOffset>
+) {
+	if timer.is_running()
+	{
+		wheel.clear();
+		scroll.set_if_neq(ScrollOffset(0));
+		return
+	}
+	if extrusion.0
+	{
+		wheel.clear();
+	}
+	let mut delta = wheel.read()
+		.map(|event| match event.unit
+		{
+			MouseScrollUnit::Line => event.y,
+			MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE
+		})
+		.sum::<f32>()
+		.round() as isize;
+	if keys.just_pressed(bindings.page_back)
+	{
+		delta += AUTOMATON_HISTORY as isize;
+	}
+	if keys.just_pressed(bindings.page_forward)
+	{
+		delta -= AUTOMATON_HISTORY as isize;
+	}
+	if delta != 0
+	{
+		let limit = scrollbacks.iter()
+			.map(|scrollback| scrollback.len())
+			.max()
+			.unwrap_or(0)
+			.saturating_sub(AUTOMATON_HISTORY);
+		let offset = (scroll.0 as isize + delta).clamp(0, limit as isize);
+		scroll.set_if_neq(ScrollOffset(offset as usize));
+	}
+}
+
+/// Whenever the view [scrolls](maybe_scroll), [repaint](repaint_cells) every
+/// cell from the [scrollback](Scrollback) of its [pane](Pane), and announce how
+/// far back the view lies in a [toast](Toast).
+pub(crate) fn update_scroll(
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	language: Res<Language>,
+	scrollbacks: Query<&Scrollback>,
+	differences: Query<&Difference>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !scroll.is_changed() || scroll.is_added()
+	{
+		return
+	}
+	repaint_cells(
+		*scroll,
+		&palette,
+		&scrollbacks,
+		&differences,
+		cells.iter_mut()
+	);
+	if scroll.0 > 0
+	{
+		announcements.send(Announcement(
+			language.format(Message::GenerationsBack, &[&scroll.0])
+		));
+	}
+}
+
+/// On `P`, switch to the next [palette&#32;scheme](PaletteScheme), and
+/// announce it.
+fn maybe_change_palette(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut scheme: ResMut<PaletteScheme>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.palette)
+	{
+		*scheme = scheme.next();
+		announcements.send(Announcement(
+			language.format(Message::Palette, &[&*scheme])
+		));
+	}
+}
+
+/// Whenever the [palette&#32;scheme](PaletteScheme) changes, rederive the
+/// [palette](Palette), and repaint every cell at the current
+/// [scroll&#32;offset](ScrollOffset), along with the borders of square cells.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_palette(
+	scheme: Res<PaletteScheme>,
+	overrides: Res<PaletteOverrides>,
+	shape: Res<CellShape>,
+	scroll: Res<ScrollOffset>,
+	mut palette: ResMut<Palette>,
+	scrollbacks: Query<&Scrollback>,
+	differences: Query<&Difference>,
+	mut cells: Query<
+		(&CellPosition, &mut BackgroundColor),
+		Without<CellFrame>
+	>,
+	mut frames: Query<&mut BackgroundColor, With<CellFrame>>
+) {
+	if !scheme.is_changed() || scheme.is_added()
+	{
+		return
+	}
+	*palette = Palette::new(*scheme, &overrides);
+	repaint_cells(
+		*scroll,
+		&palette,
+		&scrollbacks,
+		&differences,
+		cells.iter_mut()
+	);
+	if *shape == CellShape::Square
+	{
+		for mut color in &mut frames
+		{
+			*color = palette.liveness_color(true);
+		}
+	}
+}
+
+/// On `E`, export every retained generation of the [focused](Focused)
+/// [pane](Pane) in the chosen [format](ExportFormat), into a new file in the
+/// working directory. Announce the outcome in the [toast](Toast).
+fn maybe_export(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	format: Res<ExportFormat>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &Scrollback), With<Focused>>,
+	mut announcements: EventWriter<Announcement>
+) {
+	// While a hexadecimal rule is being entered, `E` is a digit.
+	if keys.just_pressed(bindings.export) && !builder.claims(bindings.export)
+	{
+		let (pane, scrollback) = pane.single();
+		let stem = format!("generations-{}", pane.0);
+		announcements.send(Announcement(
+			export_generations(&stem, scrollback, *format, *language)
+		));
+	}
+}
+
+/// On `G`, switch to the next style of [gridlines](Gridlines).
+fn maybe_change_gridlines(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut gridlines: ResMut<Gridlines>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.gridlines)
+	{
+		*gridlines = gridlines.next();
+		announcements.send(Announcement(
+			language.format(Message::Gridlines, &[&*gridlines])
+		));
+	}
+}
+
+/// Whenever the [gridlines](Gridlines) change, resize the gaps between the
+/// cells of every [pane](PaneView) and the borders around each
+/// [cell](CellFrame). Because a resource always counts as changed the first
+/// time that a system sees it, this also applies the initial gridlines to the
+/// freshly built grids.
+fn update_gridlines(
+	gridlines: Res<Gridlines>,
+	mut grids: Query<&mut Style, (With<PaneView>, Without<CellFrame>)>,
+	mut frames: Query<&mut Style, (With<CellFrame>, Without<PaneView>)>
+) {
+	if !gridlines.is_changed()
+	{
+		return
+	}
+	let gap = Val::Px(gridlines.gap());
+	let border = UiRect::all(Val::Px(gridlines.border()));
+	for mut style in &mut grids
+	{
+		style.column_gap = gap;
+		style.row_gap = gap;
+	}
+	for mut style in &mut frames
+	{
+		style.padding = border;
+	}
+}
+
+/// On `V`, toggle vsync, and announce the new setting.
+fn maybe_toggle_vsync(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut pacing: ResMut<FramePacing>,
+	mut windows: Query<&mut Window, With<PrimaryWindow>>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.vsync)
+	{
+		pacing.vsync = !pacing.vsync;
+		if let Ok(mut window) = windows.get_single_mut()
+		{
+			window.present_mode = pacing.present_mode();
+		}
+		let message = match pacing.vsync
+		{
+			true => Message::VsyncOn,
+			false => Message::VsyncOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// Sleep away whatever remains of the shortest frame permitted by the
+/// [frame&#32;rate&#32;cap](FramePacing), if any, so that the application
+/// doesn't render faster than necessary. Runs at the very end of each frame.
+/// Browsers pace frames themselves, so this is available for native builds
+/// only.
+#[cfg(not(target_family = "wasm"))]
+fn limit_frame_rate(
+	pacing: Res<FramePacing>,
+	mut frame_start: Local<Option<Instant>>
+) {
+	if let (Some(cap), Some(start)) = (pacing.cap, *frame_start)
+	{
+		let elapsed = start.elapsed();
+		if elapsed < cap
+		{
+			std::thread::sleep(cap - elapsed);
+		}
+	}
+	*frame_start = Some(Instant::now());
+}
+
+/// Show the latest [announcement](Announcement) in the [toast](Toast), and
+/// hide the toast once it has been displayed for long enough.
+fn update_toast(
+	time: Res<Time>,
+	mut announcements: EventReader<Announcement>,
+	mut toast: Query<(&mut Style, &mut Toast)>,
+	mut label: Query<&mut Text, With<ToastLabel>>
+) {
+	let (mut style, mut toast) = toast.single_mut();
+	if let Some(announcement) = announcements.read().last()
+	{
+		style.display = Display::Flex;
+		toast.0.reset();
+		label.single_mut().sections[0].value = announcement.0.clone();
+	}
+	else if style.display == Display::Flex
+	{
+		toast.0.tick(time.delta());
+		if toast.0.finished()
+		{
+			style.display = Display::None;
+		}
+	}
+}
+
+/// Recompute the [history](History) of each difference pane whenever either of
+/// the compared histories has changed, e.g., because of
+/// [evolution](evolve) or [editing](maybe_toggle_cells), and update the
+/// visual history of the difference pane.
+fn update_differences(
+	sources: Query<Ref<History>, With<RuleSchedule>>,
+	mut differences: Query<(&mut History, &Difference), Without<RuleSchedule>>,
+	palette: Res<Palette>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>
+) {
+	let mut changed = false;
+	for (mut history, difference) in &mut differences
+	{
+		let left = sources.get(difference.left).unwrap();
+		let right = sources.get(difference.right).unwrap();
+		if left.is_changed() || right.is_changed()
+		{
+			*history = left.difference(&right);
+			changed = true;
+		}
+	}
+	if changed
+	{
+		for (position, color) in &mut cells
+		{
+			if let Ok((history, _)) = differences.get(position.pane)
+			{
+				repaint(color, palette.difference_color(history[*position]));
+			}
+		}
+	}
+}
+
+/// Whenever the [generation](Generation) advances, update the generation
+/// label.
+fn update_generation(
+	generation: Res<Generation>,
+	mut label: Query<&mut Text, With<GenerationLabel>>
+) {
+	if generation.is_changed()
+	{
+		let text = &mut label.single_mut();
+		text.sections[1].value = generation.0.to_string();
+	}
+}
+
+/// Beside the generation number, count down the generations until the
+/// [timeline](RuleTimeline) of the [focused](Focused)&#32;[pane](Pane) next
+/// switches rules, naming the incoming rule. Show nothing once the timeline
+/// has no more switches, or if the pane has no timeline.
+fn update_countdown(
+	language: Res<Language>,
+	panes: Query<
+		(Ref<Scrollback>, Ref<Focused>, Option<&RuleTimeline>),
+		With<Focused>
+	>,
+	mut label: Query<&mut Text, With<GenerationLabel>>
+) {
+	let Ok((scrollback, focused, timeline)) = panes.get_single() else
+	{
+		return
+	};
+	if !scrollback.is_changed() && !focused.is_added()
+	{
+		return
+	}
+	let (generation, ..) = scrollback.generation(0).unwrap();
+	let countdown = timeline
+		.and_then(|timeline| timeline.next_switch(generation))
+		.map(|(switch, rule)| language.format(
+			Message::NextSwitch,
+			&[&u8::from(rule), &(switch - generation)]
+		))
+		.unwrap_or_default();
+	let text = &mut label.single_mut();
+	if text.sections[2].value != countdown
+	{
+		text.sections[2].value = countdown;
+	}
+}
+
+/// Measure the simulation throughput, i.e., the number of
+/// [generations](Generation) advanced per second, as the
+/// [GENERATIONS_PER_SECOND] diagnostic. Unlike the frame rate, the throughput
+/// reflects the heartbeat, and falls to zero while paused.
+fn measure_generations(
+	time: Res<Time>,
+	generation: Res<Generation>,
+	mut last: Local<u64>,
+	mut diagnostics: Diagnostics
+) {
+	let delta = time.delta_seconds_f64();
+	let advanced = generation.0 - *last;
+	*last = generation.0;
+	if delta > 0.0
+	{
+		diagnostics.add_measurement(
+			GENERATIONS_PER_SECOND,
+			|| advanced as f64 / delta
+		);
+	}
+}
+
+/// Trace every [GenerationEvolved], [RuleChanged], and [CellEdited] event, as
+/// a model for other plugins that react to the evolver.
+fn trace_events(
+	mut evolved: EventReader<GenerationEvolved>,
+	mut rule_changes: EventReader<RuleChanged>,
+	mut edits: EventReader<CellEdited>
+) {
+	for event in evolved.read()
+	{
+		trace!(
+			"{:?} evolved generation {}: {}",
+			event.pane,
+			event.index,
+			event.automaton
+		);
+	}
+	for event in rule_changes.read()
+	{
+		trace!("{:?} changed rule: {} → {}", event.pane, event.old, event.new);
+	}
+	for event in edits.read()
+	{
+		let CellPosition { pane, row, column } = event.position;
+		trace!("{:?} edited cell: row {}, column {}", pane, row, column);
+	}
+}
+
+/// Update the frames per second (FPS) and generations per second (GPS)
+/// labels.
+fn update_fps(
 	diagnostics: Res<DiagnosticsStore>,
 	mut fps: Query<&mut Text, With<FpsLabel>>
 ) {
@@ -519,121 +3546,521 @@ fn update_fps(
 	{
 		text.sections[1].value = format!("{:.2}", value);
 	}
+	let gps = diagnostics.get(GENERATIONS_PER_SECOND).unwrap();
+	if let Some(value) = gps.smoothed()
+	{
+		text.sections[3].value = format!("{:.2}", value);
+	}
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 //                              User interface.                               //
 ////////////////////////////////////////////////////////////////////////////////
 
-/// Build the grid that corresponds to the [history](History).
-fn build_history(builder: &mut ChildBuilder, history: &History)
-{
+/// Build the grid that corresponds to the [history](History) of the specified
+/// [pane](Pane). The grid occupies the specified percentage of the window's
+/// width. Each cell takes the specified [shape](CellShape), rendered with the
+/// accompanying [image](UiImage), and is painted by applying `paint` to the
+/// [palette](Palette) and its state. If `editable`, then the cells of the
+/// [newest](History::newest) generation are clickable.
+#[allow(clippy::too_many_arguments)]
+fn build_history(
+	builder: &mut ChildBuilder,
+	pane: Entity,
+	history: &History,
+	width: f32,
+	(shape, image): (CellShape, &UiImage),
+	palette: &Palette,
+	paint: fn(&Palette, bool) -> BackgroundColor,
+	editable: bool
+) {
 	builder
-		.spawn(NodeBundle {
-			style: Style {
-				display: Display::Grid,
-				height: Val::Percent(100.0),
-				width: Val::Percent(100.0),
-				aspect_ratio: Some(1.0),
-				padding: UiRect::all(Val::Px(24.0)),
-				column_gap: Val::Px(1.0),
-				row_gap: Val::Px(1.0),
-				grid_template_columns: RepeatedGridTrack::flex(
-					AUTOMATON_LENGTH as u16, 1.0),
-				grid_template_rows: RepeatedGridTrack::flex(
-					AUTOMATON_HISTORY as u16, 1.0),
-				..default()
-			},
-			background_color: BackgroundColor(Color::DARK_GRAY),
-			..default()
-		})
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::Grid,
+						height: Val::Percent(100.0),
+						width: Val::Percent(width),
+						aspect_ratio: Some(1.0),
+						padding: UiRect::all(Val::Px(24.0)),
+						border: UiRect::all(Val::Px(FOCUS_BORDER)),
+						grid_template_columns: RepeatedGridTrack::flex(
+							AUTOMATON_LENGTH as u16, 1.0),
+						grid_template_rows: RepeatedGridTrack::flex(
+							AUTOMATON_HISTORY as u16, 1.0),
+						..default()
+					},
+					background_color: BackgroundColor(palette.chrome),
+					border_color: BorderColor(Color::NONE),
+					..default()
+				},
+				PaneView(pane)
+			)
+		)
 		.with_children(|builder| {
-			for (row, automaton) in history.iter().enumerate()
+			for row in 0 .. AUTOMATON_HISTORY
 			{
-				for (column, is_live) in automaton.iter().enumerate()
+				for column in 0 .. AUTOMATON_LENGTH
 				{
-					cell(builder, CellPosition { row, column }, *is_live);
+					let position = CellPosition { pane, row, column };
+					cell(
+						builder,
+						position,
+						(shape, image),
+						palette,
+						paint(palette, history[position]),
+						CellState::of(history, None, position),
+						editable && position.is_active_automaton()
+					);
 				}
 			}
 		});
 }
 
-/// Add a visual cell to the component whose [builder](ChildBuilder) is
-/// specified, attaching the specified [position](CellPosition) as a
-/// [component](Component). Render a live cell with [LIVE_COLOR]. Render a dead
-/// cell with [DEAD_COLOR]. Use [LIVE_COLOR] to paint a border around the cell.
-/// If the [position](CellPosition) designates the [newest](History::newest)
-/// generation, then emit clickable buttons instead of colorful rectangles.
-fn cell(builder: &mut ChildBuilder, position: CellPosition, live: bool)
-{
+/// Add a visual cell to the component whose [builder](ChildBuilder) is
+/// specified, attaching the specified [position](CellPosition) and
+/// [state](CellState) as [components](Component). Render the cell in the
+/// specified [shape](CellShape), by tinting the accompanying [image](UiImage)
+/// with the specified [color](BackgroundColor). Use the live color of the
+/// [palette](Palette) to paint the [frame](CellFrame) around a square cell,
+/// whose width follows the [gridlines](Gridlines); other shapes stand apart
+/// without one. Offset the odd rows of hexagonal cells by half a cell, so that
+/// the rows interlock. If `interactive`, then emit a toggleable button instead
+/// of a colorful shape; either way, the cell reacts to the mouse. Cover the
+/// cell with its transparent [overlay](CellOverlay).
+fn cell(
+	builder: &mut ChildBuilder,
+	position: CellPosition,
+	(shape, image): (CellShape, &UiImage),
+	palette: &Palette,
+	color: BackgroundColor,
+	state: CellState,
+	interactive: bool
+) {
+	let border = match shape
+	{
+		CellShape::Square => palette.liveness_color(true),
+		_ => BackgroundColor(Color::NONE)
+	};
+	// Percentages of a grid item's insets resolve against its grid area, so
+	// this shifts the cell by half of its own width.
+	let offset = match shape.is_offset(position.row)
+	{
+		true => Val::Percent(50.0),
+		false => Val::Auto
+	};
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::Grid,
+						left: offset,
+						..default()
+					},
+					background_color: border,
+					..default()
+				},
+				CellFrame
+			)
+		)
+		.with_children(|builder| {
+			let mut cell = match interactive
+			{
+				true => builder.spawn(
+					(
+						ButtonBundle {
+							background_color: color,
+							image: image.clone(),
+							..default()
+						},
+						position,
+						state
+					)
+				),
+				// An ImageBundle would size the cell to fit its texture, so
+				// attach the image to a plain node instead.
+				false => builder.spawn(
+					(
+						NodeBundle {
+							background_color: color,
+							..default()
+						},
+						image.clone(),
+						Interaction::default(),
+						position,
+						state
+					)
+				)
+			};
+			cell.with_children(|builder| {
+				builder.spawn(
+					(
+						NodeBundle {
+							style: Style {
+								position_type: PositionType::Absolute,
+								left: Val::Px(0.0),
+								right: Val::Px(0.0),
+								top: Val::Px(0.0),
+								bottom: Val::Px(0.0),
+								..default()
+							},
+							background_color: BackgroundColor(Color::NONE),
+							..default()
+						},
+						image.clone(),
+						CellOverlay(position)
+					)
+				);
+			});
+		});
+}
+
+/// Create a transparent overlay that is visible when the evolver is paused,
+/// initially only if `visible`. It stacks the [instructions](instructions) for
+/// the specified [key&#32;bindings](KeyBindings) vertically, one line apiece.
+/// Note that centering text is particularly hard, and all of the online
+/// examples I could find were wrong, so here are the salient points:
+///
+/// * Set `display` to `Display::Flex` in the parent.
+/// * Set `justify_content` to `JustifyContent::Center` in the parent.
+/// * Set `align_self` to `AlignSelf::Center` in the `style` of the `TextBundle`
+///   itself.
+fn build_instruction_banner(
+	builder: &mut ChildBuilder,
+	bindings: &KeyBindings,
+	language: Language,
+	visible: bool
+) {
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: match visible
+						{
+							true => Display::Flex,
+							false => Display::None
+						},
+						flex_direction: FlexDirection::Column,
+						position_type: PositionType::Absolute,
+						width: Val::Percent(100.0),
+						padding: UiRect::all(Val::Px(8.0)),
+						top: Val::Px(50.0),
+						justify_content: JustifyContent::Center,
+						..default()
+					},
+					background_color: BackgroundColor(
+						Color::rgba(0.0, 0.0, 0.0, 0.8)
+					),
+					..default()
+				},
+				Instructions
+			)
+		)
+		.with_children(|builder| {
+			for line in instructions(bindings, language)
+			{
+				builder.spawn(
+					TextBundle::from_section(
+						line,
+						TextStyle {
+							font_size: 28.0,
+							color: LABEL_COLOR,
+							..default()
+						}
+					)
+						.with_style(Style {
+							align_self: AlignSelf::Center,
+							..default()
+						})
+				);
+			}
+		});
+}
+
+/// Create a label that explains why the evolver paused itself, but only if it
+/// did. Place it in the upper left, below the instructional banner.
+fn build_steady_state_banner(builder: &mut ChildBuilder, language: Language)
+{
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						position_type: PositionType::Absolute,
+						padding: UiRect::all(Val::Px(8.0)),
+						top: Val::Px(260.0),
+						left: Val::Px(50.0),
+						..default()
+					},
+					background_color: BackgroundColor(
+						Color::rgba(0.0, 0.0, 0.0, 0.8)
+					),
+					..default()
+				},
+				SteadyStateBanner
+			)
+		)
+		.with_children(|builder| {
+			builder
+				.spawn(
+					(
+						TextBundle::from_sections([
+							TextSection::new(
+								language.text(Message::Paused),
+								TextStyle {
+									font_size: 32.0,
+									color: LABEL_COLOR,
+									..default()
+								},
+							),
+							TextSection::from_style(TextStyle {
+								font_size: 32.0,
+								color: LABEL_COLOR,
+								..default()
+							})
+						]),
+						SteadyStateLabel
+					)
+				);
+		});
+}
+
+/// Create a toast that briefly announces a change. Place it at the top center,
+/// so that it is noticeable without obscuring much of the evolution.
+fn build_toast(builder: &mut ChildBuilder)
+{
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						position_type: PositionType::Absolute,
+						width: Val::Percent(100.0),
+						top: Val::Px(8.0),
+						justify_content: JustifyContent::Center,
+						..default()
+					},
+					// Keep toasts visible above any overlay.
+					z_index: ZIndex::Global(TOAST_Z_INDEX),
+					..default()
+				},
+				Toast(Timer::new(TOAST_DURATION, TimerMode::Once))
+			)
+		)
+		.with_children(|builder| {
+			builder
+				.spawn(
+					NodeBundle {
+						style: Style {
+							padding: UiRect::all(Val::Px(8.0)),
+							..default()
+						},
+						background_color: BackgroundColor(
+							Color::rgba(0.0, 0.0, 0.0, 0.8)
+						),
+						..default()
+					}
+				)
+				.with_children(|builder| {
+					builder.spawn(
+						(
+							TextBundle::from_section(
+								"",
+								TextStyle {
+									font_size: 32.0,
+									color: LABEL_COLOR,
+									..default()
+								}
+							),
+							ToastLabel
+						)
+					);
+				});
+		});
+}
+
+/// Create a label that displays the next rule to run, but only if such a rule
+/// is actively being input. Place it in the lower left.
+fn build_next_rule_banner(builder: &mut ChildBuilder, language: Language)
+{
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						position_type: PositionType::Absolute,
+						height: Val::Px(50.0),
+						min_width: Val::Px(300.0),
+						padding: UiRect::all(Val::Px(8.0)),
+						bottom: Val::Px(50.0),
+						left: Val::Px(50.0),
+						..default()
+					},
+					background_color: BackgroundColor(
+						Color::rgba(0.0, 0.0, 0.0, 0.8)
+					),
+					..default()
+				},
+				NextRule
+			)
+		)
+		.with_children(|builder| {
+			builder
+				.spawn(
+					(
+						TextBundle::from_sections([
+							TextSection::new(
+								language.text(Message::NextUp),
+								TextStyle {
+									font_size: 32.0,
+									color: LABEL_COLOR,
+									..default()
+								},
+							),
+							TextSection::from_style(TextStyle {
+								font_size: 32.0,
+								color: LABEL_COLOR,
+								..default()
+							})
+						]),
+						NextRuleLabel
+					)
+				);
+		});
+}
+
+/// Create an overlay that compares the outgoing and incoming rules of the most
+/// recent mutation, atop the breadcrumb trail of abandoned rules. Place it in
+/// the lower left, above the next rule banner.
+fn build_mutation_banner(
+	builder: &mut ChildBuilder,
+	bindings: &KeyBindings,
+	language: Language
+) {
+	let style = TextStyle {
+		font_size: 32.0,
+		color: LABEL_COLOR,
+		..default()
+	};
+	let trail_style = TextStyle {
+		font_size: 20.0,
+		color: LABEL_COLOR,
+		..default()
+	};
 	builder
-		.spawn(NodeBundle {
-			style: Style {
-				display: Display::Grid,
-				padding: UiRect::all(Val::Px(2.0)),
-				..default()
-			},
-			background_color: liveness_color(true),
-			..default()
-		})
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						flex_direction: FlexDirection::Column,
+						position_type: PositionType::Absolute,
+						width: Val::Px(500.0),
+						padding: UiRect::all(Val::Px(8.0)),
+						bottom: Val::Px(120.0),
+						left: Val::Px(50.0),
+						..default()
+					},
+					background_color: BackgroundColor(
+						Color::rgba(0.0, 0.0, 0.0, 0.8)
+					),
+					..default()
+				},
+				Mutation
+			)
+		)
 		.with_children(|builder| {
-			if position.is_active_automaton()
-			{
-				builder.spawn(
+			builder
+				.spawn(
 					(
-						ButtonBundle {
-							background_color: liveness_color(live),
-							..default()
-						},
-						position
+						TextBundle::from_sections([
+							TextSection::from_style(style.clone()),
+							TextSection::new("  →  ", style.clone()),
+							TextSection::from_style(style)
+						]),
+						MutationLabel
 					)
 				);
-			}
-			else
-			{
-				builder.spawn(
+			builder
+				.spawn(
 					(
-						NodeBundle {
-							background_color: liveness_color(live),
-							..default()
-						},
-						position
+						TextBundle::from_sections([
+							TextSection::new(
+								language.format(
+									Message::WalkBack,
+									&[&format!(
+										"[{}]",
+										key_name(bindings.walk_back)
+									)]
+								),
+								trail_style.clone()
+							),
+							TextSection::from_style(trail_style)
+						]),
+						RuleTrailLabel
 					)
 				);
-			}
 		});
 }
 
-/// Answer the appropriate [BackgroundColor] for the specified cell liveness,
-/// rendering a live cell with [LIVE_COLOR] and a dead cell with [DEAD_COLOR].
-#[inline]
-fn liveness_color(live: bool) -> BackgroundColor
+/// Create an overlay that shows the schedule, but only if several rules are
+/// scheduled. Place it in the upper right, below the instructional banner.
+fn build_schedule_banner(builder: &mut ChildBuilder)
 {
-	BackgroundColor(if live { LIVE_COLOR } else { DEAD_COLOR })
+	builder
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						position_type: PositionType::Absolute,
+						padding: UiRect::all(Val::Px(8.0)),
+						top: Val::Px(120.0),
+						right: Val::Px(50.0),
+						..default()
+					},
+					background_color: BackgroundColor(
+						Color::rgba(0.0, 0.0, 0.0, 0.8)
+					),
+					..default()
+				},
+				Schedule
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn((TextBundle::default(), ScheduleLabel));
+		});
 }
 
-/// Create a transparent overlay that is visible when the evolver is paused.
-/// Note that centering text is particularly hard, and all of the online
-/// examples I could find were wrong, so here are the salient points:
-///
-/// * Set `display` to `Display::Flex` in the parent.
-/// * Set `justify_content` to `JustifyContent::Center` in the parent.
-/// * Set `align_self` to `AlignSelf::Center` in the `style` of the `TextBundle`
-///   itself.
-fn build_instruction_banner(builder: &mut ChildBuilder)
+/// Create an overlay that shows the binary expansion of the active rule,
+/// beneath the neighborhoods. Place it in the lower right, above the FPS label.
+fn build_rule_bits_banner(builder: &mut ChildBuilder)
 {
+	let style = TextStyle {
+		font_size: 20.0,
+		color: LABEL_COLOR,
+		..default()
+	};
+	let neighborhoods = (0 ..= 7u8).rev()
+		.map(|ordinal| format!("{:03b}", ordinal))
+		.collect::<Vec<_>>()
+		.join(" ");
 	builder
 		.spawn(
 			(
 				NodeBundle {
 					style: Style {
-						display: Display::Flex,
+						display: Display::None,
 						position_type: PositionType::Absolute,
-						height: Val::Px(50.0),
-						width: Val::Percent(100.0),
 						padding: UiRect::all(Val::Px(8.0)),
-						top: Val::Px(50.0),
-						justify_content: JustifyContent::Center,
+						bottom: Val::Px(120.0),
+						right: Val::Px(50.0),
 						..default()
 					},
 					background_color: BackgroundColor(
@@ -641,44 +4068,41 @@ fn build_instruction_banner(builder: &mut ChildBuilder)
 					),
 					..default()
 				},
-				Instructions
+				RuleBits
 			)
 		)
 		.with_children(|builder| {
-			builder.spawn(
-				TextBundle::from_section(
-					"[space] to resume/pause, [right shift] to \
-						show FPS, or type a new rule",
-					TextStyle {
-						font_size: 28.0,
-						color: LABEL_COLOR,
-						..default()
-					}
-				)
-					.with_style(Style {
-						align_self: AlignSelf::Center,
-						..default()
-					})
-			);
+			builder
+				.spawn(
+					(
+						TextBundle::from_sections([
+							TextSection::new(
+								format!("{}\n", neighborhoods),
+								style.clone()
+							),
+							TextSection::from_style(style.clone()),
+							TextSection::from_style(style)
+						]),
+						RuleBitsLabel
+					)
+				);
 		});
 }
 
-/// Create a label that displays the next rule to run, but only if such a rule
-/// is actively being input. Place it in the lower left.
-fn build_next_rule_banner(builder: &mut ChildBuilder)
+/// Create a label that displays the absolute [generation](Generation) number,
+/// followed by the countdown to the next switch of any
+/// [timeline](RuleTimeline). Place it in the upper left corner.
+fn build_generation_banner(builder: &mut ChildBuilder, language: Language)
 {
 	builder
 		.spawn(
 			(
 				NodeBundle {
 					style: Style {
-						display: Display::None,
 						position_type: PositionType::Absolute,
-						height: Val::Px(50.0),
-						width: Val::Px(300.0),
 						padding: UiRect::all(Val::Px(8.0)),
-						bottom: Val::Px(50.0),
-						left: Val::Px(50.0),
+						top: Val::Px(8.0),
+						left: Val::Px(8.0),
 						..default()
 					},
 					background_color: BackgroundColor(
@@ -686,7 +4110,7 @@ fn build_next_rule_banner(builder: &mut ChildBuilder)
 					),
 					..default()
 				},
-				NextRule
+				GenerationHud
 			)
 		)
 		.with_children(|builder| {
@@ -695,27 +4119,39 @@ fn build_next_rule_banner(builder: &mut ChildBuilder)
 					(
 						TextBundle::from_sections([
 							TextSection::new(
-								"Next up: ",
+								language.text(Message::Generation),
 								TextStyle {
-									font_size: 32.0,
+									font_size: 24.0,
 									color: LABEL_COLOR,
 									..default()
 								},
 							),
-							TextSection::from_style(TextStyle {
-								font_size: 32.0,
-								color: LABEL_COLOR,
-								..default()
-							})
+							TextSection::new(
+								"0",
+								TextStyle {
+									font_size: 24.0,
+									color: LABEL_COLOR,
+									..default()
+								}
+							),
+							TextSection::new(
+								"",
+								TextStyle {
+									font_size: 24.0,
+									color: LABEL_COLOR,
+									..default()
+								}
+							)
 						]),
-						NextRuleLabel
+						GenerationLabel
 					)
 				);
 		});
 }
 
-/// Create an FPS label that displays only when the player holds right shift.
-/// Place it in the lower right.
+/// Create a label for the frames per second (FPS) and the generations per
+/// second (GPS) that displays only when the player holds right shift. Place it
+/// in the lower right.
 fn build_fps_banner(builder: &mut ChildBuilder)
 {
 	builder
@@ -725,7 +4161,7 @@ fn build_fps_banner(builder: &mut ChildBuilder)
 					style: Style {
 						display: Display::None,
 						position_type: PositionType::Absolute,
-						height: Val::Px(50.0),
+						height: Val::Px(90.0),
 						width: Val::Px(200.0),
 						padding: UiRect::all(Val::Px(8.0)),
 						bottom: Val::Px(50.0),
@@ -753,6 +4189,19 @@ fn build_fps_banner(builder: &mut ChildBuilder)
 									..default()
 								},
 							),
+							TextSection::from_style(TextStyle {
+								font_size: 32.0,
+								color: LABEL_COLOR,
+								..default()
+							}),
+							TextSection::new(
+								"\nGPS: ",
+								TextStyle {
+									font_size: 32.0,
+									color: LABEL_COLOR,
+									..default()
+								},
+							),
 							TextSection::from_style(TextStyle {
 								font_size: 32.0,
 								color: LABEL_COLOR,
@@ -765,31 +4214,345 @@ fn build_fps_banner(builder: &mut ChildBuilder)
 		});
 }
 
-/// Set the title of the window to show the active [rule](AutomatonRule).
+/// Compose a window title that shows the [schedule](RuleSchedule) of every
+/// [pane](Pane), from left to right.
+fn title<'a>(
+	panes: impl Iterator<Item=(&'a Pane, &'a RuleSchedule)>
+) -> String
+{
+	let mut panes = panes.collect::<Vec<_>>();
+	panes.sort_by_key(|(pane, _)| **pane);
+	panes.iter()
+		.map(|(_, schedule)| schedule.to_string())
+		.collect::<Vec<_>>()
+		.join(" | ")
+}
+
+/// Write the specified [scrollback](Scrollback) to a new file in the working
+/// directory, in the specified [format](ExportFormat), named by the specified
+/// stem, the current time, and the extension of the format. Answer a message,
+/// in the specified [language](Language), that describes the outcome.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn export_generations(
+	stem: &str,
+	scrollback: &Scrollback,
+	format: ExportFormat,
+	language: Language
+) -> String
+{
+	use std::io::Write;
+	let seconds = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|elapsed| elapsed.as_secs())
+		.unwrap_or_default();
+	let path = format!("{}-{}.{}", stem, seconds, format.extension());
+	let written = std::fs::File::create(&path)
+		.map(std::io::BufWriter::new)
+		.and_then(|mut file| {
+			format.write(scrollback, &mut file)?;
+			file.flush()
+		});
+	match written
+	{
+		Ok(()) => language.format(
+			Message::Exported,
+			&[&scrollback.len(), &path]
+		),
+		Err(e) => language.format(Message::CannotExport, &[&path, &e])
+	}
+}
+
+/// The browser has no working directory to export into.
+#[cfg(target_family = "wasm")]
+pub(crate) fn export_generations(
+	_stem: &str,
+	_scrollback: &Scrollback,
+	_format: ExportFormat,
+	language: Language
+) -> String
+{
+	language.text(Message::ExportUnavailable).to_string()
+}
+
+/// Paint each of the specified cells from the [scrollback](Scrollback) of its
+/// [pane](Pane), as the view appears at the specified
+/// [scroll&#32;offset](ScrollOffset). Generations older than the oldest
+/// retained generation are painted vacant. Difference panes compare the
+/// scrollbacks of their sources.
+fn repaint_cells<'a>(
+	scroll: ScrollOffset,
+	palette: &Palette,
+	scrollbacks: &Query<&Scrollback>,
+	differences: &Query<&Difference>,
+	cells: impl Iterator<
+		Item = (&'a CellPosition, Mut<'a, BackgroundColor>)
+	>
+) {
+	let cell = |pane: Entity, position: &CellPosition| {
+		let back = scroll.0 + AUTOMATON_HISTORY - 1 - position.row;
+		scrollbacks.get(pane).ok()
+			.and_then(|scrollback| scrollback.back(back))
+			.map(|automaton| {
+				automaton[AUTOMATON_LENGTH - position.column - 1]
+			})
+			.unwrap_or(false)
+	};
+	for (position, color) in cells
+	{
+		repaint(color, match differences.get(position.pane)
+		{
+			Ok(difference) => palette.difference_color(
+				cell(difference.left, position)
+					!= cell(difference.right, position)
+			),
+			Err(_) => palette.liveness_color(cell(position.pane, position))
+		});
+	}
+}
+
+/// Rewrite the [color](BackgroundColor) of a cell, but only if it actually
+/// changes. Most cells keep their colors from one generation to the next, so
+/// this spares [change&#32;detection](DetectChanges) from flagging, and the
+/// renderer from reconsidering, every cell of every pane at every heartbeat.
+#[inline]
+pub(crate) fn repaint(mut color: Mut<BackgroundColor>, new: BackgroundColor)
+{
+	if color.0 != new.0
+	{
+		*color = new;
+	}
+}
+
+/// If the [timeline](RuleTimeline) of the specified [pane](Pane) switches
+/// rules at its [newest](Scrollback::generation) generation, then replace its
+/// [schedule](RuleSchedule), and announce the switch as a [RuleChanged] event.
+fn follow_timeline(
+	pane: Entity,
+	timeline: Option<&RuleTimeline>,
+	scrollback: &Scrollback,
+	schedule: &mut RuleSchedule,
+	rule_changes: &mut EventWriter<RuleChanged>
+) {
+	let Some(timeline) = timeline else { return };
+	let (generation, ..) = scrollback.generation(0).unwrap();
+	if let Some(old) = timeline.follow(generation, schedule)
+	{
+		rule_changes.send(RuleChanged {
+			pane,
+			old,
+			new: schedule.clone()
+		});
+	}
+}
+
+/// Answer the address of the page, with its rule and seed
+/// [replaced](set_rule_and_seed) by those of the specified
+/// [schedule](RuleSchedule) and [scrollback](Scrollback).
+#[cfg(target_family = "wasm")]
+fn synced_url(
+	schedule: &RuleSchedule,
+	scrollback: &Scrollback
+) -> Option<String>
+{
+	let href = web_sys::window()?.location().href().ok()?;
+	let url = web_sys::Url::new(&href).ok()?;
+	// The search parameters are live, so changing them changes the address.
+	let params = url.search_params();
+	set_rule_and_seed(&params, schedule, scrollback);
+	Some(url.href())
+}
+
+/// Replace the `rule` or `rules` query parameter within the specified
+/// [parameters](web_sys::UrlSearchParams) by the specified
+/// [schedule](RuleSchedule), and the `seed` query parameter by the first
+/// generation of the specified [scrollback](Scrollback), if it has been
+/// retained. A `pattern` would supersede the `seed`, so it is removed.
+#[cfg(target_family = "wasm")]
+fn set_rule_and_seed(
+	params: &web_sys::UrlSearchParams,
+	schedule: &RuleSchedule,
+	scrollback: &Scrollback
+) {
+	let rules = schedule.iter()
+		.map(RuleKind::abbreviation)
+		.collect::<Vec<_>>();
+	match rules.as_slice()
+	{
+		[rule] =>
+		{
+			params.set("rule", rule);
+			params.delete("rules");
+		},
+		rules =>
+		{
+			params.set("rules", &rules.join(","));
+			params.delete("rule");
+		}
+	}
+	if let Some((0, first, _)) = scrollback.generation(scrollback.len() - 1)
+	{
+		params.set("seed", &u64::from(*first).to_string());
+		params.delete("pattern");
+	}
+}
+
+/// Replace the address of the page, without reloading it or adding an entry to
+/// the browser's history.
+#[cfg(target_family = "wasm")]
+fn replace_url(url: &str)
+{
+	if let Some(history) = web_sys::window()
+		.and_then(|window| window.history().ok())
+	{
+		let _ = history.replace_state_with_url(
+			&wasm_bindgen::JsValue::NULL,
+			"",
+			Some(url)
+		);
+	}
+}
+
+/// Set the title of the window.
 #[cfg(not(target_family = "wasm"))]
-fn set_title(window: &mut Window, rule: AutomatonRule)
+fn set_title(window: &mut Window, title: &str)
 {
-	window.title = rule.to_string();
+	window.title = title.to_string();
 }
 
-/// Set the title of the window to show the active [rule](AutomatonRule). The
-/// Bevy window is not wired to the browser, so it doesn't have a title bar.
-/// Tell the document to update its label instead.
+/// Set the title of the window. The Bevy window is not wired to the browser, so
+/// it doesn't have a title bar. Tell the document to update its label instead.
 #[cfg(target_family = "wasm")]
-fn set_title(_window: &mut Window, rule: AutomatonRule)
+fn set_title(_window: &mut Window, title: &str)
 {
-	web_sys::window().unwrap().document().unwrap().set_title(&rule.to_string());
+	web_sys::window().unwrap().document().unwrap().set_title(title);
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                 Utilities.                                 //
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Answer the lines of the instructional overlay, from top to bottom, in the
+/// specified [language](Language), naming the keys according to the specified
+/// [bindings](KeyBindings).
+fn instructions(bindings: &KeyBindings, language: Language) -> Vec<String>
+{
+	let key = |key: KeyCode| format!("[{}]", key_name(key));
+	vec![
+		language.format(
+			Message::ResumePause,
+			&[&key(bindings.pause), &key(bindings.show_fps)]
+		),
+		language.format(Message::AppendRule, &[&key(bindings.append_rule)]),
+		language.format(Message::DropRule, &[&key(bindings.drop_rule)]),
+		language.format(
+			Message::MutateRule,
+			&[
+				&key(bindings.mutate),
+				&key(bindings.surprise),
+				&key(bindings.walk_back)
+			]
+		),
+		language.format(
+			Message::Favorites,
+			&[
+				&key(bindings.favorite),
+				&key(bindings.previous_favorite),
+				&key(bindings.next_favorite)
+			]
+		),
+		language.text(Message::Bookmarks).to_string(),
+		language.format(
+			Message::TransformRule,
+			&[
+				&key(bindings.mirror),
+				&key(bindings.complement),
+				&key(bindings.mirrored_complement)
+			]
+		),
+		language.format(
+			Message::FocusMute,
+			&[&key(bindings.next_pane), &key(bindings.mute)]
+		),
+		language.format(
+			Message::ScrollExport,
+			&[
+				&key(bindings.page_back),
+				&key(bindings.page_forward),
+				&key(bindings.export)
+			]
+		),
+		language.format(Message::ShareCard, &[&key(bindings.share_card)]),
+		language.format(Message::Poster, &[&key(bindings.poster)]),
+		language.format(Message::Checkpoint, &[&key(bindings.checkpoint)]),
+		language.format(
+			Message::GridlinesVsync,
+			&[&key(bindings.gridlines), &key(bindings.vsync)]
+		),
+		language.format(
+			Message::ChangePalette,
+			&[&key(bindings.palette), &key(bindings.effects)]
+		),
+		language.format(
+			Message::CellCursor,
+			&[
+				&key(bindings.cursor_left),
+				&key(bindings.cursor_right),
+				&key(bindings.toggle_cell)
+			]
+		),
+		language.format(
+			Message::TransformSeed,
+			&[
+				&key(bindings.rotate_left),
+				&key(bindings.rotate_right),
+				&key(bindings.invert),
+				&key(bindings.symmetrize)
+			]
+		),
+		language.format(
+			Message::LongestTransient,
+			&[&key(bindings.longest_transient)]
+		),
+		language.format(
+			Message::FastForward,
+			&[&key(bindings.fast_forward), &FAST_FORWARD_GENERATIONS]
+		),
+		language.format(Message::Turbo, &[&key(bindings.turbo)]),
+		language.format(Message::SlowMotion, &[&key(bindings.slow_motion)]),
+		language.format(
+			Message::ToggleExtrusion,
+			&[&key(bindings.extrusion), &key(bindings.auto_rotate)]
+		),
+		language.format(Message::ToggleTape, &[&key(bindings.tape)]),
+		language.format(Message::ToggleNeon, &[&key(bindings.neon)]),
+		language.format(Message::ToggleParticles, &[&key(bindings.particles)]),
+		language.text(Message::LightCone).to_string(),
+		language.format(Message::ToggleAncestry, &[&key(bindings.ancestry)]),
+		language.format(Message::ToggleMinimap, &[&key(bindings.minimap)]),
+		language.format(Message::ToggleDensity, &[&key(bindings.density)]),
+		language.format(
+			Message::ToggleStatistics,
+			&[&key(bindings.statistics)]
+		),
+		language.format(Message::ToggleAnalysis, &[&key(bindings.analysis)]),
+		language.format(
+			Message::ToggleBackground,
+			&[&key(bindings.background)]
+		),
+		language.format(Message::ToggleChanges, &[&key(bindings.changes)]),
+		language.format(Message::ToggleGallery, &[&key(bindings.gallery)]),
+		language.format(Message::PaintRule, &[&key(bindings.paint_rule)])
+	]
+}
+
 /// Contract for value conversion to a digit character.
 trait ToDigit: Copy
 {
 	/// Convert the receiver into a digit character.
 	fn to_digit(self) -> Option<char>;
+
+	/// Convert the receiver into a lowercase letter.
+	fn to_letter(self) -> Option<char>;
 }
 
 impl ToDigit for KeyCode
@@ -814,6 +4577,19 @@ impl ToDigit for KeyCode
 			None
 		}
 	}
+
+	fn to_letter(self) -> Option<char>
+	{
+		if LETTER_RANGE.contains(&(self as u32))
+		{
+			let delta = self as u32 - KeyCode::A as u32;
+			char::from_u32('a' as u32 + delta)
+		}
+		else
+		{
+			None
+		}
+	}
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -823,26 +4599,132 @@ impl ToDigit for KeyCode
 /// The heartbeat for a running [evolution&#32;system](evolve).
 const HEARTBEAT: Duration = Duration::from_millis(250);
 
+/// The key under which the [settings](persist_settings) are kept in the
+/// browser's local storage.
+#[cfg(target_family = "wasm")]
+pub(crate) const SETTINGS_STORAGE_KEY: &str = "cellular-automata";
+
+/// The default logical resolution of the primary window.
+const WINDOW_RESOLUTION: [f32; 2] = [1024.0, 768.0];
+
+/// How many pixels of [mouse&#32;wheel](MouseWheel) travel count as one line,
+/// i.e., one generation of [scrolling](maybe_scroll).
+pub(crate) const PIXELS_PER_LINE: f32 = 20.0;
+
+/// The [z-index](ZIndex) of a [toast](Toast), which lies above every other
+/// node, including overlays.
+pub(crate) const TOAST_Z_INDEX: i32 = 100;
+
+/// The [diagnostic](Diagnostic) that measures the simulation throughput, in
+/// [generations](Generation) advanced per second.
+pub const GENERATIONS_PER_SECOND: DiagnosticId =
+	DiagnosticId::from_u128(0x6d1f_0c2e_94b3_4a57_8e21_5c7a_b0d4_19f3);
+
+/// The number of frames over which the [GENERATIONS_PER_SECOND] diagnostic is
+/// smoothed.
+const GENERATIONS_PER_SECOND_HISTORY: usize = 60;
+
+/// How long a [toast](Toast) remains visible.
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+
+/// The most generations that the [evolver](evolve) evolves in a single frame,
+/// lest a long frame, e.g., while the window is dragged, stall the next one
+/// with a backlog of evolution.
+const MAX_GENERATIONS_PER_FRAME: u32 = 64;
+
+/// How many times longer each heartbeat lasts in
+/// [slow&#32;motion](SlowMotion).
+const SLOW_MOTION_FACTOR: u32 = 8;
+
+/// The number of generations through which the
+/// [fast-forward&#32;key](maybe_fast_forward) evolves the focused pane.
+const FAST_FORWARD_GENERATIONS: usize = 100;
+
+/// The number of random first generations that the
+/// [search&#32;for&#32;the&#32;longest&#32;transient](maybe_find_transient)
+/// examines.
+const TRANSIENT_SEARCH_SAMPLES: usize = 64;
+
+/// The number of evolutions after which the
+/// [search&#32;for&#32;the&#32;longest&#32;transient](maybe_find_transient)
+/// gives up on a first generation, lest it stall the frame.
+const TRANSIENT_SEARCH_LIMIT: usize = 10_000;
+
 /// How long to delay between digit submissions before accepting the input so
 /// far as the next [rule](AutomatonRule).
 const RULE_ENTRY_GRACE: Duration = Duration::from_millis(600);
 
-/// The [color](Color) to use for live cells.
+/// The default [color](Color) to use for live cells.
 const LIVE_COLOR: Color = Color::BLACK;
 
-/// The [color](Color) to use for dead cells.
+/// The default [color](Color) to use for dead cells.
 const DEAD_COLOR: Color = Color::WHITE;
 
-/// The [color](Color) of a depressed button.
+/// The default [color](Color) to use for cells of a difference pane where the
+/// compared histories disagree.
+const DIFFERENCE_COLOR: Color = Color::CRIMSON;
+
+/// The default [color](Color) of a depressed button.
 const PRESSED_COLOR: Color = Color::YELLOW;
 
+/// Vermilion, from the Okabe–Ito palette for color blindness.
+const VERMILION: Color = Color::rgb(0.835, 0.369, 0.0);
+
+/// Sky blue, from the Okabe–Ito palette for color blindness.
+const SKY_BLUE: Color = Color::rgb(0.337, 0.706, 0.914);
+
+/// Blue, from the Okabe–Ito palette for color blindness.
+const BLUE: Color = Color::rgb(0.0, 0.447, 0.698);
+
+/// Orange, from the Okabe–Ito palette for color blindness.
+const ORANGE: Color = Color::rgb(0.902, 0.624, 0.0);
+
+/// The color of the chrome around and between the cells, in every palette.
+const CHROME_COLOR: Color = Color::DARK_GRAY;
+
+/// The color of live cells in the muted palette.
+const SLATE: Color = Color::rgb(0.361, 0.420, 0.494);
+
+/// The color of dead cells in the muted palette.
+const DUSK: Color = Color::rgb(0.118, 0.129, 0.157);
+
+/// The color of differences in the muted palette.
+const DUSTY_ROSE: Color = Color::rgb(0.576, 0.400, 0.427);
+
+/// The color of highlights in the muted palette.
+const PALE_GOLD: Color = Color::rgb(0.690, 0.631, 0.424);
+
 /// The [color](Color) of text labels.
 const LABEL_COLOR: Color = Color::YELLOW;
 
+/// The [color](Color) that highlights the
+/// [active](RuleSchedule::active)&#32;[rule](AutomatonRule) of the
+/// [schedule](RuleSchedule).
+const ACTIVE_RULE_COLOR: Color = Color::CYAN;
+
+/// The [color](Color) of the border around the [focused](Focused)
+/// [pane](Pane).
+const FOCUS_COLOR: Color = Color::CYAN;
+
+/// The width of the border around each [pane](Pane), in pixels.
+const FOCUS_BORDER: f32 = 4.0;
+
+/// The [color](Color) of the ring around the cell under the keyboard
+/// [cursor](CellCursor).
+const CURSOR_COLOR: Color = Color::CYAN;
+
+/// The width of the ring around the cell under the keyboard
+/// [cursor](CellCursor), in pixels.
+const CURSOR_BORDER: f32 = 2.0;
+
 /// The range of [key&#32;codes](KeyCode) that correspond to the number row.
 const NUMBER_ROW_RANGE: RangeInclusive<u32> =
 	KeyCode::Key1 as u32 ..= KeyCode::Key0 as u32;
 
 /// The range of [key&#32;codes](KeyCode) that correspond to the numpad digits.
 const NUMPAD_RANGE: RangeInclusive<u32> =
-	KeyCode::Numpad0 as u32 ..= KeyCode::Numpad9 as u32;
\ No newline at end of file
+	KeyCode::Numpad0 as u32 ..= KeyCode::Numpad9 as u32;
+
+/// The range of [key&#32;codes](KeyCode) that correspond to the letters.
+const LETTER_RANGE: RangeInclusive<u32> =
+	KeyCode::A as u32 ..= KeyCode::Z as u32;
\ No newline at end of file
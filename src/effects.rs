@@ -0,0 +1,273 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use bevy::prelude::{
+	App, BackgroundColor, BuildChildren, Button, Color, Commands, Component,
+	default, DespawnRecursiveExt, DetectChanges, Entity, EventWriter, Input,
+	IntoSystemConfigs, KeyCode, NodeBundle, Plugin, PositionType, Query, Ref,
+	Res, ResMut, Resource, Style, Time, Timer, UiRect, Update, Val, Vec2, With,
+	ZIndex
+};
+use bevy::time::TimerMode;
+use rand::random;
+
+use crate::automata::{History, Scrollback};
+use crate::ecs::{Announcement, CellPosition, evolve, Palette};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the flashy presentation mode, in which
+/// each cell of the newest generation that comes alive glows briefly and
+/// throws off a burst of sparks, and each cell that dies fades out. The
+/// [Effects] resource governs whether the mode is active.
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Effects>()
+			.add_systems(Update, maybe_toggle_effects)
+			.add_systems(Update, spawn_effects.after(evolve))
+			.add_systems(Update, animate_flashes.after(spawn_effects))
+			.add_systems(Update, animate_sparks);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether births and deaths are accompanied by visual effects.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Effects(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A cell of the newest generation that is transitioning between colors: a
+/// newborn cell cools from the glow color to the live color, while a dying
+/// cell fades from the live color to the dead color.
+#[derive(Component)]
+struct Flash
+{
+	/// Whether the cell was born, as opposed to having died.
+	born: bool,
+
+	/// The progress of the transition.
+	timer: Timer
+}
+
+/// A spark thrown off by a newborn cell, which flies outward from the center
+/// of the cell while fading away.
+#[derive(Component)]
+struct Spark
+{
+	/// The velocity of the spark, in pixels per second.
+	velocity: Vec2,
+
+	/// The displacement of the spark from the center of its cell, in pixels.
+	offset: Vec2,
+
+	/// The remaining life of the spark.
+	timer: Timer
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `L`, toggle the [effects](Effects), and announce the new setting.
+fn maybe_toggle_effects(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut effects: ResMut<Effects>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.effects)
+	{
+		effects.0 = !effects.0;
+		let message = match effects.0
+		{
+			true => Message::EffectsOn,
+			false => Message::EffectsOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// Whenever the [history](History) of a pane changes, compare each cell of its
+/// [newest](History::newest) generation against the generation before. Flash
+/// each cell that was born or died, and throw sparks from each newborn cell.
+/// Difference panes have no [scrollback](Scrollback), so they are left alone.
+fn spawn_effects(
+	effects: Res<Effects>,
+	panes: Query<Ref<History>, With<Scrollback>>,
+	cells: Query<(Entity, &CellPosition), With<Button>>,
+	mut commands: Commands
+) {
+	if !effects.0
+	{
+		return
+	}
+	for (entity, position) in &cells
+	{
+		let Ok(history) = panes.get(position.pane) else
+		{
+			continue
+		};
+		if !position.is_active_automaton()
+			|| !history.is_changed()
+			|| history.is_added()
+		{
+			continue
+		}
+		let previous = CellPosition { row: position.row - 1, ..*position };
+		let (now, before) = (history[*position], history[previous]);
+		if now == before
+		{
+			continue
+		}
+		commands.entity(entity).insert(Flash {
+			born: now,
+			timer: Timer::new(FLASH_DURATION, TimerMode::Once)
+		});
+		if now
+		{
+			commands.entity(entity).with_children(|builder| {
+				let phase = random::<f32>() * TAU;
+				for index in 0 .. SPARKS_PER_BIRTH
+				{
+					let angle =
+						phase + TAU * index as f32 / SPARKS_PER_BIRTH as f32;
+					let speed = SPARK_SPEED * (0.5 + random::<f32>());
+					builder.spawn(spark(Vec2::from_angle(angle) * speed));
+				}
+			});
+		}
+	}
+}
+
+/// Advance every [flash](Flash), blending the color of its cell from the start
+/// color to the end color. Remove each flash once it completes, leaving its
+/// cell in the end color.
+fn animate_flashes(
+	time: Res<Time>,
+	palette: Res<Palette>,
+	mut flashes: Query<(Entity, &mut Flash, &mut BackgroundColor)>,
+	mut commands: Commands
+) {
+	for (entity, mut flash, mut color) in &mut flashes
+	{
+		flash.timer.tick(time.delta());
+		let (from, to) = match flash.born
+		{
+			true => (GLOW_COLOR, palette.live),
+			false => (palette.live, palette.dead)
+		};
+		*color = BackgroundColor(blend(from, to, flash.timer.percent()));
+		if flash.timer.finished()
+		{
+			commands.entity(entity).remove::<Flash>();
+		}
+	}
+}
+
+/// Advance every [spark](Spark) along its trajectory, fading it as it goes.
+/// Despawn each spark once it burns out.
+fn animate_sparks(
+	time: Res<Time>,
+	mut sparks: Query<(Entity, &mut Spark, &mut Style, &mut BackgroundColor)>,
+	mut commands: Commands
+) {
+	for (entity, mut spark, mut style, mut color) in &mut sparks
+	{
+		spark.timer.tick(time.delta());
+		if spark.timer.finished()
+		{
+			commands.entity(entity).despawn_recursive();
+			continue
+		}
+		let delta = spark.velocity * time.delta_seconds();
+		spark.offset += delta;
+		style.margin = UiRect {
+			left: Val::Px(spark.offset.x),
+			top: Val::Px(spark.offset.y),
+			..default()
+		};
+		*color = BackgroundColor(
+			GLOW_COLOR.with_a(1.0 - spark.timer.percent())
+		);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer a [spark](Spark) with the specified velocity, ready to be spawned
+/// at the center of its cell.
+fn spark(velocity: Vec2) -> (NodeBundle, Spark)
+{
+	(
+		NodeBundle {
+			style: Style {
+				position_type: PositionType::Absolute,
+				left: Val::Percent(50.0),
+				top: Val::Percent(50.0),
+				width: Val::Px(SPARK_SIZE),
+				height: Val::Px(SPARK_SIZE),
+				..default()
+			},
+			background_color: BackgroundColor(GLOW_COLOR),
+			z_index: ZIndex::Global(1),
+			..default()
+		},
+		Spark {
+			velocity,
+			offset: Vec2::ZERO,
+			timer: Timer::new(SPARK_DURATION, TimerMode::Once)
+		}
+	)
+}
+
+/// Answer the [color](Color) that lies the specified fraction of the way from
+/// `from` to `to`, interpolating each channel linearly.
+pub(crate) fn blend(from: Color, to: Color, fraction: f32) -> Color
+{
+	let [r0, g0, b0, a0] = from.as_rgba_f32();
+	let [r1, g1, b1, a1] = to.as_rgba_f32();
+	let lerp = |start: f32, end: f32| start + (end - start) * fraction;
+	Color::rgba(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1), lerp(a0, a1))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [color](Color) in which newborn cells glow and sparks burn.
+const GLOW_COLOR: Color = Color::ORANGE;
+
+/// How long a [flash](Flash) takes to settle into the end color. This is a
+/// little shorter than the default heartbeat, so that each generation settles
+/// before the next arrives.
+const FLASH_DURATION: Duration = Duration::from_millis(200);
+
+/// The number of [sparks](Spark) thrown off by each newborn cell.
+const SPARKS_PER_BIRTH: usize = 4;
+
+/// The average speed of a [spark](Spark), in pixels per second.
+const SPARK_SPEED: f32 = 60.0;
+
+/// The width and height of a [spark](Spark), in pixels.
+const SPARK_SIZE: f32 = 3.0;
+
+/// How long a [spark](Spark) burns.
+const SPARK_DURATION: Duration = Duration::from_millis(400);
@@ -0,0 +1,548 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::prelude::{
+	AlphaMode, App, Assets, BuildChildren, Camera, Camera2d, Camera3dBundle,
+	Color, Commands, Component, default, DespawnRecursiveExt, DetectChanges,
+	DirectionalLight, DirectionalLightBundle, Entity, EventReader,
+	EventWriter, Handle, Input, IntoSystemConfigs, KeyCode, Local, Mesh,
+	MouseButton,
+	PbrBundle, Plugin, Query, Ref, Res, ResMut, Resource, shape, SpatialBundle,
+	StandardMaterial, Startup, Time, Transform, Update, Vec2, Vec3, With
+};
+use bevy::ui::camera_config::UiCameraConfig;
+
+use crate::automata::{Automaton, AUTOMATON_LENGTH, Scrollback};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, Focused, Generation, Palette,
+	PIXELS_PER_LINE
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the 3D view, which extrudes the
+/// [scrollback](Scrollback) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane) into a landscape of blocks,
+/// one row of blocks per generation, so that the history of the automaton can
+/// be seen as the surface that it is. The newest generation lies at the front,
+/// and older generations recede into the distance. The [Extrusion] resource
+/// governs whether the view is shown. Dragging with the left mouse button
+/// orbits the camera around the landscape, the mouse wheel zooms, and the
+/// [AutoRotate] resource governs whether the camera circles on its own.
+pub struct ExtrusionPlugin;
+
+impl Plugin for ExtrusionPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Extrusion>()
+			.init_resource::<AutoRotate>()
+			.add_systems(Startup, add_extrusion_assets)
+			.add_systems(Update, maybe_toggle_extrusion)
+			.add_systems(Update, maybe_toggle_auto_rotate)
+			.add_systems(
+				Update,
+				orbit_camera
+					.after(maybe_toggle_extrusion)
+					.after(maybe_toggle_auto_rotate)
+			)
+			.add_systems(
+				Update,
+				update_extrusion
+					.after(maybe_toggle_extrusion)
+					.after(apply_actions)
+					.after(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the 3D view replaces the usual 2D view.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Extrusion(pub(crate) bool);
+
+/// Whether the camera of the 3D view circles the landscape on its own, which
+/// is handy for demonstrations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct AutoRotate(pub(crate) bool);
+
+/// The [mesh](Mesh) and [materials](StandardMaterial) shared by every block of
+/// the 3D view.
+#[derive(Resource)]
+struct ExtrusionAssets
+{
+	/// The mesh of a single block.
+	block: Handle<Mesh>,
+
+	/// The material of the blocks, i.e., of live cells.
+	live: Handle<StandardMaterial>,
+
+	/// The mesh of the ground beneath the blocks.
+	ground: Handle<Mesh>,
+
+	/// The material of the ground, i.e., of dead cells.
+	dead: Handle<StandardMaterial>
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Marks every entity that belongs to the 3D view, so that the whole scene can
+/// be despawned when the view is dismissed.
+#[derive(Component)]
+struct ExtrusionScene;
+
+/// The camera of the 3D view, which orbits a fixed point of the landscape. Its
+/// position is given in spherical coordinates about that point.
+#[derive(Copy, Clone, Debug, PartialEq, Component)]
+pub(crate) struct ExtrusionCamera
+{
+	/// The angle about the vertical axis, in radians. At `0`, the camera faces
+	/// the newest generation head on.
+	yaw: f32,
+
+	/// The angle above the ground, in radians.
+	pitch: f32,
+
+	/// The distance from the focal point, in world units.
+	distance: f32
+}
+
+impl ExtrusionCamera
+{
+	/// Answer the [transform](Transform) that places the camera at its
+	/// position, looking at the focal point.
+	fn transform(&self) -> Transform
+	{
+		let offset = Vec3::new(
+			self.pitch.cos() * self.yaw.sin(),
+			self.pitch.sin(),
+			self.pitch.cos() * self.yaw.cos()
+		) * self.distance;
+		Transform::from_translation(CAMERA_FOCUS + offset)
+			.looking_at(CAMERA_FOCUS, Vec3::Y)
+	}
+}
+
+impl Default for ExtrusionCamera
+{
+	fn default() -> Self
+	{
+		Self {
+			yaw: 0.0,
+			pitch: INITIAL_PITCH,
+			distance: INITIAL_DISTANCE
+		}
+	}
+}
+
+/// A row of blocks that represents a single generation, given as its age: `0`
+/// for the newest generation, `1` for the generation before, and so forth.
+#[derive(Component)]
+struct Layer(usize);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Startup systems.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Create the [assets](ExtrusionAssets) shared by every block of the 3D view,
+/// colored according to the [palette](Palette).
+fn add_extrusion_assets(
+	palette: Res<Palette>,
+	mut meshes: ResMut<Assets<Mesh>>,
+	mut materials: ResMut<Assets<StandardMaterial>>,
+	mut commands: Commands
+) {
+	let depth = MAX_LAYERS as f32 * LAYER_PITCH;
+	commands.insert_resource(ExtrusionAssets {
+		block: meshes.add(Mesh::from(shape::Cube { size: BLOCK_SIZE })),
+		live: materials.add(material(palette.live)),
+		ground: meshes.add(Mesh::from(shape::Box::new(
+			AUTOMATON_LENGTH as f32 * LAYER_PITCH,
+			GROUND_THICKNESS,
+			depth
+		))),
+		dead: materials.add(material(palette.dead))
+	});
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `T`, toggle the 3D view, and announce the new setting. While the 3D view
+/// is shown, the 2D camera, and with it the whole user interface, is
+/// deactivated, though the hotkeys continue to work. The user interface also
+/// stops responding to the mouse, so that dragging the camera cannot toggle
+/// the hidden cells.
+#[allow(clippy::too_many_arguments)]
+fn maybe_toggle_extrusion(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	assets: Res<ExtrusionAssets>,
+	mut extrusion: ResMut<Extrusion>,
+	mut cameras: Query<(Entity, &mut Camera), With<Camera2d>>,
+	scene: Query<Entity, With<ExtrusionScene>>,
+	mut commands: Commands,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.extrusion)
+	{
+		return
+	}
+	extrusion.0 = !extrusion.0;
+	for (entity, mut camera) in &mut cameras
+	{
+		camera.is_active = !extrusion.0;
+		commands
+			.entity(entity)
+			.insert(UiCameraConfig { show_ui: !extrusion.0 });
+	}
+	match extrusion.0
+	{
+		true => build_scene(&assets, &mut commands),
+		false =>
+		{
+			for entity in &scene
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+	}
+	let message = match extrusion.0
+	{
+		true => Message::ExtrusionOn,
+		false => Message::ExtrusionOff
+	};
+	announcements.send(Announcement(language.text(message).to_string()));
+}
+
+/// On `R`, toggle [automatic rotation](AutoRotate), and announce the new
+/// setting.
+fn maybe_toggle_auto_rotate(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut auto_rotate: ResMut<AutoRotate>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.auto_rotate)
+	{
+		auto_rotate.0 = !auto_rotate.0;
+		let message = match auto_rotate.0
+		{
+			true => Message::AutoRotateOn,
+			false => Message::AutoRotateOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the 3D view is shown, orbit its [camera](ExtrusionCamera): dragging
+/// with the left mouse button swings the camera around and over the landscape,
+/// and the mouse wheel zooms in and out. While the mouse button is up and
+/// [automatic rotation](AutoRotate) is on, the camera circles the landscape at
+/// a leisurely pace.
+fn orbit_camera(
+	time: Res<Time>,
+	buttons: Res<Input<MouseButton>>,
+	auto_rotate: Res<AutoRotate>,
+	mut motion: EventReader<MouseMotion>,
+	mut wheel: EventReader<MouseWheel>,
+	mut cameras: Query<(&mut ExtrusionCamera, &mut Transform)>
+) {
+	let drag = motion.read().map(|event| event.delta).sum::<Vec2>();
+	let zoom = wheel.read()
+		.map(|event| match event.unit
+		{
+			MouseScrollUnit::Line => event.y,
+			MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE
+		})
+		.sum::<f32>();
+	for (mut camera, mut transform) in &mut cameras
+	{
+		let before = *camera;
+		if buttons.pressed(MouseButton::Left)
+		{
+			camera.yaw -= drag.x * ORBIT_SENSITIVITY;
+			camera.pitch = (camera.pitch + drag.y * ORBIT_SENSITIVITY)
+				.clamp(-MAX_PITCH, MAX_PITCH);
+		}
+		else if auto_rotate.0
+		{
+			camera.yaw += AUTO_ROTATE_SPEED * time.delta_seconds();
+		}
+		camera.distance = (camera.distance * ZOOM_FACTOR.powf(-zoom))
+			.clamp(MIN_DISTANCE, MAX_DISTANCE);
+		if *camera != before
+		{
+			*transform = camera.transform();
+		}
+	}
+}
+
+/// While the 3D view is shown, keep its [layers](Layer) in step with the
+/// [scrollback](Scrollback) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane). When new generations
+/// evolve, push every layer back by one row per generation and add a layer at
+/// the front for each, discarding any layer that falls off the back. After any
+/// other change, e.g., a toggled cell, a new seed, or a change of focus,
+/// rebuild every layer.
+/// Whenever the [palette](Palette) changes, recolor the
+/// [materials](ExtrusionAssets).
+#[allow(clippy::too_many_arguments)]
+fn update_extrusion(
+	extrusion: Res<Extrusion>,
+	assets: Res<ExtrusionAssets>,
+	generation: Res<Generation>,
+	palette: Res<Palette>,
+	pane: Query<(Ref<Scrollback>, Ref<Focused>)>,
+	mut layers: Query<(Entity, &mut Layer, &mut Transform)>,
+	mut materials: ResMut<Assets<StandardMaterial>>,
+	mut commands: Commands,
+	mut last: Local<u64>
+) {
+	// Several generations may evolve in a single frame.
+	let advanced = generation.0.saturating_sub(*last) as usize;
+	*last = generation.0;
+	if palette.is_changed()
+	{
+		if let Some(material) = materials.get_mut(&assets.live)
+		{
+			material.base_color = palette.live;
+		}
+		if let Some(material) = materials.get_mut(&assets.dead)
+		{
+			material.base_color = palette.dead;
+		}
+	}
+	if !extrusion.0
+	{
+		return
+	}
+	let Ok((scrollback, focused)) = pane.get_single() else
+	{
+		return
+	};
+	if extrusion.is_changed() || focused.is_added()
+	{
+		rebuild_layers(&assets, &scrollback, &mut layers, &mut commands);
+	}
+	else if generation.is_changed()
+	{
+		for (entity, mut layer, mut transform) in &mut layers
+		{
+			layer.0 += advanced;
+			match layer.0 < MAX_LAYERS
+			{
+				true => transform.translation.z = layer_z(layer.0),
+				false => commands.entity(entity).despawn_recursive()
+			}
+		}
+		for age in 0 .. advanced.min(MAX_LAYERS)
+		{
+			if let Some(automaton) = scrollback.back(age)
+			{
+				spawn_layer(&assets, automaton, age, &mut commands);
+			}
+		}
+	}
+	else if scrollback.is_changed()
+	{
+		rebuild_layers(&assets, &scrollback, &mut layers, &mut commands);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the fixed parts of the 3D view: the camera, the light, and the
+/// ground. The [layers](Layer) are left to [update_extrusion].
+fn build_scene(assets: &ExtrusionAssets, commands: &mut Commands)
+{
+	let depth = MAX_LAYERS as f32 * LAYER_PITCH;
+	let camera = ExtrusionCamera::default();
+	commands.spawn(
+		(
+			Camera3dBundle {
+				transform: camera.transform(),
+				..default()
+			},
+			// The 2D user interface would otherwise cover the whole view.
+			UiCameraConfig { show_ui: false },
+			camera,
+			ExtrusionScene
+		)
+	);
+	commands.spawn(
+		(
+			DirectionalLightBundle {
+				directional_light: DirectionalLight {
+					shadows_enabled: true,
+					..default()
+				},
+				transform: Transform::from_xyz(-1.0, 2.0, 1.0)
+					.looking_at(Vec3::ZERO, Vec3::Y),
+				..default()
+			},
+			ExtrusionScene
+		)
+	);
+	commands.spawn(
+		(
+			PbrBundle {
+				mesh: assets.ground.clone(),
+				material: assets.dead.clone(),
+				transform: Transform::from_xyz(
+					0.0,
+					-GROUND_THICKNESS / 2.0,
+					-depth / 2.0 + LAYER_PITCH / 2.0
+				),
+				..default()
+			},
+			ExtrusionScene
+		)
+	);
+}
+
+/// Despawn every [layer](Layer), then spawn a layer for each of the most recent
+/// generations of the specified [scrollback](Scrollback), up to
+/// [MAX_LAYERS].
+fn rebuild_layers(
+	assets: &ExtrusionAssets,
+	scrollback: &Scrollback,
+	layers: &mut Query<(Entity, &mut Layer, &mut Transform)>,
+	commands: &mut Commands
+) {
+	for (entity, _, _) in layers.iter()
+	{
+		commands.entity(entity).despawn_recursive();
+	}
+	(0 .. MAX_LAYERS)
+		.map_while(|age| Some((age, scrollback.back(age)?)))
+		.for_each(|(age, automaton)| {
+			spawn_layer(assets, automaton, age, commands)
+		});
+}
+
+/// Spawn the [layer](Layer) of the specified age, with a block atop each live
+/// cell of the specified [automaton](Automaton). Columns advance from left to
+/// right, as in the 2D view.
+fn spawn_layer(
+	assets: &ExtrusionAssets,
+	automaton: &Automaton,
+	age: usize,
+	commands: &mut Commands
+) {
+	commands
+		.spawn(
+			(
+				SpatialBundle::from_transform(
+					Transform::from_xyz(0.0, 0.0, layer_z(age))
+				),
+				Layer(age),
+				ExtrusionScene
+			)
+		)
+		.with_children(|builder| {
+			for column in 0 .. AUTOMATON_LENGTH
+			{
+				if automaton[AUTOMATON_LENGTH - column - 1]
+				{
+					let x = (column as f32 - AUTOMATON_LENGTH as f32 / 2.0
+						+ 0.5) * LAYER_PITCH;
+					builder.spawn(PbrBundle {
+						mesh: assets.block.clone(),
+						material: assets.live.clone(),
+						transform:
+							Transform::from_xyz(x, BLOCK_SIZE / 2.0, 0.0),
+						..default()
+					});
+				}
+			}
+		});
+}
+
+/// Answer the depth of the [layer](Layer) of the specified age.
+#[inline]
+fn layer_z(age: usize) -> f32
+{
+	-(age as f32) * LAYER_PITCH
+}
+
+/// Answer an opaque, slightly glossy [material](StandardMaterial) of the
+/// specified [color](Color).
+fn material(color: Color) -> StandardMaterial
+{
+	StandardMaterial {
+		base_color: color,
+		perceptual_roughness: 0.6,
+		alpha_mode: AlphaMode::Opaque,
+		..default()
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The maximum number of generations extruded into the 3D view.
+const MAX_LAYERS: usize = 128;
+
+/// The distance between the centers of adjacent blocks, in world units.
+const LAYER_PITCH: f32 = 1.0;
+
+/// The edge length of a block, in world units. Slightly less than the
+/// [pitch](LAYER_PITCH), so that adjacent blocks remain distinguishable.
+const BLOCK_SIZE: f32 = 0.9;
+
+/// The thickness of the ground beneath the blocks, in world units.
+const GROUND_THICKNESS: f32 = 0.2;
+
+/// The point about which the [camera](ExtrusionCamera) orbits: a quarter of
+/// the way back from the newest generation, so that the recent history fills
+/// most of the view.
+const CAMERA_FOCUS: Vec3 =
+	Vec3::new(0.0, 0.0, -(MAX_LAYERS as f32) * LAYER_PITCH / 4.0);
+
+/// The initial [pitch](ExtrusionCamera::pitch) of the camera, in radians:
+/// above and in front of the newest generation, looking back over the history.
+const INITIAL_PITCH: f32 = 0.45;
+
+/// The initial [distance](ExtrusionCamera::distance) of the camera from its
+/// [focal point](CAMERA_FOCUS), in world units.
+const INITIAL_DISTANCE: f32 = 90.0;
+
+/// The steepest [pitch](ExtrusionCamera::pitch) of the camera, in radians,
+/// above or below the ground. Just shy of vertical, so that the camera never
+/// flips over.
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+/// How far the camera swings, in radians, per pixel that the mouse is dragged.
+const ORBIT_SENSITIVITY: f32 = 0.005;
+
+/// The factor by which each line of [mouse&#32;wheel](MouseWheel) travel
+/// shrinks the [distance](ExtrusionCamera::distance) of the camera.
+const ZOOM_FACTOR: f32 = 1.1;
+
+/// The closest that the camera may approach its [focal point](CAMERA_FOCUS).
+const MIN_DISTANCE: f32 = 10.0;
+
+/// The farthest that the camera may retreat from its
+/// [focal point](CAMERA_FOCUS).
+const MAX_DISTANCE: f32 = 300.0;
+
+/// How fast the camera circles the landscape under
+/// [automatic rotation](AutoRotate), in radians per second.
+const AUTO_ROTATE_SPEED: f32 = 0.3;
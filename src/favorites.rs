@@ -0,0 +1,229 @@
+use std::path::PathBuf;
+
+#[cfg(not(target_family = "wasm"))]
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventWriter, Input, IntoSystemConfigs, KeyCode, Plugin, Query, Res,
+	ResMut, Resource, Update, With
+};
+
+use crate::automata::{AutomatonRule, RuleKind, RuleSchedule};
+#[cfg(not(target_family = "wasm"))]
+use crate::config::Config;
+use crate::ecs::{Action, Announcement, apply_actions, Focused, Pane};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for favorite rules, which can be marked
+/// with a single key and then cycled through with two more, far quicker than
+/// typing their codes. The [Favorites] resource holds the favorites, and keeps
+/// them in the [configuration&#32;file](Config) between runs.
+pub struct FavoritesPlugin;
+
+impl Plugin for FavoritesPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Favorites>()
+			.add_systems(Update, maybe_favorite)
+			.add_systems(Update, maybe_cycle_favorites.before(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The favorite [rules](AutomatonRule), in the order in which they were
+/// marked.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct Favorites
+{
+	/// The favorite rules, without duplicates.
+	rules: Vec<AutomatonRule>,
+
+	/// The [configuration&#32;file](Config) that keeps the favorites between
+	/// runs, if any. Always [None] for WASM builds.
+	#[cfg_attr(target_family = "wasm", allow(dead_code))]
+	path: Option<PathBuf>
+}
+
+impl Favorites
+{
+	/// Construct a new [Favorites] from the specified rules, ignoring
+	/// duplicates. If a `path` is specified, then every change is saved to the
+	/// [configuration&#32;file](Config) there.
+	pub fn new(
+		rules: impl IntoIterator<Item=AutomatonRule>,
+		path: Option<PathBuf>
+	) -> Self
+	{
+		let mut favorites = Self { rules: Vec::new(), path };
+		for rule in rules
+		{
+			if !favorites.rules.contains(&rule)
+			{
+				favorites.rules.push(rule);
+			}
+		}
+		favorites
+	}
+
+	/// Answer the number of favorite rules.
+	pub fn count(&self) -> usize
+	{
+		self.rules.len()
+	}
+
+	/// Mark the specified rule as a favorite, or unmark it if it is already a
+	/// favorite. Answer `true` if the rule is now a favorite.
+	pub fn toggle(&mut self, rule: AutomatonRule) -> bool
+	{
+		match self.rules.iter().position(|&favorite| favorite == rule)
+		{
+			Some(index) =>
+			{
+				self.rules.remove(index);
+				false
+			},
+			None =>
+			{
+				self.rules.push(rule);
+				true
+			}
+		}
+	}
+
+	/// Answer the favorite that follows the specified rule, or that precedes
+	/// it if not `forward`, wrapping around at either end, along with its
+	/// index. A rule that is not a favorite, or no rule at all, is followed by
+	/// the first favorite and preceded by the last. Answer [None] if there are
+	/// no favorites.
+	pub fn cycle(
+		&self,
+		rule: Option<AutomatonRule>,
+		forward: bool
+	) -> Option<(usize, AutomatonRule)>
+	{
+		let len = self.rules.len();
+		if len == 0
+		{
+			return None
+		}
+		let current = rule.and_then(|rule| {
+			self.rules.iter().position(|&favorite| favorite == rule)
+		});
+		let index = match (current, forward)
+		{
+			(Some(index), true) => (index + 1) % len,
+			(Some(index), false) => (index + len - 1) % len,
+			(None, true) => 0,
+			(None, false) => len - 1
+		};
+		Some((index, self.rules[index]))
+	}
+
+	/// Save the favorites to the [configuration&#32;file](Config), if any.
+	/// Failures are logged, but otherwise ignored.
+	fn save(&self)
+	{
+		#[cfg(not(target_family = "wasm"))]
+		if let Some(ref path) = self.path
+		{
+			let codes = self.rules.iter()
+				.map(|&rule| u8::from(rule))
+				.collect::<Vec<_>>();
+			if let Err(e) = Config::save_favorites(path, &codes)
+			{
+				warn!("Cannot save favorites to {}: {}", path.display(), e);
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `U`, mark the [active](RuleSchedule::active) rule of the
+/// [focused](Focused)&#32;[pane](Pane) as a [favorite](Favorites), or unmark
+/// it if it is already a favorite. Save the favorites, and announce the
+/// change in the toast. Only elementary rules can be favorites.
+fn maybe_favorite(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	pane: Query<&RuleSchedule, With<Focused>>,
+	mut favorites: ResMut<Favorites>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.favorite)
+	{
+		return
+	}
+	let rule = match *pane.single().active()
+	{
+		RuleKind::Wolfram(rule) => rule,
+		#[allow(unreachable_patterns)]
+		_ => return
+	};
+	let message = match favorites.toggle(rule)
+	{
+		true => Message::FavoriteAdded,
+		false => Message::FavoriteRemoved
+	};
+	favorites.save();
+	announcements.send(
+		Announcement(language.format(message, &[&u8::from(rule)]))
+	);
+}
+
+/// On `=`, replace the [active](RuleSchedule::active) rule of the
+/// [focused](Focused)&#32;[pane](Pane) with the next [favorite](Favorites),
+/// or on `-`, with the previous favorite, leaving the outgoing
+/// [schedule](RuleSchedule) on the pane's breadcrumb trail, just like a
+/// mutation. Announce the favorite in the toast.
+fn maybe_cycle_favorites(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	favorites: Res<Favorites>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>,
+	mut announcements: EventWriter<Announcement>
+) {
+	let forward = match (
+		keys.just_pressed(bindings.previous_favorite),
+		keys.just_pressed(bindings.next_favorite)
+	) {
+		(_, true) => true,
+		(true, false) => false,
+		(false, false) => return
+	};
+	let (pane, schedule) = pane.single();
+	// A rule that isn't elementary can't be a favorite, so start from the
+	// first or the last favorite.
+	let rule = match *schedule.active()
+	{
+		RuleKind::Wolfram(rule) => Some(rule),
+		#[allow(unreachable_patterns)]
+		_ => None
+	};
+	let Some((index, rule)) = favorites.cycle(rule, forward) else
+	{
+		announcements.send(
+			Announcement(language.text(Message::NoFavorites).to_string())
+		);
+		return
+	};
+	actions.send(Action::Mutate { pane: pane.0, rule });
+	announcements.send(Announcement(language.format(
+		Message::Favorite,
+		&[&(index + 1), &favorites.count(), &u8::from(rule)]
+	)));
+}
@@ -0,0 +1,425 @@
+use bevy::prelude::{
+	App, Assets, BackgroundColor, BorderColor, BuildChildren, ButtonBundle,
+	ChildBuilder, Changed, Color, Commands, Component, default,
+	DespawnRecursiveExt, DetectChanges, Entity, EventWriter, FlexDirection,
+	Handle, Image, ImageBundle, Input, Interaction, IntoSystemConfigs, KeyCode,
+	NodeBundle, Plugin, PositionType, Query, Res, ResMut, Resource, Style,
+	TextBundle, TextStyle, UiImage, UiRect, Update, Val, With, ZIndex
+};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+use bevy::render::texture::ImageSampler;
+use bevy::ui::{AlignItems, FocusPolicy};
+
+use crate::automata::{
+	AUTOMATON_HISTORY, AUTOMATON_LENGTH, ExportFormat, History
+};
+use crate::bookmarks::{
+	Bookmarks, maybe_bookmark, restore_bookmarks, RestoreBookmark
+};
+use crate::ecs::{
+	Announcement, export_generations, Palette, TOAST_Z_INDEX
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the gallery, a lightweight session
+/// manager that lists the [bookmarks](Bookmarks) in the corner of the window,
+/// each pictured by a thumbnail of its [history](History). Pressing a
+/// thumbnail [restores](RestoreBookmark) the bookmark into the focused pane,
+/// and the buttons beside it delete the bookmark or export its scrollback in
+/// the chosen [format](ExportFormat). The [Gallery] resource governs whether
+/// the gallery is shown.
+pub struct GalleryPlugin;
+
+impl Plugin for GalleryPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Gallery>()
+			.add_systems(Update, maybe_toggle_gallery)
+			.add_systems(Update, maybe_press_gallery.before(restore_bookmarks))
+			.add_systems(
+				Update,
+				update_gallery
+					.after(maybe_toggle_gallery)
+					.after(maybe_press_gallery)
+					.after(maybe_bookmark)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the gallery is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Gallery(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The frame of the gallery, which holds an entry for every bookmark.
+#[derive(Component)]
+struct GalleryFrame;
+
+/// The thumbnail of the bookmark in the specified slot, which restores the
+/// bookmark when pressed.
+#[derive(Component)]
+struct Thumbnail(usize);
+
+/// The button that deletes the bookmark in the specified slot.
+#[derive(Component)]
+struct DeleteButton(usize);
+
+/// The button that exports the scrollback of the bookmark in the specified
+/// slot.
+#[derive(Component)]
+struct ExportButton(usize);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `K`, toggle the [gallery](Gallery), and announce the new setting.
+fn maybe_toggle_gallery(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut gallery: ResMut<Gallery>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.gallery)
+	{
+		gallery.0 = !gallery.0;
+		let message = match gallery.0
+		{
+			true => Message::GalleryOn,
+			false => Message::GalleryOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// Carry out presses within the [gallery](Gallery).
+///
+/// * On press of a [thumbnail](Thumbnail), restore its bookmark.
+/// * On press of a [delete&#32;button](DeleteButton), delete its bookmark,
+///   and announce the deletion in the toast.
+/// * On press of an [export&#32;button](ExportButton), export the scrollback
+///   of its bookmark, in the chosen [format](ExportFormat), into a new file
+///   in the working directory, and announce the outcome in the toast.
+#[allow(clippy::too_many_arguments)]
+fn maybe_press_gallery(
+	format: Res<ExportFormat>,
+	language: Res<Language>,
+	mut bookmarks: ResMut<Bookmarks>,
+	thumbnails: Query<(&Interaction, &Thumbnail), Changed<Interaction>>,
+	deletes: Query<(&Interaction, &DeleteButton), Changed<Interaction>>,
+	exports: Query<(&Interaction, &ExportButton), Changed<Interaction>>,
+	mut restorations: EventWriter<RestoreBookmark>,
+	mut announcements: EventWriter<Announcement>
+) {
+	for (interaction, thumbnail) in &thumbnails
+	{
+		if *interaction == Interaction::Pressed
+		{
+			restorations.send(RestoreBookmark(thumbnail.0));
+		}
+	}
+	for (interaction, button) in &exports
+	{
+		if *interaction == Interaction::Pressed
+		{
+			if let Some(bookmark) = bookmarks.get(button.0)
+			{
+				let stem = format!("bookmark-{}", button.0);
+				announcements.send(Announcement(export_generations(
+					&stem,
+					bookmark.scrollback(),
+					*format,
+					*language
+				)));
+			}
+		}
+	}
+	for (interaction, button) in &deletes
+	{
+		if *interaction == Interaction::Pressed
+			&& bookmarks.remove(button.0).is_some()
+		{
+			announcements.send(Announcement(
+				language.format(Message::BookmarkDeleted, &[&button.0])
+			));
+		}
+	}
+}
+
+/// While the [gallery](Gallery) is shown, keep its entries in step with the
+/// [bookmarks](Bookmarks), rebuilding the whole gallery whenever the
+/// bookmarks, the [palette](Palette), or the language change. The gallery is
+/// small, so rebuilding it is cheaper than reconciling it. Despawn the gallery
+/// when it is hidden, releasing its thumbnails.
+fn update_gallery(
+	gallery: Res<Gallery>,
+	bookmarks: Res<Bookmarks>,
+	palette: Res<Palette>,
+	language: Res<Language>,
+	frames: Query<Entity, With<GalleryFrame>>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands
+) {
+	let stale = gallery.is_changed()
+		|| bookmarks.is_changed()
+		|| palette.is_changed()
+		|| language.is_changed();
+	if !stale
+	{
+		return
+	}
+	for entity in &frames
+	{
+		commands.entity(entity).despawn_recursive();
+	}
+	if gallery.0
+	{
+		build_gallery(
+			&bookmarks,
+			&palette,
+			*language,
+			&mut images,
+			&mut commands
+		);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [frame](GalleryFrame) of the gallery in the top left corner of
+/// the window, under a heading, with an entry for each of the specified
+/// [bookmarks](Bookmarks), or a note that there are none.
+fn build_gallery(
+	bookmarks: &Bookmarks,
+	palette: &Palette,
+	language: Language,
+	images: &mut Assets<Image>,
+	commands: &mut Commands
+) {
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						left: Val::Px(GALLERY_MARGIN),
+						top: Val::Px(GALLERY_MARGIN),
+						flex_direction: FlexDirection::Column,
+						row_gap: Val::Px(GALLERY_GAP),
+						padding: UiRect::all(Val::Px(GALLERY_GAP)),
+						border: UiRect::all(Val::Px(GALLERY_BORDER)),
+						..default()
+					},
+					background_color: BackgroundColor(palette.chrome),
+					border_color: BorderColor(GALLERY_FRAME_COLOR),
+					focus_policy: FocusPolicy::Block,
+					z_index: ZIndex::Global(GALLERY_Z_INDEX),
+					..default()
+				},
+				GalleryFrame
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(label(language.text(Message::Gallery)));
+			let mut empty = true;
+			for (slot, bookmark) in bookmarks.iter()
+			{
+				empty = false;
+				let image = images.add(draw(bookmark.history(), palette));
+				build_entry(builder, slot, image, language);
+			}
+			if empty
+			{
+				builder.spawn(label(language.text(Message::GalleryEmpty)));
+			}
+		});
+}
+
+/// Add the entry for the bookmark in the specified slot to the gallery: its
+/// slot number, its [thumbnail](Thumbnail), which shows the specified
+/// [image](Image), and its [delete](DeleteButton) and [export](ExportButton)
+/// buttons.
+fn build_entry(
+	builder: &mut ChildBuilder,
+	slot: usize,
+	image: Handle<Image>,
+	language: Language
+) {
+	builder
+		.spawn(NodeBundle {
+			style: Style {
+				align_items: AlignItems::Center,
+				column_gap: Val::Px(GALLERY_GAP),
+				..default()
+			},
+			..default()
+		})
+		.with_children(|builder| {
+			builder.spawn(label(&slot.to_string()));
+			builder.spawn(
+				(
+					ButtonBundle {
+						style: Style {
+							width: Val::Px(THUMBNAIL_WIDTH),
+							height: Val::Px(THUMBNAIL_HEIGHT),
+							border: UiRect::all(Val::Px(GALLERY_BORDER)),
+							..default()
+						},
+						border_color: BorderColor(GALLERY_FRAME_COLOR),
+						..default()
+					},
+					Thumbnail(slot)
+				)
+			).with_children(|builder| {
+				builder.spawn(ImageBundle {
+					style: Style {
+						width: Val::Percent(100.0),
+						height: Val::Percent(100.0),
+						..default()
+					},
+					image: UiImage::new(image),
+					..default()
+				});
+			});
+			builder
+				.spawn(NodeBundle {
+					style: Style {
+						flex_direction: FlexDirection::Column,
+						row_gap: Val::Px(GALLERY_GAP),
+						..default()
+					},
+					..default()
+				})
+				.with_children(|builder| {
+					let delete = language.text(Message::Delete);
+					builder.spawn((button(), DeleteButton(slot)))
+						.with_children(|builder| {
+							builder.spawn(label(delete));
+						});
+					let export = language.text(Message::Export);
+					builder.spawn((button(), ExportButton(slot)))
+						.with_children(|builder| {
+							builder.spawn(label(export));
+						});
+				});
+		});
+}
+
+/// Answer a [button](ButtonBundle) for the gallery, ready to hold a
+/// [label](label).
+fn button() -> ButtonBundle
+{
+	ButtonBundle {
+		style: Style {
+			padding: UiRect::axes(Val::Px(GALLERY_GAP), Val::Px(2.0)),
+			border: UiRect::all(Val::Px(GALLERY_BORDER)),
+			..default()
+		},
+		border_color: BorderColor(GALLERY_FRAME_COLOR),
+		background_color: BackgroundColor(Color::NONE),
+		..default()
+	}
+}
+
+/// Answer a text label for the gallery.
+fn label(text: &str) -> TextBundle
+{
+	TextBundle::from_section(
+		text,
+		TextStyle {
+			font_size: GALLERY_FONT_SIZE,
+			color: GALLERY_LABEL_COLOR,
+			..default()
+		}
+	)
+}
+
+/// Draw the specified [history](History) into an [image](Image), one row of
+/// pixels per generation, oldest at the top, so that the thumbnail looks just
+/// like the pane did when it was bookmarked.
+fn draw(history: &History, palette: &Palette) -> Image
+{
+	let live = palette.live.as_rgba_u8();
+	let dead = palette.dead.as_rgba_u8();
+	let mut data = Vec::with_capacity(AUTOMATON_LENGTH * AUTOMATON_HISTORY * 4);
+	for automaton in history.iter()
+	{
+		// Cells run right to left, but pixels run left to right.
+		for column in 0 .. AUTOMATON_LENGTH
+		{
+			let color = match automaton[AUTOMATON_LENGTH - column - 1]
+			{
+				true => live,
+				false => dead
+			};
+			data.extend_from_slice(&color);
+		}
+	}
+	let mut image = Image::new(
+		Extent3d {
+			width: AUTOMATON_LENGTH as u32,
+			height: AUTOMATON_HISTORY as u32,
+			depth_or_array_layers: 1
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb
+	);
+	// Keep the cells crisp when stretched.
+	image.sampler = ImageSampler::nearest();
+	image
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The width of each [thumbnail](Thumbnail), in pixels.
+const THUMBNAIL_WIDTH: f32 = 128.0;
+
+/// The height of each [thumbnail](Thumbnail), in pixels, which preserves the
+/// proportions of a pane.
+const THUMBNAIL_HEIGHT: f32 =
+	THUMBNAIL_WIDTH * AUTOMATON_HISTORY as f32 / AUTOMATON_LENGTH as f32;
+
+/// The distance between the [frame](GalleryFrame) of the gallery and the
+/// corner of the window, in pixels.
+const GALLERY_MARGIN: f32 = 12.0;
+
+/// The space between adjacent parts of the gallery, in pixels.
+const GALLERY_GAP: f32 = 6.0;
+
+/// The width of the borders within the gallery, in pixels.
+const GALLERY_BORDER: f32 = 1.0;
+
+/// The [color](Color) of the borders within the gallery.
+const GALLERY_FRAME_COLOR: Color = Color::GRAY;
+
+/// The font size of the labels within the gallery.
+const GALLERY_FONT_SIZE: f32 = 18.0;
+
+/// The [color](Color) of the labels within the gallery.
+const GALLERY_LABEL_COLOR: Color = Color::YELLOW;
+
+/// The [z-index](ZIndex) of the gallery, which lies above the panes, but
+/// beneath the tooltip, the growing tape, and toasts.
+const GALLERY_Z_INDEX: i32 = TOAST_Z_INDEX - 2;
@@ -0,0 +1,246 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::str::FromStr;
+
+use bevy::prelude::{Image, Resource};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Cell shapes.                                //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The shape in which each cell is rendered. Every shape but the square is
+/// rendered by tinting a procedurally generated [texture](Self::texture), so
+/// that the grid, its colors, and its interactivity are the same regardless of
+/// shape.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum CellShape
+{
+	/// Square cells, abutting in rows and columns.
+	#[default]
+	Square,
+
+	/// Circular cells, inscribed within the squares.
+	Circle,
+
+	/// Pointy-topped hexagonal cells, with every other row offset by half a
+	/// cell, as in a honeycomb.
+	Hexagon
+}
+
+impl CellShape
+{
+	/// Determine whether the cells of the specified row are offset by half a
+	/// cell to the right.
+	pub fn is_offset(self, row: usize) -> bool
+	{
+		self == CellShape::Hexagon && row % 2 == 1
+	}
+
+	/// Answer a white silhouette of the shape on a transparent background,
+	/// suitable for tinting, or [None] if the shape needs no texture.
+	pub fn texture(self) -> Option<Image>
+	{
+		let covers: fn(f32, f32) -> bool = match self
+		{
+			CellShape::Square => return None,
+			CellShape::Circle => |x, y| x * x + y * y <= 1.0,
+			// The hexagon is stretched horizontally to fill the square.
+			CellShape::Hexagon => |x, y| x.abs() / 2.0 + y.abs() <= 1.0
+		};
+		let pixels = (TEXTURE_SIZE * TEXTURE_SIZE) as usize;
+		let mut data = Vec::with_capacity(pixels * 4);
+		for row in 0 .. TEXTURE_SIZE
+		{
+			for column in 0 .. TEXTURE_SIZE
+			{
+				// Supersample each pixel to smooth the edges of the shape.
+				let mut covered = 0;
+				for i in 0 .. SUPERSAMPLES
+				{
+					for j in 0 .. SUPERSAMPLES
+					{
+						let x = to_unit(column, j);
+						let y = to_unit(row, i);
+						if covers(x, y)
+						{
+							covered += 1;
+						}
+					}
+				}
+				let alpha = covered * 255 / (SUPERSAMPLES * SUPERSAMPLES);
+				data.extend_from_slice(&[255, 255, 255, alpha as u8]);
+			}
+		}
+		Some(Image::new(
+			Extent3d {
+				width: TEXTURE_SIZE,
+				height: TEXTURE_SIZE,
+				depth_or_array_layers: 1
+			},
+			TextureDimension::D2,
+			data,
+			TextureFormat::Rgba8UnormSrgb
+		))
+	}
+}
+
+impl FromStr for CellShape
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"square" => Ok(CellShape::Square),
+			"circle" => Ok(CellShape::Circle),
+			"hexagon" => Ok(CellShape::Hexagon),
+			_ => Err(format!(
+				"unknown cell shape '{}': expected square, circle, or hexagon",
+				s
+			))
+		}
+	}
+}
+
+impl Display for CellShape
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			CellShape::Square => "square",
+			CellShape::Circle => "circle",
+			CellShape::Hexagon => "hexagon"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Gridlines.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How strongly the cells are separated from one another, by the gaps between
+/// them and the borders around them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum Gridlines
+{
+	/// Neither gaps nor borders, so that the cells form a dense bitmap.
+	Bitmap,
+
+	/// Narrow gaps and thin borders.
+	#[default]
+	Fine,
+
+	/// Wide gaps and thin borders, so that each cell stands out clearly, e.g.,
+	/// for teaching.
+	Strong
+}
+
+impl Gridlines
+{
+	/// Answer the next gridlines in the cycle, wrapping around at the end.
+	pub fn next(self) -> Self
+	{
+		match self
+		{
+			Gridlines::Fine => Gridlines::Strong,
+			Gridlines::Strong => Gridlines::Bitmap,
+			Gridlines::Bitmap => Gridlines::Fine
+		}
+	}
+
+	/// Answer the width of the gaps between adjacent cells, in pixels.
+	pub fn gap(self) -> f32
+	{
+		match self
+		{
+			Gridlines::Bitmap => 0.0,
+			Gridlines::Fine => FINE_GAP,
+			Gridlines::Strong => STRONG_GAP
+		}
+	}
+
+	/// Answer the width of the border around each cell, in pixels.
+	pub fn border(self) -> f32
+	{
+		match self
+		{
+			Gridlines::Bitmap => 0.0,
+			Gridlines::Fine | Gridlines::Strong => CELL_BORDER
+		}
+	}
+}
+
+impl FromStr for Gridlines
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"bitmap" => Ok(Gridlines::Bitmap),
+			"fine" => Ok(Gridlines::Fine),
+			"strong" => Ok(Gridlines::Strong),
+			_ => Err(format!(
+				"unknown gridlines '{}': expected bitmap, fine, or strong",
+				s
+			))
+		}
+	}
+}
+
+impl Display for Gridlines
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			Gridlines::Bitmap => "bitmap",
+			Gridlines::Fine => "fine",
+			Gridlines::Strong => "strong"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Map the specified subsample of the specified pixel onto `[-1,1]`.
+#[inline]
+fn to_unit(pixel: u32, subsample: u32) -> f32
+{
+	let offset = (subsample as f32 + 0.5) / SUPERSAMPLES as f32;
+	(pixel as f32 + offset) / TEXTURE_SIZE as f32 * 2.0 - 1.0
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The width and height of a [cell&#32;texture](CellShape::texture), in
+/// pixels.
+const TEXTURE_SIZE: u32 = 64;
+
+/// The number of subsamples along each axis of each pixel of a
+/// [cell&#32;texture](CellShape::texture).
+const SUPERSAMPLES: u32 = 4;
+
+/// The width of the gaps between adjacent cells, for [fine](Gridlines::Fine)
+/// gridlines, in pixels.
+const FINE_GAP: f32 = 1.0;
+
+/// The width of the gaps between adjacent cells, for
+/// [strong](Gridlines::Strong) gridlines, in pixels.
+const STRONG_GAP: f32 = 3.0;
+
+/// The width of the border around each cell, unless the
+/// [gridlines](Gridlines) form a [bitmap](Gridlines::Bitmap), in pixels.
+const CELL_BORDER: f32 = 2.0;
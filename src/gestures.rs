@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use bevy::input::touch::Touches;
+use bevy::prelude::{
+	App, DetectChanges, DetectChangesMut, EventWriter, GlobalTransform,
+	IntoSystemConfigs, Local, Node, Plugin, Query, Res, ResMut, Resource, Style,
+	Time, Update, Val, Vec2, With
+};
+
+use crate::ecs::{
+	Action, apply_actions, CellPosition, EvolutionTimer, Pane, PaneView,
+	ScrollOffset
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for touch gestures, so that the evolver is
+/// usable from a phone:
+///
+/// * Pinch with two fingers to [zoom](Zoom) the grid, and drag them to pan it.
+/// * Long-press a cell of the newest generation _while paused_ to toggle it.
+///   A mere tap does not toggle the cell, so that pinching and panning never
+///   disturb the first generation by accident.
+pub struct GesturePlugin;
+
+impl Plugin for GesturePlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Zoom>()
+			.add_systems(Update, maybe_pinch)
+			.add_systems(Update, update_zoom.after(maybe_pinch))
+			.add_systems(Update, maybe_long_press.before(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The magnification of the grid, along with the offset of the grid from its
+/// natural position, in logical pixels. At the natural magnification, the grid
+/// rests at its natural position.
+#[derive(Copy, Clone, Debug, PartialEq, Resource)]
+struct Zoom
+{
+	/// The magnification, between [MIN_ZOOM] and [MAX_ZOOM], inclusive.
+	scale: f32,
+
+	/// The offset from the natural position.
+	offset: Vec2
+}
+
+impl Default for Zoom
+{
+	fn default() -> Self
+	{
+		Self { scale: MIN_ZOOM, offset: Vec2::ZERO }
+	}
+}
+
+/// A long press in progress.
+#[derive(Copy, Clone, Debug)]
+struct LongPress
+{
+	/// The identifier of the pressing finger.
+	id: u64,
+
+	/// How long the finger has been held down.
+	held: Duration,
+
+	/// Whether the press has already been spent, either because it toggled a
+	/// cell or because the finger strayed too far to count as a press.
+	spent: bool
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// While exactly two fingers touch the screen, [zoom](Zoom) the grid as they
+/// spread or pinch, and pan it as they move together, keeping the point
+/// between them fixed beneath them. Zooming all the way out restores the grid
+/// to its natural position.
+fn maybe_pinch(
+	touches: Res<Touches>,
+	mut zoom: ResMut<Zoom>,
+	mut last: Local<Option<(f32, Vec2)>>
+) {
+	let mut fingers = touches.iter();
+	let (Some(first), Some(second), None) =
+		(fingers.next(), fingers.next(), fingers.next())
+	else
+	{
+		*last = None;
+		return
+	};
+	let distance = first.position().distance(second.position());
+	let focus = (first.position() + second.position()) / 2.0;
+	if let Some((last_distance, last_focus)) = *last
+	{
+		if last_distance > 0.0
+		{
+			let scale = (zoom.scale * distance / last_distance)
+				.clamp(MIN_ZOOM, MAX_ZOOM);
+			let offset = match scale == MIN_ZOOM
+			{
+				true => Vec2::ZERO,
+				false =>
+					focus - (last_focus - zoom.offset) * (scale / zoom.scale)
+			};
+			zoom.set_if_neq(Zoom { scale, offset });
+		}
+	}
+	*last = Some((distance, focus));
+}
+
+/// Whenever the [zoom](Zoom) changes, resize and reposition every
+/// [pane&#32;view](PaneView) accordingly.
+fn update_zoom(
+	zoom: Res<Zoom>,
+	mut views: Query<&mut Style, With<PaneView>>
+) {
+	if !zoom.is_changed()
+	{
+		return
+	}
+	let width = 100.0 / views.iter().count().max(1) as f32;
+	for mut style in &mut views
+	{
+		style.width = Val::Percent(width * zoom.scale);
+		style.height = Val::Percent(100.0 * zoom.scale);
+		style.left = Val::Px(zoom.offset.x);
+		style.top = Val::Px(zoom.offset.y);
+	}
+}
+
+/// Once a lone finger has rested on a cell of the newest generation for
+/// [LONG_PRESS], toggle the cell, but only _while paused_ and while the view
+/// isn't [scrolled](ScrollOffset) back. A finger that strays farther than
+/// [LONG_PRESS_SLOP] from where it landed is panning, not pressing.
+#[allow(clippy::too_many_arguments)]
+fn maybe_long_press(
+	time: Res<Time>,
+	touches: Res<Touches>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	panes: Query<&Pane>,
+	cells: Query<(&CellPosition, &Node, &GlobalTransform)>,
+	mut actions: EventWriter<Action>,
+	mut press: Local<Option<LongPress>>
+) {
+	let mut fingers = touches.iter();
+	let (Some(touch), None) = (fingers.next(), fingers.next()) else
+	{
+		*press = None;
+		return
+	};
+	let current = match *press
+	{
+		Some(press) if press.id == touch.id() => LongPress {
+			held: press.held + time.delta(),
+			..press
+		},
+		_ => LongPress { id: touch.id(), held: Duration::ZERO, spent: false }
+	};
+	let strayed =
+		touch.position().distance(touch.start_position()) > LONG_PRESS_SLOP;
+	let ripe = !current.spent && !strayed && current.held >= LONG_PRESS;
+	*press = Some(LongPress {
+		spent: current.spent || strayed || ripe,
+		..current
+	});
+	if !ripe || timer.is_running() || scroll.0 != 0
+	{
+		return
+	}
+	let pressed = cells.iter()
+		.filter(|(position, _, _)| position.is_active_automaton())
+		.find(|(_, node, transform)| {
+			node.logical_rect(transform).contains(touch.position())
+		});
+	if let Some((position, _, _)) = pressed
+	{
+		if let Ok(pane) = panes.get(position.pane)
+		{
+			actions.send(Action::ToggleCell {
+				pane: pane.0,
+				column: position.column
+			});
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The natural magnification of the grid, which is also the least.
+const MIN_ZOOM: f32 = 1.0;
+
+/// The greatest magnification of the grid.
+const MAX_ZOOM: f32 = 8.0;
+
+/// How long a finger must rest on a cell to toggle it.
+const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// How far, in logical pixels, a finger may stray from where it landed and
+/// still count as pressing.
+const LONG_PRESS_SLOP: f32 = 12.0;
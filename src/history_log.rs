@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, Entity, IntoSystemConfigs, Plugin, Query, ResMut, Resource, Update
+};
+
+use crate::automata::{Automaton, RuleKind, Scrollback};
+use crate::ecs::{apply_actions, evolve, Pane};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the history log, an append-only binary
+/// file that receives every generation of every [pane](Pane) as it evolves, so
+/// that multi-hour runs can later be re-rendered, scrubbed, or analyzed without
+/// simulating them again. Generations that are edited, reseeded, or restored
+/// from a [checkpoint](crate::checkpoint) are logged again, and supersede any
+/// earlier record of the same pane and index. Insert a [HistoryLog] before
+/// adding the plugin. Available for native builds only.
+pub struct HistoryLogPlugin;
+
+impl Plugin for HistoryLogPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(
+			Update,
+			log_generations.after(apply_actions).after(evolve)
+		);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The destination of the history log. The log begins with the header
+/// [HISTORY_LOG_MAGIC], followed by fixed-length [records](HistoryLog::write),
+/// so that a reader can seek straight to any record. The file is flushed after
+/// every frame in which something was logged, so that the log survives an
+/// abrupt exit.
+#[derive(Resource)]
+pub struct HistoryLog
+{
+	/// The log.
+	out: BufWriter<File>,
+
+	/// The index and the cells of the generation most recently logged for
+	/// each pane, given as an entity.
+	logged: HashMap<Entity, (usize, Automaton)>
+}
+
+impl HistoryLog
+{
+	/// Open the history log at the specified path for appending, creating it
+	/// if necessary. An existing file must already be a history log, so that
+	/// several runs can accumulate in the same log.
+	pub fn open(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let mut file = OpenOptions::new()
+			.read(true)
+			.append(true)
+			.create(true)
+			.open(path)?;
+		if file.metadata()?.len() == 0
+		{
+			file.write_all(HISTORY_LOG_MAGIC)?;
+		}
+		else
+		{
+			let mut magic = [0; HISTORY_LOG_MAGIC.len()];
+			file.read_exact(&mut magic)?;
+			if magic != *HISTORY_LOG_MAGIC
+			{
+				return Err(io::Error::new(
+					io::ErrorKind::InvalidData,
+					"not a history log"
+				))
+			}
+		}
+		Ok(Self { out: BufWriter::new(file), logged: HashMap::new() })
+	}
+
+	/// Append a record of the specified generation to the log, logging any
+	/// failure. Each record occupies [HISTORY_LOG_RECORD_LENGTH] bytes, all
+	/// integers big-endian:
+	///
+	/// * The ordinal of the [pane](Pane), in four bytes.
+	/// * The index of the generation, counting from the first generation, in
+	///   eight bytes.
+	/// * The Wolfram code of the rule that produced the generation, in two
+	///   bytes, or `0xFFFF` for the first generation and for rules that have no
+	///   Wolfram code.
+	/// * The cells, in eight bytes, the leftmost cell in the most significant
+	///   bit, where `1` is live and `0` is dead.
+	fn write(
+		&mut self,
+		pane: usize,
+		index: usize,
+		rule: Option<&RuleKind>,
+		automaton: &Automaton
+	) {
+		let rule = match rule
+		{
+			Some(RuleKind::Wolfram(rule)) => u8::from(*rule) as u16,
+			#[allow(unreachable_patterns)]
+			_ => NO_RULE
+		};
+		let mut record = [0; HISTORY_LOG_RECORD_LENGTH];
+		record[0 .. 4].copy_from_slice(&(pane as u32).to_be_bytes());
+		record[4 .. 12].copy_from_slice(&(index as u64).to_be_bytes());
+		record[12 .. 14].copy_from_slice(&rule.to_be_bytes());
+		record[14 .. 22].copy_from_slice(&u64::from(*automaton).to_be_bytes());
+		if let Err(e) = self.out.write_all(&record)
+		{
+			warn!("Cannot log generation {} of pane {}: {}", index, pane, e);
+		}
+	}
+
+	/// Flush the log, logging any failure.
+	fn flush(&mut self)
+	{
+		if let Err(e) = self.out.flush()
+		{
+			warn!("Cannot flush history log: {}", e);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Append to the [history&#32;log](HistoryLog) every generation of every
+/// [pane](Pane) since the last frame, reading them from the
+/// [scrollback](Scrollback), so that generations skipped by a fast-forward are
+/// logged too. If the newest generation was edited instead, or the evolution
+/// started over or was rewound, then log the newest generation alone.
+/// Difference panes have no scrollback, and thus are not logged.
+fn log_generations(
+	log: Option<ResMut<HistoryLog>>,
+	panes: Query<(Entity, &Pane, &Scrollback)>
+) {
+	let Some(mut log) = log else { return };
+	let mut dirty = false;
+	for (entity, pane, scrollback) in &panes
+	{
+		let (index, &newest, _) = scrollback.generation(0).unwrap();
+		let backlog = match log.logged.get(&entity)
+		{
+			Some(&(logged, automaton))
+				if logged == index && automaton == newest => continue,
+			// Generations forgotten by a capped scrollback are lost.
+			Some(&(logged, _)) if logged < index =>
+				(index - logged).min(scrollback.len()),
+			_ => 1
+		};
+		for back in (0 .. backlog).rev()
+		{
+			let (index, automaton, rule) = scrollback.generation(back).unwrap();
+			log.write(pane.0, index, rule, automaton);
+		}
+		log.logged.insert(entity, (index, newest));
+		dirty = true;
+	}
+	if dirty
+	{
+		log.flush();
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The header of every history log: the letters `CAHL`, then the version of
+/// the format.
+const HISTORY_LOG_MAGIC: &[u8; 5] = b"CAHL\x01";
+
+/// The length of a record of the [history&#32;log](HistoryLog), in bytes.
+const HISTORY_LOG_RECORD_LENGTH: usize = 22;
+
+/// The rule recorded for the first generation, and for rules that have no
+/// Wolfram code.
+const NO_RULE: u16 = 0xFFFF;
@@ -0,0 +1,262 @@
+use std::io::Read;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventWriter, IntoSystemConfigs, Plugin, Query, Res, ResMut, Resource,
+	Update, With
+};
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::automata::{RuleSchedule, Scrollback};
+use crate::control::Command;
+use crate::ecs::{
+	Action, apply_actions, EvolutionTimer, Focused, Generation, Pane
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for remote control over HTTP, so that a
+/// phone or an automation script can drive the simulation, e.g., during an
+/// installation. The endpoints are:
+///
+/// * `GET /` answers a small page of controls, for use from a phone.
+/// * `GET /state` answers the state of the simulation, as JSON.
+/// * `POST /rule` replaces the schedule of the focused pane with the rule in
+///   the body.
+/// * `POST /pause` pauses the evolution, and `POST /resume` resumes it.
+/// * `POST /seed` reseeds the focused pane with the seed in the body.
+/// * `POST /speed` evolves as many generations per second as the body says.
+///
+/// The bodies are just as the [commands](Command) of the
+/// [external&#32;control](crate::control) take them. Commands are carried
+/// out as [actions](Action) at the start of the next frame. Insert an
+/// [HttpControl] before adding the plugin. Available only with the `http`
+/// feature.
+pub struct HttpControlPlugin;
+
+impl Plugin for HttpControlPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(Update, serve_requests.before(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The HTTP requests received, each with its body, awaiting the next frame. A
+/// background thread accepts requests and reads their bodies, so that a slow
+/// client never stalls the frame.
+#[derive(Resource)]
+pub struct HttpControl(Mutex<Receiver<(Request, String)>>);
+
+impl HttpControl
+{
+	/// Listen for HTTP requests at the specified address, e.g.,
+	/// `0.0.0.0:8080`.
+	pub fn bind(address: &str) -> Result<Self, String>
+	{
+		let server = Server::http(address).map_err(|e| e.to_string())?;
+		let (sender, receiver) = channel();
+		thread::spawn(move || {
+			for mut request in server.incoming_requests()
+			{
+				let mut body = String::new();
+				let read = request.as_reader()
+					.take(MAX_BODY_LENGTH)
+					.read_to_string(&mut body);
+				if let Err(e) = read
+				{
+					respond(request, Response::from_string(e.to_string())
+						.with_status_code(400));
+					continue
+				}
+				if sender.send((request, body)).is_err()
+				{
+					break
+				}
+			}
+		});
+		Ok(Self(Mutex::new(receiver)))
+	}
+
+	/// Answer every request received since the last call.
+	fn drain(&self) -> Vec<(Request, String)>
+	{
+		self.0.lock().unwrap().try_iter().collect()
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer every request received by the [server](HttpControl). Carry out the
+/// [commands](Command) upon the [focused](Focused)&#32;[pane](Pane), just as
+/// [external&#32;control](crate::control) does, answering `204 No Content`
+/// once accepted, or `400 Bad Request` if malformed.
+fn serve_requests(
+	control: Res<HttpControl>,
+	generation: Res<Generation>,
+	focused: Query<&Pane, With<Focused>>,
+	panes: Query<(&Pane, &Scrollback, &RuleSchedule)>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut actions: EventWriter<Action>
+) {
+	let requests = control.drain();
+	if requests.is_empty()
+	{
+		return
+	}
+	let focused = focused.get_single().ok().copied();
+	let mut running = timer.is_running();
+	for (request, body) in requests
+	{
+		let path = request.url().split('?').next().unwrap_or_default();
+		let response = match (request.method(), path)
+		{
+			(Method::Get, "/") => Response::from_string(PAGE)
+				.with_header(content_type("text/html; charset=utf-8")),
+			(Method::Get, "/state") =>
+			{
+				let mut panes = panes.iter().collect::<Vec<_>>();
+				panes.sort_by_key(|(pane, ..)| **pane);
+				let panes = panes.into_iter()
+					.map(|(pane, scrollback, schedule)| {
+						let (index, automaton, _) =
+							scrollback.generation(0).unwrap();
+						json!({
+							"pane": pane.0,
+							"rules": schedule.iter()
+								.map(|rule| rule.abbreviation())
+								.collect::<Vec<_>>(),
+							"active": schedule.active_index(),
+							"index": index,
+							"cells": automaton.to_hex()
+						})
+					})
+					.collect::<Vec<_>>();
+				let state = json!({
+					"generation": generation.get(),
+					"running": running,
+					"heartbeat": timer.heartbeat().as_millis() as u64,
+					"focused": focused.map(|pane| pane.0),
+					"panes": panes
+				});
+				Response::from_string(state.to_string())
+					.with_header(content_type("application/json"))
+			},
+			(
+				Method::Post,
+				"/rule" | "/pause" | "/resume" | "/seed" | "/speed"
+			) =>
+			{
+				let line = format!("{} {}", &path[1..], body.trim());
+				match (line.parse::<Command>(), focused)
+				{
+					(Ok(command), Some(pane)) =>
+					{
+						command.carry_out(
+							pane.0,
+							&mut running,
+							&mut timer,
+							&mut actions
+						);
+						Response::from_string("").with_status_code(204)
+					},
+					(Ok(_), None) => Response::from_string("no focused pane")
+						.with_status_code(409),
+					(Err(e), _) => Response::from_string(e)
+						.with_status_code(400)
+				}
+			},
+			(
+				_,
+				"/" | "/state" | "/rule" | "/pause" | "/resume" | "/seed"
+					| "/speed"
+			) => Response::from_string("method not allowed")
+				.with_status_code(405),
+			_ => Response::from_string("not found").with_status_code(404)
+		};
+		respond(request, response);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Send the specified response to the specified request. A client that has
+/// gone away is no cause for concern, so merely log the failure.
+fn respond<R: Read>(request: Request, response: Response<R>)
+{
+	if let Err(e) = request.respond(response)
+	{
+		warn!("Cannot answer HTTP request: {}", e);
+	}
+}
+
+/// Answer a `Content-Type` header with the specified value.
+fn content_type(value: &str) -> Header
+{
+	Header::from_bytes("Content-Type", value).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The longest body, in bytes, that a request may have. Longer bodies are
+/// truncated.
+const MAX_BODY_LENGTH: u64 = 4096;
+
+/// The page of controls answered for `GET /`.
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Cellular Automata</title>
+<style>
+body { font-family: sans-serif; margin: 1em; }
+button, input { font-size: 1.2em; margin: 0.2em; }
+pre { white-space: pre-wrap; }
+</style>
+</head>
+<body>
+<h1>Cellular Automata</h1>
+<p>
+<button onclick="send('pause')">Pause</button>
+<button onclick="send('resume')">Resume</button>
+</p>
+<p><input id="rule" placeholder="110"> <button onclick="send('rule',
+	document.getElementById('rule').value)">Rule</button></p>
+<p><input id="seed" placeholder="0xdeadbeef"> <button onclick="send('seed',
+	document.getElementById('seed').value)">Seed</button></p>
+<p><input id="speed" placeholder="20"> <button onclick="send('speed',
+	document.getElementById('speed').value)">Speed</button></p>
+<pre id="state"></pre>
+<script>
+async function send(command, body) {
+	const response = await fetch("/" + command, { method: "POST", body });
+	if (!response.ok) alert(await response.text());
+}
+async function refresh() {
+	const state = await (await fetch("/state")).json();
+	document.getElementById("state").textContent =
+		JSON.stringify(state, null, 2);
+}
+setInterval(refresh, 1000);
+refresh();
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,1228 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use bevy::prelude::Resource;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Languages.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The language of the user interface: the instructions, the banners, the
+/// announcements, and the settings panel. Key names, rule names, and numbers
+/// are not translated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Resource)]
+pub enum Language
+{
+	/// English, the default.
+	#[default]
+	English,
+
+	/// Spanish.
+	Spanish,
+
+	/// French.
+	French,
+
+	/// German.
+	German
+}
+
+impl Language
+{
+	/// Every supported language, in the order of their codes.
+	pub const ALL: [Language; 4] = [
+		Language::German,
+		Language::English,
+		Language::Spanish,
+		Language::French
+	];
+
+	/// Answer the ISO 639-1 code of the language, e.g., `"en"`.
+	pub const fn code(self) -> &'static str
+	{
+		match self
+		{
+			Language::English => "en",
+			Language::Spanish => "es",
+			Language::French => "fr",
+			Language::German => "de"
+		}
+	}
+
+	/// Answer the text of the specified [message](Message) in the language.
+	/// The text may contain `{}` placeholders, which [format](Self::format)
+	/// fills in.
+	pub fn text(self, message: Message) -> &'static str
+	{
+		match self
+		{
+			Language::English => english(message),
+			Language::Spanish => spanish(message),
+			Language::French => french(message),
+			Language::German => german(message)
+		}
+	}
+
+	/// Answer the text of the specified [message](Message) in the language,
+	/// replacing its placeholders, in order, with the specified arguments.
+	/// Surplus placeholders are left alone, and surplus arguments are ignored.
+	pub fn format(self, message: Message, args: &[&dyn Display]) -> String
+	{
+		let mut args = args.iter();
+		let mut pieces = self.text(message).split("{}");
+		let mut text = pieces.next().unwrap_or_default().to_string();
+		for piece in pieces
+		{
+			match args.next()
+			{
+				Some(arg) => text.push_str(&arg.to_string()),
+				None => text.push_str("{}")
+			}
+			text.push_str(piece);
+		}
+		text
+	}
+}
+
+impl FromStr for Language
+{
+	type Err = String;
+
+	/// Parse a language from its ISO 639-1 code, ignoring case and any region
+	/// subtag, so that `"es"`, `"ES"`, and `"es-MX"` all denote Spanish.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let code = s.trim().split(['-', '_']).next().unwrap_or_default();
+		Language::ALL.into_iter()
+			.find(|language| language.code().eq_ignore_ascii_case(code))
+			.ok_or_else(|| {
+				let codes = Language::ALL.map(Language::code).join(", ");
+				format!("unsupported language '{}' (try {})", s, codes)
+			})
+	}
+}
+
+impl Display for Language
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.code())
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Messages.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The keys of the translatable messages of the user interface. Each
+/// [language](Language) maps every key to its own text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Message
+{
+	/// Instruction: the pause and FPS keys.
+	ResumePause,
+
+	/// Instruction: the schedule append key.
+	AppendRule,
+
+	/// Instruction: the schedule drop key.
+	DropRule,
+
+	/// Instruction: the mutate, surprise, and walk back keys.
+	MutateRule,
+
+	/// Instruction: the favorite keys.
+	Favorites,
+
+	/// Instruction: the bookmark keys.
+	Bookmarks,
+
+	/// Instruction: the symmetry keys.
+	TransformRule,
+
+	/// Instruction: the focus and mute keys.
+	FocusMute,
+
+	/// Instruction: the scroll and export keys.
+	ScrollExport,
+
+	/// Instruction: the gridlines and vsync keys.
+	GridlinesVsync,
+
+	/// Instruction: the palette and effects keys.
+	ChangePalette,
+
+	/// Instruction: the cell cursor keys.
+	CellCursor,
+
+	/// Instruction: the seed rotation, inversion, and symmetrization keys.
+	TransformSeed,
+
+	/// Instruction: the longest transient key.
+	LongestTransient,
+
+	/// Instruction: the fast-forward key; the number of generations follows.
+	FastForward,
+
+	/// Instruction: the turbo key.
+	Turbo,
+
+	/// Instruction: the slow-motion key.
+	SlowMotion,
+
+	/// Instruction: the 3D view and automatic rotation keys.
+	ToggleExtrusion,
+
+	/// Instruction: the growing tape key.
+	ToggleTape,
+
+	/// Instruction: the neon key.
+	ToggleNeon,
+
+	/// Instruction: the particle detection key.
+	ToggleParticles,
+
+	/// Instruction: clicking an older cell shows its light cone.
+	LightCone,
+
+	/// Instruction: the ancestry tracing key.
+	ToggleAncestry,
+
+	/// Instruction: the minimap key.
+	ToggleMinimap,
+
+	/// Instruction: the density histogram key.
+	ToggleDensity,
+
+	/// Instruction: the statistics panel key.
+	ToggleStatistics,
+
+	/// Instruction: the analysis view key.
+	ToggleAnalysis,
+
+	/// Instruction: the background filter key.
+	ToggleBackground,
+
+	/// Instruction: the change highlighting key.
+	ToggleChanges,
+
+	/// Instruction: the gallery key.
+	ToggleGallery,
+
+	/// Instruction: the rule painting key.
+	PaintRule,
+
+	/// Instruction: the share card key.
+	ShareCard,
+
+	/// Instruction: the poster key.
+	Poster,
+
+	/// Instruction: the checkpoint key.
+	Checkpoint,
+
+	/// Banner: the reason for an automatic pause follows.
+	Paused,
+
+	/// Banner: the absolute generation number follows.
+	Generation,
+
+	/// Banner: the rule being typed follows.
+	NextUp,
+
+	/// Banner: the breadcrumb trail of abandoned rules follows.
+	WalkBack,
+
+	/// Banner: the rule being typed is invalid.
+	Error,
+
+	/// Banner: the active rule follows, then its mirror, complement, and
+	/// mirrored complement, separated by slashes.
+	Equivalents,
+
+	/// Banner: the representative of the equivalence class of the active rule
+	/// follows.
+	RuleClass,
+
+	/// Tooltip: the column, generation, and state of a cell follow.
+	TooltipCell,
+
+	/// Tooltip: the state of a live cell.
+	TooltipLive,
+
+	/// Tooltip: the state of a dead cell.
+	TooltipDead,
+
+	/// Tooltip: the neighborhood that produced a cell, its fate, and the rule
+	/// follow.
+	TooltipCase,
+
+	/// Steady state: every cell died.
+	Extinct,
+
+	/// Steady state: the generation stopped changing.
+	FixedPoint,
+
+	/// Announcement: the rule was mirrored.
+	Mirror,
+
+	/// Announcement: the rule was complemented.
+	Complement,
+
+	/// Announcement: the rule was mirrored and complemented.
+	MirroredComplement,
+
+	/// Announcement: a surprising rule replaced the active rule.
+	Surprise,
+
+	/// Announcement: the newest generation was rotated to the left; its
+	/// population follows.
+	RotatedLeft,
+
+	/// Announcement: the newest generation was rotated to the right; its
+	/// population follows.
+	RotatedRight,
+
+	/// Announcement: the newest generation was inverted; its population
+	/// follows.
+	Inverted,
+
+	/// Announcement: the newest generation was made mirror-symmetric; its
+	/// population follows.
+	Symmetrized,
+
+	/// Announcement: a seed with the longest transient was found; the
+	/// transient and the period of the cycle follow.
+	FoundTransient,
+
+	/// Announcement: no seed fell into a cycle; the limit follows.
+	NoTransient,
+
+	/// Announcement: the evolution fast-forwarded; the number of generations
+	/// follows.
+	FastForwarded,
+
+	/// Announcement: a rule was marked as a favorite; the rule follows.
+	FavoriteAdded,
+
+	/// Announcement: a rule was unmarked as a favorite; the rule follows.
+	FavoriteRemoved,
+
+	/// Announcement: the focused pane switched to a favorite rule; the
+	/// position of the favorite, the number of favorites, and the rule follow.
+	Favorite,
+
+	/// Announcement: there are no favorite rules to switch to.
+	NoFavorites,
+
+	/// Announcement: the focused pane was saved to a bookmark; the slot
+	/// follows.
+	BookmarkSaved,
+
+	/// Announcement: the focused pane was restored from a bookmark; the slot
+	/// follows.
+	BookmarkRestored,
+
+	/// Announcement: there is nothing to restore from a bookmark; the slot
+	/// follows.
+	NoBookmark,
+
+	/// Announcement: the focused pane jumped back to a checkpoint; the index of
+	/// its generation follows.
+	RestoredCheckpoint,
+
+	/// Announcement: the focused pane has no checkpoint to jump back to.
+	NoCheckpoint,
+
+	/// Announcement: the view scrolled back.
+	GenerationsBack,
+
+	/// Announcement: the gridlines changed.
+	Gridlines,
+
+	/// Announcement: vsync was turned on.
+	VsyncOn,
+
+	/// Announcement: vsync was turned off.
+	VsyncOff,
+
+	/// Announcement: the palette scheme changed.
+	Palette,
+
+	/// Announcement: the birth and death effects were turned on.
+	EffectsOn,
+
+	/// Announcement: the birth and death effects were turned off.
+	EffectsOff,
+
+	/// Announcement: neon was turned on.
+	NeonOn,
+
+	/// Announcement: neon was turned off.
+	NeonOff,
+
+	/// Announcement: the 3D view was shown.
+	ExtrusionOn,
+
+	/// Announcement: the 3D view was hidden.
+	ExtrusionOff,
+
+	/// Announcement: the automatic rotation of the 3D view was turned on.
+	AutoRotateOn,
+
+	/// Announcement: the automatic rotation of the 3D view was turned off.
+	AutoRotateOff,
+
+	/// Announcement: the growing tape was shown.
+	TapeOn,
+
+	/// Announcement: the growing tape was hidden.
+	TapeOff,
+
+	/// Announcement: particle detection was turned on.
+	ParticlesOn,
+
+	/// Announcement: particle detection was turned off.
+	ParticlesOff,
+
+	/// Announcement: ancestry tracing was turned on.
+	AncestryOn,
+
+	/// Announcement: ancestry tracing was turned off.
+	AncestryOff,
+
+	/// Announcement: the minimap was shown.
+	MinimapOn,
+
+	/// Announcement: the minimap was hidden.
+	MinimapOff,
+
+	/// Announcement: the density histogram was shown.
+	DensityOn,
+
+	/// Announcement: the density histogram was hidden.
+	DensityOff,
+
+	/// Announcement: the statistics panel was shown.
+	StatisticsOn,
+
+	/// Announcement: the statistics panel was hidden.
+	StatisticsOff,
+
+	/// Statistics: the population of the newest generation follows.
+	Population,
+
+	/// Statistics: the complexity of the newest generations follows.
+	Complexity,
+
+	/// Announcement: the analysis view was shown.
+	AnalysisOn,
+
+	/// Announcement: the analysis view was hidden.
+	AnalysisOff,
+
+	/// Analysis: the characteristic wavelength, in cells, follows.
+	Wavelength,
+
+	/// Analysis: there is no characteristic wavelength.
+	NoWavelength,
+
+	/// Announcement: the background filter was activated.
+	BackgroundOn,
+
+	/// Announcement: the background filter was deactivated.
+	BackgroundOff,
+
+	/// Background: the spatial period, in cells, and the temporal period, in
+	/// generations, follow.
+	BackgroundPeriods,
+
+	/// Background: the spatial period, in cells, follows.
+	BackgroundPeriod,
+
+	/// Background: no periodic background was detected.
+	NoBackground,
+
+	/// Announcement: change highlighting was activated.
+	ChangesOn,
+
+	/// Announcement: change highlighting was deactivated.
+	ChangesOff,
+
+	/// Announcement: the gallery was shown.
+	GalleryOn,
+
+	/// Announcement: the gallery was hidden.
+	GalleryOff,
+
+	/// Announcement: rule painting was turned on.
+	PaintingOn,
+
+	/// Announcement: rule painting was turned off.
+	PaintingOff,
+
+	/// Announcement: the rule map of a pane changed; the map follows.
+	RuleMap,
+
+	/// Announcement: the rule map of a pane became empty.
+	RuleMapEmpty,
+
+	/// Generation label: the timeline switches rules soon; the incoming rule
+	/// and the number of generations until the switch follow.
+	NextSwitch,
+
+	/// Announcement: a guest joined the collaborative session; the address of
+	/// the guest follows.
+	GuestJoined,
+
+	/// Announcement: a guest left the collaborative session; the address of
+	/// the guest follows.
+	GuestLeft,
+
+	/// Announcement: the host left the collaborative session.
+	HostLeft,
+
+	/// Announcement: the host denied an edit, because the evolution is
+	/// running.
+	EditDenied,
+
+	/// Announcement: a bookmark was deleted from the gallery; the slot
+	/// follows.
+	BookmarkDeleted,
+
+	/// Gallery: heading.
+	Gallery,
+
+	/// Gallery: there are no bookmarks to show.
+	GalleryEmpty,
+
+	/// Gallery: the delete button.
+	Delete,
+
+	/// Gallery: the export button.
+	Export,
+
+	/// Announcement: the export succeeded.
+	Exported,
+
+	/// Announcement: the export failed.
+	CannotExport,
+
+	/// Announcement: the export is impossible in the browser.
+	ExportUnavailable,
+
+	/// Announcement: the share card was exported to the path that follows.
+	SharedCard,
+
+	/// Announcement: a poster of the size that follows is rendering.
+	RenderingPoster,
+
+	/// Announcement: the poster was rendered to the path that follows.
+	RenderedPoster,
+
+	/// Settings panel: heading.
+	Settings,
+
+	/// Settings panel: the rule.
+	Rule,
+
+	/// Settings panel: the heartbeat.
+	Heartbeat,
+
+	/// Settings panel: the palette scheme.
+	Scheme,
+
+	/// Settings panel: the musical scale.
+	Scale,
+
+	/// Settings panel: the volume.
+	Volume,
+
+	/// Settings panel: the mute checkbox.
+	Mute
+}
+
+/// Answer the English text of the specified [message](Message).
+fn english(message: Message) -> &'static str
+{
+	match message
+	{
+		Message::ResumePause => "{} to resume/pause, {} to show FPS",
+		Message::AppendRule =>
+			"Type a new rule, or type a rule then {} to append it to the \
+				schedule",
+		Message::DropRule => "{} to drop the last scheduled rule",
+		Message::MutateRule =>
+			"{} to mutate the rule, {} for a surprise, {} to walk back",
+		Message::Favorites =>
+			"{} to mark a favorite rule, {}/{} to cycle through favorites",
+		Message::Bookmarks =>
+			"[ctrl]+[1-9] to bookmark the pane, hold [1-9] to restore it",
+		Message::TransformRule =>
+			"{} to mirror the rule, {} to complement it, {} for both",
+		Message::FocusMute => "{} to focus the next pane, {} to mute/unmute",
+		Message::ScrollExport =>
+			"[wheel] or {}/{} to scroll back, {} to export as CSV",
+		Message::GridlinesVsync =>
+			"{} to change the gridlines, {} to toggle vsync",
+		Message::ChangePalette =>
+			"{} to switch to the next palette, {} to toggle effects",
+		Message::CellCursor =>
+			"While paused, {}/{} to move the cursor, {} to toggle its cell",
+		Message::TransformSeed =>
+			"{}/{} to rotate the newest generation, {} to invert it, {} to \
+				make it symmetric",
+		Message::LongestTransient =>
+			"{} for the seed with the longest transient",
+		Message::FastForward => "{} to fast-forward {} generations",
+		Message::Turbo => "Hold {} to race ahead",
+		Message::SlowMotion => "Hold {} for slow motion",
+		Message::ToggleExtrusion =>
+			"{} for the 3D view: drag to orbit, [wheel] to zoom, {} to rotate",
+		Message::ToggleTape => "{} to toggle the growing tape",
+		Message::ToggleNeon => "{} to toggle neon",
+		Message::ToggleParticles => "{} to outline the particles of rule 110",
+		Message::LightCone => "Click an older cell to show its light cone",
+		Message::ToggleAncestry =>
+			"{} to trace a clicked cell's ancestry instead",
+		Message::ToggleMinimap => "{} to toggle the scrollback minimap",
+		Message::ToggleDensity => "{} to toggle the column density histogram",
+		Message::ToggleStatistics => "{} to toggle the statistics panel",
+		Message::ToggleAnalysis =>
+			"{} to toggle the spatial frequency analysis",
+		Message::ToggleBackground => "{} to dim the periodic background",
+		Message::ToggleChanges => "{} to highlight births and deaths",
+		Message::ToggleGallery => "{} to toggle the bookmark gallery",
+		Message::PaintRule =>
+			"{} to paint the active rule onto clicked columns",
+		Message::ShareCard => "{} to export a share card",
+		Message::Poster => "{} to render a high-resolution poster",
+		Message::Checkpoint => "{} to jump back to the previous checkpoint",
+		Message::Paused => "Paused: ",
+		Message::Generation => "Generation: ",
+		Message::NextUp => "Next up: ",
+		Message::WalkBack => "{} to walk back: ",
+		Message::Error => "Error",
+		Message::Equivalents => "Rule {} ≡ {}",
+		Message::RuleClass => "Class of rule {}",
+		Message::TooltipCell => "Column {}, generation {}: {}",
+		Message::TooltipLive => "live",
+		Message::TooltipDead => "dead",
+		Message::TooltipCase => "Neighborhood {} → {} under rule {}",
+		Message::Extinct => "the automaton died out",
+		Message::FixedPoint => "the automaton reached a fixed point",
+		Message::Mirror => "Mirror",
+		Message::Complement => "Complement",
+		Message::MirroredComplement => "Mirrored complement",
+		Message::Surprise => "Surprise",
+		Message::RotatedLeft => "Rotated left: {} live cells",
+		Message::RotatedRight => "Rotated right: {} live cells",
+		Message::Inverted => "Inverted: {} live cells",
+		Message::Symmetrized => "Symmetrized: {} live cells",
+		Message::FoundTransient =>
+			"Longest transient: {} generations, then a cycle of {}",
+		Message::NoTransient => "No cycle within {} generations",
+		Message::FastForwarded => "Fast-forwarded {} generations",
+		Message::FavoriteAdded => "Rule {} added to favorites",
+		Message::FavoriteRemoved => "Rule {} removed from favorites",
+		Message::Favorite => "Favorite {} of {}: rule {}",
+		Message::NoFavorites => "No favorite rules yet",
+		Message::BookmarkSaved => "Saved bookmark {}",
+		Message::BookmarkRestored => "Restored bookmark {}",
+		Message::NoBookmark => "Bookmark {} is empty",
+		Message::RestoredCheckpoint => "Jumped back to generation {}",
+		Message::NoCheckpoint => "No checkpoint to jump back to",
+		Message::GenerationsBack => "{} generations back",
+		Message::Gridlines => "Gridlines: {}",
+		Message::VsyncOn => "Vsync: on",
+		Message::VsyncOff => "Vsync: off",
+		Message::Palette => "Palette: {}",
+		Message::EffectsOn => "Effects: on",
+		Message::EffectsOff => "Effects: off",
+		Message::NeonOn => "Neon: on",
+		Message::NeonOff => "Neon: off",
+		Message::ExtrusionOn => "3D view: on",
+		Message::ExtrusionOff => "3D view: off",
+		Message::AutoRotateOn => "Auto-rotate: on",
+		Message::AutoRotateOff => "Auto-rotate: off",
+		Message::TapeOn => "Growing tape: on",
+		Message::TapeOff => "Growing tape: off",
+		Message::ParticlesOn => "Particles: on",
+		Message::ParticlesOff => "Particles: off",
+		Message::AncestryOn => "Ancestry: on",
+		Message::AncestryOff => "Ancestry: off",
+		Message::MinimapOn => "Minimap: on",
+		Message::MinimapOff => "Minimap: off",
+		Message::DensityOn => "Density histogram: on",
+		Message::DensityOff => "Density histogram: off",
+		Message::StatisticsOn => "Statistics: on",
+		Message::StatisticsOff => "Statistics: off",
+		Message::Population => "Population: {}",
+		Message::Complexity => "Complexity: {}",
+		Message::AnalysisOn => "Analysis: on",
+		Message::AnalysisOff => "Analysis: off",
+		Message::Wavelength => "Wavelength: {} cells",
+		Message::NoWavelength => "Wavelength: none",
+		Message::BackgroundOn => "Background filter: on",
+		Message::BackgroundOff => "Background filter: off",
+		Message::BackgroundPeriods =>
+			"Background: {} cells, every {} generations",
+		Message::BackgroundPeriod => "Background: {} cells",
+		Message::NoBackground => "Background: none",
+		Message::ChangesOn => "Change highlighting: on",
+		Message::ChangesOff => "Change highlighting: off",
+		Message::GalleryOn => "Gallery: on",
+		Message::GalleryOff => "Gallery: off",
+		Message::PaintingOn => "Rule painting: on",
+		Message::PaintingOff => "Rule painting: off",
+		Message::RuleMap => "Rule map: {}",
+		Message::RuleMapEmpty => "Rule map: empty",
+		Message::NextSwitch => "  →  rule {} in {}",
+		Message::GuestJoined => "Guest {} joined",
+		Message::GuestLeft => "Guest {} left",
+		Message::HostLeft => "The host left; continuing alone",
+		Message::EditDenied => "Pause the evolution before editing",
+		Message::BookmarkDeleted => "Deleted bookmark {}",
+		Message::Gallery => "Bookmarks",
+		Message::GalleryEmpty => "No bookmarks yet",
+		Message::Delete => "Delete",
+		Message::Export => "Export",
+		Message::Exported => "Exported {} generations to {}",
+		Message::CannotExport => "Cannot export {}: {}",
+		Message::ExportUnavailable => "Export is unavailable in the browser",
+		Message::SharedCard => "Exported a share card to {}",
+		Message::RenderingPoster => "Rendering a {} poster…",
+		Message::RenderedPoster => "Rendered a poster to {}",
+		Message::Settings => "Settings",
+		Message::Rule => "Rule",
+		Message::Heartbeat => "Heartbeat",
+		Message::Scheme => "Palette",
+		Message::Scale => "Scale",
+		Message::Volume => "Volume",
+		Message::Mute => "Mute"
+	}
+}
+
+/// Answer the Spanish text of the specified [message](Message).
+fn spanish(message: Message) -> &'static str
+{
+	match message
+	{
+		Message::ResumePause =>
+			"{} para reanudar/pausar, {} para mostrar los FPS",
+		Message::AppendRule =>
+			"Escribe una regla nueva, o escribe una regla y luego {} para \
+				añadirla al programa",
+		Message::DropRule => "{} para quitar la última regla programada",
+		Message::MutateRule =>
+			"{} para mutar la regla, {} para una sorpresa, {} para retroceder",
+		Message::Favorites =>
+			"{} para marcar una regla favorita, {}/{} para recorrer las \
+				favoritas",
+		Message::Bookmarks =>
+			"[ctrl]+[1-9] para guardar el panel en un marcador, mantén [1-9] \
+				para restaurarlo",
+		Message::TransformRule =>
+			"{} para reflejar la regla, {} para complementarla, {} para ambas",
+		Message::FocusMute =>
+			"{} para enfocar el siguiente panel, {} para silenciar/activar el \
+				sonido",
+		Message::ScrollExport =>
+			"[rueda] o {}/{} para retroceder, {} para exportar como CSV",
+		Message::GridlinesVsync =>
+			"{} para cambiar la cuadrícula, {} para activar/desactivar vsync",
+		Message::ChangePalette =>
+			"{} para cambiar a la siguiente paleta, {} para activar/desactivar \
+				los efectos",
+		Message::CellCursor =>
+			"En pausa, {}/{} para mover el cursor, {} para alternar su celda",
+		Message::TransformSeed =>
+			"{}/{} para rotar la generación más reciente, {} para invertirla, \
+				{} para hacerla simétrica",
+		Message::LongestTransient =>
+			"{} para la semilla con el transitorio más largo",
+		Message::FastForward => "{} para avanzar {} generaciones",
+		Message::Turbo => "Mantén {} para acelerar",
+		Message::SlowMotion => "Mantén {} para cámara lenta",
+		Message::ToggleExtrusion =>
+			"{} para la vista 3D: arrastra para orbitar, [rueda] para acercar, \
+				{} para girar",
+		Message::ToggleTape => "{} para alternar la cinta creciente",
+		Message::ToggleNeon => "{} para alternar el neón",
+		Message::ToggleParticles =>
+			"{} para resaltar las partículas de la regla 110",
+		Message::LightCone =>
+			"Haz clic en una celda anterior para ver su cono de luz",
+		Message::ToggleAncestry =>
+			"{} para rastrear en su lugar la ascendencia de la celda",
+		Message::ToggleMinimap => "{} para alternar el minimapa del historial",
+		Message::ToggleDensity =>
+			"{} para alternar el histograma de densidad por columna",
+		Message::ToggleStatistics =>
+			"{} para alternar el panel de estadísticas",
+		Message::ToggleAnalysis =>
+			"{} para alternar el análisis de frecuencias espaciales",
+		Message::ToggleBackground => "{} para atenuar el fondo periódico",
+		Message::ToggleChanges => "{} para resaltar nacimientos y muertes",
+		Message::ToggleGallery => "{} para alternar la galería de marcadores",
+		Message::PaintRule =>
+			"{} para pintar la regla activa en las columnas pulsadas",
+		Message::ShareCard => "{} para exportar una tarjeta para compartir",
+		Message::Poster => "{} para renderizar un póster en alta resolución",
+		Message::Checkpoint => "{} para volver al punto de control anterior",
+		Message::Paused => "En pausa: ",
+		Message::Generation => "Generación: ",
+		Message::NextUp => "Siguiente: ",
+		Message::WalkBack => "{} para retroceder: ",
+		Message::Error => "Error",
+		Message::Equivalents => "Regla {} ≡ {}",
+		Message::RuleClass => "Clase de la regla {}",
+		Message::TooltipCell => "Columna {}, generación {}: {}",
+		Message::TooltipLive => "viva",
+		Message::TooltipDead => "muerta",
+		Message::TooltipCase => "Vecindario {} → {} según la regla {}",
+		Message::Extinct => "el autómata se extinguió",
+		Message::FixedPoint => "el autómata alcanzó un punto fijo",
+		Message::Mirror => "Reflejo",
+		Message::Complement => "Complemento",
+		Message::MirroredComplement => "Complemento reflejado",
+		Message::Surprise => "Sorpresa",
+		Message::RotatedLeft => "Rotada a la izquierda: {} celdas vivas",
+		Message::RotatedRight => "Rotada a la derecha: {} celdas vivas",
+		Message::Inverted => "Invertida: {} celdas vivas",
+		Message::Symmetrized => "Simetrizada: {} celdas vivas",
+		Message::FoundTransient =>
+			"Transitorio más largo: {} generaciones, luego un ciclo de {}",
+		Message::NoTransient => "Ningún ciclo en {} generaciones",
+		Message::FastForwarded => "Avance de {} generaciones",
+		Message::FavoriteAdded => "Regla {} añadida a las favoritas",
+		Message::FavoriteRemoved => "Regla {} quitada de las favoritas",
+		Message::Favorite => "Favorita {} de {}: regla {}",
+		Message::NoFavorites => "Aún no hay reglas favoritas",
+		Message::BookmarkSaved => "Marcador {} guardado",
+		Message::BookmarkRestored => "Marcador {} restaurado",
+		Message::NoBookmark => "El marcador {} está vacío",
+		Message::RestoredCheckpoint => "De vuelta a la generación {}",
+		Message::NoCheckpoint => "No hay ningún punto de control",
+		Message::GenerationsBack => "{} generaciones atrás",
+		Message::Gridlines => "Cuadrícula: {}",
+		Message::VsyncOn => "Vsync: activado",
+		Message::VsyncOff => "Vsync: desactivado",
+		Message::Palette => "Paleta: {}",
+		Message::EffectsOn => "Efectos: activados",
+		Message::EffectsOff => "Efectos: desactivados",
+		Message::NeonOn => "Neón: activado",
+		Message::NeonOff => "Neón: desactivado",
+		Message::ExtrusionOn => "Vista 3D: activada",
+		Message::ExtrusionOff => "Vista 3D: desactivada",
+		Message::AutoRotateOn => "Rotación automática: activada",
+		Message::AutoRotateOff => "Rotación automática: desactivada",
+		Message::TapeOn => "Cinta creciente: activada",
+		Message::TapeOff => "Cinta creciente: desactivada",
+		Message::ParticlesOn => "Partículas: activadas",
+		Message::ParticlesOff => "Partículas: desactivadas",
+		Message::AncestryOn => "Ascendencia: activada",
+		Message::AncestryOff => "Ascendencia: desactivada",
+		Message::MinimapOn => "Minimapa: activado",
+		Message::MinimapOff => "Minimapa: desactivado",
+		Message::DensityOn => "Histograma de densidad: activado",
+		Message::DensityOff => "Histograma de densidad: desactivado",
+		Message::StatisticsOn => "Estadísticas: activadas",
+		Message::StatisticsOff => "Estadísticas: desactivadas",
+		Message::Population => "Población: {}",
+		Message::Complexity => "Complejidad: {}",
+		Message::AnalysisOn => "Análisis: activado",
+		Message::AnalysisOff => "Análisis: desactivado",
+		Message::Wavelength => "Longitud de onda: {} células",
+		Message::NoWavelength => "Longitud de onda: ninguna",
+		Message::BackgroundOn => "Filtro de fondo: activado",
+		Message::BackgroundOff => "Filtro de fondo: desactivado",
+		Message::BackgroundPeriods =>
+			"Fondo: {} células, cada {} generaciones",
+		Message::BackgroundPeriod => "Fondo: {} células",
+		Message::NoBackground => "Fondo: ninguno",
+		Message::ChangesOn => "Resaltado de cambios: activado",
+		Message::ChangesOff => "Resaltado de cambios: desactivado",
+		Message::GalleryOn => "Galería: activada",
+		Message::GalleryOff => "Galería: desactivada",
+		Message::PaintingOn => "Pintura de reglas: activada",
+		Message::PaintingOff => "Pintura de reglas: desactivada",
+		Message::RuleMap => "Mapa de reglas: {}",
+		Message::RuleMapEmpty => "Mapa de reglas: vacío",
+		Message::NextSwitch => "  →  regla {} en {}",
+		Message::GuestJoined => "Se unió el invitado {}",
+		Message::GuestLeft => "Se fue el invitado {}",
+		Message::HostLeft => "El anfitrión se fue; continuando a solas",
+		Message::EditDenied => "Pausa la evolución antes de editar",
+		Message::BookmarkDeleted => "Marcador {} eliminado",
+		Message::Gallery => "Marcadores",
+		Message::GalleryEmpty => "Aún no hay marcadores",
+		Message::Delete => "Eliminar",
+		Message::Export => "Exportar",
+		Message::Exported => "Se exportaron {} generaciones a {}",
+		Message::CannotExport => "No se puede exportar {}: {}",
+		Message::ExportUnavailable =>
+			"La exportación no está disponible en el navegador",
+		Message::SharedCard => "Se exportó una tarjeta para compartir a {}",
+		Message::RenderingPoster => "Renderizando un póster de {}…",
+		Message::RenderedPoster => "Se renderizó un póster en {}",
+		Message::Settings => "Ajustes",
+		Message::Rule => "Regla",
+		Message::Heartbeat => "Latido",
+		Message::Scheme => "Paleta",
+		Message::Scale => "Escala",
+		Message::Volume => "Volumen",
+		Message::Mute => "Silenciar"
+	}
+}
+
+/// Answer the French text of the specified [message](Message).
+fn french(message: Message) -> &'static str
+{
+	match message
+	{
+		Message::ResumePause =>
+			"{} pour reprendre/mettre en pause, {} pour afficher les FPS",
+		Message::AppendRule =>
+			"Tapez une nouvelle règle, ou tapez une règle puis {} pour \
+				l'ajouter au programme",
+		Message::DropRule => "{} pour retirer la dernière règle programmée",
+		Message::MutateRule =>
+			"{} pour muter la règle, {} pour une surprise, {} pour revenir en \
+				arrière",
+		Message::Favorites =>
+			"{} pour marquer une règle favorite, {}/{} pour parcourir les \
+				favorites",
+		Message::Bookmarks =>
+			"[ctrl]+[1-9] pour marquer le panneau, maintenez [1-9] pour le \
+				restaurer",
+		Message::TransformRule =>
+			"{} pour refléter la règle, {} pour la complémenter, {} pour les \
+				deux",
+		Message::FocusMute =>
+			"{} pour passer au panneau suivant, {} pour couper/rétablir le son",
+		Message::ScrollExport =>
+			"[molette] ou {}/{} pour remonter, {} pour exporter en CSV",
+		Message::GridlinesVsync =>
+			"{} pour changer le quadrillage, {} pour activer/désactiver la \
+				vsync",
+		Message::ChangePalette =>
+			"{} pour passer à la palette suivante, {} pour activer/désactiver \
+				les effets",
+		Message::CellCursor =>
+			"En pause, {}/{} pour déplacer le curseur, {} pour basculer sa \
+				cellule",
+		Message::TransformSeed =>
+			"{}/{} pour faire pivoter la dernière génération, \
+				{} pour l'inverser, {} pour la rendre symétrique",
+		Message::LongestTransient =>
+			"{} pour la graine au plus long régime transitoire",
+		Message::FastForward => "{} pour avancer de {} générations",
+		Message::Turbo => "Maintenir {} pour accélérer",
+		Message::SlowMotion => "Maintenir {} pour le ralenti",
+		Message::ToggleExtrusion =>
+			"{} pour la vue 3D : glisser pour orbiter, [molette] pour zoomer, \
+				{} pour tourner",
+		Message::ToggleTape => "{} pour basculer le ruban extensible",
+		Message::ToggleNeon => "{} pour basculer le néon",
+		Message::ToggleParticles =>
+			"{} pour repérer les particules de la règle 110",
+		Message::LightCone =>
+			"Cliquez sur une cellule antérieure pour voir son cône de lumière",
+		Message::ToggleAncestry =>
+			"{} pour retracer plutôt l'ascendance de la cellule",
+		Message::ToggleMinimap =>
+			"{} pour basculer la mini-carte de l'historique",
+		Message::ToggleDensity =>
+			"{} pour basculer l'histogramme de densité par colonne",
+		Message::ToggleStatistics =>
+			"{} pour basculer le panneau des statistiques",
+		Message::ToggleAnalysis =>
+			"{} pour basculer l'analyse des fréquences spatiales",
+		Message::ToggleBackground => "{} pour estomper le fond périodique",
+		Message::ToggleChanges =>
+			"{} pour surligner les naissances et les morts",
+		Message::ToggleGallery =>
+			"{} pour basculer la galerie des signets",
+		Message::PaintRule =>
+			"{} pour peindre la règle active sur les colonnes cliquées",
+		Message::ShareCard => "{} pour exporter une carte à partager",
+		Message::Poster => "{} pour rendre une affiche en haute résolution",
+		Message::Checkpoint => "{} pour revenir au point de contrôle précédent",
+		Message::Paused => "En pause : ",
+		Message::Generation => "Génération : ",
+		Message::NextUp => "Ensuite : ",
+		Message::WalkBack => "{} pour revenir : ",
+		Message::Error => "Erreur",
+		Message::Equivalents => "Règle {} ≡ {}",
+		Message::RuleClass => "Classe de la règle {}",
+		Message::TooltipCell => "Colonne {}, génération {} : {}",
+		Message::TooltipLive => "vivante",
+		Message::TooltipDead => "morte",
+		Message::TooltipCase => "Voisinage {} → {} selon la règle {}",
+		Message::Extinct => "l'automate s'est éteint",
+		Message::FixedPoint => "l'automate a atteint un point fixe",
+		Message::Mirror => "Miroir",
+		Message::Complement => "Complément",
+		Message::MirroredComplement => "Complément miroir",
+		Message::Surprise => "Surprise",
+		Message::RotatedLeft => "Pivotée à gauche : {} cellules vivantes",
+		Message::RotatedRight => "Pivotée à droite : {} cellules vivantes",
+		Message::Inverted => "Inversée : {} cellules vivantes",
+		Message::Symmetrized => "Symétrisée : {} cellules vivantes",
+		Message::FoundTransient =>
+			"Plus long transitoire : {} générations, puis un cycle de {}",
+		Message::NoTransient => "Aucun cycle en {} générations",
+		Message::FastForwarded => "Avance rapide de {} générations",
+		Message::FavoriteAdded => "Règle {} ajoutée aux favorites",
+		Message::FavoriteRemoved => "Règle {} retirée des favorites",
+		Message::Favorite => "Favorite {} sur {} : règle {}",
+		Message::NoFavorites => "Pas encore de règle favorite",
+		Message::BookmarkSaved => "Signet {} enregistré",
+		Message::BookmarkRestored => "Signet {} restauré",
+		Message::NoBookmark => "Le signet {} est vide",
+		Message::RestoredCheckpoint => "Retour à la génération {}",
+		Message::NoCheckpoint => "Aucun point de contrôle où revenir",
+		Message::GenerationsBack => "{} générations en arrière",
+		Message::Gridlines => "Quadrillage : {}",
+		Message::VsyncOn => "Vsync : activée",
+		Message::VsyncOff => "Vsync : désactivée",
+		Message::Palette => "Palette : {}",
+		Message::EffectsOn => "Effets : activés",
+		Message::EffectsOff => "Effets : désactivés",
+		Message::NeonOn => "Néon : activé",
+		Message::NeonOff => "Néon : désactivé",
+		Message::ExtrusionOn => "Vue 3D : activée",
+		Message::ExtrusionOff => "Vue 3D : désactivée",
+		Message::AutoRotateOn => "Rotation automatique : activée",
+		Message::AutoRotateOff => "Rotation automatique : désactivée",
+		Message::TapeOn => "Ruban extensible : activé",
+		Message::TapeOff => "Ruban extensible : désactivé",
+		Message::ParticlesOn => "Particules : activées",
+		Message::ParticlesOff => "Particules : désactivées",
+		Message::AncestryOn => "Ascendance : activée",
+		Message::AncestryOff => "Ascendance : désactivée",
+		Message::MinimapOn => "Mini-carte : activée",
+		Message::MinimapOff => "Mini-carte : désactivée",
+		Message::DensityOn => "Histogramme de densité : activé",
+		Message::DensityOff => "Histogramme de densité : désactivé",
+		Message::StatisticsOn => "Statistiques : activées",
+		Message::StatisticsOff => "Statistiques : désactivées",
+		Message::Population => "Population : {}",
+		Message::Complexity => "Complexité : {}",
+		Message::AnalysisOn => "Analyse : activée",
+		Message::AnalysisOff => "Analyse : désactivée",
+		Message::Wavelength => "Longueur d'onde : {} cellules",
+		Message::NoWavelength => "Longueur d'onde : aucune",
+		Message::BackgroundOn => "Filtre de fond : activé",
+		Message::BackgroundOff => "Filtre de fond : désactivé",
+		Message::BackgroundPeriods =>
+			"Fond : {} cellules, toutes les {} générations",
+		Message::BackgroundPeriod => "Fond : {} cellules",
+		Message::NoBackground => "Fond : aucun",
+		Message::ChangesOn => "Surlignage des changements : activé",
+		Message::ChangesOff => "Surlignage des changements : désactivé",
+		Message::GalleryOn => "Galerie : activée",
+		Message::GalleryOff => "Galerie : désactivée",
+		Message::PaintingOn => "Peinture de règles : activée",
+		Message::PaintingOff => "Peinture de règles : désactivée",
+		Message::RuleMap => "Carte des règles : {}",
+		Message::RuleMapEmpty => "Carte des règles : vide",
+		Message::NextSwitch => "  →  règle {} dans {}",
+		Message::GuestJoined => "L'invité {} a rejoint",
+		Message::GuestLeft => "L'invité {} est parti",
+		Message::HostLeft => "L'hôte est parti ; suite en solo",
+		Message::EditDenied => "Mettez l'évolution en pause avant de modifier",
+		Message::BookmarkDeleted => "Signet {} supprimé",
+		Message::Gallery => "Signets",
+		Message::GalleryEmpty => "Pas encore de signet",
+		Message::Delete => "Supprimer",
+		Message::Export => "Exporter",
+		Message::Exported => "{} générations exportées vers {}",
+		Message::CannotExport => "Impossible d'exporter {} : {}",
+		Message::ExportUnavailable =>
+			"L'exportation n'est pas disponible dans le navigateur",
+		Message::SharedCard => "Carte à partager exportée vers {}",
+		Message::RenderingPoster => "Rendu d'une affiche de {}…",
+		Message::RenderedPoster => "Affiche rendue vers {}",
+		Message::Settings => "Réglages",
+		Message::Rule => "Règle",
+		Message::Heartbeat => "Battement",
+		Message::Scheme => "Palette",
+		Message::Scale => "Gamme",
+		Message::Volume => "Volume",
+		Message::Mute => "Muet"
+	}
+}
+
+/// Answer the German text of the specified [message](Message).
+fn german(message: Message) -> &'static str
+{
+	match message
+	{
+		Message::ResumePause =>
+			"{} zum Fortsetzen/Pausieren, {} zum Anzeigen der FPS",
+		Message::AppendRule =>
+			"Gib eine neue Regel ein, oder gib eine Regel und dann {} ein, um \
+				sie an den Plan anzuhängen",
+		Message::DropRule => "{} zum Entfernen der letzten geplanten Regel",
+		Message::MutateRule =>
+			"{} zum Mutieren der Regel, {} für eine Überraschung, {} zum \
+				Zurückgehen",
+		Message::Favorites =>
+			"{} zum Merken einer Lieblingsregel, {}/{} zum Blättern durch die \
+				Favoriten",
+		Message::Bookmarks =>
+			"[Strg]+[1-9] zum Speichern des Felds als Lesezeichen, [1-9] \
+				halten zum Wiederherstellen",
+		Message::TransformRule =>
+			"{} zum Spiegeln der Regel, {} zum Komplementieren, {} für beides",
+		Message::FocusMute =>
+			"{} für das nächste Feld, {} zum Stummschalten/Einschalten",
+		Message::ScrollExport =>
+			"[Mausrad] oder {}/{} zum Zurückblättern, {} zum Exportieren als \
+				CSV",
+		Message::GridlinesVsync =>
+			"{} zum Ändern der Gitterlinien, {} zum Umschalten von Vsync",
+		Message::ChangePalette =>
+			"{} zum Wechseln der Farbpalette, {} zum Umschalten der Effekte",
+		Message::CellCursor =>
+			"Pausiert: {}/{} bewegen den Cursor, {} schaltet seine Zelle um",
+		Message::TransformSeed =>
+			"{}/{} rotiert die neueste Generation, {} invertiert sie, {} macht \
+				sie symmetrisch",
+		Message::LongestTransient =>
+			"{} für den Startwert mit der längsten Einschwingphase",
+		Message::FastForward => "{} spult {} Generationen vor",
+		Message::Turbo => "{} gedrückt halten zum Beschleunigen",
+		Message::SlowMotion => "{} gedrückt halten für Zeitlupe",
+		Message::ToggleExtrusion =>
+			"{} für die 3D-Ansicht: ziehen zum Kreisen, [Mausrad] zum Zoomen, \
+				{} zum Drehen",
+		Message::ToggleTape => "{} schaltet das wachsende Band um",
+		Message::ToggleNeon => "{} schaltet Neon um",
+		Message::ToggleParticles => "{} markiert die Teilchen von Regel 110",
+		Message::LightCone =>
+			"Ein Klick auf eine ältere Zelle zeigt ihren Lichtkegel",
+		Message::ToggleAncestry =>
+			"{} verfolgt stattdessen die Abstammung der Zelle",
+		Message::ToggleMinimap => "{} schaltet die Minikarte des Verlaufs um",
+		Message::ToggleDensity =>
+			"{} schaltet das Dichtehistogramm der Spalten um",
+		Message::ToggleStatistics => "{} schaltet die Statistiktafel um",
+		Message::ToggleAnalysis =>
+			"{} schaltet die Analyse der räumlichen Frequenzen um",
+		Message::ToggleBackground =>
+			"{} dämpft den periodischen Hintergrund",
+		Message::ToggleChanges => "{} hebt Geburten und Tode hervor",
+		Message::ToggleGallery => "{} schaltet die Lesezeichengalerie um",
+		Message::PaintRule =>
+			"{} malt die aktive Regel auf angeklickte Spalten",
+		Message::ShareCard => "{} exportiert eine Teilen-Karte",
+		Message::Poster => "{} rendert ein hochauflösendes Poster",
+		Message::Checkpoint => "{} springt zum vorigen Kontrollpunkt zurück",
+		Message::Paused => "Pausiert: ",
+		Message::Generation => "Generation: ",
+		Message::NextUp => "Als Nächstes: ",
+		Message::WalkBack => "{} zum Zurückgehen: ",
+		Message::Error => "Fehler",
+		Message::Equivalents => "Regel {} ≡ {}",
+		Message::RuleClass => "Klasse von Regel {}",
+		Message::TooltipCell => "Spalte {}, Generation {}: {}",
+		Message::TooltipLive => "lebendig",
+		Message::TooltipDead => "tot",
+		Message::TooltipCase => "Nachbarschaft {} → {} nach Regel {}",
+		Message::Extinct => "der Automat ist ausgestorben",
+		Message::FixedPoint => "der Automat hat einen Fixpunkt erreicht",
+		Message::Mirror => "Spiegelung",
+		Message::Complement => "Komplement",
+		Message::MirroredComplement => "Gespiegeltes Komplement",
+		Message::Surprise => "Überraschung",
+		Message::RotatedLeft => "Nach links rotiert: {} lebende Zellen",
+		Message::RotatedRight => "Nach rechts rotiert: {} lebende Zellen",
+		Message::Inverted => "Invertiert: {} lebende Zellen",
+		Message::Symmetrized => "Symmetrisiert: {} lebende Zellen",
+		Message::FoundTransient =>
+			"Längste Einschwingphase: {} Generationen, dann ein Zyklus von {}",
+		Message::NoTransient => "Kein Zyklus innerhalb von {} Generationen",
+		Message::FastForwarded => "{} Generationen vorgespult",
+		Message::FavoriteAdded => "Regel {} zu den Favoriten hinzugefügt",
+		Message::FavoriteRemoved => "Regel {} aus den Favoriten entfernt",
+		Message::Favorite => "Favorit {} von {}: Regel {}",
+		Message::NoFavorites => "Noch keine Lieblingsregeln",
+		Message::BookmarkSaved => "Lesezeichen {} gespeichert",
+		Message::BookmarkRestored => "Lesezeichen {} wiederhergestellt",
+		Message::NoBookmark => "Lesezeichen {} ist leer",
+		Message::RestoredCheckpoint => "Zurück zu Generation {}",
+		Message::NoCheckpoint => "Kein Kontrollpunkt zum Zurückspringen",
+		Message::GenerationsBack => "{} Generationen zurück",
+		Message::Gridlines => "Gitterlinien: {}",
+		Message::VsyncOn => "Vsync: an",
+		Message::VsyncOff => "Vsync: aus",
+		Message::Palette => "Farbpalette: {}",
+		Message::EffectsOn => "Effekte: an",
+		Message::EffectsOff => "Effekte: aus",
+		Message::NeonOn => "Neon: an",
+		Message::NeonOff => "Neon: aus",
+		Message::ExtrusionOn => "3D-Ansicht: an",
+		Message::ExtrusionOff => "3D-Ansicht: aus",
+		Message::AutoRotateOn => "Automatische Drehung: an",
+		Message::AutoRotateOff => "Automatische Drehung: aus",
+		Message::TapeOn => "Wachsendes Band: an",
+		Message::TapeOff => "Wachsendes Band: aus",
+		Message::ParticlesOn => "Teilchen: an",
+		Message::ParticlesOff => "Teilchen: aus",
+		Message::AncestryOn => "Abstammung: an",
+		Message::AncestryOff => "Abstammung: aus",
+		Message::MinimapOn => "Minikarte: an",
+		Message::MinimapOff => "Minikarte: aus",
+		Message::DensityOn => "Dichtehistogramm: an",
+		Message::DensityOff => "Dichtehistogramm: aus",
+		Message::StatisticsOn => "Statistik: an",
+		Message::StatisticsOff => "Statistik: aus",
+		Message::Population => "Population: {}",
+		Message::Complexity => "Komplexität: {}",
+		Message::AnalysisOn => "Analyse: an",
+		Message::AnalysisOff => "Analyse: aus",
+		Message::Wavelength => "Wellenlänge: {} Zellen",
+		Message::NoWavelength => "Wellenlänge: keine",
+		Message::BackgroundOn => "Hintergrundfilter: an",
+		Message::BackgroundOff => "Hintergrundfilter: aus",
+		Message::BackgroundPeriods =>
+			"Hintergrund: {} Zellen, alle {} Generationen",
+		Message::BackgroundPeriod => "Hintergrund: {} Zellen",
+		Message::NoBackground => "Hintergrund: keiner",
+		Message::ChangesOn => "Änderungen hervorheben: an",
+		Message::ChangesOff => "Änderungen hervorheben: aus",
+		Message::GalleryOn => "Galerie: an",
+		Message::GalleryOff => "Galerie: aus",
+		Message::PaintingOn => "Regeln malen: an",
+		Message::PaintingOff => "Regeln malen: aus",
+		Message::RuleMap => "Regelkarte: {}",
+		Message::RuleMapEmpty => "Regelkarte: leer",
+		Message::NextSwitch => "  →  Regel {} in {}",
+		Message::GuestJoined => "Gast {} ist beigetreten",
+		Message::GuestLeft => "Gast {} ist gegangen",
+		Message::HostLeft => "Der Host ist gegangen; allein weiter",
+		Message::EditDenied => "Evolution vor dem Bearbeiten pausieren",
+		Message::BookmarkDeleted => "Lesezeichen {} gelöscht",
+		Message::Gallery => "Lesezeichen",
+		Message::GalleryEmpty => "Noch keine Lesezeichen",
+		Message::Delete => "Löschen",
+		Message::Export => "Exportieren",
+		Message::Exported => "{} Generationen nach {} exportiert",
+		Message::CannotExport => "{} kann nicht exportiert werden: {}",
+		Message::ExportUnavailable =>
+			"Der Export ist im Browser nicht verfügbar",
+		Message::SharedCard => "Teilen-Karte nach {} exportiert",
+		Message::RenderingPoster => "Poster mit {} wird gerendert…",
+		Message::RenderedPoster => "Poster nach {} gerendert",
+		Message::Settings => "Einstellungen",
+		Message::Rule => "Regel",
+		Message::Heartbeat => "Herzschlag",
+		Message::Scheme => "Farbpalette",
+		Message::Scale => "Tonleiter",
+		Message::Volume => "Lautstärke",
+		Message::Mute => "Stumm"
+	}
+}
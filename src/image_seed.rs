@@ -0,0 +1,81 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::path::Path;
+
+use image::ImageError;
+
+use crate::automata::Automaton;
+
+////////////////////////////////////////////////////////////////////////////////
+//                               Image seeding.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Load the image at the specified path, and use the specified row of pixels as
+/// the first generation of an [automaton](Automaton). The row is converted to
+/// grayscale, [resampled](Automaton::from_brightness) to the width of the
+/// automaton, and thresholded at half brightness, so that dark pixels come
+/// alive. If no row is specified, then use the middle row.
+pub fn seed_from_image<const K: usize>(
+	path: impl AsRef<Path>,
+	row: Option<u32>
+) -> Result<Automaton<K>, ImageSeedError>
+{
+	let image = image::open(path)?.into_luma8();
+	let height = image.height();
+	let row = row.unwrap_or(height / 2);
+	if row >= height
+	{
+		return Err(ImageSeedError::RowOutOfRange { row, height })
+	}
+	let pixels = (0 .. image.width())
+		.map(|x| image.get_pixel(x, row).0[0])
+		.collect::<Vec<_>>();
+	Ok(Automaton::from_brightness(&pixels, THRESHOLD))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why [seeding](seed_from_image) from an image may fail.
+#[derive(Debug)]
+pub enum ImageSeedError
+{
+	/// The image could not be read or decoded.
+	Image(ImageError),
+
+	/// The requested row lies beyond the bottom of the image.
+	RowOutOfRange { row: u32, height: u32 }
+}
+
+impl From<ImageError> for ImageSeedError
+{
+	fn from(value: ImageError) -> Self
+	{
+		ImageSeedError::Image(value)
+	}
+}
+
+impl Display for ImageSeedError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			ImageSeedError::Image(e) => write!(f, "cannot load image: {}", e),
+			ImageSeedError::RowOutOfRange { row, height } => write!(
+				f,
+				"row {} is out of range; the image has only {} rows",
+				row,
+				height
+			)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The brightness below which a pixel comes alive: half brightness.
+const THRESHOLD: u8 = 128;
@@ -0,0 +1,382 @@
+use bevy::prelude::{KeyCode, Resource};
+
+////////////////////////////////////////////////////////////////////////////////
+//                               Key bindings.                                //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [key](KeyCode) bound to each hotkey action. Insert this resource before
+/// adding the [AutomataPlugin](crate::ecs::AutomataPlugin) to rebind the keys.
+/// Rules are always typed with the digit keys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Resource)]
+pub struct KeyBindings
+{
+	/// Resume or pause the evolution.
+	pub(crate) pause: KeyCode,
+
+	/// Show the frames per second (FPS), while held.
+	pub(crate) show_fps: KeyCode,
+
+	/// Move the keyboard focus to the next pane.
+	pub(crate) next_pane: KeyCode,
+
+	/// Append the rule being typed to the schedule.
+	pub(crate) append_rule: KeyCode,
+
+	/// Drop the last rule from the schedule.
+	pub(crate) drop_rule: KeyCode,
+
+	/// Mutate the active rule.
+	pub(crate) mutate: KeyCode,
+
+	/// Replace the active rule with a surprising one.
+	pub(crate) surprise: KeyCode,
+
+	/// Walk back along the breadcrumb trail.
+	pub(crate) walk_back: KeyCode,
+
+	/// Mark or unmark the active rule as a favorite.
+	pub(crate) favorite: KeyCode,
+
+	/// Switch to the previous favorite rule.
+	pub(crate) previous_favorite: KeyCode,
+
+	/// Switch to the next favorite rule.
+	pub(crate) next_favorite: KeyCode,
+
+	/// Replace the active rule with its mirror image.
+	pub(crate) mirror: KeyCode,
+
+	/// Replace the active rule with its complement.
+	pub(crate) complement: KeyCode,
+
+	/// Replace the active rule with its mirrored complement.
+	pub(crate) mirrored_complement: KeyCode,
+
+	/// Mute or unmute the sonification.
+	pub(crate) mute: KeyCode,
+
+	/// Export the generations of the focused pane, by default as CSV.
+	pub(crate) export: KeyCode,
+
+	/// Export a share card of the focused pane.
+	pub(crate) share_card: KeyCode,
+
+	/// Render a poster of the focused pane.
+	pub(crate) poster: KeyCode,
+
+	/// Jump back to the previous checkpoint of the focused pane.
+	pub(crate) checkpoint: KeyCode,
+
+	/// Change the gridlines.
+	pub(crate) gridlines: KeyCode,
+
+	/// Toggle vsync.
+	pub(crate) vsync: KeyCode,
+
+	/// Switch to the next palette scheme.
+	pub(crate) palette: KeyCode,
+
+	/// Toggle the birth and death effects.
+	pub(crate) effects: KeyCode,
+
+	/// Toggle the neon presentation mode.
+	pub(crate) neon: KeyCode,
+
+	/// Toggle the 3D view.
+	pub(crate) extrusion: KeyCode,
+
+	/// Toggle the automatic rotation of the 3D view.
+	pub(crate) auto_rotate: KeyCode,
+
+	/// Toggle the growing tape.
+	pub(crate) tape: KeyCode,
+
+	/// Toggle the outlines of the particles of Rule 110.
+	pub(crate) particles: KeyCode,
+
+	/// Toggle the tracing of the ancestry of the selected cell.
+	pub(crate) ancestry: KeyCode,
+
+	/// Toggle the minimap of the scrollback.
+	pub(crate) minimap: KeyCode,
+
+	/// Toggle the density histogram beneath each grid.
+	pub(crate) density: KeyCode,
+
+	/// Toggle the statistics panel.
+	pub(crate) statistics: KeyCode,
+
+	/// Toggle the analysis view of spatial frequencies.
+	pub(crate) analysis: KeyCode,
+
+	/// Toggle the dimming of the periodic background.
+	pub(crate) background: KeyCode,
+
+	/// Toggle the highlighting of births and deaths.
+	pub(crate) changes: KeyCode,
+
+	/// Toggle the gallery of bookmarks.
+	pub(crate) gallery: KeyCode,
+
+	/// Toggle the painting of rules onto columns.
+	pub(crate) paint_rule: KeyCode,
+
+	/// Move the cell cursor to the left.
+	pub(crate) cursor_left: KeyCode,
+
+	/// Move the cell cursor to the right.
+	pub(crate) cursor_right: KeyCode,
+
+	/// Toggle the cell under the cell cursor.
+	pub(crate) toggle_cell: KeyCode,
+
+	/// Rotate the newest generation one column to the left.
+	pub(crate) rotate_left: KeyCode,
+
+	/// Rotate the newest generation one column to the right.
+	pub(crate) rotate_right: KeyCode,
+
+	/// Invert the newest generation.
+	pub(crate) invert: KeyCode,
+
+	/// Mirror the left half of the newest generation onto its right half.
+	pub(crate) symmetrize: KeyCode,
+
+	/// Reseed with the first generation that has the longest transient.
+	pub(crate) longest_transient: KeyCode,
+
+	/// Fast-forward through many generations at once.
+	pub(crate) fast_forward: KeyCode,
+
+	/// Evolve once per frame, while held.
+	pub(crate) turbo: KeyCode,
+
+	/// Evolve in slow motion, while held.
+	pub(crate) slow_motion: KeyCode,
+
+	/// Scroll back by a page of generations.
+	pub(crate) page_back: KeyCode,
+
+	/// Scroll forward by a page of generations.
+	pub(crate) page_forward: KeyCode
+}
+
+impl KeyBindings
+{
+	/// Answer every action paired with its bound [key](KeyCode), in the order
+	/// of declaration. Actions are named as in the `[keys]` table of the
+	/// [configuration&#32;file](crate::config::Config).
+	pub fn actions(&self) -> [(&'static str, KeyCode); 50]
+	{
+		[
+			("pause", self.pause),
+			("show_fps", self.show_fps),
+			("next_pane", self.next_pane),
+			("append_rule", self.append_rule),
+			("drop_rule", self.drop_rule),
+			("mutate", self.mutate),
+			("surprise", self.surprise),
+			("walk_back", self.walk_back),
+			("favorite", self.favorite),
+			("previous_favorite", self.previous_favorite),
+			("next_favorite", self.next_favorite),
+			("mirror", self.mirror),
+			("complement", self.complement),
+			("mirrored_complement", self.mirrored_complement),
+			("mute", self.mute),
+			("export", self.export),
+			("share_card", self.share_card),
+			("poster", self.poster),
+			("checkpoint", self.checkpoint),
+			("gridlines", self.gridlines),
+			("vsync", self.vsync),
+			("palette", self.palette),
+			("effects", self.effects),
+			("neon", self.neon),
+			("extrusion", self.extrusion),
+			("auto_rotate", self.auto_rotate),
+			("tape", self.tape),
+			("particles", self.particles),
+			("ancestry", self.ancestry),
+			("minimap", self.minimap),
+			("density", self.density),
+			("statistics", self.statistics),
+			("analysis", self.analysis),
+			("background", self.background),
+			("changes", self.changes),
+			("gallery", self.gallery),
+			("paint_rule", self.paint_rule),
+			("cursor_left", self.cursor_left),
+			("cursor_right", self.cursor_right),
+			("toggle_cell", self.toggle_cell),
+			("rotate_left", self.rotate_left),
+			("rotate_right", self.rotate_right),
+			("invert", self.invert),
+			("symmetrize", self.symmetrize),
+			("longest_transient", self.longest_transient),
+			("fast_forward", self.fast_forward),
+			("turbo", self.turbo),
+			("slow_motion", self.slow_motion),
+			("page_back", self.page_back),
+			("page_forward", self.page_forward)
+		]
+	}
+
+	/// Answer the names of the first two actions that are bound to the same
+	/// [key](KeyCode), together with that key, or [None] if every action has
+	/// its own key. Both actions would fire on a single press.
+	pub fn conflict(&self) -> Option<(&'static str, &'static str, KeyCode)>
+	{
+		let actions = self.actions();
+		actions.iter()
+			.enumerate()
+			.find_map(|(i, &(first, key))| {
+				actions[i + 1 ..].iter()
+					.find(|&&(_, other)| other == key)
+					.map(|&(second, _)| (first, second, key))
+			})
+	}
+}
+
+impl Default for KeyBindings
+{
+	fn default() -> Self
+	{
+		Self {
+			pause: KeyCode::Space,
+			show_fps: KeyCode::ShiftRight,
+			next_pane: KeyCode::Tab,
+			append_rule: KeyCode::Comma,
+			drop_rule: KeyCode::Delete,
+			mutate: KeyCode::M,
+			surprise: KeyCode::S,
+			walk_back: KeyCode::Back,
+			// Not every keyboard has a numpad, and `*` moves between layouts,
+			// so a letter marks favorites.
+			favorite: KeyCode::U,
+			// `[` and `]` are taken by rotation.
+			previous_favorite: KeyCode::Minus,
+			next_favorite: KeyCode::Equals,
+			mirror: KeyCode::H,
+			complement: KeyCode::C,
+			mirrored_complement: KeyCode::B,
+			mute: KeyCode::N,
+			export: KeyCode::E,
+			share_card: KeyCode::Q,
+			// Every letter is taken.
+			poster: KeyCode::F12,
+			checkpoint: KeyCode::Home,
+			gridlines: KeyCode::G,
+			vsync: KeyCode::V,
+			palette: KeyCode::P,
+			effects: KeyCode::L,
+			neon: KeyCode::D,
+			extrusion: KeyCode::T,
+			auto_rotate: KeyCode::R,
+			tape: KeyCode::W,
+			particles: KeyCode::I,
+			ancestry: KeyCode::A,
+			minimap: KeyCode::O,
+			density: KeyCode::F3,
+			statistics: KeyCode::F4,
+			analysis: KeyCode::F5,
+			background: KeyCode::F6,
+			changes: KeyCode::F7,
+			gallery: KeyCode::K,
+			paint_rule: KeyCode::X,
+			cursor_left: KeyCode::Left,
+			cursor_right: KeyCode::Right,
+			toggle_cell: KeyCode::Return,
+			rotate_left: KeyCode::BracketLeft,
+			rotate_right: KeyCode::BracketRight,
+			invert: KeyCode::Slash,
+			symmetrize: KeyCode::Y,
+			longest_transient: KeyCode::J,
+			fast_forward: KeyCode::Period,
+			turbo: KeyCode::F,
+			// S would be the natural choice, but it is taken by surprise.
+			slow_motion: KeyCode::Z,
+			page_back: KeyCode::PageUp,
+			page_forward: KeyCode::PageDown
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Key names.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the [key](KeyCode) with the specified name, ignoring case, or [None]
+/// if no key has that name. Names are those of [KEY_NAMES], e.g., `space`,
+/// `right shift`, or `f`.
+pub fn key_from_name(name: &str) -> Option<KeyCode>
+{
+	KEY_NAMES.iter()
+		.find(|(candidate, _)| candidate.eq_ignore_ascii_case(name.trim()))
+		.map(|&(_, key)| key)
+}
+
+/// Answer the name of the specified [key](KeyCode), suitable for showing to
+/// the user. Keys without names in [KEY_NAMES] answer `?`.
+pub fn key_name(key: KeyCode) -> &'static str
+{
+	KEY_NAMES.iter()
+		.find(|(_, candidate)| *candidate == key)
+		.map(|&(name, _)| name)
+		.unwrap_or("?")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The names of the [keys](KeyCode) that may be bound to actions.
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+	("a", KeyCode::A), ("b", KeyCode::B), ("c", KeyCode::C),
+	("d", KeyCode::D), ("e", KeyCode::E), ("f", KeyCode::F),
+	("g", KeyCode::G), ("h", KeyCode::H), ("i", KeyCode::I),
+	("j", KeyCode::J), ("k", KeyCode::K), ("l", KeyCode::L),
+	("m", KeyCode::M), ("n", KeyCode::N), ("o", KeyCode::O),
+	("p", KeyCode::P), ("q", KeyCode::Q), ("r", KeyCode::R),
+	("s", KeyCode::S), ("t", KeyCode::T), ("u", KeyCode::U),
+	("v", KeyCode::V), ("w", KeyCode::W), ("x", KeyCode::X),
+	("y", KeyCode::Y), ("z", KeyCode::Z),
+	("f1", KeyCode::F1), ("f2", KeyCode::F2), ("f3", KeyCode::F3),
+	("f4", KeyCode::F4), ("f5", KeyCode::F5), ("f6", KeyCode::F6),
+	("f7", KeyCode::F7), ("f8", KeyCode::F8), ("f9", KeyCode::F9),
+	("f10", KeyCode::F10), ("f11", KeyCode::F11), ("f12", KeyCode::F12),
+	("space", KeyCode::Space),
+	("tab", KeyCode::Tab),
+	("enter", KeyCode::Return),
+	("escape", KeyCode::Escape),
+	("backspace", KeyCode::Back),
+	("delete", KeyCode::Delete),
+	("insert", KeyCode::Insert),
+	("home", KeyCode::Home),
+	("end", KeyCode::End),
+	("page up", KeyCode::PageUp),
+	("page down", KeyCode::PageDown),
+	("up", KeyCode::Up),
+	("down", KeyCode::Down),
+	("left", KeyCode::Left),
+	("right", KeyCode::Right),
+	("left shift", KeyCode::ShiftLeft),
+	("right shift", KeyCode::ShiftRight),
+	("left control", KeyCode::ControlLeft),
+	("right control", KeyCode::ControlRight),
+	("left alt", KeyCode::AltLeft),
+	("right alt", KeyCode::AltRight),
+	(",", KeyCode::Comma),
+	(".", KeyCode::Period),
+	("/", KeyCode::Slash),
+	("\\", KeyCode::Backslash),
+	(";", KeyCode::Semicolon),
+	("'", KeyCode::Apostrophe),
+	("`", KeyCode::Grave),
+	("-", KeyCode::Minus),
+	("=", KeyCode::Equals),
+	("*", KeyCode::Asterisk),
+	("numpad *", KeyCode::NumpadMultiply),
+	("[", KeyCode::BracketLeft),
+	("]", KeyCode::BracketRight)
+];
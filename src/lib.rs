@@ -0,0 +1,67 @@
+//! The evolver of elementary cellular automata, as a library for embedding in
+//! other Bevy applications. Add an [AutomataPlugin], constructed from an
+//! [AutomataConfig], to the [App](bevy::prelude::App). Spawn
+//! [panes](AutomatonBundle) beforehand to choose their seeds and schedules,
+//! and read [GenerationEvolved], [RuleChanged], and [CellEdited] to follow
+//! the evolution. The [automata](Automaton) themselves, along with their
+//! [rules](AutomatonRule) and [histories](History), work outside of any app.
+//!
+//! The application compiles the same modules for itself, along with its
+//! command line and its external controls, so items that only the application
+//! uses are dead here.
+#![allow(dead_code)]
+
+pub use crate::automata::{
+	Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule, History,
+	RuleKind, RuleSchedule, Scrollback
+};
+pub use crate::ecs::{
+	AutomataConfig, AutomataPlugin, AutomatonBundle, CellEdited, CellPosition,
+	FramePacing, GenerationEvolved, PaletteOverrides, PaletteScheme, RuleChanged
+};
+
+mod analysis;
+mod ancestry;
+mod ant;
+mod automata;
+mod background;
+mod bookmarks;
+mod cell_style;
+mod changes;
+mod checkpoint;
+mod density;
+mod ecs;
+mod effects;
+mod extrusion;
+mod favorites;
+mod gallery;
+mod geometry;
+mod gestures;
+mod i18n;
+mod keys;
+mod light_cone;
+mod minimap;
+mod neon;
+mod particles;
+mod phosphor;
+mod poster;
+mod rule_icon;
+mod rule_map;
+mod share_card;
+mod sonification;
+mod statistics;
+mod tape;
+mod tooltip;
+mod transitions;
+#[cfg(feature = "scripting")]
+mod scripting;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+mod wasm_rules;
+#[cfg(not(target_family = "wasm"))]
+mod replay;
+#[cfg(not(target_family = "wasm"))]
+mod config;
+#[cfg(not(target_family = "wasm"))]
+mod collaboration;
+#[cfg(feature = "egui")]
+mod settings;
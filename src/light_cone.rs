@@ -0,0 +1,128 @@
+use bevy::prelude::{
+	App, BackgroundColor, Button, Changed, Color, DetectChanges,
+	DetectChangesMut, Interaction, IntoSystemConfigs, Plugin, Query, Res,
+	ResMut, Resource, Update, Without
+};
+
+use crate::ancestry::AncestryTracing;
+use crate::automata::{AUTOMATON_LENGTH, Cone};
+use crate::ecs::{CellOverlay, CellPosition, EvolutionTimer};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for light cones. While the evolver is
+/// paused, clicking an older cell, i.e., any cell besides those of the newest
+/// generation, which toggle instead, selects that cell as the apex of a
+/// [light&#32;cone](Cone). The [overlays](CellOverlay) of the cells within the
+/// cone take distinct tints for the past and the future, without disturbing
+/// the liveness colors beneath. Clicking the apex again, or resuming the
+/// evolver, clears the cone. While [ancestry&#32;tracing](AncestryTracing) is
+/// on, the selected cell serves as the subject of the trace instead.
+pub struct LightConePlugin;
+
+impl Plugin for LightConePlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<LightCone>()
+			.add_systems(Update, maybe_select_apex)
+			.add_systems(Update, update_light_cone.after(maybe_select_apex));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [position](CellPosition) of the apex of the [light&#32;cone](Cone), if
+/// a cell has been selected.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub(crate) struct LightCone(pub(crate) Option<CellPosition>);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On press of an older cell _while paused_, select the cell as the apex of
+/// the [light&#32;cone](LightCone), or clear the cone if the cell is already
+/// its apex. While the evolver runs, the generations shift upward beneath the
+/// cone, so clear the cone.
+pub(crate) fn maybe_select_apex(
+	timer: Res<EvolutionTimer>,
+	interaction: Query<
+		(&Interaction, &CellPosition),
+		(Changed<Interaction>, Without<Button>)
+	>,
+	mut cone: ResMut<LightCone>
+) {
+	if timer.is_running()
+	{
+		cone.set_if_neq(LightCone(None));
+		return
+	}
+	for (interaction, position) in &interaction
+	{
+		if *interaction == Interaction::Pressed
+		{
+			*cone = match cone.0
+			{
+				Some(apex) if apex == *position => LightCone(None),
+				_ => LightCone(Some(*position))
+			};
+		}
+	}
+}
+
+/// Whenever the [light&#32;cone](LightCone) changes, tint the
+/// [overlay](CellOverlay) of every cell within it according to its part of the
+/// [cone](Cone), and clear the others. Only cells of the apex's own
+/// [pane](crate::ecs::Pane) lie within the cone. Leave the overlays alone
+/// while [ancestry&#32;tracing](AncestryTracing) is on, but repaint them once
+/// it turns off.
+fn update_light_cone(
+	tracing: Res<AncestryTracing>,
+	cone: Res<LightCone>,
+	mut overlays: Query<(&CellOverlay, &mut BackgroundColor)>
+) {
+	if tracing.0 || !(cone.is_changed() || tracing.is_changed())
+	{
+		return
+	}
+	for (overlay, mut color) in &mut overlays
+	{
+		let position = overlay.0;
+		// Columns mirror indices, which preserves the distances between cells.
+		let part = cone.0
+			.filter(|apex| apex.pane == position.pane)
+			.and_then(|apex| Cone::locate::<AUTOMATON_LENGTH>(
+				(apex.row, apex.column),
+				(position.row, position.column)
+			));
+		let tint = match part
+		{
+			Some(Cone::Apex) => APEX_TINT,
+			Some(Cone::Past) => PAST_TINT,
+			Some(Cone::Future) => FUTURE_TINT,
+			None => Color::NONE
+		};
+		color.set_if_neq(BackgroundColor(tint));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tint of the apex of the [light&#32;cone](Cone).
+pub(crate) const APEX_TINT: Color = Color::rgba(1.0, 1.0, 1.0, 0.7);
+
+/// The tint of the past [light&#32;cone](Cone), i.e., the cells that could
+/// have influenced the apex.
+const PAST_TINT: Color = Color::rgba(0.2, 0.5, 1.0, 0.45);
+
+/// The tint of the future [light&#32;cone](Cone), i.e., the cells that the
+/// apex could influence.
+const FUTURE_TINT: Color = Color::rgba(1.0, 0.55, 0.1, 0.45);
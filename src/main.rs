@@ -1,36 +1,644 @@
+#[cfg(not(target_family = "wasm"))]
+use std::path::PathBuf;
+#[cfg(all(
+	any(feature = "scripting", feature = "wasm-rules"),
+	not(target_family = "wasm")
+))]
+use std::sync::Arc;
+use std::time::Duration;
+
 use bevy::prelude::App;
 #[cfg(doc)]
-use bevy::prelude::Resource;
+use bevy::prelude::Entity;
 use rand::random;
 
+use crate::ant::AntPlugin;
 use crate::automata::{
 	Automaton, AUTOMATON_HISTORY, AUTOMATON_LENGTH, AutomatonRule,
-	History
+	Colony,
+	Decay,
+	History,
+	RuleMap, RuleSchedule, RuleTimeline,
+	Turns
+};
+#[cfg(not(target_family = "wasm"))]
+use crate::automata::{ExportFormat, RULE_ALIASES};
+#[cfg(all(
+	any(feature = "scripting", feature = "wasm-rules"),
+	not(target_family = "wasm")
+))]
+use crate::automata::RuleKind;
+use crate::cell_style::CellStyle;
+#[cfg(not(target_family = "wasm"))]
+use crate::checkpoint::{Checkpoints, DEFAULT_CHECKPOINT_INTERVAL};
+use crate::ecs::{
+	AutomataConfig, AutomataPlugin, AutomatonBundle, ClassSampling,
+	DifferenceBundle, FramePacing, Heartbeat, Mode, PaletteScheme, RuleCycle,
+	Scan, SymmetricSeeds
+};
+#[cfg(not(target_family = "wasm"))]
+use crate::favorites::Favorites;
+use crate::geometry::{CellShape, Gridlines};
+use crate::i18n::Language;
+use crate::neon::Neon;
+use crate::phosphor::Phosphor;
+#[cfg(not(target_family = "wasm"))]
+use crate::poster::PosterSize;
+use crate::sonification::{DEFAULT_VOLUME, Feedback, Scale, Sonification};
+use crate::tape::GrowingTape;
+use crate::transitions::Transition;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+use crate::scripting::ScriptedRule;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+use crate::wasm_rules::WasmRule;
+#[cfg(all(feature = "midi", not(target_family = "wasm")))]
+use crate::midi::{MidiPlugin, MidiSink};
+#[cfg(all(feature = "serial", not(target_family = "wasm")))]
+use crate::serial::{DEFAULT_BAUD_RATE, SerialPlugin, SerialSink};
+#[cfg(all(feature = "broadcast", not(target_family = "wasm")))]
+use crate::broadcast::{BroadcastFormat, Broadcaster, BroadcastPlugin};
+#[cfg(all(feature = "http", not(target_family = "wasm")))]
+use crate::http_control::{HttpControl, HttpControlPlugin};
+#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+use crate::mqtt::{
+	DEFAULT_CONTROL_TOPIC, DEFAULT_TOPIC, Mqtt, MqttPlugin
+};
+#[cfg(not(target_family = "wasm"))]
+use crate::replay::{Recorder, Replay, ReplayPlugin};
+#[cfg(not(target_family = "wasm"))]
+use crate::history_log::{HistoryLog, HistoryLogPlugin};
+#[cfg(not(target_family = "wasm"))]
+use crate::image_seed::seed_from_image;
+#[cfg(not(target_family = "wasm"))]
+use crate::config::Config;
+#[cfg(not(target_family = "wasm"))]
+use crate::control::{Control, ControlPlugin, ControlSource};
+#[cfg(not(target_family = "wasm"))]
+use crate::collaboration::{CollaborationPlugin, Guest, Host};
+#[cfg(not(target_family = "wasm"))]
+use crate::osc::{OscListener, OscPlugin, OscSender};
+#[cfg(not(target_family = "wasm"))]
+use crate::ecs::Relay;
+#[cfg(not(target_family = "wasm"))]
+use crate::commands::{
+	BatchArguments, BenchArguments, ExportArguments, RenderArguments,
+	SearchArguments, TransientArguments
 };
-use crate::ecs::AutomataPlugin;
+#[cfg(target_family = "wasm")]
+use crate::ecs::SETTINGS_STORAGE_KEY;
+#[cfg(target_family = "wasm")]
+use crate::web_api::WebApiPlugin;
 
+mod analysis;
+mod ancestry;
+mod ant;
 mod automata;
+mod background;
+mod bookmarks;
+mod cell_style;
+mod changes;
+mod checkpoint;
+mod density;
 mod ecs;
+mod effects;
+mod extrusion;
+mod favorites;
+mod gallery;
+mod geometry;
+mod gestures;
+mod i18n;
+mod keys;
+mod light_cone;
+mod minimap;
+mod neon;
+mod particles;
+mod phosphor;
+mod poster;
+mod rule_icon;
+mod rule_map;
+mod share_card;
+mod sonification;
+mod statistics;
+mod tape;
+mod tooltip;
+mod transitions;
+#[cfg(feature = "scripting")]
+mod scripting;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+mod wasm_rules;
+#[cfg(all(feature = "midi", not(target_family = "wasm")))]
+mod midi;
+#[cfg(all(feature = "serial", not(target_family = "wasm")))]
+mod serial;
+#[cfg(all(feature = "broadcast", not(target_family = "wasm")))]
+mod broadcast;
+#[cfg(all(feature = "http", not(target_family = "wasm")))]
+mod http_control;
+#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+mod mqtt;
+#[cfg(not(target_family = "wasm"))]
+mod replay;
+#[cfg(not(target_family = "wasm"))]
+mod history_log;
+#[cfg(not(target_family = "wasm"))]
+mod image_seed;
+#[cfg(not(target_family = "wasm"))]
+mod config;
+#[cfg(not(target_family = "wasm"))]
+mod control;
+#[cfg(not(target_family = "wasm"))]
+mod collaboration;
+#[cfg(not(target_family = "wasm"))]
+mod osc;
+#[cfg(not(target_family = "wasm"))]
+mod commands;
+#[cfg(feature = "egui")]
+mod settings;
+#[cfg(target_family = "wasm")]
+mod web_api;
 
 /// The entry point for the whole application. Parse the
-/// [command&#32;line&#32;arguments](Arguments), attach them to the [App] as
-/// [resources](Resource), then hand control over to Bevy.
+/// [command&#32;line&#32;arguments](Arguments), supplementing them from the
+/// [configuration&#32;file](Config), if any, spawn one [pane](AutomatonBundle)
+/// [entity](Entity) for each automaton that they describe, then hand control
+/// over to Bevy.
 fn main()
 {
 	let args = arguments().unwrap_or(Arguments::default());
-	let rule = args.rule
-		.and_then(|rule| Some(AutomatonRule::from(rule)))
-		.unwrap_or_else(|| random::<u8>().into());
+	#[cfg(not(target_family = "wasm"))]
+	if args.list_rules
+	{
+		list_rules();
+		return
+	}
+	#[cfg(not(target_family = "wasm"))]
+	let config = {
+		let config = match args.config
+		{
+			Some(ref path) => Config::load(path),
+			None => Config::load_default()
+		};
+		match config
+		{
+			Ok(config) => config,
+			Err(e) =>
+			{
+				eprintln!("{}", e);
+				std::process::exit(1)
+			}
+		}
+	};
+	#[cfg(not(target_family = "wasm"))]
+	let args = args.merge(&config);
+	let schedule: RuleSchedule = match args.rules.is_empty()
+	{
+		true => args.rule
+			.unwrap_or_else(|| ClassSampling(args.classes).random_rule())
+			.into(),
+		false => RuleSchedule::from(args.rules.clone())
+	};
+	#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+	let schedule = match args.script
+	{
+		Some(ref path) => match ScriptedRule::load(path)
+		{
+			Ok(script) => RuleKind::Scripted(Arc::new(script)).into(),
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		},
+		None => schedule
+	};
+	#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+	let schedule = match args.wasm_rule
+	{
+		Some(ref path) => match WasmRule::load(path)
+		{
+			Ok(module) => RuleKind::Wasm(Arc::new(module)).into(),
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		},
+		None => schedule
+	};
 	let seed = args.seed
 		.and_then(|seed| Some(Automaton::<AUTOMATON_LENGTH>::from(seed)))
-		.unwrap_or_else(|| random::<u64>().into());
-	App::new()
-		.insert_resource(
-			History::<AUTOMATON_LENGTH, AUTOMATON_HISTORY>::from(seed)
-		)
-		.insert_resource(rule)
-		.add_plugins(AutomataPlugin)
-		.run();
+		.or(args.seed_hex)
+		.or(args.pattern)
+		.unwrap_or_else(|| {
+			let seed = Automaton::from(random::<u64>());
+			match args.symmetric
+			{
+				true => seed.symmetrize(),
+				false => seed
+			}
+		});
+	#[cfg(not(target_family = "wasm"))]
+	let seed = match args.seed_image
+	{
+		Some(ref path) => match seed_from_image(path, args.row)
+		{
+			Ok(seed) => seed,
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		},
+		None => seed
+	};
+	let panes: Vec<(Automaton, RuleSchedule)> =
+		if !args.diff.is_empty()
+		{
+			// Same seed, two rules, and a difference pane (spawned below).
+			args.diff.iter()
+				.map(|&rule| (seed, rule.into()))
+				.collect()
+		}
+		else if !args.compare.is_empty()
+		{
+			// Same seed, different rules.
+			args.compare.iter()
+				.map(|&rule| (seed, rule.into()))
+				.collect()
+		}
+		else if !args.compare_seeds.is_empty()
+		{
+			// Same rules, different seeds.
+			args.compare_seeds.iter()
+				.map(|&seed| (seed.into(), schedule.clone()))
+				.collect()
+		}
+		else
+		{
+			vec![(seed, schedule)]
+		};
+	let mut app = App::new();
+	let panes = panes.into_iter()
+		.enumerate()
+		.map(|(index, (seed, schedule))| {
+			// The timeline governs the first generation too.
+			let schedule = args.timeline.as_ref()
+				.and_then(|timeline| timeline.rule_at(0))
+				.map(RuleSchedule::from)
+				.unwrap_or(schedule);
+			let mut pane = app.world.spawn(AutomatonBundle::new(
+				index,
+				History::<AUTOMATON_LENGTH, AUTOMATON_HISTORY>::from(seed),
+				schedule,
+				args.scrollback
+			));
+			if let Some(&millis) = args.heartbeats.get(index)
+				.filter(|&&millis| millis > 0)
+			{
+				pane.insert(Heartbeat::new(Duration::from_millis(millis)));
+			}
+			if let Some(ref map) = args.rule_map
+			{
+				pane.insert(map.clone());
+			}
+			if let Some(ref timeline) = args.timeline
+			{
+				pane.insert(timeline.clone());
+			}
+			if let Some(generations) = args.decay.filter(|&n| n > 0)
+			{
+				pane.insert(Decay::<AUTOMATON_LENGTH, AUTOMATON_HISTORY>::new(
+					generations
+				));
+			}
+			pane.id()
+		})
+		.collect::<Vec<_>>();
+	if !args.diff.is_empty()
+	{
+		app.world.spawn(DifferenceBundle::new(panes.len(), panes[0], panes[1]));
+	}
+	if let Some(period) = args.cycle
+		.and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+		.filter(|period| !period.is_zero())
+	{
+		app.insert_resource(RuleCycle::new(period, args.reseed));
+	}
+	// Browsers pace frames themselves, so the frame rate cannot be capped.
+	#[cfg(not(target_family = "wasm"))]
+	let fps_cap = args.fps_cap;
+	#[cfg(target_family = "wasm")]
+	let fps_cap = None;
+	#[cfg(not(target_family = "wasm"))]
+	let ambient = args.ambient;
+	#[cfg(target_family = "wasm")]
+	let ambient = false;
+	#[cfg(not(target_family = "wasm"))]
+	let transparent = args.transparent;
+	#[cfg(target_family = "wasm")]
+	let transparent = false;
+	let mut automata = AutomataConfig {
+		scheme: args.palette.unwrap_or(match ambient
+		{
+			true => PaletteScheme::Muted,
+			false => PaletteScheme::default()
+		}),
+		pacing: FramePacing::new(fps_cap, !args.no_vsync),
+		paused: !args.run && !ambient,
+		ambient,
+		transparent,
+		..AutomataConfig::default()
+	};
+	if ambient
+	{
+		automata.heartbeat = AMBIENT_HEARTBEAT;
+	}
+	if let Some(heartbeat) = args.heartbeat.filter(|millis| *millis > 0)
+	{
+		automata.heartbeat = Duration::from_millis(heartbeat);
+	}
+	#[cfg(not(target_family = "wasm"))]
+	{
+		automata.overrides = config.palette_overrides();
+		let path = args.config.clone()
+			.unwrap_or_else(|| Config::default_path().to_path_buf());
+		let bindings = match config.key_bindings()
+		{
+			Ok(bindings) => bindings,
+			Err(e) =>
+			{
+				eprintln!("{}", e);
+				std::process::exit(1)
+			}
+		};
+		app
+			.insert_resource(bindings)
+			.insert_resource(Favorites::new(
+				config.favorites.iter().map(|&code| AutomatonRule::from(code)),
+				Some(path)
+			));
+	}
+	app
+		.insert_resource(Sonification::new(
+			args.scale.unwrap_or_default(),
+			args.volume.unwrap_or(DEFAULT_VOLUME),
+			args.mute || ambient
+		))
+		.insert_resource(Feedback::new(args.mute_feedback || ambient))
+		.insert_resource(args.cell_shape.unwrap_or_default())
+		.insert_resource(args.grid.unwrap_or_default())
+		.insert_resource(args.lang.unwrap_or_default())
+		.insert_resource(GrowingTape(args.grow))
+		.insert_resource(Scan(args.scan))
+		.insert_resource(SymmetricSeeds(args.symmetric))
+		.insert_resource(ClassSampling(args.classes))
+		.insert_resource(args.transition.unwrap_or_default())
+		.insert_resource(Phosphor(args.phosphor))
+		.insert_resource(args.style.unwrap_or_default())
+		.insert_resource(Neon(args.neon))
+		.insert_resource(args.mode.unwrap_or_default())
+		.add_plugins(AutomataPlugin::new(automata));
+	if args.mode == Some(Mode::Ant)
+	{
+		app
+			.insert_resource(
+				Colony::<AUTOMATON_LENGTH, AUTOMATON_HISTORY>::new(
+					args.turns.clone().unwrap_or_default(),
+					args.ants.filter(|&count| count > 0).unwrap_or(1)
+				)
+			)
+			.add_plugins(AntPlugin);
+	}
+	#[cfg(all(feature = "midi", not(target_family = "wasm")))]
+	if let Some(ref port) = args.midi_port
+	{
+		match MidiSink::connect(port)
+		{
+			Ok(sink) =>
+			{
+				app.insert_non_send_resource(sink).add_plugins(MidiPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}", e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(all(feature = "serial", not(target_family = "wasm")))]
+	if let Some(ref path) = args.serial
+	{
+		let baud_rate = args.serial_baud.unwrap_or(DEFAULT_BAUD_RATE);
+		match SerialSink::open(path, baud_rate)
+		{
+			Ok(sink) =>
+			{
+				app.insert_non_send_resource(sink).add_plugins(SerialPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(all(feature = "broadcast", not(target_family = "wasm")))]
+	if let Some(ref url) = args.broadcast
+	{
+		match Broadcaster::bind(url, args.broadcast_format.unwrap_or_default())
+		{
+			Ok(broadcaster) =>
+			{
+				app.insert_resource(broadcaster).add_plugins(BroadcastPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", url, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	app.insert_resource(args.export_format.unwrap_or_default());
+	#[cfg(not(target_family = "wasm"))]
+	app.insert_resource(args.poster_size.unwrap_or_default());
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref directory) = args.checkpoint_dir
+	{
+		if let Err(e) = std::fs::create_dir_all(directory)
+		{
+			eprintln!("{}: {}", directory.display(), e);
+			std::process::exit(1)
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	app.insert_resource(Checkpoints::new(
+		args.checkpoint_interval.unwrap_or(DEFAULT_CHECKPOINT_INTERVAL),
+		args.checkpoint_dir.clone()
+	));
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref path) = args.record
+	{
+		match Recorder::create(path)
+		{
+			Ok(recorder) =>
+			{
+				app.insert_resource(recorder).add_plugins(ReplayPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref path) = args.replay
+	{
+		match Replay::load(path)
+		{
+			Ok(replay) =>
+			{
+				app.insert_resource(replay).add_plugins(ReplayPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref path) = args.history_log
+	{
+		match HistoryLog::open(path)
+		{
+			Ok(log) =>
+			{
+				app.insert_resource(log).add_plugins(HistoryLogPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", path.display(), e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(source) = args.control
+	{
+		app.insert_resource(Control::open(source)).add_plugins(ControlPlugin);
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref address) = args.osc_listen
+	{
+		match OscListener::bind(address)
+		{
+			Ok(listener) =>
+			{
+				app.insert_resource(listener);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", address, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref address) = args.osc_send
+	{
+		match OscSender::connect(address)
+		{
+			Ok(sender) =>
+			{
+				app.insert_resource(sender);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", address, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if args.osc_listen.is_some() || args.osc_send.is_some()
+	{
+		app.add_plugins(OscPlugin);
+	}
+	#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+	if let Some(ref url) = args.mqtt
+	{
+		let topic = args.mqtt_topic.as_deref().unwrap_or(DEFAULT_TOPIC);
+		let control = args.mqtt_control_topic.as_deref()
+			.unwrap_or(DEFAULT_CONTROL_TOPIC);
+		match Mqtt::connect(url, topic, control)
+		{
+			Ok(mqtt) =>
+			{
+				app.insert_resource(mqtt).add_plugins(MqttPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", url, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(all(feature = "http", not(target_family = "wasm")))]
+	if let Some(ref address) = args.http
+	{
+		match HttpControl::bind(address)
+		{
+			Ok(control) =>
+			{
+				app.insert_resource(control).add_plugins(HttpControlPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", address, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref address) = args.host
+	{
+		match Host::bind(address)
+		{
+			Ok(host) =>
+			{
+				app.insert_resource(host).add_plugins(CollaborationPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", address, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(ref address) = args.join
+	{
+		match Guest::join(address)
+		{
+			Ok(guest) =>
+			{
+				app
+					.insert_resource(guest)
+					.init_resource::<Relay>()
+					.add_plugins(CollaborationPlugin);
+			},
+			Err(e) =>
+			{
+				eprintln!("{}: {}", address, e);
+				std::process::exit(1)
+			}
+		}
+	}
+	#[cfg(target_family = "wasm")]
+	app.add_plugins(WebApiPlugin);
+	app.run();
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -38,49 +646,857 @@ fn main()
 ////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(not(target_family = "wasm"))]
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
 /// Fun with cellular automata! Set the first generation with a known seed
 /// and/or rule, or let the program choose randomly. Watch the automaton evolve,
-/// and influence its evolution with the keyboard and mouse.
+/// and influence its evolution with the keyboard and mouse. Or use one of the
+/// headless commands to render, search, batch, benchmark, or export without a
+/// window.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli
+{
+	/// The command to carry out. Defaults to `run`.
+	#[command(subcommand)]
+	command: Option<Command>,
+
+	/// The arguments to `run`, which may also be given without naming it.
+	#[command(flatten)]
+	run: Arguments
+}
+
+/// The commands available from the command line. Only `run` opens a window;
+/// the others work headlessly, then exit, unless asked to open the evolver
+/// afterward. Available for native builds only.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug, Subcommand)]
+enum Command
+{
+	/// Watch an automaton evolve, and influence its evolution with the
+	/// keyboard and mouse.
+	Run(Arguments),
+
+	/// Render the evolution of an automaton to an image or an animated GIF.
+	Render(RenderArguments),
+
+	/// Explore the elementary rules, listing or picking those whose evolutions
+	/// look most interesting.
+	Search(SearchArguments),
+
+	/// Search for the first generation that runs longest under a rule before
+	/// falling into a cycle.
+	Transient(TransientArguments),
+
+	/// Simulate many rules from many random first generations, and report
+	/// statistics for each rule as JSON or CSV.
+	Batch(BatchArguments),
+
+	/// Measure how quickly an automaton evolves.
+	Bench(BenchArguments),
+
+	/// Export the evolution of an automaton as CSV or as a Wolfram Language
+	/// expression.
+	Export(ExportArguments)
+}
+
+/// The arguments that govern the interactive evolver.
 #[derive(Debug, Default)]
-#[cfg_attr(not(target_family = "wasm"), derive(Parser))]
+#[cfg_attr(not(target_family = "wasm"), derive(Args))]
 struct Arguments
 {
-	/// The rule, specified as a Wolfram code between 0 and 255, inclusive. If
-	/// unspecified, the rule will be chosen randomly.
+	/// The rule, specified as a Wolfram code between 0 and 255, inclusive, in
+	/// decimal, binary (`0b01101110`), or hex (`0x6E`), or by the name of a
+	/// well-known rule, e.g., `sierpinski`. If unspecified, the rule will be
+	/// chosen randomly.
 	#[cfg_attr(not(target_family = "wasm"), arg(short, long))]
-	rule: Option<u8>,
+	rule: Option<AutomatonRule>,
+
+	/// Several rules, specified as a comma-separated list of Wolfram codes or
+	/// names, that the evolver applies in rotation, one per generation.
+	/// Supersedes `rule`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_delimiter = ',', conflicts_with = "rule")
+	)]
+	rules: Vec<AutomatonRule>,
+
+	/// A Rhai script that defines the rule, either cell by cell as
+	/// `next_cell(left, center, right)` or generation by generation as
+	/// `next_row(cells)`. The script is reloaded whenever it changes.
+	/// Supersedes `rule` and `rules`. Available only with the `scripting`
+	/// feature.
+	#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+	#[arg(long, conflicts_with_all = ["rule", "rules", "compare", "diff"])]
+	script: Option<PathBuf>,
+
+	/// A WebAssembly module that defines the rule, either cell by cell as
+	/// `next_cell(left, center, right)` or generation by generation as
+	/// `next_row(len)`. Supersedes `rule`, `rules`, and `script`. Available
+	/// only with the `wasm-rules` feature.
+	#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+	#[arg(long, conflicts_with_all = ["rule", "rules", "compare", "diff"])]
+	wasm_rule: Option<PathBuf>,
 
 	/// The first generation, specified as a 64-bit integer that represents the
 	/// complete population. Lower numbered bits correspond to cells on the
 	/// right of the visualization. If unspecified, the first generation will be
 	/// chosen randomly.
 	#[cfg_attr(not(target_family = "wasm"), arg(short, long))]
-	seed: Option<u64>
+	seed: Option<u64>,
+
+	/// The first generation, specified as a string of hexadecimal digits of
+	/// any length, optionally prefixed by `0x`, so that automata wider than 64
+	/// cells can be seeded completely. As with `seed`, the last digit supplies
+	/// the cells on the right. Underscores are ignored. For binary digits, use
+	/// `pattern`. Supersedes `seed`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(
+			long,
+			value_name = "HEX",
+			value_parser = Automaton::<AUTOMATON_LENGTH>::from_hex,
+			conflicts_with_all = [
+				"seed", "pattern", "seed_image", "compare_seeds"
+			]
+		)
+	)]
+	seed_hex: Option<Automaton>,
+
+	/// Make random first generations mirror-symmetric, including those chosen
+	/// by `reseed`, so that rules that are their own mirror images, e.g., rule
+	/// 90, grow symmetric fractals. Press `Y` to make the newest generation
+	/// symmetric at runtime.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	symmetric: bool,
+
+	/// The first generation, drawn as a pattern of glyphs from left to right:
+	/// `X`, `1`, or `#` for a live cell, and `•`, `0`, or `.` for a dead cell,
+	/// e.g., `#..##.#`. Shorter patterns are padded on the left with dead
+	/// cells. Supersedes `seed`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, conflicts_with_all = ["seed", "seed_image", "compare_seeds"])
+	)]
+	pattern: Option<Automaton>,
+
+	/// An image whose pixels supply the first generation. One row of the image
+	/// is converted to grayscale, resampled to the width of the automaton, and
+	/// thresholded, so that dark pixels come alive. Supersedes `seed`.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(
+		long,
+		value_name = "PATH",
+		conflicts_with_all = ["seed", "compare_seeds"]
+	)]
+	seed_image: Option<PathBuf>,
+
+	/// The row of the `seed_image` to use, counting from 0 at the top. Defaults
+	/// to the middle row.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, requires = "seed_image")]
+	row: Option<u32>,
+
+	/// Several rules, specified as a comma-separated list of Wolfram codes or
+	/// names. Each rule evolves the same first generation in its own pane,
+	/// side by side with the others. Supersedes `rule` and `rules`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_delimiter = ',', conflicts_with_all = ["rule", "rules"])
+	)]
+	compare: Vec<AutomatonRule>,
+
+	/// Several first generations, specified as a comma-separated list of
+	/// 64-bit integers. Each first generation evolves under the same rules in
+	/// its own pane, side by side with the others. Supersedes `seed`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(
+			long,
+			value_delimiter = ',',
+			conflicts_with_all = ["seed", "compare"]
+		)
+	)]
+	compare_seeds: Vec<u64>,
+
+	/// Exactly two rules, specified as a comma-separated pair of Wolfram
+	/// codes or names. Each rule evolves the same first generation in its own
+	/// pane, and a third pane highlights the cells where the two evolutions
+	/// disagree.
+	/// Supersedes `rule`, `rules`, `compare`, and `compare_seeds`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(
+			long,
+			value_delimiter = ',',
+			num_args = 2,
+			conflicts_with_all = ["rule", "rules", "compare", "compare_seeds"]
+		)
+	)]
+	diff: Vec<AutomatonRule>,
+
+	/// Rules for particular columns, specified as a comma-separated list of
+	/// assignments, each a column or an inclusive range of columns, counting
+	/// from 0 at the left, then a colon and a rule, e.g.,
+	/// `0-31:90,32-63:30`. Mapped columns evolve under their own rules, and
+	/// the rest under the schedule, so that two dynamics can meet side by side.
+	/// Every pane receives the same map. Press `X` to paint rules onto columns
+	/// at runtime.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "MAP"))]
+	rule_map: Option<RuleMap>,
+
+	/// A file that assigns rules to ranges of generations, separated by commas
+	/// or newlines, each a generation or an inclusive range of generations,
+	/// then a colon and a rule, e.g., `0-99: 30, 100-199: 110, 200-: 90`. A
+	/// range without an end never ends. As the evolution enters each range,
+	/// its rule replaces the schedule; between ranges, the schedule keeps
+	/// whatever rules it has. Every pane follows the same timeline, and the
+	/// generation counter counts down to the next switch.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_name = "FILE", value_parser = read_timeline)
+	)]
+	timeline: Option<RuleTimeline>,
+
+	/// The musical scale used to sonify the evolution: `pentatonic`, `major`,
+	/// `minor`, or `chromatic`. Defaults to `pentatonic`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	scale: Option<Scale>,
+
+	/// The volume of the sonification, between 0 and 1, inclusive. Defaults to
+	/// 0.5.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	volume: Option<f32>,
+
+	/// Start with the sonification muted.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	mute: bool,
+
+	/// Mute the brief sounds that acknowledge toggling cells, committing
+	/// rules, and pausing or resuming.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	mute_feedback: bool,
+
+	/// The interval between successive generations, in milliseconds. An
+	/// interval shorter than a frame evolves several generations per frame.
+	/// Defaults to 250.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "MILLIS"))]
+	heartbeat: Option<u64>,
+
+	/// The intervals between successive generations of individual panes,
+	/// specified as a comma-separated list of milliseconds, one per pane, from
+	/// left to right. A pane whose interval is 0, or that lies beyond the end
+	/// of the list, keeps the shared `heartbeat`.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_delimiter = ',', value_name = "MILLIS")
+	)]
+	heartbeats: Vec<u64>,
+
+	/// Start evolving immediately, rather than paused with the instructions
+	/// showing.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	run: bool,
+
+	/// The maximum number of generations to keep for scrolling back through
+	/// the evolution. If unspecified, every generation is kept.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_name = "GENERATIONS")
+	)]
+	scrollback: Option<usize>,
+
+	/// Screensaver mode: while running, switch every pane to a new random rule
+	/// every so many seconds.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "SECONDS"))]
+	cycle: Option<f32>,
+
+	/// In screensaver mode, also reseed every pane with a random first
+	/// generation at each switch.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, requires = "cycle"))]
+	reseed: bool,
+
+	/// Draw random rules, including those chosen by `cycle`, uniformly from
+	/// the 88 equivalence classes under mirroring and complementation, rather
+	/// than from all 256 rules, and name the class of the active rule beneath
+	/// its bits. A new rule thereby never merely reflects or inverts another.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	classes: bool,
+
+	/// The shape of each cell: `square`, `circle`, or `hexagon`. Hexagonal
+	/// cells offset every other row by half a cell. Defaults to `square`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "SHAPE"))]
+	cell_shape: Option<CellShape>,
+
+	/// The gridlines between cells: `bitmap` for neither gaps nor borders,
+	/// `fine` for narrow gaps and thin borders, or `strong` for wide gaps.
+	/// Defaults to `fine`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "STYLE"))]
+	grid: Option<Gridlines>,
+
+	/// The maximum number of frames to render per second, independent of the
+	/// pace of the evolution. If unspecified, the frame rate is uncapped.
+	/// Available for native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FPS")]
+	fps_cap: Option<f32>,
+
+	/// Run as a live wallpaper: a borderless, click-through window that fills
+	/// the screen and stays on top of every other window, evolving slowly and
+	/// silently in muted colors. The heartbeat defaults to 1000 and the palette
+	/// to `muted`, but either may be chosen explicitly. Available for native
+	/// builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long)]
+	ambient: bool,
+
+	/// Render dead cells and the chrome around them fully transparent, in a
+	/// window with an alpha channel, so that streaming software can overlay
+	/// the evolution upon other content. Available for native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long)]
+	transparent: bool,
+
+	/// Don't synchronize frames with the display's refresh rate.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	no_vsync: bool,
+
+	/// The initial palette: `classic`, `deuteranopia`, `protanopia`,
+	/// `high-contrast`, or `muted`. Press `P` to switch palettes at runtime.
+	/// Defaults to `classic`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "SCHEME"))]
+	palette: Option<PaletteScheme>,
+
+	/// The language of the user interface, specified by its two-letter code:
+	/// `en`, `es`, `fr`, or `de`. Defaults to `en`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "CODE"))]
+	lang: Option<Language>,
+
+	/// Start with the growing tape shown, on which the focused automaton grows
+	/// without bound. Press `W` to show or hide the tape at runtime.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	grow: bool,
+
+	/// In slow motion, while `Z` is held, reveal each new generation cell by
+	/// cell, from left to right, to show the rule scanning the previous
+	/// generation.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	scan: bool,
+
+	/// How each new generation arrives: `snap` at once, `fade` in from the
+	/// dead color, or `slide` in from below as every row scrolls upward
+	/// smoothly over the heartbeat. Defaults to `snap`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "STYLE"))]
+	transition: Option<Transition>,
+
+	/// Render like the phosphor of a cathode-ray tube: cells that go dark fade
+	/// out over several frames, leaving trails behind moving patterns.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	phosphor: bool,
+
+	/// The shader with which to render square cells: `flat` for plain color,
+	/// `glow` for soft glowing dots, `rounded` for rounded corners, or
+	/// `scanlines` for the horizontal lines of an old monitor. Defaults to
+	/// `flat`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "STYLE"))]
+	style: Option<CellStyle>,
+
+	/// Start in neon: live cells glow vividly against darkness, haloed by
+	/// bloom. Press `D` to toggle neon at runtime.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	neon: bool,
+
+	/// What to simulate: `elementary` for elementary cellular automata, or
+	/// `ant` for Langton's ants, which wander the whole grid. Defaults to
+	/// `elementary`.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	mode: Option<Mode>,
+
+	/// In ant mode, the turns that the ants make, one letter per state of a
+	/// cell: `L` for left, `R` for right, `N` for none, or `U` for a U-turn,
+	/// e.g., `LLRR`. Defaults to `RL`, the turns of Langton's original ant.
+	#[cfg_attr(not(target_family = "wasm"), arg(long))]
+	turns: Option<Turns>,
+
+	/// In ant mode, the number of ants, which start out evenly spaced across
+	/// the middle row. Defaults to 1.
+	#[cfg_attr(not(target_family = "wasm"), arg(long, value_name = "COUNT"))]
+	ants: Option<usize>,
+
+	/// The number of generations that a dying cell takes to vanish, after the
+	/// fashion of "Generations" rules. A decaying cell fades from the live
+	/// color toward the dead color, and cannot be reborn until it vanishes,
+	/// e.g., `1` gives the dying cells of Brian's Brain. Defaults to 0, i.e.,
+	/// dying cells vanish at once.
+	#[cfg_attr(
+		not(target_family = "wasm"),
+		arg(long, value_name = "GENERATIONS")
+	)]
+	decay: Option<u8>,
+
+	/// A TOML file that supplies defaults for the rule, the seed, the
+	/// heartbeat, the scrollback, the palette, and the key bindings. Arguments
+	/// given on the command line take precedence. Defaults to
+	/// `cellular-automata.toml` in the working directory, if it exists.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FILE")]
+	config: Option<PathBuf>,
+
+	/// The format in which `E` and the gallery export generations: `csv`, or
+	/// `wolfram` for a Wolfram Language expression that Mathematica can
+	/// evaluate. Defaults to `csv`.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FORMAT")]
+	export_format: Option<ExportFormat>,
+
+	/// The width and height of the posters rendered by `F12`, in pixels, as
+	/// `<WIDTH>x<HEIGHT>`, up to 16384 each. Posters are rendered offscreen,
+	/// so they may be far larger than the window. Defaults to `8192x6400`.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "SIZE")]
+	poster_size: Option<PosterSize>,
+
+	/// The number of generations between the automatic checkpoints of each
+	/// pane, to which `Home` jumps back, or `0` to take none. Defaults to
+	/// 1000.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "GENERATIONS")]
+	checkpoint_interval: Option<usize>,
+
+	/// Spill the oldest checkpoints into the specified directory, creating it
+	/// if necessary, rather than forgetting them once too many accumulate in
+	/// memory, so that even very long runs can jump back to their beginning.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "DIR")]
+	checkpoint_dir: Option<PathBuf>,
+
+	/// Record every user action, with timestamps, into the specified file. If
+	/// the file ends with `.car`, then the recording is compact binary;
+	/// otherwise, it is text.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FILE")]
+	record: Option<PathBuf>,
+
+	/// Replay the user actions recorded in the specified file. The recording
+	/// includes the first generation and the rules, so other options that
+	/// choose them are unnecessary.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FILE", conflicts_with = "record")]
+	replay: Option<PathBuf>,
+
+	/// Append every generation of every pane to the specified binary log file,
+	/// creating it if necessary, so that long runs can later be re-rendered,
+	/// scrubbed, or analyzed without simulating them again.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "FILE")]
+	history_log: Option<PathBuf>,
+
+	/// Accept commands from the specified source while running, so that other
+	/// programs and scripts can drive the evolver. With `stdin`, read one
+	/// command per line from standard input: `rule <RULE>`, `pause`,
+	/// `resume`, `seed <SEED>`, or `speed <GENERATIONS_PER_SECOND>`. Available
+	/// for native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "SOURCE")]
+	control: Option<ControlSource>,
+
+	/// Accept commands over HTTP at the specified address, e.g.,
+	/// `0.0.0.0:8080`, so that a phone or an automation script can drive the
+	/// evolver remotely: `POST /rule`, `/pause`, `/resume`, `/seed`, or
+	/// `/speed`, with the argument as the body, or `GET /state`. `GET /`
+	/// answers a page of controls. Available only with the `http` feature.
+	#[cfg(all(feature = "http", not(target_family = "wasm")))]
+	#[arg(long, value_name = "ADDRESS")]
+	http: Option<String>,
+
+	/// Accept Open Sound Control messages over UDP at the specified address,
+	/// e.g., `0.0.0.0:9000`: `/rule <RULE>`, `/pause`, `/resume`,
+	/// `/seed <SEED>`, or `/speed <GENERATIONS_PER_SECOND>`. Available for
+	/// native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "ADDRESS")]
+	osc_listen: Option<String>,
+
+	/// Send an Open Sound Control message over UDP to the specified address,
+	/// e.g., `127.0.0.1:57120`, for every new generation of every pane:
+	/// `/population <PANE> <INDEX> <POPULATION> <DENSITY>`. Available for
+	/// native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "ADDRESS")]
+	osc_send: Option<String>,
+
+	/// Host a collaborative session at the specified address, e.g.,
+	/// `0.0.0.0:7000`, so that other instances can `join` it and share the
+	/// simulation. Guests may pause and resume at any time, but may only edit
+	/// while the evolution is paused. Available for native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long, value_name = "ADDRESS", conflicts_with = "replay")]
+	host: Option<String>,
+
+	/// Join the collaborative session hosted at the specified address, e.g.,
+	/// `192.168.1.20:7000`. The host supplies the panes' first generations and
+	/// rules, but not their number, so run with the same number of panes as
+	/// the host. Available for native builds only.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(
+		long,
+		value_name = "ADDRESS",
+		conflicts_with_all = ["host", "replay", "record"]
+	)]
+	join: Option<String>,
+
+	/// A MIDI output port, specified by index or by some part of its name.
+	/// Each generation sends a note-on for every cell born and a note-off for
+	/// every cell that died. Available only with the `midi` feature.
+	#[cfg(all(feature = "midi", not(target_family = "wasm")))]
+	#[arg(long)]
+	midi_port: Option<String>,
+
+	/// A serial port, e.g., `/dev/ttyUSB0`, through which to drive a 64-LED
+	/// strip or matrix from a microcontroller. Whenever the newest generation
+	/// of the focused pane changes, it is sent as an 11-byte frame:
+	/// `0xA5 0x5A`, eight bytes of cells, from left to right, most significant
+	/// bit first, then the exclusive-or of those eight bytes. Available only
+	/// with the `serial` feature.
+	#[cfg(all(feature = "serial", not(target_family = "wasm")))]
+	#[arg(long, value_name = "PORT")]
+	serial: Option<String>,
+
+	/// The baud rate of the `serial` port. Defaults to `115200`. Available only
+	/// with the `serial` feature.
+	#[cfg(all(feature = "serial", not(target_family = "wasm")))]
+	#[arg(long, value_name = "BAUD", requires = "serial")]
+	serial_baud: Option<u32>,
+
+	/// Publish every new generation and every rule change over WebSocket at
+	/// the specified URL, e.g., `ws://0.0.0.0:9000`, so that dashboards or a
+	/// second display can mirror the simulation live. Clients that connect
+	/// midway first receive the latest generation and rules of every pane.
+	/// Available only with the `broadcast` feature.
+	#[cfg(all(feature = "broadcast", not(target_family = "wasm")))]
+	#[arg(long, value_name = "URL")]
+	broadcast: Option<String>,
+
+	/// The encoding of each generation published by `broadcast`: `json` for
+	/// text frames that hold JSON objects, or `binary` for binary frames.
+	/// Defaults to `json`. Available only with the `broadcast` feature.
+	#[cfg(all(feature = "broadcast", not(target_family = "wasm")))]
+	#[arg(long, value_name = "FORMAT", requires = "broadcast")]
+	broadcast_format: Option<BroadcastFormat>,
+
+	/// Connect to the MQTT broker at the specified URL, e.g.,
+	/// `mqtt://raspberrypi.local:1883`, publish every new generation of every
+	/// pane as a bit string, and accept commands on the control topic.
+	/// Available only with the `mqtt` feature.
+	#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+	#[arg(long, value_name = "URL")]
+	mqtt: Option<String>,
+
+	/// The topic beneath which `mqtt` publishes generations, each pane to
+	/// `<TOPIC>/<PANE>`. Defaults to `cellular-automata/generation`. Available
+	/// only with the `mqtt` feature.
+	#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+	#[arg(long, value_name = "TOPIC", requires = "mqtt")]
+	mqtt_topic: Option<String>,
+
+	/// The topic on which `mqtt` accepts commands, one per line: `rule <RULE>`,
+	/// `pause`, `resume`, `seed <SEED>`, or `speed <GENERATIONS_PER_SECOND>`.
+	/// Defaults to `cellular-automata/control`. Available only with the `mqtt`
+	/// feature.
+	#[cfg(all(feature = "mqtt", not(target_family = "wasm")))]
+	#[arg(long, value_name = "TOPIC", requires = "mqtt")]
+	mqtt_control_topic: Option<String>,
+
+	/// List the names of the well-known rules, then exit.
+	#[cfg(not(target_family = "wasm"))]
+	#[arg(long)]
+	list_rules: bool
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 //                         Reading program arguments.                         //
 ////////////////////////////////////////////////////////////////////////////////
 
-/// Read the program [arguments](Arguments) from the command line. Available for
-/// native builds only.
+/// Read the program [arguments](Arguments) from the command line. If they name
+/// a headless [command](Command), then carry it out and exit instead, unless
+/// the command asks to open the evolver afterward. Available for native builds
+/// only.
 #[cfg(not(target_family = "wasm"))]
 fn arguments() -> Option<Arguments>
 {
-	Some(Arguments::parse())
+	let cli = Cli::parse();
+	let executed = match cli.command
+	{
+		None => return Some(cli.run),
+		Some(Command::Run(args)) => return Some(args),
+		Some(Command::Render(ref args)) => args.execute(),
+		Some(Command::Search(ref args)) => args.execute(),
+		Some(Command::Transient(ref args)) => match args.execute()
+		{
+			// Install the champion in the evolver.
+			Ok(Some((rule, seed))) => return Some(Arguments {
+				rule: Some(rule),
+				seed: Some(seed),
+				..Arguments::default()
+			}),
+			executed => executed.map(|_| ())
+		},
+		Some(Command::Batch(ref args)) => args.execute(),
+		Some(Command::Bench(ref args)) => args.execute(),
+		Some(Command::Export(ref args)) => args.execute()
+	};
+	match executed
+	{
+		Ok(()) => std::process::exit(0),
+		Err(e) =>
+		{
+			eprintln!("{}", e);
+			std::process::exit(1)
+		}
+	}
+}
+
+/// Read a [timeline](RuleTimeline) from the file at the specified path.
+/// Available for native builds only.
+#[cfg(not(target_family = "wasm"))]
+fn read_timeline(path: &str) -> Result<RuleTimeline, String>
+{
+	std::fs::read_to_string(path)
+		.map_err(|e| format!("{}: {}", path, e))?
+		.parse()
+		.map_err(|e| format!("{}: {}", path, e))
+}
+
+/// Print the table of [rule&#32;aliases](RULE_ALIASES), one per line.
+/// Available for native builds only.
+#[cfg(not(target_family = "wasm"))]
+fn list_rules()
+{
+	for (alias, code, description) in RULE_ALIASES
+	{
+		println!("{:<16} {:>3}  {}", alias, code, description);
+	}
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Arguments
+{
+	/// Answer the arguments, with every argument left unspecified on the
+	/// command line filled in from the specified [configuration](Config), so
+	/// that the command line takes precedence. Rules and seeds are taken from
+	/// the configuration only if the command line doesn't choose any.
+	fn merge(mut self, config: &Config) -> Self
+	{
+		if self.rule.is_none() && self.rules.is_empty()
+		{
+			self.rule = config.rule.map(AutomatonRule::from);
+			self.rules = config.rules.iter()
+				.map(|&rule| AutomatonRule::from(rule))
+				.collect();
+		}
+		if self.seed.is_none()
+			&& self.seed_hex.is_none()
+			&& self.pattern.is_none()
+			&& self.seed_image.is_none()
+		{
+			self.seed = config.seed;
+		}
+		self.heartbeat = self.heartbeat.or(config.heartbeat);
+		self.scrollback = self.scrollback.or(config.scrollback);
+		self.palette = self.palette.or(config.palette_scheme());
+		self
+	}
 }
 
 /// Read the program [arguments](Arguments) from the search parameters within
-/// the query string. Available for WASM builds only.
+/// the query string. Absent any search parameters, read them instead from the
+/// [settings](SETTINGS_STORAGE_KEY) persisted during the last visit, if any.
+/// Available for WASM builds only.
 #[cfg(target_family = "wasm")]
 fn arguments() -> Option<Arguments>
 {
-	let href = web_sys::window()?.location().href().ok()?;
+	let window = web_sys::window()?;
+	let href = window.location().href().ok()?;
 	let url = web_sys::Url::new(&href).ok()?;
-	let params = url.search_params();
+	let params = match url.search().is_empty()
+	{
+		true => window.local_storage().ok().flatten()
+			.and_then(|storage| {
+				storage.get_item(SETTINGS_STORAGE_KEY).ok().flatten()
+			})
+			.and_then(|settings| {
+				web_sys::UrlSearchParams::new_with_str(&settings).ok()
+			})
+			.unwrap_or_else(|| url.search_params()),
+		false => url.search_params()
+	};
 	let rule = params.get("rule").and_then(|rule| rule.parse().ok());
+	let rules = params.get("rules")
+		.and_then(|rules| {
+			rules.split(',').map(|rule| rule.parse().ok()).collect()
+		})
+		.unwrap_or_default();
 	let seed = params.get("seed").and_then(|seed| seed.parse().ok());
-	Some(Arguments { rule, seed })
+	let seed_hex = params.get("seed_hex")
+		.and_then(|seed| Automaton::from_hex(&seed).ok());
+	let symmetric = params.get("symmetric").is_some();
+	let pattern = params.get("pattern")
+		.and_then(|pattern| pattern.parse().ok());
+	let compare = params.get("compare")
+		.and_then(|rules| {
+			rules.split(',').map(|rule| rule.parse().ok()).collect()
+		})
+		.unwrap_or_default();
+	let compare_seeds = params.get("compare_seeds")
+		.and_then(|seeds| {
+			seeds.split(',').map(|seed| seed.parse().ok()).collect()
+		})
+		.unwrap_or_default();
+	let diff = params.get("diff")
+		.and_then(|rules| {
+			rules.split(',').map(|rule| rule.parse().ok()).collect()
+		})
+		.filter(|rules: &Vec<AutomatonRule>| rules.len() == 2)
+		.unwrap_or_default();
+	let rule_map = params.get("rule_map").and_then(|map| map.parse().ok());
+	let timeline = params.get("timeline")
+		.and_then(|timeline| timeline.parse().ok());
+	let scale = params.get("scale").and_then(|scale| scale.parse().ok());
+	let volume = params.get("volume").and_then(|volume| volume.parse().ok());
+	let mute = params.get("mute").is_some();
+	let mute_feedback = params.get("mute_feedback").is_some();
+	// A speed, in generations per second, stands in for a missing heartbeat.
+	let heartbeat = params.get("heartbeat")
+		.and_then(|heartbeat| heartbeat.parse().ok())
+		.or_else(|| {
+			params.get("speed")
+				.and_then(|speed| speed.parse::<f64>().ok())
+				.filter(|speed| speed.is_finite() && *speed > 0.0)
+				.map(|speed| (1000.0 / speed).round().max(1.0) as u64)
+		});
+	let heartbeats = params.get("heartbeats")
+		.and_then(|heartbeats| {
+			heartbeats.split(',').map(|millis| millis.parse().ok()).collect()
+		})
+		.unwrap_or_default();
+	let run = params.get("run").is_some()
+		|| params.get("paused")
+			.is_some_and(|paused| matches!(paused.as_str(), "false" | "0"));
+	let scrollback = params.get("scrollback")
+		.or_else(|| params.get("history"))
+		.and_then(|scrollback| scrollback.parse().ok());
+	let cycle = params.get("cycle").and_then(|cycle| cycle.parse().ok());
+	let reseed = params.get("reseed").is_some();
+	let classes = params.get("classes").is_some();
+	let cell_shape = params.get("cell_shape")
+		.and_then(|shape| shape.parse().ok());
+	let grid = params.get("grid").and_then(|grid| grid.parse().ok());
+	let no_vsync = params.get("no_vsync").is_some();
+	let palette = params.get("palette")
+		.and_then(|palette| palette.parse().ok());
+	// Absent an explicit choice, follow the browser's preferred language.
+	let lang = params.get("lang")
+		.or_else(|| web_sys::window()?.navigator().language())
+		.and_then(|lang| lang.parse().ok());
+	let grow = params.get("grow").is_some();
+	let scan = params.get("scan").is_some();
+	let transition = params.get("transition")
+		.and_then(|transition| transition.parse().ok());
+	let phosphor = params.get("phosphor").is_some();
+	let style = params.get("style").and_then(|style| style.parse().ok());
+	let neon = params.get("neon").is_some();
+	let mode = params.get("mode").and_then(|mode| mode.parse().ok());
+	let turns = params.get("turns").and_then(|turns| turns.parse().ok());
+	let ants = params.get("ants").and_then(|ants| ants.parse().ok());
+	let decay = params.get("decay").and_then(|decay| decay.parse().ok());
+	if let Err(e) = check_fixed_parameters(&params)
+	{
+		// Refuse to start, just as for an invalid configuration file.
+		web_sys::console::error_1(&e.into());
+		std::process::exit(1)
+	}
+	Some(Arguments {
+		rule,
+		rules,
+		seed,
+		seed_hex,
+		symmetric,
+		pattern,
+		compare,
+		compare_seeds,
+		diff,
+		rule_map,
+		timeline,
+		scale,
+		volume,
+		mute,
+		mute_feedback,
+		heartbeat,
+		heartbeats,
+		run,
+		scrollback,
+		cycle,
+		reseed,
+		classes,
+		cell_shape,
+		grid,
+		no_vsync,
+		palette,
+		lang,
+		grow,
+		scan,
+		transition,
+		phosphor,
+		style,
+		neon,
+		mode,
+		turns,
+		ants,
+		decay
+	})
 }
+
+/// Check the `width` and `boundary` search parameters, which embedding pages
+/// may supply to describe the complete setup. Neither can vary: the width is
+/// fixed at [AUTOMATON_LENGTH] when the application is built, and the two ends
+/// of every automaton are always adjacent, i.e., the boundary wraps. Answer
+/// an error that describes the first other value, since the page would
+/// otherwise show a different setup than the one that it asked for. Available
+/// for WASM builds only.
+#[cfg(target_family = "wasm")]
+fn check_fixed_parameters(
+	params: &web_sys::UrlSearchParams
+) -> Result<(), String>
+{
+	if let Some(width) = params.get("width")
+	{
+		if width.parse::<usize>().ok() != Some(AUTOMATON_LENGTH)
+		{
+			return Err(format!(
+				"unsupported width={}: this build is {} cells wide",
+				width,
+				AUTOMATON_LENGTH
+			))
+		}
+	}
+	if let Some(boundary) = params.get("boundary")
+	{
+		if !matches!(boundary.as_str(), "wrap" | "periodic")
+		{
+			return Err(format!(
+				"unsupported boundary={}: the only boundary is `wrap`",
+				boundary
+			))
+		}
+	}
+	Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The default heartbeat in [ambient](Arguments::ambient) mode, slow enough to
+/// watch from the corner of an eye.
+const AMBIENT_HEARTBEAT: Duration = Duration::from_millis(1000);
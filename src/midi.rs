@@ -0,0 +1,254 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, DetectChanges, Entity, NonSendMut, Plugin, Query, Ref, Update, With
+};
+use midir::{ConnectError, InitError, MidiOutput, MidiOutputConnection};
+
+use crate::automata::{Automaton, AUTOMATON_LENGTH, History};
+use crate::ecs::Focused;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for driving a MIDI device from the
+/// evolution of the [focused](Focused)&#32;[automaton](Automaton). Whenever the
+/// newest generation changes, each cell that was born sends a note-on event and
+/// each cell that died sends a note-off event, with pitches rising from the
+/// left edge of the automaton to the right edge. A [MidiSink] must already have
+/// been inserted as a non-[Send] resource.
+pub struct MidiPlugin;
+
+impl Plugin for MidiPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		assert!(
+			app.world.get_non_send_resource::<MidiSink>().is_some(),
+			"MidiSink resource to be inserted already"
+		);
+		app.add_systems(Update, send_births_and_deaths);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The connection to a MIDI output port, along with the generation most
+/// recently sent to it. Because a [connection](MidiOutputConnection) cannot be
+/// shared between threads, this must be inserted as a non-[Send] resource.
+pub struct MidiSink
+{
+	/// The connection to the MIDI output port.
+	connection: MidiOutputConnection,
+
+	/// The [pane](Focused) entity whose generation was most recently sent,
+	/// along with that generation, if any.
+	previous: Option<(Entity, Automaton)>
+}
+
+impl MidiSink
+{
+	/// Connect to the MIDI output port designated by `port`, which is either
+	/// the index of a port or some substring of its name.
+	pub fn connect(port: &str) -> Result<Self, MidiError>
+	{
+		let output = MidiOutput::new(CLIENT_NAME)?;
+		let ports = output.ports();
+		let names = ports.iter()
+			.map(|port| output.port_name(port).unwrap_or_default())
+			.collect::<Vec<_>>();
+		let index = port.parse::<usize>().ok()
+			.filter(|index| *index < ports.len())
+			.or_else(|| names.iter().position(|name| name.contains(port)));
+		match index
+		{
+			Some(index) =>
+			{
+				let connection = output.connect(&ports[index], CLIENT_NAME)?;
+				Ok(Self { connection, previous: None })
+			},
+			None => Err(MidiError::NoSuchPort(port.to_string(), names))
+		}
+	}
+
+	/// Send the births and deaths that separate the previously sent generation
+	/// from the specified generation of the specified pane. If the pane has
+	/// changed, then silence the old pane before treating every occupied cell
+	/// of the new pane as a birth.
+	fn send(&mut self, pane: Entity, automaton: &Automaton)
+	{
+		let previous = match self.previous
+		{
+			Some((entity, previous)) if entity == pane => previous,
+			Some(_) =>
+			{
+				self.silence();
+				Automaton::new()
+			},
+			None => Automaton::new()
+		};
+		for i in 0 .. AUTOMATON_LENGTH
+		{
+			let status = match (previous[i], automaton[i])
+			{
+				(false, true) => NOTE_ON,
+				(true, false) => NOTE_OFF,
+				_ => continue
+			};
+			self.transmit(&[status, note(AUTOMATON_LENGTH - i - 1), VELOCITY]);
+		}
+		self.previous = Some((pane, *automaton));
+	}
+
+	/// Turn off every note on the channel.
+	fn silence(&mut self)
+	{
+		self.transmit(&[CONTROL_CHANGE, ALL_NOTES_OFF, 0]);
+		self.previous = None;
+	}
+
+	/// Transmit a single MIDI message, logging any failure.
+	fn transmit(&mut self, message: &[u8])
+	{
+		if let Err(e) = self.connection.send(message)
+		{
+			warn!("MIDI send failed: {}", e);
+		}
+	}
+}
+
+impl Drop for MidiSink
+{
+	/// Don't leave notes hanging on the device after the application exits.
+	fn drop(&mut self)
+	{
+		self.silence();
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why a [MidiSink] may fail to connect.
+#[derive(Debug)]
+pub enum MidiError
+{
+	/// The MIDI subsystem could not be initialized.
+	Init(InitError),
+
+	/// No port matched the request. Carries the request and the names of the
+	/// available ports.
+	NoSuchPort(String, Vec<String>),
+
+	/// The port could not be opened.
+	Connect(ConnectError<MidiOutput>)
+}
+
+impl From<InitError> for MidiError
+{
+	fn from(value: InitError) -> Self
+	{
+		MidiError::Init(value)
+	}
+}
+
+impl From<ConnectError<MidiOutput>> for MidiError
+{
+	fn from(value: ConnectError<MidiOutput>) -> Self
+	{
+		MidiError::Connect(value)
+	}
+}
+
+impl Display for MidiError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			MidiError::Init(e) => write!(f, "cannot initialize MIDI: {}", e),
+			MidiError::NoSuchPort(port, names) =>
+			{
+				write!(f, "no MIDI output port matches '{}'", port)?;
+				if names.is_empty()
+				{
+					write!(f, "; no ports are available")
+				}
+				else
+				{
+					write!(f, "; available ports are:")?;
+					for (i, name) in names.iter().enumerate()
+					{
+						write!(f, "\n  {}: {}", i, name)?;
+					}
+					Ok(())
+				}
+			},
+			MidiError::Connect(e) => write!(f, "cannot open MIDI port: {}", e)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whenever the [history](History) of the [focused](Focused) pane changes,
+/// send the births and deaths of its [newest](History::newest) generation to
+/// the [MidiSink].
+fn send_births_and_deaths(
+	history: Query<(Entity, Ref<History>), With<Focused>>,
+	mut sink: NonSendMut<MidiSink>
+) {
+	if let Ok((pane, history)) = history.get_single()
+	{
+		if history.is_changed()
+		{
+			sink.send(pane, history.newest());
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the MIDI note number for the specified column, counting from the
+/// left edge of the automaton. Columns beyond the range of MIDI notes share
+/// the highest note.
+#[inline]
+fn note(column: usize) -> u8
+{
+	(LOWEST_NOTE as usize + column).min(127) as u8
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name under which this application registers with the MIDI subsystem.
+const CLIENT_NAME: &str = "cellular-automata";
+
+/// The MIDI note number for the leftmost column: C2.
+const LOWEST_NOTE: u8 = 36;
+
+/// The velocity of every note.
+const VELOCITY: u8 = 100;
+
+/// The status byte for a note-on event on channel 1.
+const NOTE_ON: u8 = 0x90;
+
+/// The status byte for a note-off event on channel 1.
+const NOTE_OFF: u8 = 0x80;
+
+/// The status byte for a control change event on channel 1.
+const CONTROL_CHANGE: u8 = 0xB0;
+
+/// The controller number for "all notes off".
+const ALL_NOTES_OFF: u8 = 123;
@@ -0,0 +1,385 @@
+use bevy::prelude::{
+	App, Assets, BorderColor, BuildChildren, Color, Commands, Component,
+	default, DespawnRecursiveExt, DetectChanges, Entity, EventWriter,
+	GlobalTransform, Handle, Image, ImageBundle, Input, Interaction,
+	IntoSystemConfigs, KeyCode, Node, NodeBundle, Plugin, PositionType, Query,
+	Ref, Res, ResMut, Resource, Style, UiImage, UiRect, Update, Val, Window,
+	With, ZIndex
+};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+use bevy::render::texture::ImageSampler;
+use bevy::ui::FocusPolicy;
+
+use crate::automata::{AUTOMATON_HISTORY, AUTOMATON_LENGTH, Scrollback};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, EvolutionTimer, Focused, Palette,
+	ScrollOffset, TOAST_Z_INDEX, update_scroll
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the minimap, which pictures the whole
+/// [scrollback](Scrollback) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane) in the corner of the
+/// window, and marks the generations currently in view with a rectangle. While
+/// the evolver is paused, pressing the minimap [scrolls](ScrollOffset) the
+/// view to the generation beneath the mouse. The [Minimap] resource governs
+/// whether the minimap is shown.
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Minimap>()
+			.add_systems(Update, maybe_toggle_minimap)
+			.add_systems(Update, maybe_jump.before(update_scroll))
+			.add_systems(
+				Update,
+				update_minimap
+					.after(maybe_toggle_minimap)
+					.after(maybe_jump)
+					.after(apply_actions)
+					.after(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the minimap is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Minimap(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The frame of the minimap, which holds its [picture](MinimapPicture) and
+/// its [viewport](MinimapViewport), and receives presses of the mouse.
+#[derive(Component)]
+struct MinimapFrame;
+
+/// The picture of the minimap, and the [image](Image) into which the
+/// [scrollback](Scrollback) is drawn.
+#[derive(Component)]
+struct MinimapPicture(Handle<Image>);
+
+/// The rectangle that marks the generations in view.
+#[derive(Component)]
+struct MinimapViewport;
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `O`, toggle the [minimap](Minimap), and announce the new setting.
+fn maybe_toggle_minimap(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut minimap: ResMut<Minimap>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.minimap)
+	{
+		minimap.0 = !minimap.0;
+		let message = match minimap.0
+		{
+			true => Message::MinimapOn,
+			false => Message::MinimapOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the evolver is paused and the [frame](MinimapFrame) of the minimap
+/// is pressed, [scroll](ScrollOffset) the view so that the generation beneath
+/// the mouse lies in the middle of the view, or as near as the
+/// [scrollback](Scrollback) permits. Holding the button and dragging sweeps
+/// the view through the scrollback.
+fn maybe_jump(
+	timer: Res<EvolutionTimer>,
+	frames: Query<(&Interaction, &Node, &GlobalTransform), With<MinimapFrame>>,
+	windows: Query<&Window>,
+	focused: Query<&Scrollback, With<Focused>>,
+	scrollbacks: Query<&Scrollback>,
+	mut scroll: ResMut<ScrollOffset>
+) {
+	if timer.is_running()
+	{
+		return
+	}
+	let Ok((interaction, node, transform)) = frames.get_single() else
+	{
+		return
+	};
+	if *interaction != Interaction::Pressed
+	{
+		return
+	}
+	let cursor = windows.get_single().ok()
+		.and_then(|window| window.cursor_position());
+	let Some(cursor) = cursor else
+	{
+		return
+	};
+	let Ok(scrollback) = focused.get_single() else
+	{
+		return
+	};
+	let rect = node.logical_rect(transform);
+	let fraction = ((cursor.y - rect.min.y) / rect.height()).clamp(0.0, 1.0);
+	// The oldest generation lies at the top of the minimap.
+	let len = scrollback.len();
+	let index = ((fraction * len as f32) as usize).min(len - 1);
+	let back = len - index - 1;
+	let limit = scrollbacks.iter()
+		.map(|scrollback| scrollback.len())
+		.max()
+		.unwrap_or(0)
+		.saturating_sub(AUTOMATON_HISTORY);
+	let offset = back.saturating_sub(AUTOMATON_HISTORY / 2).min(limit);
+	scroll.set_if_neq(ScrollOffset(offset));
+}
+
+/// While the [minimap](Minimap) is shown, keep its [picture](MinimapPicture)
+/// in step with the [scrollback](Scrollback) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane), redrawing it whenever the
+/// scrollback, the focus, or the [palette](Palette) changes. Keep the
+/// [viewport](MinimapViewport) over the generations in view, moving it
+/// whenever the view [scrolls](ScrollOffset).
+#[allow(clippy::too_many_arguments)]
+fn update_minimap(
+	minimap: Res<Minimap>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	pane: Query<(Ref<Scrollback>, Ref<Focused>)>,
+	frames: Query<Entity, With<MinimapFrame>>,
+	pictures: Query<&MinimapPicture>,
+	mut viewports: Query<&mut Style, With<MinimapViewport>>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands
+) {
+	if !minimap.0
+	{
+		if minimap.is_changed()
+		{
+			for entity in &frames
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+		return
+	}
+	let Ok((scrollback, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let Ok(picture) = pictures.get_single() else
+	{
+		build_minimap(
+			&scrollback,
+			*scroll,
+			&palette,
+			&mut images,
+			&mut commands
+		);
+		return
+	};
+	let redraw = scrollback.is_changed()
+		|| focused.is_added()
+		|| palette.is_changed();
+	if redraw
+	{
+		if let Some(image) = images.get_mut(&picture.0)
+		{
+			*image = draw(&scrollback, &palette);
+		}
+	}
+	if redraw || scroll.is_changed()
+	{
+		if let Ok(mut style) = viewports.get_single_mut()
+		{
+			let (top, height) = viewport(&scrollback, *scroll);
+			if style.top != top || style.height != height
+			{
+				style.top = top;
+				style.height = height;
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [frame](MinimapFrame) of the minimap in the bottom right corner
+/// of the window, holding the [picture](MinimapPicture) of the specified
+/// [scrollback](Scrollback) and, atop it, the [viewport](MinimapViewport) for
+/// the specified [scroll&#32;offset](ScrollOffset).
+fn build_minimap(
+	scrollback: &Scrollback,
+	scroll: ScrollOffset,
+	palette: &Palette,
+	images: &mut Assets<Image>,
+	commands: &mut Commands
+) {
+	let image = images.add(draw(scrollback, palette));
+	let (top, height) = viewport(scrollback, scroll);
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						right: Val::Px(MINIMAP_MARGIN),
+						bottom: Val::Px(MINIMAP_MARGIN),
+						width: Val::Px(MINIMAP_WIDTH),
+						height: Val::Px(MINIMAP_HEIGHT),
+						border: UiRect::all(Val::Px(MINIMAP_BORDER)),
+						..default()
+					},
+					border_color: BorderColor(MINIMAP_FRAME_COLOR),
+					focus_policy: FocusPolicy::Block,
+					z_index: ZIndex::Global(MINIMAP_Z_INDEX),
+					..default()
+				},
+				Interaction::default(),
+				MinimapFrame
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					ImageBundle {
+						style: Style {
+							width: Val::Percent(100.0),
+							height: Val::Percent(100.0),
+							..default()
+						},
+						image: UiImage::new(image.clone()),
+						..default()
+					},
+					MinimapPicture(image)
+				)
+			);
+			builder.spawn(
+				(
+					NodeBundle {
+						style: Style {
+							position_type: PositionType::Absolute,
+							left: Val::Px(0.0),
+							right: Val::Px(0.0),
+							top,
+							height,
+							border: UiRect::all(Val::Px(MINIMAP_BORDER)),
+							..default()
+						},
+						border_color: BorderColor(palette.difference),
+						..default()
+					},
+					MinimapViewport
+				)
+			);
+		});
+}
+
+/// Answer the top and the height of the [viewport](MinimapViewport), as
+/// percentages of the picture of the specified [scrollback](Scrollback), when
+/// the view is scrolled back by the specified [offset](ScrollOffset). The
+/// view may reach back further than a short scrollback, so the viewport never
+/// extends above the oldest generation.
+fn viewport(scrollback: &Scrollback, scroll: ScrollOffset) -> (Val, Val)
+{
+	let len = scrollback.len().max(1);
+	let newest = scroll.0.min(len - 1);
+	let oldest = (scroll.0 + AUTOMATON_HISTORY - 1).min(len - 1);
+	let top = (len - oldest - 1) as f32 / len as f32;
+	let height = (oldest - newest + 1) as f32 / len as f32;
+	(Val::Percent(top * 100.0), Val::Percent(height * 100.0))
+}
+
+/// Draw the specified [scrollback](Scrollback) into an [image](Image), one
+/// row of pixels per generation, oldest at the top. Scrollbacks longer than
+/// [MAX_MINIMAP_ROWS] are squeezed, such that each row of pixels shows the
+/// oldest generation that it covers.
+fn draw(scrollback: &Scrollback, palette: &Palette) -> Image
+{
+	let len = scrollback.len();
+	let height = len.clamp(1, MAX_MINIMAP_ROWS);
+	let live = palette.live.as_rgba_u8();
+	let dead = palette.dead.as_rgba_u8();
+	let mut data = Vec::with_capacity(AUTOMATON_LENGTH * height * 4);
+	for row in 0 .. height
+	{
+		let index = row * len / height;
+		let Some(automaton) = scrollback.back(len - index - 1) else
+		{
+			break
+		};
+		// Cells run right to left, but pixels run left to right.
+		for column in 0 .. AUTOMATON_LENGTH
+		{
+			let color = match automaton[AUTOMATON_LENGTH - column - 1]
+			{
+				true => live,
+				false => dead
+			};
+			data.extend_from_slice(&color);
+		}
+	}
+	data.resize(AUTOMATON_LENGTH * height * 4, 0);
+	let mut image = Image::new(
+		Extent3d {
+			width: AUTOMATON_LENGTH as u32,
+			height: height as u32,
+			depth_or_array_layers: 1
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb
+	);
+	// Keep the cells crisp when stretched.
+	image.sampler = ImageSampler::nearest();
+	image
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The most rows of pixels to draw into the [picture](MinimapPicture). Longer
+/// [scrollbacks](Scrollback) are squeezed to fit.
+const MAX_MINIMAP_ROWS: usize = 1024;
+
+/// The width of the [frame](MinimapFrame) of the minimap, in pixels.
+const MINIMAP_WIDTH: f32 = 96.0;
+
+/// The height of the [frame](MinimapFrame) of the minimap, in pixels.
+const MINIMAP_HEIGHT: f32 = 240.0;
+
+/// The distance between the [frame](MinimapFrame) of the minimap and the
+/// corner of the window, in pixels.
+const MINIMAP_MARGIN: f32 = 12.0;
+
+/// The width of the borders of the [frame](MinimapFrame) and the
+/// [viewport](MinimapViewport), in pixels.
+const MINIMAP_BORDER: f32 = 2.0;
+
+/// The [color](Color) of the border of the [frame](MinimapFrame).
+const MINIMAP_FRAME_COLOR: Color = Color::GRAY;
+
+/// The [z-index](ZIndex) of the minimap, which lies above the panes, but
+/// beneath the tooltip, the growing tape, and toasts.
+const MINIMAP_Z_INDEX: i32 = TOAST_Z_INDEX - 2;
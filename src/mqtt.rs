@@ -0,0 +1,252 @@
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventReader, EventWriter, IntoSystemConfigs, Plugin, Query, Res,
+	ResMut, Resource, Update, With
+};
+use rand::random;
+use rumqttc::{Client, Event, MqttOptions, Packet, QoS};
+
+use crate::automata::AUTOMATON_LENGTH;
+use crate::control::Command;
+use crate::ecs::{
+	Action, apply_actions, evolve, EvolutionTimer, Focused, GenerationEvolved,
+	Pane
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for MQTT, through which IoT displays, e.g.,
+/// LED walls driven by a Raspberry Pi, can mirror the simulation. Every
+/// [new&#32;generation](GenerationEvolved) of every pane is published to
+/// `<topic>/<pane>` as a [bit&#32;string](bit_string), and retained, so that
+/// a display that subscribes midway receives the newest generation at once.
+/// Messages published to the control topic are carried out as the
+/// [commands](Command) of [external&#32;control](crate::control), one per
+/// line. Insert an [Mqtt] before adding the plugin. Available only with the
+/// `mqtt` feature.
+pub struct MqttPlugin;
+
+impl Plugin for MqttPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Update, accept_commands.before(apply_actions))
+			.add_systems(Update, publish_generations.after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The connection to the MQTT broker. A background thread drives the
+/// connection, reconnecting as necessary, and forwards the commands that
+/// arrive on the control topic, so that the broker never stalls the frame.
+#[derive(Resource)]
+pub struct Mqtt
+{
+	/// The client, through which generations are published.
+	client: Client,
+
+	/// The topic beneath which generations are published.
+	topic: String,
+
+	/// The lines of command text received on the control topic, awaiting the
+	/// next frame.
+	commands: Mutex<Receiver<String>>
+}
+
+impl Mqtt
+{
+	/// Connect to the broker at the specified URL, e.g.,
+	/// `mqtt://raspberrypi.local:1883`, then publish generations beneath the
+	/// specified topic, and subscribe to the specified control topic. The
+	/// scheme and the port may be omitted. Secure connections are not
+	/// supported.
+	pub fn connect(
+		url: &str,
+		topic: &str,
+		control: &str
+	) -> Result<Self, String>
+	{
+		if url.starts_with("mqtts://")
+		{
+			return Err("secure connections are not supported".to_string())
+		}
+		let address = url.strip_prefix("mqtt://").unwrap_or(url);
+		let address = address.trim_end_matches('/');
+		let (host, port) = match address.rsplit_once(':')
+		{
+			Some((host, port)) => (
+				host,
+				port.parse()
+					.map_err(|_| format!("bad port '{}'", port))?
+			),
+			None => (address, DEFAULT_PORT)
+		};
+		let id = format!("cellular-automata-{:08x}", random::<u32>());
+		let mut options = MqttOptions::new(id, host, port);
+		options.set_keep_alive(KEEP_ALIVE);
+		let (client, mut connection) = Client::new(options, CAPACITY);
+		let subscriber = client.clone();
+		let control = control.to_string();
+		let (sender, commands) = channel();
+		thread::spawn(move || {
+			for event in connection.iter()
+			{
+				match event
+				{
+					// Subscribe upon every connection, since the broker
+					// forgets subscriptions whenever the connection drops.
+					Ok(Event::Incoming(Packet::ConnAck(_))) =>
+					{
+						if let Err(e) =
+							subscriber.try_subscribe(&control, QoS::AtMostOnce)
+						{
+							warn!("Cannot subscribe to {}: {}", control, e);
+						}
+					},
+					Ok(Event::Incoming(Packet::Publish(publish)))
+						if publish.topic == control =>
+					{
+						let text = String::from_utf8_lossy(&publish.payload);
+						for line in text.lines()
+						{
+							if sender.send(line.to_string()).is_err()
+							{
+								return
+							}
+						}
+					},
+					Ok(_) => {},
+					Err(e) =>
+					{
+						// The connection retries upon the next iteration, so
+						// wait a little first.
+						warn!("MQTT connection failed: {}", e);
+						thread::sleep(RECONNECT_DELAY);
+					}
+				}
+			}
+		});
+		Ok(Self {
+			client,
+			topic: topic.trim_end_matches('/').to_string(),
+			commands: Mutex::new(commands)
+		})
+	}
+
+	/// Answer every line of command text received since the last call.
+	fn drain(&self) -> Vec<String>
+	{
+		self.commands.lock().unwrap().try_iter().collect()
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Carry out every [command](Command) received on the control topic upon the
+/// [focused](Focused)&#32;[pane](Pane), just as
+/// [external&#32;control](crate::control) does. Blank lines are ignored, and
+/// malformed commands are logged and skipped.
+fn accept_commands(
+	mqtt: Res<Mqtt>,
+	focused: Query<&Pane, With<Focused>>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut actions: EventWriter<Action>
+) {
+	let Ok(pane) = focused.get_single() else
+	{
+		return
+	};
+	let mut running = timer.is_running();
+	for line in mqtt.drain()
+	{
+		if line.trim().is_empty()
+		{
+			continue
+		}
+		match line.parse::<Command>()
+		{
+			Ok(command) => command.carry_out(
+				pane.0,
+				&mut running,
+				&mut timer,
+				&mut actions
+			),
+			Err(e) => warn!("Ignoring MQTT command: {}", e)
+		}
+	}
+}
+
+/// Publish every [new&#32;generation](GenerationEvolved) of every
+/// [pane](Pane) to `<topic>/<pane>`, as a [bit&#32;string](bit_string). If
+/// the connection cannot keep up, then drop generations rather than stall the
+/// frame.
+fn publish_generations(
+	mqtt: Res<Mqtt>,
+	panes: Query<&Pane>,
+	mut evolved: EventReader<GenerationEvolved>
+) {
+	for event in evolved.read()
+	{
+		let Ok(pane) = panes.get(event.pane) else { continue };
+		let topic = format!("{}/{}", mqtt.topic, pane.0);
+		// The connection thread already complains about outages, so quietly
+		// drop whatever it cannot queue.
+		let _ = mqtt.client.try_publish(
+			topic,
+			QoS::AtMostOnce,
+			true,
+			bit_string(event)
+		);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the specified generation as a compact bit string: one ASCII digit
+/// per cell, `1` for live and `0` for dead, from left to right, e.g.,
+/// `0000…0110…0000`, which a microcontroller can consume without parsing.
+fn bit_string(event: &GenerationEvolved) -> String
+{
+	(0 .. AUTOMATON_LENGTH)
+		.rev()
+		.map(|i| if event.automaton[i] { '1' } else { '0' })
+		.collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The topic beneath which generations are published, unless otherwise
+/// specified.
+pub const DEFAULT_TOPIC: &str = "cellular-automata/generation";
+
+/// The topic on which commands are accepted, unless otherwise specified.
+pub const DEFAULT_CONTROL_TOPIC: &str = "cellular-automata/control";
+
+/// The port of the broker, if the URL omits it.
+const DEFAULT_PORT: u16 = 1883;
+
+/// How often to assure the broker that the connection lives.
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+
+/// How many requests may await the connection before publishing fails.
+const CAPACITY: usize = 64;
+
+/// How long to wait after a connection failure before reconnecting.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
@@ -0,0 +1,222 @@
+use bevy::core_pipeline::bloom::BloomSettings;
+use bevy::prelude::{
+	App, BackgroundColor, Camera, Camera2d, ClearColor, Color, Commands,
+	Component, default, DespawnRecursiveExt, DetectChanges, Entity,
+	EventWriter, GlobalTransform, Input, IntoSystemConfigs, KeyCode, Local,
+	Node, Plugin, Query, Res, ResMut, Resource, Sprite, SpriteBundle, Transform,
+	Update, Vec3, Window, With, Without
+};
+use bevy::window::PrimaryWindow;
+
+use crate::ecs::{
+	accept_digit, Announcement, AutomatonRuleBuilder, CellPosition, evolve,
+	PaletteOverrides, PaletteScheme
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the neon presentation mode, in which
+/// live cells glow in a vivid color against darkness, haloed by Bevy's
+/// built-in [bloom](BloomSettings). The [Neon] resource governs whether the
+/// mode is active.
+///
+/// Bloom only touches the world, never the user interface, which is drawn
+/// afterward. So every cell is shadowed by an [emitter](Emitter), a sprite in
+/// the world directly beneath the cell that shines with an exaggerated
+/// version of the cell's color. The dead color and the chrome become
+/// transparent, so that the halos show through between the live cells.
+pub struct NeonPlugin;
+
+impl Plugin for NeonPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Neon>()
+			.add_systems(Update, maybe_toggle_neon.before(accept_digit))
+			.add_systems(Update, update_neon.after(maybe_toggle_neon))
+			.add_systems(
+				Update,
+				update_emitters.after(update_neon).after(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether live cells glow like neon.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Neon(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A sprite beneath some cell, which shines with an HDR version of the cell's
+/// color so that the [bloom](BloomSettings) spreads it into a halo.
+#[derive(Component)]
+struct Emitter(Entity);
+
+/// Marks a cell that already has its [emitter](Emitter).
+#[derive(Component)]
+struct Emissive;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Systems.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `D`, toggle [neon](Neon), and announce the new setting.
+fn maybe_toggle_neon(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
+	mut neon: ResMut<Neon>,
+	mut announcements: EventWriter<Announcement>
+) {
+	// While a hexadecimal rule is being entered, `D` is a digit.
+	if keys.just_pressed(bindings.neon) && !builder.claims(bindings.neon)
+	{
+		neon.0 = !neon.0;
+		let message = match neon.0
+		{
+			true => Message::NeonOn,
+			false => Message::NeonOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// Whenever [neon](Neon) changes, switch the camera to HDR with
+/// [bloom](BloomSettings), recolor the [palette](PaletteOverrides) for neon,
+/// and darken the background, or else undo all of that. The replaced palette
+/// overrides and clear color are remembered, so that leaving neon restores
+/// them exactly. Upon leaving, also despawn every [emitter](Emitter).
+#[allow(clippy::too_many_arguments)]
+fn update_neon(
+	neon: Res<Neon>,
+	mut scheme: ResMut<PaletteScheme>,
+	mut overrides: ResMut<PaletteOverrides>,
+	mut clear: ResMut<ClearColor>,
+	mut commands: Commands,
+	mut cameras: Query<(Entity, &mut Camera), With<Camera2d>>,
+	emitters: Query<Entity, With<Emitter>>,
+	cells: Query<Entity, With<Emissive>>,
+	mut saved: Local<Option<(PaletteOverrides, Color)>>
+) {
+	// The palette ignores a change to a freshly added scheme, so wait a frame
+	// before entering neon from the command line.
+	if scheme.is_added() || neon.0 == saved.is_some()
+	{
+		return
+	}
+	for (entity, mut camera) in &mut cameras
+	{
+		camera.hdr = neon.0;
+		match neon.0
+		{
+			true => commands.entity(entity).insert(BloomSettings::NATURAL),
+			false => commands.entity(entity).remove::<BloomSettings>()
+		};
+	}
+	match saved.take()
+	{
+		None =>
+		{
+			*saved = Some((*overrides, clear.0));
+			overrides.live = Some(NEON_COLOR);
+			overrides.dead = Some(Color::NONE);
+			overrides.chrome = Some(Color::NONE);
+			clear.0 = Color::BLACK;
+		},
+		Some((previous, color)) =>
+		{
+			*overrides = previous;
+			clear.0 = color;
+			for emitter in &emitters
+			{
+				commands.entity(emitter).despawn_recursive();
+			}
+			for cell in &cells
+			{
+				commands.entity(cell).remove::<Emissive>();
+			}
+		}
+	}
+	// Repaint every cell in the new palette.
+	scheme.set_changed();
+}
+
+/// In [neon](Neon) mode, give every cell an [emitter](Emitter), and keep
+/// every emitter directly beneath its cell, at the same size, shining with
+/// the cell's color amplified by [NEON_INTENSITY]. An emitter whose cell has
+/// been despawned, e.g., because the panes were rebuilt, is despawned too.
+fn update_emitters(
+	neon: Res<Neon>,
+	windows: Query<&Window, With<PrimaryWindow>>,
+	mut commands: Commands,
+	fresh: Query<Entity, (With<CellPosition>, Without<Emissive>)>,
+	cells: Query<(&Node, &GlobalTransform, &BackgroundColor)>,
+	mut emitters: Query<(Entity, &Emitter, &mut Transform, &mut Sprite)>
+) {
+	if !neon.0
+	{
+		return
+	}
+	let Ok(window) = windows.get_single()
+	else
+	{
+		return
+	};
+	for cell in &fresh
+	{
+		commands.spawn((SpriteBundle::default(), Emitter(cell)));
+		commands.entity(cell).insert(Emissive);
+	}
+	for (entity, emitter, mut transform, mut sprite) in &mut emitters
+	{
+		let Ok((node, global, color)) = cells.get(emitter.0)
+		else
+		{
+			commands.entity(entity).despawn_recursive();
+			continue
+		};
+		// The user interface measures from the top left corner of the window,
+		// downward, but the world measures from the center, upward.
+		let center = global.translation();
+		transform.translation = Vec3::new(
+			center.x - window.width() / 2.0,
+			window.height() / 2.0 - center.y,
+			0.0
+		);
+		let [r, g, b, a] = color.0.as_linear_rgba_f32();
+		*sprite = Sprite {
+			color: Color::rgba_linear(
+				r * NEON_INTENSITY,
+				g * NEON_INTENSITY,
+				b * NEON_INTENSITY,
+				a
+			),
+			custom_size: Some(node.size()),
+			..default()
+		};
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The color of live cells in [neon](Neon) mode.
+const NEON_COLOR: Color = Color::rgb(0.0, 1.0, 0.85);
+
+/// The factor by which an [emitter](Emitter) amplifies the color of its cell.
+/// Only the portion of the color beyond `1` contributes much to the
+/// [bloom](BloomSettings).
+const NEON_INTENSITY: f32 = 4.0;
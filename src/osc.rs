@@ -0,0 +1,410 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::net::UdpSocket;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, EventReader, EventWriter, IntoSystemConfigs, Plugin, Query, Res,
+	ResMut, Resource, Update, With
+};
+
+use crate::automata::AUTOMATON_LENGTH;
+use crate::control::Command;
+use crate::ecs::{
+	Action, apply_actions, evolve, EvolutionTimer, Focused, GenerationEvolved,
+	Pane
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for Open Sound Control, through which the
+/// simulation joins live-coding and VJ environments, e.g., SuperCollider or
+/// TouchDesigner. An [OscListener] accepts the same [commands](Command) as
+/// [external&#32;control](crate::control), one per message, named by the
+/// address and followed by the argument, if any, e.g., `/rule 110`, `/pause`,
+/// `/resume`, `/seed 12345`, or `/speed 20.5`. An [OscSender] emits
+/// `/population <pane> <index> <population> <density>` for every
+/// [new&#32;generation](GenerationEvolved) of every pane. Insert either or
+/// both before adding the plugin. Available for native builds only.
+pub struct OscPlugin;
+
+impl Plugin for OscPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Update, receive_messages.before(apply_actions))
+			.add_systems(Update, send_population.after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The OSC messages received, as lines of [command](Command) text, awaiting
+/// the next frame. A background thread reads the socket, so that a quiet
+/// sender never stalls the frame.
+#[derive(Resource)]
+pub struct OscListener(Mutex<Receiver<String>>);
+
+impl OscListener
+{
+	/// Listen for OSC packets over UDP at the specified address, e.g.,
+	/// `0.0.0.0:9000`.
+	pub fn bind(address: &str) -> io::Result<Self>
+	{
+		let socket = UdpSocket::bind(address)?;
+		let (sender, receiver) = channel();
+		thread::spawn(move || {
+			let mut buffer = [0; MAX_PACKET_LENGTH];
+			loop
+			{
+				let length = match socket.recv(&mut buffer)
+				{
+					Ok(length) => length,
+					Err(e) =>
+					{
+						warn!("Cannot receive OSC packet: {}", e);
+						continue
+					}
+				};
+				let messages = match decode_packet(&buffer[.. length])
+				{
+					Ok(messages) => messages,
+					Err(e) =>
+					{
+						warn!("Ignoring OSC packet: {}", e);
+						continue
+					}
+				};
+				for message in messages
+				{
+					if sender.send(message.to_string()).is_err()
+					{
+						return
+					}
+				}
+			}
+		});
+		Ok(Self(Mutex::new(receiver)))
+	}
+
+	/// Answer every command received since the last call.
+	fn drain(&self) -> Vec<String>
+	{
+		self.0.lock().unwrap().try_iter().collect()
+	}
+}
+
+/// The emitter of OSC messages about each new generation.
+#[derive(Resource)]
+pub struct OscSender(UdpSocket);
+
+impl OscSender
+{
+	/// Send OSC packets over UDP to the specified address, e.g.,
+	/// `127.0.0.1:57120`.
+	pub fn connect(address: &str) -> io::Result<Self>
+	{
+		let socket = UdpSocket::bind("0.0.0.0:0")?;
+		socket.connect(address)?;
+		Ok(Self(socket))
+	}
+
+	/// Send the specified message. Nobody need be listening, so failures are
+	/// ignored.
+	fn send(&self, message: &OscMessage)
+	{
+		let _ = self.0.send(&message.encode());
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Messages.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// An OSC message: an address, followed by any number of arguments.
+#[derive(Clone, Debug, PartialEq)]
+struct OscMessage
+{
+	/// The address of the message, e.g., `/rule`.
+	address: String,
+
+	/// The arguments of the message.
+	arguments: Vec<OscArgument>
+}
+
+impl OscMessage
+{
+	/// Encode the message as an OSC packet.
+	fn encode(&self) -> Vec<u8>
+	{
+		let mut packet = Vec::new();
+		push_string(&mut packet, &self.address);
+		let tags = self.arguments.iter()
+			.map(|argument| match argument
+			{
+				OscArgument::Int(_) => 'i',
+				OscArgument::Float(_) => 'f',
+				OscArgument::String(_) => 's'
+			})
+			.collect::<String>();
+		push_string(&mut packet, &format!(",{}", tags));
+		for argument in &self.arguments
+		{
+			match argument
+			{
+				OscArgument::Int(i) => packet.extend(i.to_be_bytes()),
+				OscArgument::Float(f) => packet.extend(f.to_be_bytes()),
+				OscArgument::String(s) => push_string(&mut packet, s)
+			}
+		}
+		packet
+	}
+}
+
+impl Display for OscMessage
+{
+	/// Render the message as a line of [command](Command) text, e.g.,
+	/// `rule 110` for `/rule 110`.
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.address.trim_start_matches('/'))?;
+		for argument in &self.arguments
+		{
+			write!(f, " {}", argument)?;
+		}
+		Ok(())
+	}
+}
+
+/// An argument of an [OSC&#32;message](OscMessage). 64-bit arguments are
+/// narrowed upon receipt.
+#[derive(Clone, Debug, PartialEq)]
+enum OscArgument
+{
+	/// A 32-bit integer, tagged `i`.
+	Int(i32),
+
+	/// A 32-bit float, tagged `f`.
+	Float(f32),
+
+	/// A string, tagged `s`.
+	String(String)
+}
+
+impl Display for OscArgument
+{
+	/// Render the argument as [command](Command) text. Whole floats render
+	/// without a fraction, since many environments send only floats.
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			OscArgument::Int(i) => write!(f, "{}", i),
+			OscArgument::Float(x) => write!(f, "{}", x),
+			OscArgument::String(s) => write!(f, "{}", s)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Carry out every [command](Command) received by the [OscListener] upon the
+/// [focused](Focused)&#32;[pane](Pane), just as
+/// [external&#32;control](crate::control) does. Malformed commands are logged
+/// and skipped.
+fn receive_messages(
+	listener: Option<Res<OscListener>>,
+	focused: Query<&Pane, With<Focused>>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut actions: EventWriter<Action>
+) {
+	let Some(listener) = listener else { return };
+	let Ok(pane) = focused.get_single() else
+	{
+		return
+	};
+	let mut running = timer.is_running();
+	for line in listener.drain()
+	{
+		match line.parse::<Command>()
+		{
+			Ok(command) => command.carry_out(
+				pane.0,
+				&mut running,
+				&mut timer,
+				&mut actions
+			),
+			Err(e) => warn!("Ignoring OSC message: {}", e)
+		}
+	}
+}
+
+/// Send `/population <pane> <index> <population> <density>` through the
+/// [OscSender] for every [new&#32;generation](GenerationEvolved), where the
+/// population counts the live cells, and the density is the fraction of cells
+/// that live.
+fn send_population(
+	sender: Option<Res<OscSender>>,
+	panes: Query<&Pane>,
+	mut evolved: EventReader<GenerationEvolved>
+) {
+	let Some(sender) = sender else { return };
+	for event in evolved.read()
+	{
+		let Ok(pane) = panes.get(event.pane) else { continue };
+		let population = event.automaton.population_count();
+		sender.send(&OscMessage {
+			address: "/population".to_string(),
+			arguments: vec![
+				OscArgument::Int(pane.0 as i32),
+				OscArgument::Int(event.index as i32),
+				OscArgument::Int(population as i32),
+				OscArgument::Float(population as f32 / AUTOMATON_LENGTH as f32)
+			]
+		});
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Decode the specified OSC packet, which holds either a single message or a
+/// bundle of messages and bundles. Time tags are ignored, so bundled messages
+/// take effect immediately.
+fn decode_packet(packet: &[u8]) -> Result<Vec<OscMessage>, String>
+{
+	let mut messages = Vec::new();
+	let mut packets = vec![packet];
+	while let Some(packet) = packets.pop()
+	{
+		let mut reader = Reader(packet);
+		if packet.starts_with(b"#bundle\0")
+		{
+			// Skip the time tag, then gather the elements.
+			reader.take(16)?;
+			let mut elements = Vec::new();
+			while !reader.0.is_empty()
+			{
+				let length = reader.int()?;
+				let length = usize::try_from(length)
+					.map_err(|_| "negative bundle element length")?;
+				elements.push(reader.take(length)?);
+			}
+			// Push in reverse, so that the elements pop in order.
+			packets.extend(elements.into_iter().rev());
+			continue
+		}
+		let address = reader.string()?;
+		if !address.starts_with('/')
+		{
+			return Err(format!("bad address '{}'", address))
+		}
+		let tags = match reader.0.is_empty()
+		{
+			// Very old senders omit the type tags of argumentless messages.
+			true => String::from(","),
+			false => reader.string()?
+		};
+		let Some(tags) = tags.strip_prefix(',') else
+		{
+			return Err(format!("bad type tags '{}'", tags))
+		};
+		let mut arguments = Vec::new();
+		for tag in tags.chars()
+		{
+			let argument = match tag
+			{
+				'i' => OscArgument::Int(reader.int()?),
+				'f' => OscArgument::Float(f32::from_be_bytes(reader.word()?)),
+				's' | 'S' => OscArgument::String(reader.string()?),
+				'h' => OscArgument::Int(
+					i64::from_be_bytes(reader.double_word()?) as i32
+				),
+				'd' => OscArgument::Float(
+					f64::from_be_bytes(reader.double_word()?) as f32
+				),
+				'T' | 'F' | 'N' | 'I' => continue,
+				_ => return Err(format!("unsupported type tag '{}'", tag))
+			};
+			arguments.push(argument);
+		}
+		messages.push(OscMessage { address, arguments });
+	}
+	Ok(messages)
+}
+
+/// Append the specified string to the specified packet, terminated by `NUL`
+/// and padded to a multiple of four bytes.
+fn push_string(packet: &mut Vec<u8>, s: &str)
+{
+	packet.extend(s.as_bytes());
+	packet.resize(packet.len() + 4 - s.len() % 4, 0);
+}
+
+/// A cursor over the unread remainder of an OSC packet.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a>
+{
+	/// Read the specified number of bytes.
+	fn take(&mut self, length: usize) -> Result<&'a [u8], String>
+	{
+		if length > self.0.len()
+		{
+			return Err("truncated packet".to_string())
+		}
+		let (taken, rest) = self.0.split_at(length);
+		self.0 = rest;
+		Ok(taken)
+	}
+
+	/// Read four bytes.
+	fn word(&mut self) -> Result<[u8; 4], String>
+	{
+		Ok(self.take(4)?.try_into().unwrap())
+	}
+
+	/// Read eight bytes.
+	fn double_word(&mut self) -> Result<[u8; 8], String>
+	{
+		Ok(self.take(8)?.try_into().unwrap())
+	}
+
+	/// Read a big-endian 32-bit integer.
+	fn int(&mut self) -> Result<i32, String>
+	{
+		Ok(i32::from_be_bytes(self.word()?))
+	}
+
+	/// Read a `NUL`-terminated string, padded to a multiple of four bytes.
+	fn string(&mut self) -> Result<String, String>
+	{
+		let end = self.0.iter()
+			.position(|&b| b == 0)
+			.ok_or("unterminated string")?;
+		let s = std::str::from_utf8(&self.0[.. end])
+			.map_err(|e| e.to_string())?
+			.to_string();
+		self.take((end / 4 + 1) * 4)?;
+		Ok(s)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The longest OSC packet, in bytes, that can be received. Longer packets are
+/// truncated, and probably rejected.
+const MAX_PACKET_LENGTH: usize = 1536;
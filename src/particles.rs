@@ -0,0 +1,249 @@
+use bevy::prelude::{
+	App, BackgroundColor, BorderColor, BuildChildren, Color, Commands,
+	Component, default, DespawnRecursiveExt, DetectChanges, Entity,
+	EventWriter, Input, IntoSystemConfigs, KeyCode, NodeBundle, Plugin,
+	PositionType, Query, Ref, Res, ResMut, Resource, Style, Text, TextBundle,
+	TextStyle, UiRect, Update, Val, With
+};
+use bevy::ui::{AlignItems, GridPlacement, JustifyContent};
+
+use crate::automata::{
+	AUTOMATON_LENGTH, AutomatonRule, History, Particle, RuleKind, RuleSchedule
+};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, Palette, PaneView, ScrollOffset,
+	update_scroll
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for particle detection, which outlines the
+/// [particles](Particle) of Rule 110 atop the grid of every
+/// [pane](crate::ecs::Pane) that evolves under Rule 110 alone, and labels each
+/// outline with the name of its [glider](crate::automata::Glider). The
+/// [ParticleDetection] resource governs whether the outlines are shown.
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<ParticleDetection>()
+			.add_systems(Update, maybe_toggle_particles)
+			.add_systems(
+				Update,
+				update_particles
+					.after(maybe_toggle_particles)
+					.after(apply_actions)
+					.after(evolve)
+					.after(update_scroll)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the [particles](Particle) of Rule 110 are outlined.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct ParticleDetection(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Marks the outline of a [particle](Particle), so that every outline can be
+/// despawned before the particles are found anew.
+#[derive(Component)]
+struct ParticleOutline;
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `I`, toggle [particle&#32;detection](ParticleDetection), and announce
+/// the new setting.
+fn maybe_toggle_particles(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut detection: ResMut<ParticleDetection>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.particles)
+	{
+		detection.0 = !detection.0;
+		let message = match detection.0
+		{
+			true => Message::ParticlesOn,
+			false => Message::ParticlesOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While [particle&#32;detection](ParticleDetection) is on, outline the
+/// [particles](Particle) of every [pane](crate::ecs::Pane) whose
+/// [schedule](RuleSchedule) comprises Rule 110 alone. The particles are found
+/// in the [history](History), so hide the outlines while the view is
+/// [scrolled](ScrollOffset) back. Find the particles anew whenever a history
+/// changes, e.g., through evolution, a toggled cell, or a new seed, and
+/// whenever the [palette](Palette) changes.
+fn update_particles(
+	detection: Res<ParticleDetection>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(Ref<History>, &RuleSchedule)>,
+	views: Query<(Entity, &PaneView)>,
+	outlines: Query<Entity, With<ParticleOutline>>,
+	mut commands: Commands
+) {
+	let changed = detection.is_changed()
+		|| scroll.is_changed()
+		|| palette.is_changed()
+		|| panes.iter().any(|(history, _)| history.is_changed());
+	if !changed
+	{
+		return
+	}
+	for entity in &outlines
+	{
+		commands.entity(entity).despawn_recursive();
+	}
+	if !detection.0 || scroll.0 > 0
+	{
+		return
+	}
+	let rule_110 = RuleKind::from(AutomatonRule::from(RULE_110));
+	for (view, pane) in &views
+	{
+		// Difference panes have no schedule, so they are never outlined.
+		let Ok((history, schedule)) = panes.get(pane.0) else
+		{
+			continue
+		};
+		if !schedule.iter().all(|rule| *rule == rule_110)
+		{
+			continue
+		}
+		for particle in history.particles()
+		{
+			outline(&particle, view, &palette, &mut commands);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Outline the specified [particle](Particle) atop the grid of the specified
+/// [view](PaneView), and label the outline with the name of the particle's
+/// [glider](crate::automata::Glider), or `?` if it resembles none. Each
+/// outline is an absolutely positioned child of the grid that spans the
+/// particle's rows and columns, so the grid itself lays out the outline over
+/// the right cells. A particle that straddles the edge of its automaton is
+/// split into two outlines, the first of which bears the label.
+fn outline(
+	particle: &Particle,
+	view: Entity,
+	palette: &Palette,
+	commands: &mut Commands
+) {
+	let label = particle.glider
+		.map(|glider| glider.to_string())
+		.unwrap_or_else(|| "?".to_string());
+	// Indices run right to left, but columns run left to right.
+	let end = particle.start + particle.width;
+	let spans = match end <= AUTOMATON_LENGTH
+	{
+		true => vec![(particle.start, end)],
+		false => vec![
+			(particle.start, AUTOMATON_LENGTH),
+			(0, end - AUTOMATON_LENGTH)
+		]
+	};
+	let row = *particle.rows.start() as i16 + 1;
+	let height = particle.rows.clone().count() as u16;
+	for (index, (low, high)) in spans.into_iter().enumerate()
+	{
+		let column = (AUTOMATON_LENGTH - high) as i16 + 1;
+		let width = (high - low) as u16;
+		commands.entity(view).with_children(|builder| {
+			builder
+				.spawn(
+					(
+						NodeBundle {
+							style: Style {
+								position_type: PositionType::Absolute,
+								grid_row: GridPlacement::start_span(
+									row,
+									height
+								),
+								grid_column: GridPlacement::start_span(
+									column,
+									width
+								),
+								left: Val::Px(0.0),
+								right: Val::Px(0.0),
+								top: Val::Px(0.0),
+								bottom: Val::Px(0.0),
+								border: UiRect::all(Val::Px(OUTLINE_WIDTH)),
+								align_items: AlignItems::FlexStart,
+								justify_content: JustifyContent::FlexStart,
+								..default()
+							},
+							border_color: BorderColor(palette.difference),
+							..default()
+						},
+						ParticleOutline
+					)
+				)
+				.with_children(|builder| {
+					if index == 0
+					{
+						builder.spawn(
+							TextBundle {
+								text: Text::from_section(
+									label.clone(),
+									TextStyle {
+										font_size: LABEL_FONT_SIZE,
+										color: palette.difference,
+										..default()
+									}
+								),
+								background_color: BackgroundColor(
+									LABEL_BACKGROUND
+								),
+								..default()
+							}
+						);
+					}
+				});
+		});
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The Wolfram code of Rule 110, the only rule whose
+/// [particles](Particle) are known.
+const RULE_110: u8 = 110;
+
+/// The width of the border of each outline, in pixels.
+const OUTLINE_WIDTH: f32 = 2.0;
+
+/// The font size of the label of each outline.
+const LABEL_FONT_SIZE: f32 = 16.0;
+
+/// The [color](Color) behind the label of each outline, which keeps the label
+/// legible atop the cells.
+const LABEL_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
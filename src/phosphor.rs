@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use bevy::prelude::{
+	App, BackgroundColor, Commands, Component, DetectChanges, Entity,
+	IntoSystemConfigs, Plugin, Query, Res, Resource, Time, Update, With
+};
+
+use crate::automata::{Decay, History, Scrollback};
+use crate::ecs::{
+	CellPosition, evolve, Palette, repaint, ScrollOffset, update_palette,
+	update_scan
+};
+use crate::effects::blend;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the CRT-style phosphor mode, in which
+/// each cell that goes dark keeps glowing in the live color for a moment,
+/// fading out over several frames rather than switching to the dead color at
+/// once. Because the rows scroll upward with every generation, moving patterns
+/// leave trails behind them. The [Phosphor] resource governs whether the mode
+/// is active.
+pub struct PhosphorPlugin;
+
+impl Plugin for PhosphorPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Phosphor>()
+			.add_systems(
+				Update,
+				update_afterglow
+					.after(evolve)
+					.after(update_palette)
+					.after(update_scan)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether cells that go dark fade out like the phosphor of a cathode-ray
+/// tube.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Phosphor(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The lingering intensity of a cell on screen, from `1` while the cell is
+/// live down to `0` once a dark cell has faded out completely.
+#[derive(Component)]
+struct Afterglow(f32);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Systems.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// In [phosphor](Phosphor) mode, update the [afterglow](Afterglow) of every
+/// cell on screen, and blend each dark cell from the live color toward its
+/// true color in proportion to its remaining intensity. A live cell glows at
+/// full intensity, and a dark cell loses all of its intensity over
+/// [PHOSPHOR_PERSISTENCE]. Cells acquire their afterglow the first time that
+/// they are seen. While the view is scrolled back, there are no trails to
+/// leave, so every afterglow is extinguished. Difference panes have no
+/// [scrollback](Scrollback), so they are left alone. When the mode is turned
+/// off, any cells still fading return to their true colors at once.
+fn update_afterglow(
+	phosphor: Res<Phosphor>,
+	time: Res<Time>,
+	scroll: Res<ScrollOffset>,
+	palette: Res<Palette>,
+	panes: Query<(&History, Option<&Decay>), With<Scrollback>>,
+	mut commands: Commands,
+	mut cells: Query<(
+		Entity,
+		&CellPosition,
+		&mut BackgroundColor,
+		Option<&mut Afterglow>
+	)>
+) {
+	if !phosphor.0
+	{
+		if phosphor.is_changed()
+		{
+			for (_, position, color, afterglow) in &mut cells
+			{
+				let (Some(mut afterglow), Ok((history, decay))) =
+					(afterglow, panes.get(position.pane))
+				else
+				{
+					continue
+				};
+				if afterglow.0 > 0.0 && scroll.0 == 0
+				{
+					afterglow.0 = 0.0;
+					repaint(
+						color,
+						palette.cell_color(history, decay, *position)
+					);
+				}
+			}
+		}
+		return
+	}
+	let dimming = time.delta_seconds() / PHOSPHOR_PERSISTENCE.as_secs_f32();
+	for (entity, position, color, afterglow) in &mut cells
+	{
+		let Ok((history, decay)) = panes.get(position.pane)
+		else
+		{
+			continue
+		};
+		let Some(mut afterglow) = afterglow
+		else
+		{
+			commands.entity(entity).insert(Afterglow(0.0));
+			continue
+		};
+		if scroll.0 != 0
+		{
+			afterglow.0 = 0.0;
+			continue
+		}
+		if history[*position]
+		{
+			afterglow.0 = 1.0;
+			continue
+		}
+		if afterglow.0 <= 0.0
+		{
+			continue
+		}
+		afterglow.0 = (afterglow.0 - dimming).max(0.0);
+		let target = palette.cell_color(history, decay, *position);
+		let new = blend(target.0, palette.live, afterglow.0);
+		repaint(color, BackgroundColor(new));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How long a cell that goes dark takes to fade out completely. This is twice
+/// the default heartbeat, so that a trail spans a couple of generations.
+const PHOSPHOR_PERSISTENCE: Duration = Duration::from_millis(500);
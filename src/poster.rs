@@ -0,0 +1,386 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use bevy::prelude::{
+	App, EventWriter, Input, KeyCode, Plugin, Query, Res, Resource, Update,
+	With
+};
+#[cfg(not(target_family = "wasm"))]
+use image::RgbaImage;
+#[cfg(not(target_family = "wasm"))]
+use rayon::prelude::*;
+
+use crate::automata::{Automaton, AUTOMATON_HISTORY, Scrollback};
+#[cfg(not(target_family = "wasm"))]
+use crate::automata::AUTOMATON_LENGTH;
+use crate::ecs::{
+	Announcement, AutomatonRuleBuilder, Focused, Palette, Pane, ScrollOffset
+};
+use crate::geometry::{CellShape, Gridlines};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for posters. On `F12`, the generations on
+/// view in the [focused](Focused) pane are rendered offscreen into a PNG of the
+/// [poster&#32;size](PosterSize), e.g., 8192×6400, whatever the size of the
+/// window. The poster follows the [palette](Palette), the
+/// [cell&#32;shape](CellShape), and the [gridlines](Gridlines), but it is
+/// rasterized directly, rather than by laying out a grid of UI nodes, so its
+/// resolution is limited only by memory. Rendering happens on a background
+/// thread, so that the frame never stalls; the outcome is announced in the
+/// toast once the file is written. The browser has no working directory to
+/// render into, so there the key merely says as much.
+pub struct PosterPlugin;
+
+impl Plugin for PosterPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<PosterSize>()
+			.init_resource::<Posters>()
+			.add_systems(Update, (maybe_render_poster, announce_posters));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The width and height of a poster, in pixels. Defaults to 8192×6400.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Resource)]
+pub struct PosterSize
+{
+	/// The width of a poster, in pixels.
+	width: u32,
+
+	/// The height of a poster, in pixels.
+	height: u32
+}
+
+impl Default for PosterSize
+{
+	fn default() -> Self
+	{
+		Self { width: 8192, height: 6400 }
+	}
+}
+
+impl FromStr for PosterSize
+{
+	type Err = String;
+
+	/// Parse a poster size as `<WIDTH>x<HEIGHT>`, e.g., `8192x6400`. Each
+	/// dimension must lie between 1 and [MAX_POSTER_DIMENSION], inclusive.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let malformed = || format!(
+			"bad poster size '{}': expected <WIDTH>x<HEIGHT>, e.g., 8192x6400",
+			s
+		);
+		let (width, height) = s.trim()
+			.split_once(['x', 'X', '×'])
+			.ok_or_else(malformed)?;
+		let dimension = |d: &str| d.trim().parse::<u32>()
+			.ok()
+			.filter(|d| (1 ..= MAX_POSTER_DIMENSION).contains(d));
+		match (dimension(width), dimension(height))
+		{
+			(Some(width), Some(height)) => Ok(Self { width, height }),
+			_ => Err(format!(
+				"bad poster size '{}': each dimension must lie between 1 and \
+					{}",
+				s,
+				MAX_POSTER_DIMENSION
+			))
+		}
+	}
+}
+
+impl Display for PosterSize
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}×{}", self.width, self.height)
+	}
+}
+
+/// The announcements of posters rendered on background threads, awaiting the
+/// next frame.
+#[derive(Resource)]
+struct Posters
+{
+	/// The sender, cloned for each background thread.
+	sender: Mutex<Sender<String>>,
+
+	/// The announcements received.
+	receiver: Mutex<Receiver<String>>
+}
+
+impl Default for Posters
+{
+	fn default() -> Self
+	{
+		let (sender, receiver) = channel();
+		Self {
+			sender: Mutex::new(sender),
+			receiver: Mutex::new(receiver)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F12`, render the generations on view in the
+/// [focused](Focused)&#32;[pane](Pane), as they appear at the current
+/// [scroll&#32;offset](ScrollOffset), into a new PNG file in the working
+/// directory. Announce the start in the toast at once, and the outcome
+/// [later](announce_posters).
+#[allow(clippy::too_many_arguments)]
+fn maybe_render_poster(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	size: Res<PosterSize>,
+	palette: Res<Palette>,
+	shape: Res<CellShape>,
+	gridlines: Res<Gridlines>,
+	scroll: Res<ScrollOffset>,
+	language: Res<Language>,
+	posters: Res<Posters>,
+	pane: Query<(&Pane, &Scrollback), With<Focused>>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if !keys.just_pressed(bindings.poster) || builder.claims(bindings.poster)
+	{
+		return
+	}
+	// Difference panes have no scrollback of their own.
+	let Ok((pane, scrollback)) = pane.get_single() else { return };
+	let rows = (0 .. AUTOMATON_HISTORY)
+		.map(|row| {
+			scrollback.back(scroll.0 + AUTOMATON_HISTORY - 1 - row).copied()
+		})
+		.collect::<Vec<_>>();
+	let poster = Poster {
+		rows,
+		size: *size,
+		palette: *palette,
+		shape: *shape,
+		gridlines: *gridlines
+	};
+	let stem = format!("poster-{}", pane.0);
+	let sender = posters.sender.lock().unwrap().clone();
+	announcements.send(Announcement(
+		render_poster(&stem, poster, sender, *language)
+	));
+}
+
+/// Announce in the toast the outcome of every poster rendered since the last
+/// frame.
+fn announce_posters(
+	posters: Res<Posters>,
+	mut announcements: EventWriter<Announcement>
+) {
+	for announcement in posters.receiver.lock().unwrap().try_iter()
+	{
+		announcements.send(Announcement(announcement));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Everything needed to render a poster, detached from the world, so that it
+/// can travel to a background thread.
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+struct Poster
+{
+	/// The generations on view, from top to bottom. Generations older than
+	/// the oldest retained generation are [None], and painted vacant.
+	rows: Vec<Option<Automaton>>,
+
+	/// The width and height of the poster, in pixels.
+	size: PosterSize,
+
+	/// The colors of the cells and the chrome.
+	palette: Palette,
+
+	/// The shape of the cells.
+	shape: CellShape,
+
+	/// The gaps between the cells, and the borders around them.
+	gridlines: Gridlines
+}
+
+/// Render the specified [poster](Poster) on a background thread, into a new PNG
+/// file in the working directory, named by the specified stem and the current
+/// time. Send a message, in the specified [language](Language), that describes
+/// the outcome through the specified sender. Answer a message that describes
+/// the start.
+#[cfg(not(target_family = "wasm"))]
+fn render_poster(
+	stem: &str,
+	poster: Poster,
+	sender: Sender<String>,
+	language: Language
+) -> String
+{
+	let seconds = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|elapsed| elapsed.as_secs())
+		.unwrap_or_default();
+	let path = format!("{}-{}.png", stem, seconds);
+	let started = language.format(Message::RenderingPoster, &[&poster.size]);
+	std::thread::spawn(move || {
+		let announcement = match draw_poster(&poster).save(&path)
+		{
+			Ok(()) => language.format(Message::RenderedPoster, &[&path]),
+			Err(e) => language.format(Message::CannotExport, &[&path, &e])
+		};
+		let _ = sender.send(announcement);
+	});
+	started
+}
+
+/// The browser has no working directory to render into.
+#[cfg(target_family = "wasm")]
+fn render_poster(
+	_stem: &str,
+	_poster: Poster,
+	_sender: Sender<String>,
+	language: Language
+) -> String
+{
+	language.text(Message::ExportUnavailable).to_string()
+}
+
+/// Rasterize the specified [poster](Poster). The cells are as large as fit,
+/// centered within the chrome, and the gaps and borders grow with them, in
+/// proportion to their [reference&#32;pitch](REFERENCE_PITCH) on screen.
+/// Each pixel is supersampled, so that the edges of round cells stay smooth.
+/// The rows are rasterized in parallel.
+#[cfg(not(target_family = "wasm"))]
+fn draw_poster(poster: &Poster) -> RgbaImage
+{
+	let PosterSize { width, height } = poster.size;
+	let offset = match poster.shape
+	{
+		CellShape::Hexagon => 0.5,
+		_ => 0.0
+	};
+	let columns = AUTOMATON_LENGTH as f32 + offset;
+	let rows = poster.rows.len() as f32;
+	// Leave half a cell of chrome all around.
+	let pitch = (width as f32 / (columns + 1.0))
+		.min(height as f32 / (rows + 1.0));
+	let scale = pitch / REFERENCE_PITCH;
+	let gap = poster.gridlines.gap() * scale;
+	let border = poster.gridlines.border() * scale;
+	let size = pitch - gap;
+	let left = (width as f32 - (columns * pitch - gap)) / 2.0;
+	let top = (height as f32 - (rows * pitch - gap)) / 2.0;
+	let palette = &poster.palette;
+	let chrome = palette.chrome.as_rgba_f32();
+	let live = palette.live.as_rgba_f32();
+	let dead = palette.dead.as_rgba_f32();
+	// Answer the color at the specified point of the poster.
+	let sample = |x: f32, y: f32| -> [f32; 4] {
+		let y = (y - top) / pitch;
+		let row = y.floor();
+		if row < 0.0 || row >= rows
+		{
+			return chrome
+		}
+		let row = row as usize;
+		let mut x = (x - left) / pitch;
+		if poster.shape.is_offset(row)
+		{
+			x -= 0.5;
+		}
+		let column = x.floor();
+		if column < 0.0 || column >= AUTOMATON_LENGTH as f32
+		{
+			return chrome
+		}
+		let column = column as usize;
+		// The position within the cell, in pixels.
+		let u = (x - column as f32) * pitch;
+		let v = (y - row as f32) * pitch;
+		if u >= size || v >= size
+		{
+			return chrome
+		}
+		let fill = match poster.rows[row]
+		{
+			Some(automaton) if automaton[AUTOMATON_LENGTH - column - 1] =>
+				live,
+			_ => dead
+		};
+		// The position within the cell, from -1 to 1.
+		let (ux, uy) = (u / size * 2.0 - 1.0, v / size * 2.0 - 1.0);
+		match poster.shape
+		{
+			CellShape::Square
+				if u < border || v < border
+					|| u >= size - border || v >= size - border => live,
+			CellShape::Square => fill,
+			CellShape::Circle if ux * ux + uy * uy <= 1.0 => fill,
+			CellShape::Hexagon if ux.abs() / 2.0 + uy.abs() <= 1.0 => fill,
+			_ => chrome
+		}
+	};
+	let mut pixels = vec![0; width as usize * height as usize * 4];
+	pixels.par_chunks_mut(width as usize * 4)
+		.enumerate()
+		.for_each(|(y, line)| {
+			for (x, pixel) in line.chunks_exact_mut(4).enumerate()
+			{
+				let mut color = [0.0; 4];
+				for (dx, dy) in SUBPIXELS
+				{
+					let sampled = sample(x as f32 + dx, y as f32 + dy);
+					for (sum, channel) in color.iter_mut().zip(sampled)
+					{
+						*sum += channel / SUBPIXELS.len() as f32;
+					}
+				}
+				for (channel, sum) in pixel.iter_mut().zip(color)
+				{
+					*channel = (sum * 255.0).round() as u8;
+				}
+			}
+		});
+	RgbaImage::from_raw(width, height, pixels).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The largest width or height of a poster, in pixels. A poster of the largest
+/// size occupies a gigabyte of memory while it renders.
+pub const MAX_POSTER_DIMENSION: u32 = 16384;
+
+/// The distance between adjacent cells on screen, in pixels, relative to which
+/// the [gaps](Gridlines::gap) and [borders](Gridlines::border) are given.
+#[cfg(not(target_family = "wasm"))]
+const REFERENCE_PITCH: f32 = 24.0;
+
+/// The offsets within a pixel at which it is sampled, in a rotated grid.
+#[cfg(not(target_family = "wasm"))]
+const SUBPIXELS: [(f32, f32); 4] = [
+	(0.375, 0.125),
+	(0.875, 0.375),
+	(0.125, 0.625),
+	(0.625, 0.875)
+];
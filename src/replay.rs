@@ -0,0 +1,842 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use std::{fmt, io};
+
+use bevy::log::{info, warn};
+use bevy::prelude::{
+	App, DetectChangesMut, EventReader, EventWriter, IntoSystemConfigs, Plugin,
+	Query, Res, ResMut, Resource, Startup, Time, Update
+};
+
+use crate::automata::{
+	Automaton, AUTOMATON_LENGTH, AutomatonRule, History, RuleKind, RuleMap,
+	RuleSchedule
+};
+use crate::ecs::{
+	Action, apply_actions, evolve, EvolutionTimer, Generation,
+	GenerationCeiling, Pane, SeedTransform
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for recording [actions](Action) to a file
+/// and for replaying them from a file. Insert a [Recorder] to record, or a
+/// [Replay] to replay, before adding the plugin.
+///
+/// Each action is stamped with the [generation](Generation) and the elapsed
+/// time at which it happened. Recordings are either text, one
+/// [stamped&#32;action](Stamped) per line, or [compact](RecordingFormat::Car)
+/// binary `.car` files. During replay, an action is applied as soon as
+/// the evolution reaches its generation, so that the evolution unfolds exactly
+/// as it did during recording. While paused, the elapsed time paces the
+/// actions, so that the replay also looks like the recording.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Startup, record_initial_state)
+			.add_systems(Update, replay_actions.before(apply_actions))
+			.add_systems(
+				Update,
+				record_actions.after(apply_actions).before(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The destination of a recording. The file is flushed after every frame in
+/// which something happened, so that the recording survives an abrupt exit.
+#[derive(Resource)]
+pub struct Recorder
+{
+	/// The recording.
+	out: BufWriter<File>,
+
+	/// The encoding of the recording.
+	format: RecordingFormat,
+
+	/// The generation and the elapsed time of the action most recently
+	/// recorded, from which [compact](RecordingFormat::Car) recordings measure
+	/// the next.
+	previous: (u64, Duration)
+}
+
+impl Recorder
+{
+	/// Create (or truncate) the recording at the specified path. If the path
+	/// ends with `.car`, then the recording is [compact](RecordingFormat::Car);
+	/// otherwise, it is text.
+	pub fn create(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let path = path.as_ref();
+		let format = match path.extension()
+		{
+			Some(extension) if extension.eq_ignore_ascii_case("car") =>
+				RecordingFormat::Car,
+			_ => RecordingFormat::Text
+		};
+		let mut out = BufWriter::new(File::create(path)?);
+		if format == RecordingFormat::Car
+		{
+			out.write_all(CAR_MAGIC)?;
+		}
+		Ok(Self { out, format, previous: (0, Duration::ZERO) })
+	}
+
+	/// Append the specified [stamped&#32;action](Stamped) to the recording,
+	/// logging any failure.
+	fn write(&mut self, stamped: &Stamped)
+	{
+		let result = match self.format
+		{
+			RecordingFormat::Text => writeln!(self.out, "{}", stamped),
+			RecordingFormat::Car =>
+			{
+				let mut record = Vec::new();
+				stamped.encode(self.previous, &mut record);
+				self.out.write_all(&record)
+			}
+		};
+		if let Err(e) = result
+		{
+			warn!("Cannot record {}: {}", stamped, e);
+		}
+		self.previous = (stamped.generation, stamped.elapsed);
+	}
+
+	/// Flush the recording, logging any failure.
+	fn flush(&mut self)
+	{
+		if let Err(e) = self.out.flush()
+		{
+			warn!("Cannot flush recording: {}", e);
+		}
+	}
+}
+
+/// The [actions](Action) that remain to be replayed, in order.
+#[derive(Resource)]
+pub struct Replay(VecDeque<Stamped>);
+
+impl Replay
+{
+	/// Load the recording at the specified path, recognizing
+	/// [compact](RecordingFormat::Car) recordings by their header. In text
+	/// recordings, blank lines and lines that begin with `#` are ignored.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError>
+	{
+		let mut bytes = Vec::new();
+		File::open(path)?.read_to_end(&mut bytes)?;
+		if bytes.starts_with(CAR_MAGIC)
+		{
+			return Ok(Self(decode_car(&bytes)?))
+		}
+		let text = String::from_utf8(bytes).map_err(|e| {
+			ReplayError::Io(io::Error::new(io::ErrorKind::InvalidData, e))
+		})?;
+		let mut pending = VecDeque::new();
+		for (index, line) in text.lines().enumerate()
+		{
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#')
+			{
+				continue
+			}
+			let stamped = line.parse::<Stamped>()
+				.map_err(|message| ReplayError::Parse(index + 1, message))?;
+			pending.push_back(stamped);
+		}
+		Ok(Self(pending))
+	}
+}
+
+/// The encodings of a recording.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RecordingFormat
+{
+	/// One [stamped&#32;action](Stamped) per line of text, e.g.,
+	/// `12 5230 rule 0 110`, which is easy to read and to edit by hand.
+	Text,
+
+	/// The compact binary format of `.car` files: the header `CAR\x01`, then
+	/// one record per [stamped&#32;action](Stamped), each the generation and
+	/// the elapsed milliseconds since the previous record, then a tag that
+	/// names the action, then its arguments. Numbers are LEB128 varints,
+	/// except that seeds are eight big-endian bytes and rules are single
+	/// bytes, so that a typical record occupies only a handful of bytes.
+	Car
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Stamped actions.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// An [action](Action) stamped with the [generation](Generation) and the
+/// elapsed time at which it happened. A stamped action occupies one line of a
+/// recording: the generation, the elapsed milliseconds, and then the action,
+/// all separated by whitespace, e.g., `12 5230 rule 0 110`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Stamped
+{
+	/// The generation at which the action happened.
+	generation: u64,
+
+	/// The time elapsed since startup when the action happened.
+	elapsed: Duration,
+
+	/// The action itself.
+	action: Action
+}
+
+impl Stamped
+{
+	/// Determine whether the action is due, given the current
+	/// [generation](Generation), the elapsed time since startup, and whether
+	/// the evolver is running. An action is overdue once its generation has
+	/// passed. While the evolver runs, an action is due as soon as its
+	/// generation arrives, so that it takes effect before the next evolution;
+	/// while paused, the action waits for its time to come.
+	fn is_due(&self, generation: u64, elapsed: Duration, running: bool) -> bool
+	{
+		generation > self.generation
+			|| generation == self.generation
+				&& (running || elapsed >= self.elapsed)
+	}
+
+	/// Append the [compact](RecordingFormat::Car) encoding of the stamped
+	/// action to the specified record, measuring the stamp from the specified
+	/// generation and elapsed time of the previous record. The tags are:
+	///
+	/// | Tag | Action      | Arguments                  |
+	/// |-----|-------------|----------------------------|
+	/// | 0   | `pause`     |                            |
+	/// | 1   | `seed`      | pane, seed                 |
+	/// | 2   | `rule`      | pane, rule                 |
+	/// | 3   | `append`    | pane, rule                 |
+	/// | 4   | `drop`      | pane                       |
+	/// | 5   | `mutate`    | pane, rule                 |
+	/// | 6   | `back`      | pane                       |
+	/// | 7   | `forward`   | pane, generations          |
+	/// | 8   | `toggle`    | pane, column               |
+	/// | 9   | `paint`     | pane, column, rule         |
+	/// | 10  | `paint`     | pane, column (erasing)     |
+	/// | 11  | `transform` | pane, transform (0–3)      |
+	///
+	/// The transforms are numbered in the order rotate-left, rotate-right,
+	/// invert, and symmetrize.
+	fn encode(&self, previous: (u64, Duration), record: &mut Vec<u8>)
+	{
+		let (generation, elapsed) = previous;
+		push_varint(record, self.generation.saturating_sub(generation));
+		push_varint(
+			record,
+			self.elapsed.saturating_sub(elapsed).as_millis() as u64
+		);
+		let pane = |record: &mut Vec<u8>, tag: u8, pane: usize| {
+			record.push(tag);
+			push_varint(record, pane as u64);
+		};
+		match self.action
+		{
+			Action::TogglePause => record.push(0),
+			Action::Seed { pane: p, seed } =>
+			{
+				pane(record, 1, p);
+				record.extend(u64::from(seed).to_be_bytes());
+			},
+			Action::ReplaceRule { pane: p, rule } =>
+			{
+				pane(record, 2, p);
+				record.push(rule.into());
+			},
+			Action::AppendRule { pane: p, rule } =>
+			{
+				pane(record, 3, p);
+				record.push(rule.into());
+			},
+			Action::DropRule { pane: p } => pane(record, 4, p),
+			Action::Mutate { pane: p, rule } =>
+			{
+				pane(record, 5, p);
+				record.push(rule.into());
+			},
+			Action::WalkBack { pane: p } => pane(record, 6, p),
+			Action::FastForward { pane: p, generations } =>
+			{
+				pane(record, 7, p);
+				push_varint(record, generations as u64);
+			},
+			Action::ToggleCell { pane: p, column } =>
+			{
+				pane(record, 8, p);
+				push_varint(record, column as u64);
+			},
+			Action::PaintRule { pane: p, column, rule: Some(rule) } =>
+			{
+				pane(record, 9, p);
+				push_varint(record, column as u64);
+				record.push(rule.into());
+			},
+			Action::PaintRule { pane: p, column, rule: None } =>
+			{
+				pane(record, 10, p);
+				push_varint(record, column as u64);
+			},
+			Action::TransformSeed { pane: p, transform } =>
+			{
+				pane(record, 11, p);
+				record.push(match transform
+				{
+					SeedTransform::RotateLeft => 0,
+					SeedTransform::RotateRight => 1,
+					SeedTransform::Invert => 2,
+					SeedTransform::Symmetrize => 3
+				});
+			}
+		}
+	}
+
+	/// Decode the next [compact](RecordingFormat::Car) record from the
+	/// specified reader, measuring its stamp from the specified generation and
+	/// elapsed time of the previous record.
+	fn decode(
+		reader: &mut Reader,
+		previous: (u64, Duration)
+	) -> Result<Self, String>
+	{
+		let (generation, elapsed) = previous;
+		let generation = generation + reader.varint()?;
+		let elapsed = elapsed + Duration::from_millis(reader.varint()?);
+		let tag = reader.byte()?;
+		let pane = match tag
+		{
+			0 => 0,
+			_ => reader.varint()? as usize
+		};
+		let action = match tag
+		{
+			0 => Action::TogglePause,
+			1 => Action::Seed {
+				pane,
+				seed: Automaton::from(u64::from_be_bytes(reader.double_word()?))
+			},
+			2 => Action::ReplaceRule { pane, rule: reader.byte()?.into() },
+			3 => Action::AppendRule { pane, rule: reader.byte()?.into() },
+			4 => Action::DropRule { pane },
+			5 => Action::Mutate { pane, rule: reader.byte()?.into() },
+			6 => Action::WalkBack { pane },
+			7 => Action::FastForward {
+				pane,
+				generations: reader.varint()? as usize
+			},
+			8 => Action::ToggleCell { pane, column: reader.varint()? as usize },
+			9 => Action::PaintRule {
+				pane,
+				column: reader.varint()? as usize,
+				rule: Some(reader.byte()?.into())
+			},
+			10 => Action::PaintRule {
+				pane,
+				column: reader.varint()? as usize,
+				rule: None
+			},
+			11 => Action::TransformSeed {
+				pane,
+				transform: match reader.byte()?
+				{
+					0 => SeedTransform::RotateLeft,
+					1 => SeedTransform::RotateRight,
+					2 => SeedTransform::Invert,
+					3 => SeedTransform::Symmetrize,
+					transform =>
+						return Err(format!("bad transform {}", transform))
+				}
+			},
+			_ => return Err(format!("unknown tag {}", tag))
+		};
+		Ok(Self { generation, elapsed, action })
+	}
+}
+
+impl Display for Stamped
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		write!(
+			f,
+			"{} {} {}",
+			self.generation,
+			self.elapsed.as_millis(),
+			self.action
+		)
+	}
+}
+
+impl FromStr for Stamped
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let mut fields = s.split_whitespace();
+		let generation = fields.next()
+			.and_then(|generation| generation.parse().ok())
+			.ok_or_else(|| "expected a generation".to_string())?;
+		let elapsed = fields.next()
+			.and_then(|millis| millis.parse().ok())
+			.map(Duration::from_millis)
+			.ok_or_else(|| "expected elapsed milliseconds".to_string())?;
+		let action = fields.collect::<Vec<_>>().join(" ").parse()?;
+		Ok(Self { generation, elapsed, action })
+	}
+}
+
+impl Display for Action
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match *self
+		{
+			Action::TogglePause => write!(f, "pause"),
+			Action::Seed { pane, seed } =>
+				write!(f, "seed {} {}", pane, seed.to_hex()),
+			Action::ReplaceRule { pane, rule } =>
+				write!(f, "rule {} {}", pane, u8::from(rule)),
+			Action::AppendRule { pane, rule } =>
+				write!(f, "append {} {}", pane, u8::from(rule)),
+			Action::DropRule { pane } => write!(f, "drop {}", pane),
+			Action::Mutate { pane, rule } =>
+				write!(f, "mutate {} {}", pane, u8::from(rule)),
+			Action::WalkBack { pane } => write!(f, "back {}", pane),
+			Action::FastForward { pane, generations } =>
+				write!(f, "forward {} {}", pane, generations),
+			Action::ToggleCell { pane, column } =>
+				write!(f, "toggle {} {}", pane, column),
+			Action::PaintRule { pane, column, rule: Some(rule) } =>
+				write!(f, "paint {} {} {}", pane, column, u8::from(rule)),
+			Action::PaintRule { pane, column, rule: None } =>
+				write!(f, "paint {} {} -", pane, column),
+			Action::TransformSeed { pane, transform } =>
+			{
+				let name = match transform
+				{
+					SeedTransform::RotateLeft => "rotate-left",
+					SeedTransform::RotateRight => "rotate-right",
+					SeedTransform::Invert => "invert",
+					SeedTransform::Symmetrize => "symmetrize"
+				};
+				write!(f, "transform {} {}", pane, name)
+			}
+		}
+	}
+}
+
+impl FromStr for Action
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let words = s.split_whitespace().collect::<Vec<_>>();
+		let arg = |i: usize| -> Result<u64, String> {
+			words.get(i)
+				.ok_or_else(|| format!("'{}' expects more arguments", words[0]))?
+				.parse::<u64>()
+				.map_err(|e| format!("bad argument to '{}': {}", words[0], e))
+		};
+		let rule = |i: usize| -> Result<AutomatonRule, String> {
+			u8::try_from(arg(i)?)
+				.map(AutomatonRule::from)
+				.map_err(|_| "rules must be in [0,255]".to_string())
+		};
+		let seed = |i: usize| -> Result<Automaton, String> {
+			let word = words.get(i)
+				.ok_or_else(|| format!("'{}' expects more arguments", words[0]))?;
+			// Older recordings give the seed as a decimal integer.
+			match word.parse::<u64>()
+			{
+				Ok(seed) => Ok(Automaton::from(seed)),
+				Err(_) => Automaton::from_digits(word)
+					.map_err(|e| format!("bad argument to '{}': {}", words[0], e))
+			}
+		};
+		let action = match words.first().copied()
+		{
+			Some("pause") => Action::TogglePause,
+			Some("seed") =>
+				Action::Seed { pane: arg(1)? as usize, seed: seed(2)? },
+			Some("rule") =>
+				Action::ReplaceRule { pane: arg(1)? as usize, rule: rule(2)? },
+			Some("append") =>
+				Action::AppendRule { pane: arg(1)? as usize, rule: rule(2)? },
+			Some("drop") => Action::DropRule { pane: arg(1)? as usize },
+			Some("mutate") =>
+				Action::Mutate { pane: arg(1)? as usize, rule: rule(2)? },
+			Some("back") => Action::WalkBack { pane: arg(1)? as usize },
+			Some("forward") => Action::FastForward {
+				pane: arg(1)? as usize,
+				generations: arg(2)? as usize
+			},
+			Some("toggle") => Action::ToggleCell {
+				pane: arg(1)? as usize,
+				column: arg(2)? as usize
+			},
+			// A dash erases the column.
+			Some("paint") => Action::PaintRule {
+				pane: arg(1)? as usize,
+				column: arg(2)? as usize,
+				rule: match words.get(3).copied()
+				{
+					Some("-") => None,
+					_ => Some(rule(3)?)
+				}
+			},
+			Some("transform") => Action::TransformSeed {
+				pane: arg(1)? as usize,
+				transform: match words.get(2).copied()
+				{
+					Some("rotate-left") => SeedTransform::RotateLeft,
+					Some("rotate-right") => SeedTransform::RotateRight,
+					Some("invert") => SeedTransform::Invert,
+					Some("symmetrize") => SeedTransform::Symmetrize,
+					_ => return Err("bad argument to 'transform'".to_string())
+				}
+			},
+			Some(verb) => return Err(format!("unknown action '{}'", verb)),
+			None => return Err("expected an action".to_string())
+		};
+		Ok(action)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why a [replay](Replay) may fail to load.
+#[derive(Debug)]
+pub enum ReplayError
+{
+	/// The recording could not be read.
+	Io(io::Error),
+
+	/// The specified line of the recording could not be parsed.
+	Parse(usize, String),
+
+	/// The [compact](RecordingFormat::Car) record at the specified byte
+	/// offset could not be decoded.
+	Decode(usize, String)
+}
+
+impl From<io::Error> for ReplayError
+{
+	fn from(value: io::Error) -> Self
+	{
+		ReplayError::Io(value)
+	}
+}
+
+impl Display for ReplayError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			ReplayError::Io(e) => write!(f, "cannot read replay: {}", e),
+			ReplayError::Parse(line, e) => write!(f, "line {}: {}", line, e),
+			ReplayError::Decode(offset, e) =>
+				write!(f, "byte {}: {}", offset, e)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Startup systems.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Begin the recording with the [initial&#32;actions](initial_actions) of
+/// every [pane](Pane), so that replay doesn't depend on the command line or on
+/// chance.
+fn record_initial_state(
+	recorder: Option<ResMut<Recorder>>,
+	panes: Query<(&Pane, &History, &RuleSchedule, &RuleMap)>
+) {
+	let Some(mut recorder) = recorder else { return };
+	let mut panes = panes.iter().collect::<Vec<_>>();
+	panes.sort_by_key(|(pane, ..)| **pane);
+	for (pane, history, schedule, map) in panes
+	{
+		for action in initial_actions(pane.0, history, schedule, map)
+		{
+			recorder.write(&Stamped {
+				generation: 0,
+				elapsed: Duration::ZERO,
+				action
+			});
+		}
+	}
+	recorder.flush();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Send every [action](Action) of the [replay](Replay) that has fallen due.
+/// Forbid the evolution to pass the generation of the next pending action,
+/// lest several generations in a single frame carry it past its moment.
+fn replay_actions(
+	time: Res<Time>,
+	timer: Res<EvolutionTimer>,
+	generation: Res<Generation>,
+	replay: Option<ResMut<Replay>>,
+	mut ceiling: ResMut<GenerationCeiling>,
+	mut actions: EventWriter<Action>
+) {
+	let Some(mut replay) = replay else { return };
+	let was_pending = !replay.0.is_empty();
+	while let Some(next) = replay.0.front()
+	{
+		if !next.is_due(generation.0, time.elapsed(), timer.is_running())
+		{
+			break
+		}
+		actions.send(replay.0.pop_front().unwrap().action);
+	}
+	ceiling.set_if_neq(GenerationCeiling(
+		replay.0.front().map(|next| next.generation)
+	));
+	if was_pending && replay.0.is_empty()
+	{
+		info!("Replay finished");
+	}
+}
+
+/// Append every [action](Action) just [applied](apply_actions) to the
+/// [recording](Recorder), stamped with the [generation](Generation) that it
+/// preceded.
+fn record_actions(
+	time: Res<Time>,
+	generation: Res<Generation>,
+	recorder: Option<ResMut<Recorder>>,
+	mut actions: EventReader<Action>
+) {
+	let Some(mut recorder) = recorder else { return };
+	if actions.is_empty()
+	{
+		return
+	}
+	for action in actions.read()
+	{
+		recorder.write(&Stamped {
+			generation: generation.0,
+			elapsed: time.elapsed(),
+			action: action.clone()
+		});
+	}
+	recorder.flush();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Decode the [stamped&#32;actions](Stamped) of the specified
+/// [compact](RecordingFormat::Car) recording, which begins with its header.
+fn decode_car(bytes: &[u8]) -> Result<VecDeque<Stamped>, ReplayError>
+{
+	let mut reader = Reader { bytes, offset: CAR_MAGIC.len() };
+	let mut pending = VecDeque::new();
+	let mut previous = (0, Duration::ZERO);
+	while reader.offset < bytes.len()
+	{
+		let offset = reader.offset;
+		let stamped = Stamped::decode(&mut reader, previous)
+			.map_err(|message| ReplayError::Decode(offset, message))?;
+		previous = (stamped.generation, stamped.elapsed);
+		pending.push_back(stamped);
+	}
+	Ok(pending)
+}
+
+/// Append the specified number to the specified record as an unsigned LEB128
+/// varint: seven bits per byte, least significant first, with the high bit set
+/// on every byte but the last.
+fn push_varint(record: &mut Vec<u8>, mut n: u64)
+{
+	while n >= 0x80
+	{
+		record.push(n as u8 | 0x80);
+		n >>= 7;
+	}
+	record.push(n as u8);
+}
+
+/// A cursor over a [compact](RecordingFormat::Car) recording.
+struct Reader<'a>
+{
+	/// The whole recording.
+	bytes: &'a [u8],
+
+	/// The offset of the next unread byte.
+	offset: usize
+}
+
+impl Reader<'_>
+{
+	/// Read a single byte.
+	fn byte(&mut self) -> Result<u8, String>
+	{
+		let byte = *self.bytes.get(self.offset)
+			.ok_or_else(|| "truncated record".to_string())?;
+		self.offset += 1;
+		Ok(byte)
+	}
+
+	/// Read eight bytes.
+	fn double_word(&mut self) -> Result<[u8; 8], String>
+	{
+		let mut word = [0; 8];
+		for byte in &mut word
+		{
+			*byte = self.byte()?;
+		}
+		Ok(word)
+	}
+
+	/// Read an unsigned LEB128 varint.
+	fn varint(&mut self) -> Result<u64, String>
+	{
+		let mut n = 0u64;
+		for shift in (0 .. 64).step_by(7)
+		{
+			let byte = self.byte()?;
+			n |= u64::from(byte & 0x7F) << shift;
+			if byte & 0x80 == 0
+			{
+				return Ok(n)
+			}
+		}
+		Err("overlong varint".to_string())
+	}
+}
+
+/// Answer the [actions](Action) that reproduce the state of the specified
+/// [pane](Pane) from scratch: its [newest](History::newest) generation, its
+/// [schedule](RuleSchedule), and its [rule&#32;map](RuleMap). Only elementary
+/// rules can be reproduced; other rules are left to the command line.
+pub(crate) fn initial_actions(
+	pane: usize,
+	history: &History,
+	schedule: &RuleSchedule,
+	map: &RuleMap
+) -> Vec<Action>
+{
+	let seed = *history.newest();
+	let mut actions = vec![Action::Seed { pane, seed }];
+	for (i, rule) in schedule.iter().enumerate()
+	{
+		match *rule
+		{
+			RuleKind::Wolfram(rule) if i == 0 =>
+				actions.push(Action::ReplaceRule { pane, rule }),
+			RuleKind::Wolfram(rule) =>
+				actions.push(Action::AppendRule { pane, rule }),
+			#[allow(unreachable_patterns)]
+			_ => warn!("Cannot reproduce {}", rule)
+		}
+	}
+	for column in 0 .. AUTOMATON_LENGTH
+	{
+		if let Some(rule) = map.get(column)
+		{
+			actions.push(Action::PaintRule { pane, column, rule: Some(rule) });
+		}
+	}
+	actions
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The header of every [compact](RecordingFormat::Car) recording: the
+/// letters `CAR`, then the version of the format.
+const CAR_MAGIC: &[u8] = b"CAR\x01";
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Tests.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test
+{
+	use std::time::Duration;
+
+	use crate::automata::{Automaton, AutomatonRule};
+	use crate::ecs::{Action, SeedTransform};
+	use crate::replay::{CAR_MAGIC, decode_car, ReplayError, Stamped};
+
+	/// Ensure that every kind of [action](Action) survives a round trip through
+	/// the [compact](super::RecordingFormat::Car) format, and that damaged
+	/// recordings are rejected at the offset of the damage.
+	#[test]
+	fn car()
+	{
+		let rule = AutomatonRule::from(110);
+		let seed = Automaton::from(0x8000_0000_0000_0001);
+		let actions = vec![
+			Action::Seed { pane: 0, seed },
+			Action::ReplaceRule { pane: 0, rule },
+			Action::AppendRule { pane: 1, rule },
+			Action::TogglePause,
+			Action::DropRule { pane: 1 },
+			Action::Mutate { pane: 0, rule: AutomatonRule::from(30) },
+			Action::WalkBack { pane: 0 },
+			Action::FastForward { pane: 0, generations: 1000 },
+			Action::ToggleCell { pane: 300, column: 63 },
+			Action::PaintRule { pane: 0, column: 7, rule: Some(rule) },
+			Action::PaintRule { pane: 0, column: 7, rule: None },
+			Action::TransformSeed { pane: 0, transform: SeedTransform::Invert }
+		];
+		let stamped = actions.into_iter()
+			.enumerate()
+			.map(|(i, action)| Stamped {
+				generation: i as u64 * 100,
+				elapsed: Duration::from_millis(i as u64 * 1234),
+				action
+			})
+			.collect::<Vec<_>>();
+		let mut bytes = CAR_MAGIC.to_vec();
+		let mut previous = (0, Duration::ZERO);
+		for stamped in &stamped
+		{
+			stamped.encode(previous, &mut bytes);
+			previous = (stamped.generation, stamped.elapsed);
+		}
+		let decoded = decode_car(&bytes).unwrap();
+		assert_eq!(stamped, Vec::from(decoded));
+
+		// Chop the final record, which is the transform.
+		bytes.pop();
+		match decode_car(&bytes)
+		{
+			Err(ReplayError::Decode(offset, _)) =>
+				assert_eq!(bytes.len() - 5, offset),
+			_ => panic!("truncated recording decoded")
+		}
+	}
+}
@@ -0,0 +1,284 @@
+use bevy::prelude::{
+	App, BackgroundColor, BorderColor, BuildChildren, ChildBuilder, Color,
+	Commands, Component, default, DetectChanges, Display, FlexDirection,
+	IntoSystemConfigs, JustifyContent, NodeBundle, Plugin, PositionType, Query,
+	Ref, Res, Style, UiRect, Update, Val, With, Without, ZIndex
+};
+
+use crate::automata::{RuleKind, RuleSchedule};
+use crate::ecs::{apply_actions, evolve, Focused, Palette, TOAST_Z_INDEX};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the rule icon, which pictures the
+/// active rule of the [focused](Focused)&#32;[pane](crate::ecs::Pane) in the
+/// top right corner of the window, in the classic style: eight tiles, one per
+/// neighborhood, each showing the neighborhood above the cell that it
+/// produces. The icon is always shown, so that screenshots describe
+/// themselves, but only elementary rules can be pictured.
+pub struct RuleIconPlugin;
+
+impl Plugin for RuleIconPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(
+			Update,
+			update_rule_icon.after(apply_actions).after(evolve)
+		);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The frame of the rule icon, which holds its eight tiles.
+#[derive(Component)]
+struct RuleIcon;
+
+/// A single cell of the rule icon, within the tile of some neighborhood.
+#[derive(Copy, Clone, Debug, Component)]
+struct RuleIconCell
+{
+	/// The ordinal of the neighborhood pictured by the tile, in `[0,7]`.
+	ordinal: u8,
+
+	/// Which bit of the neighborhood this cell pictures, from `2` for the
+	/// left neighbor down to `0` for the right neighbor, or [None] if this cell
+	/// pictures the outcome of the neighborhood.
+	bit: Option<u8>
+}
+
+impl RuleIconCell
+{
+	/// Determine whether this cell is live, when picturing the rule with the
+	/// specified Wolfram code.
+	fn is_live(self, code: u8) -> bool
+	{
+		match self.bit
+		{
+			Some(bit) => self.ordinal & (1 << bit) != 0,
+			None => code & (1 << self.ordinal) != 0
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Keep the [rule&#32;icon](RuleIcon) in step with the active rule of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane), repainting it whenever
+/// the rule, the focus, or the [palette](Palette) changes. Hide the icon
+/// while the active rule is not elementary.
+fn update_rule_icon(
+	palette: Res<Palette>,
+	pane: Query<(Ref<RuleSchedule>, Ref<Focused>)>,
+	mut frames: Query<(&mut Style, &mut BackgroundColor), With<RuleIcon>>,
+	mut cells: Query<(&RuleIconCell, &mut BackgroundColor), Without<RuleIcon>>,
+	mut commands: Commands
+) {
+	let Ok((schedule, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let code = wolfram_code(schedule.active());
+	let Ok((mut style, mut background)) = frames.get_single_mut() else
+	{
+		build_rule_icon(code, &palette, &mut commands);
+		return
+	};
+	if !schedule.is_changed() && !focused.is_added() && !palette.is_changed()
+	{
+		return
+	}
+	let display = match code
+	{
+		Some(_) => Display::Flex,
+		None => Display::None
+	};
+	if style.display != display
+	{
+		style.display = display;
+	}
+	if background.0 != palette.chrome
+	{
+		background.0 = palette.chrome;
+	}
+	let Some(code) = code else
+	{
+		return
+	};
+	for (cell, mut color) in &mut cells
+	{
+		let new = cell_color(*cell, code, &palette);
+		if color.0 != new
+		{
+			color.0 = new;
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [frame](RuleIcon) of the rule icon in the top right corner of the
+/// window, picturing the rule with the specified Wolfram code, or hidden if
+/// there is none. The tiles run from the neighborhood `111` on the left down
+/// to `000` on the right.
+fn build_rule_icon(code: Option<u8>, palette: &Palette, commands: &mut Commands)
+{
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: match code
+						{
+							Some(_) => Display::Flex,
+							None => Display::None
+						},
+						position_type: PositionType::Absolute,
+						right: Val::Px(RULE_ICON_MARGIN),
+						top: Val::Px(RULE_ICON_MARGIN),
+						column_gap: Val::Px(RULE_ICON_TILE_GAP),
+						padding: UiRect::all(Val::Px(RULE_ICON_TILE_GAP)),
+						border: UiRect::all(Val::Px(RULE_ICON_BORDER)),
+						..default()
+					},
+					background_color: BackgroundColor(palette.chrome),
+					border_color: BorderColor(RULE_ICON_FRAME_COLOR),
+					z_index: ZIndex::Global(RULE_ICON_Z_INDEX),
+					..default()
+				},
+				RuleIcon
+			)
+		)
+		.with_children(|builder| {
+			for ordinal in (0 ..= 7).rev()
+			{
+				build_tile(builder, ordinal, code, palette);
+			}
+		});
+}
+
+/// Add the tile of the neighborhood with the specified ordinal to the rule
+/// icon: a row with the three cells of the neighborhood, above a row with the
+/// single cell that it produces under the rule with the specified Wolfram code.
+fn build_tile(
+	builder: &mut ChildBuilder,
+	ordinal: u8,
+	code: Option<u8>,
+	palette: &Palette
+) {
+	let row = NodeBundle {
+		style: Style {
+			justify_content: JustifyContent::Center,
+			..default()
+		},
+		..default()
+	};
+	builder
+		.spawn(NodeBundle {
+			style: Style {
+				flex_direction: FlexDirection::Column,
+				..default()
+			},
+			..default()
+		})
+		.with_children(|builder| {
+			builder.spawn(row.clone()).with_children(|builder| {
+				for bit in [2, 1, 0]
+				{
+					let cell = RuleIconCell { ordinal, bit: Some(bit) };
+					build_cell(builder, cell, code, palette);
+				}
+			});
+			builder.spawn(row).with_children(|builder| {
+				let cell = RuleIconCell { ordinal, bit: None };
+				build_cell(builder, cell, code, palette);
+			});
+		});
+}
+
+/// Add the specified [cell](RuleIconCell) to a tile of the rule icon, painted
+/// for the rule with the specified Wolfram code, or dead if there is none.
+fn build_cell(
+	builder: &mut ChildBuilder,
+	cell: RuleIconCell,
+	code: Option<u8>,
+	palette: &Palette
+) {
+	let color = match code
+	{
+		Some(code) => cell_color(cell, code, palette),
+		None => palette.dead
+	};
+	builder.spawn(
+		(
+			NodeBundle {
+				style: Style {
+					width: Val::Px(RULE_ICON_CELL_SIZE),
+					height: Val::Px(RULE_ICON_CELL_SIZE),
+					..default()
+				},
+				background_color: BackgroundColor(color),
+				..default()
+			},
+			cell
+		)
+	);
+}
+
+/// Answer the [color](Color) of the specified [cell](RuleIconCell) of the rule
+/// icon, when picturing the rule with the specified Wolfram code.
+fn cell_color(cell: RuleIconCell, code: u8, palette: &Palette) -> Color
+{
+	match cell.is_live(code)
+	{
+		true => palette.live,
+		false => palette.dead
+	}
+}
+
+/// Answer the Wolfram code of the specified [rule](RuleKind), if it is
+/// elementary.
+fn wolfram_code(rule: &RuleKind) -> Option<u8>
+{
+	match *rule
+	{
+		RuleKind::Wolfram(rule) => Some(u8::from(rule)),
+		#[allow(unreachable_patterns)]
+		_ => None
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The width and height of each [cell](RuleIconCell) of the rule icon, in
+/// pixels.
+const RULE_ICON_CELL_SIZE: f32 = 6.0;
+
+/// The space between adjacent tiles of the rule icon, and between the tiles
+/// and the border of the [frame](RuleIcon), in pixels.
+const RULE_ICON_TILE_GAP: f32 = 4.0;
+
+/// The distance between the [frame](RuleIcon) of the rule icon and the corner
+/// of the window, in pixels.
+const RULE_ICON_MARGIN: f32 = 12.0;
+
+/// The width of the border of the [frame](RuleIcon), in pixels.
+const RULE_ICON_BORDER: f32 = 1.0;
+
+/// The [color](Color) of the border of the [frame](RuleIcon).
+const RULE_ICON_FRAME_COLOR: Color = Color::GRAY;
+
+/// The [z-index](ZIndex) of the rule icon, which lies above the panes, but
+/// beneath the tooltip, the growing tape, and toasts.
+const RULE_ICON_Z_INDEX: i32 = TOAST_Z_INDEX - 2;
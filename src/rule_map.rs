@@ -0,0 +1,154 @@
+use bevy::prelude::{
+	App, Button, Changed, DetectChanges, EventWriter, Input, Interaction,
+	IntoSystemConfigs, KeyCode, Local, Plugin, Query, Ref, Res, ResMut,
+	Resource, Update, With
+};
+
+use crate::automata::{RuleKind, RuleMap, RuleSchedule};
+use crate::ecs::{
+	accept_digit, Action, Announcement, apply_actions, AutomatonRuleBuilder,
+	CellPosition, Pane
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for painting rules onto columns, so that
+/// different stretches of an automaton evolve under different dynamics, and
+/// the interface between them can be watched. While [painting](Painting),
+/// clicking a cell of the newest generation assigns the active rule of its
+/// pane to the cell's column, or, if the column already has that rule,
+/// returns the column to the [schedule](RuleSchedule). Shift-clicking paints
+/// every column between the last painted column and the clicked one. Each pane
+/// keeps its own [map](RuleMap), which can also be given on the command line.
+pub struct RuleMapPlugin;
+
+impl Plugin for RuleMapPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Painting>()
+			.add_systems(Update, maybe_toggle_painting.before(accept_digit))
+			.add_systems(Update, maybe_paint.before(apply_actions))
+			.add_systems(Update, announce_rule_map.after(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether clicking a cell of the newest generation paints a rule onto its
+/// column, rather than toggling the cell.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct Painting(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `X`, toggle [painting](Painting), and announce the new setting.
+fn maybe_toggle_painting(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	language: Res<Language>,
+	mut painting: ResMut<Painting>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.paint_rule)
+		&& !builder.claims(bindings.paint_rule)
+	{
+		painting.0 = !painting.0;
+		let message = match painting.0
+		{
+			true => Message::PaintingOn,
+			false => Message::PaintingOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While [painting](Painting), on press of a cell of the newest generation,
+/// paint the [active](RuleSchedule::active) rule of its pane onto its column,
+/// or erase the column if it already has that rule. With shift held, paint or
+/// erase every column from the last one painted in the same pane. Only
+/// elementary rules can be painted.
+fn maybe_paint(
+	keys: Res<Input<KeyCode>>,
+	painting: Res<Painting>,
+	panes: Query<(&Pane, &RuleSchedule, &RuleMap)>,
+	interaction: Query<
+		(&Interaction, &CellPosition),
+		(Changed<Interaction>, With<Button>)
+	>,
+	mut last: Local<Option<CellPosition>>,
+	mut actions: EventWriter<Action>
+) {
+	if !painting.0
+	{
+		return
+	}
+	let extend = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+	for (interaction, position) in &interaction
+	{
+		if *interaction != Interaction::Pressed
+		{
+			continue
+		}
+		let Ok((pane, schedule, map)) = panes.get(position.pane) else
+		{
+			continue
+		};
+		let active = match *schedule.active()
+		{
+			RuleKind::Wolfram(rule) => rule,
+			#[allow(unreachable_patterns)]
+			_ => continue
+		};
+		let rule = match map.get(position.column)
+		{
+			Some(rule) if rule == active => None,
+			_ => Some(active)
+		};
+		let columns = match *last
+		{
+			Some(last) if extend && last.pane == position.pane =>
+				last.column.min(position.column)
+					..= last.column.max(position.column),
+			_ => position.column ..= position.column
+		};
+		actions.send_batch(columns.map(|column| Action::PaintRule {
+			pane: pane.0,
+			column,
+			rule
+		}));
+		*last = Some(*position);
+	}
+}
+
+/// Whenever the [map](RuleMap) of a pane changes, announce the map, so that
+/// the painter can see which columns follow which rules.
+fn announce_rule_map(
+	language: Res<Language>,
+	maps: Query<Ref<RuleMap>>,
+	mut announcements: EventWriter<Announcement>
+) {
+	for map in &maps
+	{
+		if !map.is_changed() || map.is_added()
+		{
+			continue
+		}
+		let text = match map.is_empty()
+		{
+			true => language.text(Message::RuleMapEmpty).to_string(),
+			false => language.format(Message::RuleMap, &[&*map])
+		};
+		announcements.send(Announcement(text));
+	}
+}
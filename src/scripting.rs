@@ -0,0 +1,328 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use std::{fs, io};
+
+use bevy::log::{info, warn};
+use bevy::prelude::{Local, Query, Res, Time, Timer};
+use bevy::time::TimerMode;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, ParseError, Scope, AST};
+
+use crate::automata::{Automaton, RuleKind, RuleSchedule};
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Scripted rules.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [ScriptedRule] is an evolutionary rule implemented by a user-supplied
+/// [Rhai](https://rhai.rs/) script. The script must define one of the following
+/// functions:
+///
+/// * `next_cell(left, center, right)`, which answers the next state of a single
+///   cell, given the current states of its neighborhood as `bool`s; or
+/// * `next_row(cells)`, which answers the next generation as an array of
+///   `bool`s, given the current generation as an array of `bool`s. Both arrays
+///   are in right-to-left order, i.e., index `0` denotes the rightmost cell.
+///
+/// If both are defined, then `next_row` wins. Scripts are sandboxed: the engine
+/// provides no access to the file system or the network, and every call is
+/// bounded in the number of operations that it may perform, the depth of its
+/// calls, and the sizes of the values that it may build.
+pub struct ScriptedRule
+{
+	/// The path to the script, which serves as its identity for the purpose of
+	/// [reloading](maybe_reload_scripts).
+	path: PathBuf,
+
+	/// The sandboxed scripting engine.
+	engine: Engine,
+
+	/// The compiled script.
+	ast: AST,
+
+	/// The entry point of the script.
+	entry: Entry,
+
+	/// The modification time of the script when it was loaded, if the
+	/// platform supports modification times.
+	modified: Option<SystemTime>
+}
+
+impl ScriptedRule
+{
+	/// Load and compile the script at the specified path.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, ScriptError>
+	{
+		let path = path.as_ref().to_path_buf();
+		let modified = fs::metadata(&path)
+			.and_then(|metadata| metadata.modified())
+			.ok();
+		let source = fs::read_to_string(&path)?;
+		let engine = sandboxed_engine();
+		let ast = engine.compile(source)?;
+		let entry = ast.iter_functions()
+			.filter_map(|function| match (function.name, function.params.len())
+			{
+				("next_row", 1) => Some(Entry::Row),
+				("next_cell", 3) => Some(Entry::Cell),
+				_ => None
+			})
+			.max()
+			.ok_or(ScriptError::MissingEntry)?;
+		Ok(Self { path, engine, ast, entry, modified })
+	}
+
+	/// Answer the file name of the script, for display purposes.
+	pub fn name(&self) -> String
+	{
+		self.path.file_name()
+			.map(|name| name.to_string_lossy().into_owned())
+			.unwrap_or_else(|| self.path.display().to_string())
+	}
+
+	/// Determine whether the script has been modified on disk since it was
+	/// loaded.
+	fn is_stale(&self) -> bool
+	{
+		let modified = fs::metadata(&self.path)
+			.and_then(|metadata| metadata.modified())
+			.ok();
+		modified.is_some() && modified != self.modified
+	}
+
+	/// Compute the successor [automaton][Automaton] by running the script. If
+	/// the script fails, then log the failure and answer the unchanged
+	/// automaton, so that a faulty script merely stalls the evolution.
+	pub fn next<const K: usize>(&self, automaton: &Automaton<K>) -> Automaton<K>
+	{
+		let next = match self.entry
+		{
+			Entry::Row => self.next_row(automaton),
+			Entry::Cell => self.next_cells(automaton)
+		};
+		next.unwrap_or_else(|e| {
+			warn!("{} failed: {}", self.name(), e);
+			*automaton
+		})
+	}
+
+	/// Compute the successor [automaton][Automaton] by calling `next_row`.
+	fn next_row<const K: usize>(
+		&self,
+		automaton: &Automaton<K>
+	) -> Result<Automaton<K>, Box<EvalAltResult>>
+	{
+		let cells = automaton.iter()
+			.map(|cell| Dynamic::from_bool(*cell))
+			.collect::<Array>();
+		let result = self.engine.call_fn::<Array>(
+			&mut Scope::new(),
+			&self.ast,
+			"next_row",
+			(cells,)
+		)?;
+		if result.len() != K
+		{
+			return Err(format!(
+				"next_row answered {} cells, but {} were expected",
+				result.len(),
+				K
+			).into());
+		}
+		let mut next = [false; K];
+		for (i, cell) in result.into_iter().enumerate()
+		{
+			next[i] = cell.as_bool()?;
+		}
+		Ok(next.into())
+	}
+
+	/// Compute the successor [automaton][Automaton] by calling `next_cell`
+	/// once for each cell. As usual, the two ends of the automaton are
+	/// considered adjacent.
+	fn next_cells<const K: usize>(
+		&self,
+		automaton: &Automaton<K>
+	) -> Result<Automaton<K>, Box<EvalAltResult>>
+	{
+		let mut scope = Scope::new();
+		let mut next = [false; K];
+		for i in 0 .. K
+		{
+			let left = automaton[(i + 1) % K];
+			let center = automaton[i];
+			let right = automaton[(i + K - 1) % K];
+			next[i] = self.engine.call_fn::<bool>(
+				&mut scope,
+				&self.ast,
+				"next_cell",
+				(left, center, right)
+			)?;
+		}
+		Ok(next.into())
+	}
+}
+
+impl Debug for ScriptedRule
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("ScriptedRule")
+			.field("path", &self.path)
+			.field("entry", &self.entry)
+			.finish()
+	}
+}
+
+/// The entry point of a [script](ScriptedRule). The ordering reflects the
+/// preference for `next_row` over `next_cell`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Entry
+{
+	/// `next_cell(left, center, right)`.
+	Cell,
+
+	/// `next_row(cells)`.
+	Row
+}
+
+/// Construct a sandboxed [scripting&#32;engine](Engine).
+fn sandboxed_engine() -> Engine
+{
+	let mut engine = Engine::new();
+	engine.set_max_operations(MAX_OPERATIONS);
+	engine.set_max_call_levels(MAX_CALL_LEVELS);
+	engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+	engine.set_max_string_size(MAX_SIZE);
+	engine.set_max_array_size(MAX_SIZE);
+	engine.set_max_map_size(MAX_SIZE);
+	engine.set_max_modules(0);
+	engine
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Errors.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The reasons why a [script](ScriptedRule) may fail to load.
+#[derive(Debug)]
+pub enum ScriptError
+{
+	/// The script could not be read.
+	Io(io::Error),
+
+	/// The script could not be compiled.
+	Compile(ParseError),
+
+	/// The script defines neither `next_cell` nor `next_row`.
+	MissingEntry
+}
+
+impl From<io::Error> for ScriptError
+{
+	fn from(value: io::Error) -> Self
+	{
+		ScriptError::Io(value)
+	}
+}
+
+impl From<ParseError> for ScriptError
+{
+	fn from(value: ParseError) -> Self
+	{
+		ScriptError::Compile(value)
+	}
+}
+
+impl Display for ScriptError
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			ScriptError::Io(e) => write!(f, "cannot read script: {}", e),
+			ScriptError::Compile(e) => write!(f, "cannot compile script: {}", e),
+			ScriptError::MissingEntry => write!(
+				f,
+				"script defines neither next_cell(left, center, right) nor \
+					next_row(cells)"
+			)
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Periodically check whether any [scripts](ScriptedRule) in use have changed
+/// on disk. Recompile each changed script, and replace every occurrence of it
+/// in every [schedule](RuleSchedule). If recompilation fails, then log the
+/// failure and keep using the old script.
+pub fn maybe_reload_scripts(
+	time: Res<Time>,
+	mut timer: Local<Option<Timer>>,
+	mut schedules: Query<&mut RuleSchedule>
+) {
+	let timer = timer.get_or_insert_with(
+		|| Timer::new(RELOAD_INTERVAL, TimerMode::Repeating)
+	);
+	timer.tick(time.delta());
+	if !timer.just_finished()
+	{
+		return
+	}
+	for mut schedule in &mut schedules
+	{
+		let stale = schedule.iter().any(|rule| match rule
+		{
+			RuleKind::Scripted(script) => script.is_stale(),
+			_ => false
+		});
+		if stale
+		{
+			for rule in schedule.iter_mut()
+			{
+				if let RuleKind::Scripted(script) = rule
+				{
+					if script.is_stale()
+					{
+						match ScriptedRule::load(&script.path)
+						{
+							Ok(reloaded) =>
+							{
+								info!("Reloaded {}", reloaded.name());
+								*script = Arc::new(reloaded);
+							},
+							Err(e) => warn!("{}: {}", script.name(), e)
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How often to check [scripts](ScriptedRule) for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximum number of operations that a single call into a
+/// [script](ScriptedRule) may perform.
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// The maximum depth of nested function calls within a
+/// [script](ScriptedRule).
+const MAX_CALL_LEVELS: usize = 32;
+
+/// The maximum depth of nested expressions within a [script](ScriptedRule).
+const MAX_EXPR_DEPTH: usize = 64;
+
+/// The maximum size of any string, array, or map built by a
+/// [script](ScriptedRule).
+const MAX_SIZE: usize = 4096;
@@ -0,0 +1,135 @@
+use std::io::Write;
+use std::time::Duration;
+
+use bevy::log::warn;
+use bevy::prelude::{
+	App, DetectChanges, Entity, NonSendMut, Plugin, Query, Ref, Update, With
+};
+use serialport::SerialPort;
+
+use crate::automata::{Automaton, History};
+use crate::ecs::Focused;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for driving an LED strip or matrix from the
+/// [focused](Focused)&#32;[automaton](Automaton), by way of a microcontroller
+/// attached to a serial port. Whenever the newest generation changes, which is
+/// every heartbeat while the evolution runs, it is sent as a [frame](frame). A
+/// [SerialSink] must already have been inserted as a non-[Send] resource.
+pub struct SerialPlugin;
+
+impl Plugin for SerialPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		assert!(
+			app.world.get_non_send_resource::<SerialSink>().is_some(),
+			"SerialSink resource to be inserted already"
+		);
+		app.add_systems(Update, send_generation);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The connection to a serial port, along with the pane most recently sent to
+/// it. Because a [port](SerialPort) cannot be shared between threads, this must
+/// be inserted as a non-[Send] resource.
+pub struct SerialSink
+{
+	/// The serial port.
+	port: Box<dyn SerialPort>,
+
+	/// The [pane](Focused) entity whose generation was most recently sent, if
+	/// any.
+	previous: Option<Entity>
+}
+
+impl SerialSink
+{
+	/// Open the specified serial port, e.g., `/dev/ttyUSB0` or `COM3`, at the
+	/// specified baud rate.
+	pub fn open(path: &str, baud_rate: u32) -> Result<Self, serialport::Error>
+	{
+		let port = serialport::new(path, baud_rate)
+			.timeout(WRITE_TIMEOUT)
+			.open()?;
+		Ok(Self { port, previous: None })
+	}
+
+	/// Send the specified generation of the specified pane, logging any
+	/// failure.
+	fn send(&mut self, pane: Entity, automaton: &Automaton)
+	{
+		if let Err(e) = self.port.write_all(&frame(automaton))
+		{
+			warn!("Serial send failed: {}", e);
+		}
+		self.previous = Some(pane);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whenever the [history](History) of the [focused](Focused) pane changes, or
+/// another pane gains the focus, send its [newest](History::newest) generation
+/// to the [SerialSink].
+fn send_generation(
+	history: Query<(Entity, Ref<History>), With<Focused>>,
+	mut sink: NonSendMut<SerialSink>
+) {
+	if let Ok((pane, history)) = history.get_single()
+	{
+		if history.is_changed() || sink.previous != Some(pane)
+		{
+			sink.send(pane, history.newest());
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the frame that carries the specified generation:
+///
+/// * The two [sync&#32;bytes](SYNC), `0xA5 0x5A`.
+/// * Eight bytes of cells, one bit per cell, from left to right, most
+///   significant bit first, where `1` is live and `0` is dead.
+/// * A checksum byte, the exclusive-or of the eight bytes of cells.
+///
+/// A receiver that loses its place waits for the sync bytes, then discards
+/// any frame whose checksum disagrees.
+fn frame(automaton: &Automaton) -> [u8; FRAME_LENGTH]
+{
+	let cells = u64::from(*automaton).to_be_bytes();
+	let mut frame = [0; FRAME_LENGTH];
+	frame[.. SYNC.len()].copy_from_slice(&SYNC);
+	frame[SYNC.len() .. FRAME_LENGTH - 1].copy_from_slice(&cells);
+	frame[FRAME_LENGTH - 1] = cells.iter().fold(0, |sum, byte| sum ^ byte);
+	frame
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The baud rate of the serial port, unless otherwise specified.
+pub const DEFAULT_BAUD_RATE: u32 = 115_200;
+
+/// The bytes that begin every [frame](frame).
+const SYNC: [u8; 2] = [0xA5, 0x5A];
+
+/// The length of a [frame](frame), in bytes: the sync bytes, eight bytes of
+/// cells, and a checksum.
+const FRAME_LENGTH: usize = SYNC.len() + 8 + 1;
+
+/// How long to wait for the serial port to accept a frame.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
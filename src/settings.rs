@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use bevy::prelude::{
+	App, DetectChangesMut, EventWriter, IntoSystemConfigs, Plugin, Query, Res,
+	ResMut, Update, With
+};
+use bevy_egui::egui::{ComboBox, DragValue, SidePanel, Slider};
+use bevy_egui::{EguiContexts, EguiPlugin};
+
+use crate::automata::{AutomatonRule, RuleKind, RuleSchedule};
+use crate::ecs::{
+	Action, apply_actions, EvolutionTimer, Focused, PaletteScheme, Pane
+};
+use crate::i18n::{Language, Message};
+use crate::sonification::{Scale, Sonification};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the settings panel, which gathers the
+/// runtime settings of the simulation into one place, so that they can be
+/// adjusted with the mouse rather than with dedicated hotkeys.
+///
+/// The panel offers no boundary condition, noise, or width: the ends of every
+/// [automaton](crate::automata::Automaton) are always adjacent, evolution is
+/// always deterministic, and the width is fixed at compile time by
+/// [AUTOMATON_LENGTH](crate::automata::AUTOMATON_LENGTH).
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_plugins(EguiPlugin)
+			.add_systems(Update, settings_panel.before(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Show the settings panel along the right edge of the window, and apply any
+/// changes made there. Resources and components are only written when a
+/// setting actually changes, so that change detection elsewhere isn't
+/// triggered every frame.
+fn settings_panel(
+	mut contexts: EguiContexts,
+	language: Res<Language>,
+	mut timer: ResMut<EvolutionTimer>,
+	mut palette: ResMut<PaletteScheme>,
+	mut sonification: ResMut<Sonification>,
+	pane: Query<(&Pane, &RuleSchedule), With<Focused>>,
+	mut actions: EventWriter<Action>
+) {
+	let (pane, schedule) = pane.single();
+	// Only elementary rules have Wolfram codes to adjust.
+	let old_rule = match *schedule.active()
+	{
+		RuleKind::Wolfram(rule) => Some(u8::from(rule)),
+		#[cfg(feature = "scripting")]
+		RuleKind::Scripted(_) => None,
+		#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+		RuleKind::Wasm(_) => None
+	};
+	let mut rule = old_rule;
+	let mut heartbeat = timer.heartbeat().as_millis() as u64;
+	let mut scheme = *palette;
+	let mut scale = sonification.scale;
+	let mut volume = sonification.volume;
+	let mut muted = sonification.muted;
+	SidePanel::right("settings").show(contexts.ctx_mut(), |ui| {
+		ui.heading(language.text(Message::Settings));
+		ui.separator();
+		ui.horizontal(|ui| {
+			ui.label(language.text(Message::Rule));
+			match rule
+			{
+				Some(ref mut rule) => ui.add(DragValue::new(rule)),
+				None => ui.label(schedule.active().to_string())
+			};
+		});
+		ui.add(
+			Slider::new(&mut heartbeat, MIN_HEARTBEAT ..= MAX_HEARTBEAT)
+				.logarithmic(true)
+				.suffix(" ms")
+				.text(language.text(Message::Heartbeat))
+		);
+		ComboBox::from_label(language.text(Message::Scheme))
+			.selected_text(scheme.to_string())
+			.show_ui(ui, |ui| {
+				for candidate in PaletteScheme::ALL
+				{
+					ui.selectable_value(
+						&mut scheme,
+						candidate,
+						candidate.to_string()
+					);
+				}
+			});
+		ui.separator();
+		ComboBox::from_label(language.text(Message::Scale))
+			.selected_text(scale.to_string())
+			.show_ui(ui, |ui| {
+				for candidate in Scale::ALL
+				{
+					ui.selectable_value(
+						&mut scale,
+						candidate,
+						candidate.to_string()
+					);
+				}
+			});
+		ui.add(
+			Slider::new(&mut volume, 0.0 ..= 1.0)
+				.text(language.text(Message::Volume))
+		);
+		ui.checkbox(&mut muted, language.text(Message::Mute));
+	});
+	if rule != old_rule
+	{
+		// As when typing a rule, the new rule replaces the whole schedule.
+		actions.send(Action::ReplaceRule {
+			pane: pane.0,
+			rule: AutomatonRule::from(rule.unwrap())
+		});
+	}
+	if heartbeat != timer.heartbeat().as_millis() as u64
+	{
+		timer.set_heartbeat(Duration::from_millis(heartbeat));
+	}
+	palette.set_if_neq(scheme);
+	if scale != sonification.scale
+		|| volume != sonification.volume
+		|| muted != sonification.muted
+	{
+		*sonification = Sonification::new(scale, volume, muted);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The shortest heartbeat offered by the settings panel, in milliseconds.
+const MIN_HEARTBEAT: u64 = 10;
+
+/// The longest heartbeat offered by the settings panel, in milliseconds.
+const MAX_HEARTBEAT: u64 = 2_000;
@@ -0,0 +1,340 @@
+use bevy::prelude::{
+	App, EventWriter, Input, KeyCode, Plugin, Query, Res, Update, With
+};
+#[cfg(not(target_family = "wasm"))]
+use image::{Rgba, RgbaImage};
+
+use crate::automata::{History, RuleKind, RuleSchedule, Scrollback};
+#[cfg(not(target_family = "wasm"))]
+use crate::automata::{AUTOMATON_HISTORY, AUTOMATON_LENGTH};
+use crate::ecs::{
+	Announcement, AutomatonRuleBuilder, Focused, Palette, Pane
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for share cards. On `Q`, the
+/// [history](History) of the [focused](Focused) pane is drawn into a PNG sized
+/// for social media, above a caption strip that names the rule, the seed, the
+/// number of generations, and an address that reproduces the evolution in the
+/// browser. The card is composited offscreen, at a fixed resolution, so it
+/// looks the same whatever the size of the window. The browser has no working
+/// directory to export into, so there the key merely says as much.
+pub struct ShareCardPlugin;
+
+impl Plugin for ShareCardPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app.add_systems(Update, maybe_export_share_card);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `Q`, export a share card of the [focused](Focused)&#32;[pane](Pane) into
+/// a new PNG file in the working directory. Announce the outcome in the toast.
+fn maybe_export_share_card(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	builder: Res<AutomatonRuleBuilder>,
+	palette: Res<Palette>,
+	language: Res<Language>,
+	pane: Query<(&Pane, &History, &Scrollback, &RuleSchedule), With<Focused>>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.share_card)
+		&& !builder.claims(bindings.share_card)
+	{
+		let (pane, history, scrollback, schedule) = pane.single();
+		let stem = format!("share-card-{}", pane.0);
+		let caption = caption(scrollback, schedule);
+		announcements.send(Announcement(
+			export_share_card(&stem, history, &caption, &palette, *language)
+		));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Answer the two lines of the caption of a share card: first, the rules of
+/// the specified [schedule](RuleSchedule), the seed, i.e., the oldest retained
+/// generation of the specified [scrollback](Scrollback), and the number of
+/// generations evolved; second, the address of the web build that reproduces
+/// the evolution.
+fn caption(scrollback: &Scrollback, schedule: &RuleSchedule) -> [String; 2]
+{
+	let rules = schedule.iter()
+		.map(RuleKind::abbreviation)
+		.collect::<Vec<_>>()
+		.join(",");
+	let (_, seed, _) = scrollback.generation(scrollback.len() - 1).unwrap();
+	let (newest, ..) = scrollback.generation(0).unwrap();
+	let seed = seed.to_hex();
+	let parameter = match schedule.len()
+	{
+		1 => "rule",
+		_ => "rules"
+	};
+	[
+		format!("rule {}   seed {}   {} generations", rules, seed, newest),
+		format!("{}?{}={}&seed_hex={}", SHARE_URL, parameter, rules, seed)
+	]
+}
+
+/// Draw a share card of the specified [history](History), captioned by the
+/// specified lines, in the colors of the specified [palette](Palette), into a
+/// new PNG file in the working directory, named by the specified stem and the
+/// current time. Answer a message, in the specified [language](Language), that
+/// describes the outcome.
+#[cfg(not(target_family = "wasm"))]
+fn export_share_card(
+	stem: &str,
+	history: &History,
+	caption: &[String; 2],
+	palette: &Palette,
+	language: Language
+) -> String
+{
+	let seconds = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|elapsed| elapsed.as_secs())
+		.unwrap_or_default();
+	let path = format!("{}-{}.png", stem, seconds);
+	match draw_share_card(history, caption, palette).save(&path)
+	{
+		Ok(()) => language.format(Message::SharedCard, &[&path]),
+		Err(e) => language.format(Message::CannotExport, &[&path, &e])
+	}
+}
+
+/// The browser has no working directory to export into.
+#[cfg(target_family = "wasm")]
+fn export_share_card(
+	_stem: &str,
+	_history: &History,
+	_caption: &[String; 2],
+	_palette: &Palette,
+	language: Language
+) -> String
+{
+	language.text(Message::ExportUnavailable).to_string()
+}
+
+/// Draw a share card: the specified [history](History), oldest generation on
+/// top, centered within the chrome, above a caption strip that holds the
+/// specified lines, the first large and the second small.
+#[cfg(not(target_family = "wasm"))]
+fn draw_share_card(
+	history: &History,
+	caption: &[String; 2],
+	palette: &Palette
+) -> RgbaImage
+{
+	let live = Rgba(palette.live.as_rgba_u8());
+	let dead = Rgba(palette.dead.as_rgba_u8());
+	let chrome = Rgba(palette.chrome.as_rgba_u8());
+	let mut card = RgbaImage::from_pixel(CARD_WIDTH, CARD_HEIGHT, chrome);
+	let height = CARD_HEIGHT - CAPTION_HEIGHT;
+	let cell_size = ((CARD_WIDTH - 2 * CARD_MARGIN) / AUTOMATON_LENGTH as u32)
+		.min((height - 2 * CARD_MARGIN) / AUTOMATON_HISTORY as u32);
+	let left = (CARD_WIDTH - cell_size * AUTOMATON_LENGTH as u32) / 2;
+	let top = (height - cell_size * AUTOMATON_HISTORY as u32) / 2;
+	for (row, automaton) in history.iter().enumerate()
+	{
+		for column in 0 .. AUTOMATON_LENGTH
+		{
+			let color = match automaton[AUTOMATON_LENGTH - column - 1]
+			{
+				true => live,
+				false => dead
+			};
+			fill(
+				&mut card,
+				left + column as u32 * cell_size,
+				top + row as u32 * cell_size,
+				cell_size,
+				cell_size,
+				color
+			);
+		}
+	}
+	fill(&mut card, 0, height, CARD_WIDTH, CAPTION_HEIGHT, dead);
+	let [summary, url] = caption;
+	let summary_top = height + CARD_MARGIN;
+	draw_text(&mut card, CARD_MARGIN, summary_top, 3, summary, live);
+	let url_top = summary_top + (GLYPH_HEIGHT as u32 + 4) * 3;
+	draw_text(&mut card, CARD_MARGIN, url_top, 2, url, live);
+	card
+}
+
+/// Fill the specified rectangle of the specified image with the specified
+/// color, clipping it to the bounds of the image.
+#[cfg(not(target_family = "wasm"))]
+fn fill(
+	image: &mut RgbaImage,
+	left: u32,
+	top: u32,
+	width: u32,
+	height: u32,
+	color: Rgba<u8>
+) {
+	let right = (left + width).min(image.width());
+	let bottom = (top + height).min(image.height());
+	for y in top .. bottom
+	{
+		for x in left .. right
+		{
+			image.put_pixel(x, y, color);
+		}
+	}
+}
+
+/// Draw the specified text onto the specified image, in the specified color,
+/// with its top left corner at the specified position, enlarging each pixel of
+/// the [font](GLYPHS) by the specified scale. Text that overruns the image is
+/// clipped.
+#[cfg(not(target_family = "wasm"))]
+fn draw_text(
+	image: &mut RgbaImage,
+	left: u32,
+	top: u32,
+	scale: u32,
+	text: &str,
+	color: Rgba<u8>
+) {
+	let advance = (GLYPH_WIDTH as u32 + 1) * scale;
+	for (i, c) in text.chars().enumerate()
+	{
+		let x = left + i as u32 * advance;
+		for (row, bits) in glyph(c).iter().enumerate()
+		{
+			for column in 0 .. GLYPH_WIDTH
+			{
+				if bits >> (GLYPH_WIDTH - column - 1) & 1 == 1
+				{
+					fill(
+						image,
+						x + column as u32 * scale,
+						top + row as u32 * scale,
+						scale,
+						scale,
+						color
+					);
+				}
+			}
+		}
+	}
+}
+
+/// Answer the rows of the glyph that depicts the specified character, folding
+/// capitals into lowercase. Characters without glyphs are depicted by `?`.
+#[cfg(not(target_family = "wasm"))]
+fn glyph(c: char) -> [u8; GLYPH_HEIGHT]
+{
+	let c = c.to_ascii_lowercase();
+	GLYPHS.iter()
+		.find(|(candidate, _)| *candidate == c)
+		.or_else(|| GLYPHS.iter().find(|(candidate, _)| *candidate == '?'))
+		.map(|&(_, rows)| rows)
+		.unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The address of the web build, whose query parameters reproduce an
+/// evolution.
+const SHARE_URL: &str = "https://xebia-functional.github.io/cellular-automata/";
+
+/// The width of a share card, in pixels, as recommended for link previews by
+/// most social networks.
+#[cfg(not(target_family = "wasm"))]
+const CARD_WIDTH: u32 = 1200;
+
+/// The height of a share card, in pixels.
+#[cfg(not(target_family = "wasm"))]
+const CARD_HEIGHT: u32 = 630;
+
+/// The height of the caption strip along the bottom of a share card, in
+/// pixels.
+#[cfg(not(target_family = "wasm"))]
+const CAPTION_HEIGHT: u32 = 110;
+
+/// The least space around the history and the caption, in pixels.
+#[cfg(not(target_family = "wasm"))]
+const CARD_MARGIN: u32 = 24;
+
+/// The width of a [glyph](GLYPHS), in pixels.
+#[cfg(not(target_family = "wasm"))]
+const GLYPH_WIDTH: usize = 5;
+
+/// The height of a [glyph](GLYPHS), in pixels.
+#[cfg(not(target_family = "wasm"))]
+const GLYPH_HEIGHT: usize = 7;
+
+/// A tiny bitmap font, just large enough for captions: one row of bits per
+/// pixel row, from top to bottom, with the leftmost pixel in the most
+/// significant of the [five](GLYPH_WIDTH) bits.
+#[cfg(not(target_family = "wasm"))]
+const GLYPHS: &[(char, [u8; GLYPH_HEIGHT])] = &[
+	(' ', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+	('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+	('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+	('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+	('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+	('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+	('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+	('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+	('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+	('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+	('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+	('a', [0b00000, 0b00000, 0b01110, 0b00001, 0b01111, 0b10001, 0b01111]),
+	('b', [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b11110]),
+	('c', [0b00000, 0b00000, 0b01110, 0b10000, 0b10000, 0b10001, 0b01110]),
+	('d', [0b00001, 0b00001, 0b01101, 0b10011, 0b10001, 0b10001, 0b01111]),
+	('e', [0b00000, 0b00000, 0b01110, 0b10001, 0b11111, 0b10000, 0b01110]),
+	('f', [0b00110, 0b01001, 0b01000, 0b11100, 0b01000, 0b01000, 0b01000]),
+	('g', [0b00000, 0b01111, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110]),
+	('h', [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001]),
+	('i', [0b00100, 0b00000, 0b01100, 0b00100, 0b00100, 0b00100, 0b01110]),
+	('j', [0b00010, 0b00000, 0b00110, 0b00010, 0b00010, 0b10010, 0b01100]),
+	('k', [0b10000, 0b10000, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010]),
+	('l', [0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+	('m', [0b00000, 0b00000, 0b11010, 0b10101, 0b10101, 0b10001, 0b10001]),
+	('n', [0b00000, 0b00000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001]),
+	('o', [0b00000, 0b00000, 0b01110, 0b10001, 0b10001, 0b10001, 0b01110]),
+	('p', [0b00000, 0b00000, 0b11110, 0b10001, 0b11110, 0b10000, 0b10000]),
+	('q', [0b00000, 0b00000, 0b01101, 0b10011, 0b01111, 0b00001, 0b00001]),
+	('r', [0b00000, 0b00000, 0b10110, 0b11001, 0b10000, 0b10000, 0b10000]),
+	('s', [0b00000, 0b00000, 0b01110, 0b10000, 0b01110, 0b00001, 0b11110]),
+	('t', [0b01000, 0b01000, 0b11100, 0b01000, 0b01000, 0b01001, 0b00110]),
+	('u', [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b10011, 0b01101]),
+	('v', [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+	('w', [0b00000, 0b00000, 0b10001, 0b10001, 0b10101, 0b10101, 0b01010]),
+	('x', [0b00000, 0b00000, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001]),
+	('y', [0b00000, 0b00000, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110]),
+	('z', [0b00000, 0b00000, 0b11111, 0b00010, 0b00100, 0b01000, 0b11111]),
+	('.', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+	(',', [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000]),
+	(':', [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000]),
+	('/', [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000]),
+	('?', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+	('=', [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000]),
+	('&', [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101]),
+	('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+	('_', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111]),
+	('#', [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010]),
+	('(', [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010]),
+	(')', [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000]),
+	('+', [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000])
+];
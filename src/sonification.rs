@@ -0,0 +1,472 @@
+use std::f32::consts::TAU;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bevy::asset::Asset;
+use bevy::audio::{
+	AddAudioSource, AudioSourceBundle, Decodable, PlaybackSettings, Source
+};
+use bevy::prelude::{
+	App, Assets, Commands, DetectChanges, EventReader, Input,
+	IntoSystemConfigs, KeyCode, Plugin, Query, Ref, Res, ResMut, Resource,
+	Update, With
+};
+use bevy::reflect::TypePath;
+
+use crate::automata::{Automaton, History};
+use crate::ecs::{Action, apply_actions, EvolutionTimer, Focused};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for turning the evolution of the
+/// [focused](Focused)&#32;[automaton](Automaton) into sound. Whenever the
+/// newest generation changes, each occupied cell contributes a note to a brief
+/// chord, whose pitches rise from the left edge of the automaton to the right
+/// edge. The [Sonification] resource governs the scale and volume of the
+/// chords, and whether they play at all. Interactions with the simulator, like
+/// toggling cells, committing rules, and pausing, also sound brief
+/// [cues](Cue), unless [Feedback] is muted.
+pub struct SonificationPlugin;
+
+impl Plugin for SonificationPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Sonification>()
+			.init_resource::<Feedback>()
+			.add_audio_source::<Chord>()
+			.add_systems(Update, maybe_toggle_mute)
+			.add_systems(Update, sonify)
+			.add_systems(Update, play_cues.after(apply_actions));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The settings that govern [sonification](sonify).
+#[derive(Copy, Clone, Debug, Resource)]
+pub struct Sonification
+{
+	/// The musical scale from which pitches are drawn.
+	pub(crate) scale: Scale,
+
+	/// The peak amplitude of a chord, in `[0,1]`.
+	pub(crate) volume: f32,
+
+	/// Whether sonification is muted.
+	pub(crate) muted: bool
+}
+
+impl Sonification
+{
+	/// Construct new sonification settings. `volume` is clamped to `[0,1]`.
+	pub fn new(scale: Scale, volume: f32, muted: bool) -> Self
+	{
+		Self { scale, volume: volume.clamp(0.0, 1.0), muted }
+	}
+
+	/// Compute the [chord](Chord) that sonifies the specified
+	/// [automaton](Automaton), or [None] if no cells are occupied. Column `0`
+	/// is the leftmost cell, and sounds the lowest pitch.
+	fn chord<const K: usize>(&self, automaton: &Automaton<K>) -> Option<Chord>
+	{
+		let degrees = self.scale.degrees();
+		let span = degrees.len() * OCTAVES;
+		let mut frequencies = (0 .. K)
+			.filter(|column| automaton[K - column - 1])
+			.map(|column| column * span / K)
+			.map(|degree| {
+				let octave = (degree / degrees.len()) as f32;
+				let semitones = degrees[degree % degrees.len()] as f32;
+				BASE_FREQUENCY * 2f32.powf(octave + semitones / 12.0)
+			})
+			.collect::<Vec<_>>();
+		// Several adjacent cells may fold onto the same degree, so discard the
+		// duplicates rather than letting them dominate the mix.
+		frequencies.dedup();
+		if frequencies.is_empty()
+		{
+			return None
+		}
+		Some(Chord {
+			amplitude: self.volume / frequencies.len() as f32,
+			frequencies,
+			duration: NOTE_DURATION
+		})
+	}
+}
+
+impl Default for Sonification
+{
+	fn default() -> Self
+	{
+		Self::new(Scale::default(), DEFAULT_VOLUME, false)
+	}
+}
+
+/// The settings that govern the audible [cues](Cue) that acknowledge user
+/// interactions, which help when the audience is far from the screen. The
+/// cues play at the volume of the [sonification](Sonification), but are
+/// muted independently.
+#[derive(Copy, Clone, Debug, Default, Resource)]
+pub struct Feedback
+{
+	/// Whether the cues are muted.
+	pub(crate) muted: bool
+}
+
+impl Feedback
+{
+	/// Construct new feedback settings.
+	pub fn new(muted: bool) -> Self
+	{
+		Self { muted }
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Scales.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The musical scales available for [sonification](Sonification).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Scale
+{
+	/// The major pentatonic scale, which never sounds dissonant.
+	#[default]
+	Pentatonic,
+
+	/// The major scale.
+	Major,
+
+	/// The natural minor scale.
+	Minor,
+
+	/// All twelve semitones.
+	Chromatic
+}
+
+impl Scale
+{
+	/// Every scale, in menu order.
+	#[allow(dead_code)]
+	pub const ALL: [Scale; 4] =
+		[Scale::Pentatonic, Scale::Major, Scale::Minor, Scale::Chromatic];
+
+	/// Answer the degrees of the scale, as semitones above the tonic.
+	fn degrees(self) -> &'static [u8]
+	{
+		match self
+		{
+			Scale::Pentatonic => &[0, 2, 4, 7, 9],
+			Scale::Major => &[0, 2, 4, 5, 7, 9, 11],
+			Scale::Minor => &[0, 2, 3, 5, 7, 8, 10],
+			Scale::Chromatic => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+		}
+	}
+}
+
+impl FromStr for Scale
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"pentatonic" => Ok(Scale::Pentatonic),
+			"major" => Ok(Scale::Major),
+			"minor" => Ok(Scale::Minor),
+			"chromatic" => Ok(Scale::Chromatic),
+			_ => Err(format!(
+				"unknown scale '{}': expected pentatonic, major, minor, or \
+					chromatic",
+				s
+			))
+		}
+	}
+}
+
+impl Display for Scale
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			Scale::Pentatonic => "pentatonic",
+			Scale::Major => "major",
+			Scale::Minor => "minor",
+			Scale::Chromatic => "chromatic"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                   Cues.                                    //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The audible acknowledgments of user interactions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Cue
+{
+	/// A cell was toggled: a short, high click.
+	Click,
+
+	/// A rule change was committed: a bright major triad.
+	Confirm,
+
+	/// The evolution resumed: a high open fifth.
+	Resume,
+
+	/// The evolution paused: a low open fifth.
+	Pause
+}
+
+impl Cue
+{
+	/// Answer the cue that acknowledges the specified [action](Action), if
+	/// any. `running` tells whether the evolution runs after the action.
+	fn for_action(action: &Action, running: bool) -> Option<Self>
+	{
+		match action
+		{
+			Action::TogglePause if running => Some(Cue::Resume),
+			Action::TogglePause => Some(Cue::Pause),
+			Action::ToggleCell { .. }
+				| Action::PaintRule { .. }
+				| Action::TransformSeed { .. }
+				| Action::FastForward { .. } => Some(Cue::Click),
+			Action::ReplaceRule { .. }
+				| Action::AppendRule { .. }
+				| Action::DropRule { .. }
+				| Action::Mutate { .. }
+				| Action::WalkBack { .. } => Some(Cue::Confirm),
+			Action::Seed { .. } => None
+		}
+	}
+
+	/// Answer the [chord](Chord) that sounds the cue at the specified peak
+	/// amplitude.
+	fn chord(self, volume: f32) -> Chord
+	{
+		let (frequencies, duration) = match self
+		{
+			Cue::Click => (vec![1760.0], CLICK_DURATION),
+			Cue::Confirm => (vec![880.0, 1100.0, 1320.0], CUE_DURATION),
+			Cue::Resume => (vec![660.0, 990.0], CUE_DURATION),
+			Cue::Pause => (vec![330.0, 495.0], CUE_DURATION)
+		};
+		Chord {
+			amplitude: volume * CUE_GAIN / frequencies.len() as f32,
+			frequencies,
+			duration
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Chords.                                   //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A brief, procedurally synthesized chord of sine waves. Each chord is an
+/// [asset](Asset) that plays once and then despawns.
+#[derive(Clone, Debug, Asset, TypePath)]
+struct Chord
+{
+	/// The frequencies of the notes, in Hertz.
+	frequencies: Vec<f32>,
+
+	/// The peak amplitude of each note.
+	amplitude: f32,
+
+	/// How long the chord sounds.
+	duration: Duration
+}
+
+impl Decodable for Chord
+{
+	type DecoderItem = f32;
+	type Decoder = ChordDecoder;
+
+	fn decoder(&self) -> Self::Decoder
+	{
+		let samples = (self.duration.as_secs_f32() * SAMPLE_RATE as f32) as u32;
+		ChordDecoder { chord: self.clone(), sample: 0, samples }
+	}
+}
+
+/// The [source](Source) that synthesizes a [chord](Chord), sample by sample.
+struct ChordDecoder
+{
+	/// The chord being synthesized.
+	chord: Chord,
+
+	/// The index of the next sample.
+	sample: u32,
+
+	/// The total number of samples.
+	samples: u32
+}
+
+impl Iterator for ChordDecoder
+{
+	type Item = f32;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.sample >= self.samples
+		{
+			return None
+		}
+		let t = self.sample as f32 / SAMPLE_RATE as f32;
+		// Ramp up quickly and then decay linearly, to avoid audible clicks at
+		// either end of the chord.
+		let attack = (t / ATTACK.as_secs_f32()).min(1.0);
+		let decay = 1.0 - self.sample as f32 / self.samples as f32;
+		let envelope = attack * decay;
+		let value = self.chord.frequencies.iter()
+			.map(|frequency| (TAU * frequency * t).sin())
+			.sum::<f32>();
+		self.sample += 1;
+		Some(self.chord.amplitude * envelope * value)
+	}
+}
+
+impl Source for ChordDecoder
+{
+	fn current_frame_len(&self) -> Option<usize>
+	{
+		None
+	}
+
+	fn channels(&self) -> u16
+	{
+		1
+	}
+
+	fn sample_rate(&self) -> u32
+	{
+		SAMPLE_RATE
+	}
+
+	fn total_duration(&self) -> Option<Duration>
+	{
+		Some(self.chord.duration)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `N`, toggle whether [sonification](Sonification) is muted.
+fn maybe_toggle_mute(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	mut sonification: ResMut<Sonification>
+) {
+	if keys.just_pressed(bindings.mute)
+	{
+		sonification.muted = !sonification.muted;
+	}
+}
+
+/// Whenever the [history](History) of the [focused](Focused) pane changes,
+/// play the [chord](Chord) that corresponds to its [newest](History::newest)
+/// generation.
+fn sonify(
+	sonification: Res<Sonification>,
+	history: Query<Ref<History>, With<Focused>>,
+	mut chords: ResMut<Assets<Chord>>,
+	mut commands: Commands
+) {
+	if sonification.muted
+	{
+		return
+	}
+	if let Ok(history) = history.get_single()
+	{
+		if history.is_changed() && !history.is_added()
+		{
+			if let Some(chord) = sonification.chord(history.newest())
+			{
+				commands.spawn(AudioSourceBundle {
+					source: chords.add(chord),
+					settings: PlaybackSettings::DESPAWN
+				});
+			}
+		}
+	}
+}
+
+/// Play the [cue](Cue) that acknowledges each [action](Action) applied this
+/// frame, unless [feedback](Feedback) is muted. Several actions of the same
+/// kind in the same frame, e.g., from a [replay](crate::replay), sound only
+/// once.
+fn play_cues(
+	feedback: Res<Feedback>,
+	sonification: Res<Sonification>,
+	timer: Res<EvolutionTimer>,
+	mut actions: EventReader<Action>,
+	mut chords: ResMut<Assets<Chord>>,
+	mut commands: Commands
+) {
+	if feedback.muted
+	{
+		actions.clear();
+		return
+	}
+	let mut cues = actions.read()
+		.filter_map(|action| Cue::for_action(action, timer.is_running()))
+		.collect::<Vec<_>>();
+	cues.dedup();
+	for cue in cues
+	{
+		commands.spawn(AudioSourceBundle {
+			source: chords.add(cue.chord(sonification.volume)),
+			settings: PlaybackSettings::DESPAWN
+		});
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The frequency of the lowest pitch, in Hertz: A2.
+const BASE_FREQUENCY: f32 = 110.0;
+
+/// The number of octaves spanned by the width of the automaton.
+const OCTAVES: usize = 3;
+
+/// The sample rate of synthesized [chords](Chord), in Hertz.
+const SAMPLE_RATE: u32 = 44_100;
+
+/// How long each [chord](Chord) sounds. This is a little shorter than the
+/// evolutionary heartbeat, so that successive chords don't smear together.
+const NOTE_DURATION: Duration = Duration::from_millis(200);
+
+/// How long each [chord](Chord) takes to reach full volume.
+const ATTACK: Duration = Duration::from_millis(5);
+
+/// How long a [click](Cue::Click) sounds.
+const CLICK_DURATION: Duration = Duration::from_millis(30);
+
+/// How long every other [cue](Cue) sounds.
+const CUE_DURATION: Duration = Duration::from_millis(120);
+
+/// The loudness of a [cue](Cue) relative to the volume of the
+/// [sonification](Sonification), so that cues don't drown out the chords.
+const CUE_GAIN: f32 = 0.6;
+
+/// The default peak amplitude of a [chord](Chord).
+pub const DEFAULT_VOLUME: f32 = 0.5;
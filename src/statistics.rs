@@ -0,0 +1,395 @@
+use bevy::prelude::{
+	App, Assets, BackgroundColor, BuildChildren, Color, Commands, Component,
+	default, DespawnRecursiveExt, DetectChanges, Entity, EventWriter,
+	FlexDirection, Handle, Image, ImageBundle, Input, IntoSystemConfigs,
+	KeyCode, NodeBundle, Plugin, PositionType, Query, Ref, Res, ResMut,
+	Resource, Style, Text, TextBundle, TextStyle, UiImage, UiRect, Update, Val,
+	With, ZIndex
+};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+use bevy::render::texture::ImageSampler;
+
+use crate::automata::{
+	AUTOMATON_LENGTH, complexity, COMPLEXITY_WINDOW, Scrollback
+};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, Focused, Palette, TOAST_Z_INDEX
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for statistics, which gathers
+/// [figures](Statistics) about the recent generations of every
+/// [pane](crate::ecs::Pane) as it evolves, and shows those of the
+/// [focused](Focused) pane in a panel in the bottom left corner of the window.
+/// The panel charts the population over the last [STATISTICS_WINDOW]
+/// generations, making oscillations and decay visible at a glance, and
+/// estimates the [complexity] of the last [COMPLEXITY_WINDOW]. The
+/// [StatisticsPanel] resource governs whether the panel is shown.
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<StatisticsPanel>()
+			.add_systems(Update, maybe_toggle_statistics)
+			.add_systems(
+				Update,
+				gather_statistics
+					.after(maybe_toggle_statistics)
+					.after(apply_actions)
+					.after(evolve)
+			)
+			.add_systems(
+				Update,
+				update_statistics.after(gather_statistics)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the statistics panel is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct StatisticsPanel(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The figures gathered about the last [STATISTICS_WINDOW] generations of a
+/// [pane](crate::ecs::Pane), or fewer if its [scrollback](Scrollback) doesn't
+/// yet reach back so far.
+#[derive(Clone, Debug, Default, Component)]
+struct Statistics
+{
+	/// The number of live cells of each generation, oldest first.
+	populations: Vec<usize>,
+
+	/// The [complexity] of the newest [COMPLEXITY_WINDOW] generations.
+	complexity: f32
+}
+
+impl Statistics
+{
+	/// Gather the figures of the newest generations of the specified
+	/// [scrollback](Scrollback).
+	fn gather(scrollback: &Scrollback) -> Self
+	{
+		let skip = scrollback.len().saturating_sub(STATISTICS_WINDOW);
+		let populations = scrollback.iter()
+			.skip(skip)
+			.map(|(_, automaton, _)| automaton.population_count())
+			.collect();
+		let skip = scrollback.len().saturating_sub(COMPLEXITY_WINDOW);
+		let complexity = complexity(
+			scrollback.iter().skip(skip).map(|(_, automaton, _)| automaton)
+		);
+		Self { populations, complexity }
+	}
+
+	/// Answer the population of the newest generation.
+	fn population(&self) -> usize
+	{
+		self.populations.last().copied().unwrap_or_default()
+	}
+}
+
+/// The frame of the statistics panel, which holds its
+/// [label](StatisticsLabel) and its [chart](StatisticsChart).
+#[derive(Component)]
+struct StatisticsFrame;
+
+/// The label of the statistics panel.
+#[derive(Component)]
+struct StatisticsLabel;
+
+/// The population chart of the statistics panel, and the [image](Image) into
+/// which it is drawn.
+#[derive(Component)]
+struct StatisticsChart(Handle<Image>);
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `F4`, toggle the [statistics&#32;panel](StatisticsPanel), and announce
+/// the new setting.
+fn maybe_toggle_statistics(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut panel: ResMut<StatisticsPanel>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.statistics)
+	{
+		panel.0 = !panel.0;
+		let message = match panel.0
+		{
+			true => Message::StatisticsOn,
+			false => Message::StatisticsOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the [statistics&#32;panel](StatisticsPanel) is shown, whenever the
+/// [scrollback](Scrollback) of a [pane](crate::ecs::Pane) changes, e.g.,
+/// through evolution, a toggled cell, or a new seed, gather its
+/// [statistics](Statistics) anew. Estimating the [complexity] is too costly to
+/// keep up while nobody is looking, so gather every pane's statistics anew
+/// when the panel is shown. Difference panes have no scrollback, and thus no
+/// statistics.
+fn gather_statistics(
+	panel: Res<StatisticsPanel>,
+	mut panes: Query<(Entity, Ref<Scrollback>, Option<&mut Statistics>)>,
+	mut commands: Commands
+) {
+	if !panel.0
+	{
+		return
+	}
+	for (entity, scrollback, statistics) in &mut panes
+	{
+		match statistics
+		{
+			None =>
+			{
+				commands.entity(entity).insert(Statistics::gather(&scrollback));
+			},
+			Some(mut statistics)
+				if scrollback.is_changed() || panel.is_changed() =>
+			{
+				*statistics = Statistics::gather(&scrollback);
+			},
+			Some(_) => {}
+		}
+	}
+}
+
+/// While the [statistics&#32;panel](StatisticsPanel) is shown, keep its
+/// [label](StatisticsLabel) and its [chart](StatisticsChart) in step with the
+/// [statistics](Statistics) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane), redrawing them whenever
+/// the statistics, the focus, the [palette](Palette), or the
+/// [language](Language) changes.
+#[allow(clippy::too_many_arguments)]
+fn update_statistics(
+	panel: Res<StatisticsPanel>,
+	palette: Res<Palette>,
+	language: Res<Language>,
+	pane: Query<(Ref<Statistics>, Ref<Focused>)>,
+	frames: Query<Entity, With<StatisticsFrame>>,
+	charts: Query<&StatisticsChart>,
+	mut labels: Query<&mut Text, With<StatisticsLabel>>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands
+) {
+	if !panel.0
+	{
+		if panel.is_changed()
+		{
+			for entity in &frames
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+		return
+	}
+	let Ok((statistics, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let Ok(chart) = charts.get_single() else
+	{
+		build_statistics(
+			&statistics,
+			&palette,
+			*language,
+			&mut images,
+			&mut commands
+		);
+		return
+	};
+	let redraw = statistics.is_changed()
+		|| focused.is_added()
+		|| palette.is_changed()
+		|| language.is_changed();
+	if redraw
+	{
+		if let Some(image) = images.get_mut(&chart.0)
+		{
+			*image = draw(&statistics, &palette);
+		}
+		if let Ok(mut text) = labels.get_single_mut()
+		{
+			text.sections[0].value = label(&statistics, *language);
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the [frame](StatisticsFrame) of the statistics panel in the bottom
+/// left corner of the window, holding the [label](StatisticsLabel) and the
+/// [chart](StatisticsChart) of the specified [statistics](Statistics).
+fn build_statistics(
+	statistics: &Statistics,
+	palette: &Palette,
+	language: Language,
+	images: &mut Assets<Image>,
+	commands: &mut Commands
+) {
+	let image = images.add(draw(statistics, palette));
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						left: Val::Px(STATISTICS_MARGIN),
+						bottom: Val::Px(STATISTICS_MARGIN),
+						padding: UiRect::all(Val::Px(STATISTICS_PADDING)),
+						flex_direction: FlexDirection::Column,
+						..default()
+					},
+					background_color: BackgroundColor(STATISTICS_BACKGROUND),
+					z_index: ZIndex::Global(STATISTICS_Z_INDEX),
+					..default()
+				},
+				StatisticsFrame
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					TextBundle::from_section(
+						label(statistics, language),
+						TextStyle {
+							font_size: STATISTICS_FONT_SIZE,
+							color: STATISTICS_TEXT_COLOR,
+							..default()
+						}
+					),
+					StatisticsLabel
+				)
+			);
+			builder.spawn(
+				(
+					ImageBundle {
+						style: Style {
+							width: Val::Px(STATISTICS_WINDOW as f32),
+							height: Val::Px(CHART_ROWS as f32),
+							margin: UiRect::top(Val::Px(STATISTICS_PADDING)),
+							..default()
+						},
+						image: UiImage::new(image.clone()),
+						..default()
+					},
+					StatisticsChart(image)
+				)
+			);
+		});
+}
+
+/// Answer the text of the [label](StatisticsLabel) for the specified
+/// [statistics](Statistics), in the specified [language](Language).
+fn label(statistics: &Statistics, language: Language) -> String
+{
+	let complexity = format!("{:.2}", statistics.complexity);
+	format!(
+		"{}\n{}",
+		language.format(Message::Population, &[&statistics.population()]),
+		language.format(Message::Complexity, &[&complexity])
+	)
+}
+
+/// Draw the population chart of the specified [statistics](Statistics) into
+/// an [image](Image), one column of pixels per generation, newest at the
+/// right, and one row of pixels per possible population, from none at the
+/// bottom to every cell at the top. Consecutive generations are joined by a
+/// vertical run of pixels, so that the line stays unbroken even when the
+/// population leaps.
+fn draw(statistics: &Statistics, palette: &Palette) -> Image
+{
+	let line = palette.live.as_rgba_u8();
+	let mut data = vec![0; STATISTICS_WINDOW * CHART_ROWS * 4];
+	let start = STATISTICS_WINDOW - statistics.populations.len();
+	let mut previous = None;
+	for (offset, &population) in statistics.populations.iter().enumerate()
+	{
+		let x = start + offset;
+		let row = CHART_ROWS - population - 1;
+		let (top, bottom) = match previous
+		{
+			Some(previous) if previous < row => (previous, row),
+			Some(previous) => (row, previous),
+			None => (row, row)
+		};
+		for y in top ..= bottom
+		{
+			let pixel = (y * STATISTICS_WINDOW + x) * 4;
+			data[pixel .. pixel + 4].copy_from_slice(&line);
+		}
+		previous = Some(row);
+	}
+	let mut image = Image::new(
+		Extent3d {
+			width: STATISTICS_WINDOW as u32,
+			height: CHART_ROWS as u32,
+			depth_or_array_layers: 1
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb
+	);
+	// Keep the line crisp when stretched.
+	image.sampler = ImageSampler::nearest();
+	image
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of generations covered by the [statistics](Statistics), and
+/// thus the width of the [chart](StatisticsChart), in pixels.
+const STATISTICS_WINDOW: usize = 256;
+
+/// The height of the [chart](StatisticsChart), in pixels: one row for each
+/// possible population, from none to every cell.
+const CHART_ROWS: usize = AUTOMATON_LENGTH + 1;
+
+/// The distance between the [frame](StatisticsFrame) of the statistics panel
+/// and the corner of the window, in pixels.
+const STATISTICS_MARGIN: f32 = 12.0;
+
+/// The padding within the [frame](StatisticsFrame) of the statistics panel,
+/// in pixels.
+const STATISTICS_PADDING: f32 = 8.0;
+
+/// The font size of the [label](StatisticsLabel) of the statistics panel.
+const STATISTICS_FONT_SIZE: f32 = 18.0;
+
+/// The [color](Color) of the [label](StatisticsLabel) of the statistics panel.
+const STATISTICS_TEXT_COLOR: Color = Color::YELLOW;
+
+/// The [color](Color) behind the statistics panel.
+const STATISTICS_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
+
+/// The [z-index](ZIndex) of the statistics panel, which lies above the panes,
+/// but beneath the tooltip, the growing tape, and toasts.
+const STATISTICS_Z_INDEX: i32 = TOAST_Z_INDEX - 2;
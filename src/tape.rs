@@ -0,0 +1,324 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{
+	App, Assets, BackgroundColor, BuildChildren, Color, Commands, Component,
+	default, DespawnRecursiveExt, DetectChanges, Entity, EventWriter, Handle,
+	Image, ImageBundle, Input, IntoSystemConfigs, KeyCode, Local, NodeBundle,
+	Plugin, PositionType, Query, Ref, Res, ResMut, Resource, Style, UiImage,
+	Update, Val, With, ZIndex
+};
+use bevy::render::render_resource::{
+	Extent3d, TextureDimension, TextureFormat
+};
+use bevy::render::texture::ImageSampler;
+use bevy::ui::{AlignItems, FocusPolicy, JustifyContent};
+
+use crate::automata::{RuleKind, Scrollback, Tape};
+use crate::ecs::{
+	Announcement, apply_actions, evolve, Focused, Generation, Palette,
+	TOAST_Z_INDEX
+};
+use crate::i18n::{Language, Message};
+use crate::keys::KeyBindings;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the growing tape, which evolves the
+/// newest generation of the [focused](Focused)&#32;[pane](crate::ecs::Pane) as
+/// an unbounded [tape](Tape), rather than a ring of fixed length, and draws
+/// the tape's history over the whole window. The picture zooms out to fit as
+/// the tape grows. The [GrowingTape] resource governs whether the tape is
+/// shown.
+pub struct TapePlugin;
+
+impl Plugin for TapePlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<GrowingTape>()
+			.add_systems(Update, maybe_toggle_tape)
+			.add_systems(
+				Update,
+				update_tape
+					.after(maybe_toggle_tape)
+					.after(apply_actions)
+					.after(evolve)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the growing tape covers the usual view.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct GrowingTape(pub(crate) bool);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Marks the backdrop of the growing tape, so that the whole overlay can be
+/// despawned when the tape is dismissed.
+#[derive(Component)]
+struct TapeOverlay;
+
+/// The picture of the growing tape: the most recent generations of the
+/// [tape](Tape), and the [image](Image) into which they are drawn.
+#[derive(Component)]
+struct TapePicture
+{
+	/// The most recent generations of the tape, from oldest to newest, up to
+	/// [TAPE_HISTORY] of them.
+	tapes: VecDeque<Tape>,
+
+	/// The image into which the generations are drawn.
+	image: Handle<Image>
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// On `W`, toggle the [growing&#32;tape](GrowingTape), and announce the new
+/// setting.
+fn maybe_toggle_tape(
+	keys: Res<Input<KeyCode>>,
+	bindings: Res<KeyBindings>,
+	language: Res<Language>,
+	mut tape: ResMut<GrowingTape>,
+	mut announcements: EventWriter<Announcement>
+) {
+	if keys.just_pressed(bindings.tape)
+	{
+		tape.0 = !tape.0;
+		let message = match tape.0
+		{
+			true => Message::TapeOn,
+			false => Message::TapeOff
+		};
+		announcements.send(Announcement(language.text(message).to_string()));
+	}
+}
+
+/// While the [growing&#32;tape](GrowingTape) is shown, keep its
+/// [picture](TapePicture) in step with the [scrollback](Scrollback) of the
+/// [focused](Focused)&#32;[pane](crate::ecs::Pane). When new generations
+/// evolve, evolve the tape by the same [rules](RuleKind). Only elementary
+/// rules can evolve a tape, so the tape stands still under scripts and
+/// modules. After any other change, e.g., a toggled cell, a new seed, or a
+/// change of focus, start the tape over from the newest generation. Redraw
+/// the picture after every change, including a change of [palette](Palette).
+#[allow(clippy::too_many_arguments)]
+fn update_tape(
+	tape: Res<GrowingTape>,
+	generation: Res<Generation>,
+	palette: Res<Palette>,
+	pane: Query<(Ref<Scrollback>, Ref<Focused>)>,
+	overlays: Query<Entity, With<TapeOverlay>>,
+	mut pictures: Query<(&mut TapePicture, &mut Style)>,
+	mut images: ResMut<Assets<Image>>,
+	mut commands: Commands,
+	mut last: Local<u64>
+) {
+	// Several generations may evolve in a single frame.
+	let advanced = generation.0.saturating_sub(*last) as usize;
+	*last = generation.0;
+	if !tape.0
+	{
+		if tape.is_changed()
+		{
+			for entity in &overlays
+			{
+				commands.entity(entity).despawn_recursive();
+			}
+		}
+		return
+	}
+	let Ok((scrollback, focused)) = pane.get_single() else
+	{
+		return
+	};
+	let Some(&newest) = scrollback.back(0) else
+	{
+		return
+	};
+	let Ok((mut picture, mut style)) = pictures.get_single_mut() else
+	{
+		let tapes = VecDeque::from([Tape::from(newest)]);
+		build_overlay(tapes, &palette, &mut images, &mut commands);
+		return
+	};
+	if focused.is_added()
+		|| (scrollback.is_changed() && !generation.is_changed())
+	{
+		picture.tapes = VecDeque::from([Tape::from(newest)]);
+	}
+	else if generation.is_changed()
+	{
+		for back in (0 .. advanced).rev()
+		{
+			let Some(last) = picture.tapes.back() else
+			{
+				return
+			};
+			let rule = scrollback.generation(back)
+				.and_then(|(_, _, rule)| rule);
+			let next = match rule
+			{
+				Some(RuleKind::Wolfram(rule)) => last.next(*rule),
+				_ => last.clone()
+			};
+			if picture.tapes.len() == TAPE_HISTORY
+			{
+				picture.tapes.pop_front();
+			}
+			picture.tapes.push_back(next);
+		}
+	}
+	else if !palette.is_changed()
+	{
+		return
+	}
+	*style = picture_style(&picture.tapes);
+	if let Some(image) = images.get_mut(&picture.image)
+	{
+		*image = draw(&picture.tapes, &palette);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Spawn the overlay of the [growing&#32;tape](GrowingTape): a backdrop that
+/// covers the whole window and swallows the mouse, and atop it, the
+/// [picture](TapePicture) of the specified generations.
+fn build_overlay(
+	tapes: VecDeque<Tape>,
+	palette: &Palette,
+	images: &mut Assets<Image>,
+	commands: &mut Commands
+) {
+	let image = images.add(draw(&tapes, palette));
+	let style = picture_style(&tapes);
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						position_type: PositionType::Absolute,
+						width: Val::Percent(100.0),
+						height: Val::Percent(100.0),
+						align_items: AlignItems::Center,
+						justify_content: JustifyContent::Center,
+						..default()
+					},
+					background_color: BackgroundColor(Color::BLACK),
+					focus_policy: FocusPolicy::Block,
+					z_index: ZIndex::Global(TAPE_Z_INDEX),
+					..default()
+				},
+				TapeOverlay
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					ImageBundle {
+						style,
+						image: UiImage::new(image.clone()),
+						..default()
+					},
+					TapePicture { tapes, image }
+				)
+			);
+		});
+}
+
+/// Answer the [style](Style) of the [picture](TapePicture) of the specified
+/// generations: as large as fits within the window, with square cells.
+fn picture_style(tapes: &VecDeque<Tape>) -> Style
+{
+	let width = tapes.back().map(|tape| tape.len()).unwrap_or(1);
+	Style {
+		max_width: Val::Percent(100.0),
+		max_height: Val::Percent(100.0),
+		width: Val::Percent(100.0),
+		aspect_ratio: Some(width as f32 / tapes.len().max(1) as f32),
+		..default()
+	}
+}
+
+/// Draw the specified generations of a [tape](Tape) into an [image](Image),
+/// one row of pixels per generation, oldest at the top, with the positions of
+/// the cells aligned from row to row. The tape never shrinks, so the newest
+/// generation spans every position. Tapes wider than [MAX_PICTURE_WIDTH] are
+/// squeezed, such that each pixel blends the colors of the cells that it
+/// covers.
+fn draw(tapes: &VecDeque<Tape>, palette: &Palette) -> Image
+{
+	let positions = tapes.back()
+		.map(|tape| tape.positions())
+		.unwrap_or(0 .. 1);
+	let len = positions.len();
+	let width = len.min(MAX_PICTURE_WIDTH);
+	let height = tapes.len().max(1);
+	let live = palette.live.as_rgba_f32();
+	let dead = palette.dead.as_rgba_f32();
+	let mut data = Vec::with_capacity(width * height * 4);
+	for tape in tapes
+	{
+		// Cells run right to left, but pixels run left to right.
+		for column in (0 .. width).rev()
+		{
+			let start = positions.start + (column * len / width) as isize;
+			let end = positions.start + ((column + 1) * len / width) as isize;
+			let span = start .. end.max(start + 1);
+			let count = span.len();
+			let alive = span.filter(|&position| tape.cell(position)).count();
+			let fraction = alive as f32 / count as f32;
+			let color = Color::rgba(
+				dead[0] + (live[0] - dead[0]) * fraction,
+				dead[1] + (live[1] - dead[1]) * fraction,
+				dead[2] + (live[2] - dead[2]) * fraction,
+				dead[3] + (live[3] - dead[3]) * fraction
+			);
+			data.extend_from_slice(&color.as_rgba_u8());
+		}
+	}
+	data.resize(width * height * 4, 0);
+	let mut image = Image::new(
+		Extent3d {
+			width: width as u32,
+			height: height as u32,
+			depth_or_array_layers: 1
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb
+	);
+	// Keep the cells crisp when zoomed in.
+	image.sampler = ImageSampler::nearest();
+	image
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of generations of the [tape](Tape) to draw.
+const TAPE_HISTORY: usize = 256;
+
+/// The widest [picture](TapePicture) to draw, in pixels. Wider
+/// [tapes](Tape) are squeezed to fit, which keeps the texture within the
+/// limits of every graphics backend, including WebGL.
+const MAX_PICTURE_WIDTH: usize = 2048;
+
+/// The [z-index](ZIndex) of the overlay, which lies above the whole user
+/// interface, save for toasts.
+const TAPE_Z_INDEX: i32 = TOAST_Z_INDEX - 1;
@@ -0,0 +1,251 @@
+use bevy::prelude::{
+	App, BackgroundColor, BuildChildren, Changed, Color, Commands, Component,
+	default, Display, Interaction, IntoSystemConfigs, NodeBundle, Plugin,
+	PositionType, Query, Res, ResMut, Resource, Startup, Style, Text,
+	TextBundle, TextStyle, UiRect, Update, Val, Window, With, ZIndex
+};
+
+use crate::automata::{AUTOMATON_HISTORY, AUTOMATON_LENGTH, Scrollback};
+use crate::ecs::{
+	apply_actions, CellPosition, EvolutionTimer, ScrollOffset, TOAST_Z_INDEX
+};
+use crate::i18n::{Language, Message};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the tooltip, which describes the cell
+/// beneath the mouse while the evolver is paused: its column, the number of its
+/// generation, and its state, and, where a rule produced it, the neighborhood
+/// from which it came and the rule applied.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<HoveredCell>()
+			.add_systems(Startup, build_tooltip)
+			.add_systems(Update, track_hovered_cell)
+			.add_systems(
+				Update,
+				update_tooltip
+					.after(track_hovered_cell)
+					.after(apply_actions)
+			);
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [position](CellPosition) of the cell beneath the mouse, if any.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+struct HoveredCell(Option<CellPosition>);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                Components.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tooltip, which follows the mouse.
+#[derive(Component)]
+struct Tooltip;
+
+/// The label of the [tooltip](Tooltip).
+#[derive(Component)]
+struct TooltipLabel;
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Startup systems.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Build the [tooltip](Tooltip), initially hidden.
+fn build_tooltip(mut commands: Commands)
+{
+	commands
+		.spawn(
+			(
+				NodeBundle {
+					style: Style {
+						display: Display::None,
+						position_type: PositionType::Absolute,
+						padding: UiRect::all(Val::Px(6.0)),
+						..default()
+					},
+					background_color: BackgroundColor(TOOLTIP_BACKGROUND),
+					z_index: ZIndex::Global(TOOLTIP_Z_INDEX),
+					..default()
+				},
+				Tooltip
+			)
+		)
+		.with_children(|builder| {
+			builder.spawn(
+				(
+					TextBundle::from_section(
+						"",
+						TextStyle {
+							font_size: TOOLTIP_FONT_SIZE,
+							color: Color::WHITE,
+							..default()
+						}
+					),
+					TooltipLabel
+				)
+			);
+		});
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Keep track of the [cell](HoveredCell) beneath the mouse, as the
+/// [interactions](Interaction) of the cells change.
+fn track_hovered_cell(
+	interactions: Query<
+		(&Interaction, &CellPosition),
+		Changed<Interaction>
+	>,
+	mut hovered: ResMut<HoveredCell>
+) {
+	for (interaction, position) in &interactions
+	{
+		match interaction
+		{
+			Interaction::None if hovered.0 == Some(*position) =>
+				hovered.0 = None,
+			Interaction::None => {},
+			_ => hovered.0 = Some(*position)
+		}
+	}
+}
+
+/// While the evolver is paused, show the [tooltip](Tooltip) beside the mouse,
+/// [describing](describe) the [hovered](HoveredCell) cell as currently
+/// [scrolled](ScrollOffset). Hide the tooltip while the evolver runs, and
+/// whenever no cell of an automaton pane is hovered. Cells of difference
+/// panes go undescribed.
+#[allow(clippy::too_many_arguments)]
+fn update_tooltip(
+	timer: Res<EvolutionTimer>,
+	hovered: Res<HoveredCell>,
+	scroll: Res<ScrollOffset>,
+	language: Res<Language>,
+	scrollbacks: Query<&Scrollback>,
+	windows: Query<&Window>,
+	mut tooltip: Query<&mut Style, With<Tooltip>>,
+	mut label: Query<&mut Text, With<TooltipLabel>>
+) {
+	let Ok(mut style) = tooltip.get_single_mut() else
+	{
+		return
+	};
+	let cursor = windows.get_single().ok()
+		.and_then(|window| window.cursor_position());
+	let description = hovered.0
+		.filter(|_| !timer.is_running())
+		.and_then(|position| {
+			let scrollback = scrollbacks.get(position.pane).ok()?;
+			describe(position, *scroll, scrollback, *language)
+		});
+	let (Some(cursor), Some(description)) = (cursor, description) else
+	{
+		if style.display != Display::None
+		{
+			style.display = Display::None;
+		}
+		return
+	};
+	let (left, top) = (
+		Val::Px(cursor.x + TOOLTIP_OFFSET),
+		Val::Px(cursor.y + TOOLTIP_OFFSET)
+	);
+	if style.display != Display::Flex || style.left != left || style.top != top
+	{
+		style.display = Display::Flex;
+		style.left = left;
+		style.top = top;
+	}
+	let mut text = label.single_mut();
+	if text.sections[0].value != description
+	{
+		text.sections[0].value = description;
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Describe the cell at the specified [position](CellPosition) of the specified
+/// [scrollback](Scrollback), as [scrolled](ScrollOffset): its column, the
+/// number of its generation, and its state. If a rule produced the cell from
+/// the previous generation, describe the neighborhood from which it came,
+/// left to right, and the rule applied, too. Answer [None] if the scrollback
+/// no longer retains the cell's generation.
+fn describe(
+	position: CellPosition,
+	scroll: ScrollOffset,
+	scrollback: &Scrollback,
+	language: Language
+) -> Option<String>
+{
+	let back = scroll.0 + AUTOMATON_HISTORY - 1 - position.row;
+	let (number, automaton, rule) = scrollback.generation(back)?;
+	let index = AUTOMATON_LENGTH - position.column - 1;
+	let live = automaton[index];
+	let state = match live
+	{
+		true => Message::TooltipLive,
+		false => Message::TooltipDead
+	};
+	let mut description = language.format(
+		Message::TooltipCell,
+		&[&position.column, &number, &language.text(state)]
+	);
+	let parent = scrollback.generation(back + 1)
+		.map(|(_, parent, _)| parent);
+	if let (Some(rule), Some(parent)) = (rule, parent)
+	{
+		// An edited generation no longer follows from its predecessor.
+		if rule.next(parent) == *automaton
+		{
+			let neighbors = [
+				(index + 1) % AUTOMATON_LENGTH,
+				index,
+				(index + AUTOMATON_LENGTH - 1) % AUTOMATON_LENGTH
+			];
+			let neighborhood = neighbors.iter()
+				.map(|&neighbor| if parent[neighbor] { '1' } else { '0' })
+				.collect::<String>();
+			description.push('\n');
+			description.push_str(&language.format(
+				Message::TooltipCase,
+				&[&neighborhood, &(live as u8), &rule.abbreviation()]
+			));
+		}
+	}
+	Some(description)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The distance between the mouse and the [tooltip](Tooltip), in pixels, both
+/// across and down.
+const TOOLTIP_OFFSET: f32 = 16.0;
+
+/// The font size of the [tooltip](Tooltip).
+const TOOLTIP_FONT_SIZE: f32 = 18.0;
+
+/// The [color](Color) behind the [tooltip](Tooltip).
+const TOOLTIP_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
+
+/// The [z-index](ZIndex) of the [tooltip](Tooltip), which lies above the whole
+/// user interface, save for toasts.
+const TOOLTIP_Z_INDEX: i32 = TOAST_Z_INDEX - 1;
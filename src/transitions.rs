@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bevy::prelude::{
+	App, BackgroundColor, Children, IntoSystemConfigs, Local, Plugin, Query,
+	Res, Resource, Style, Update, Val, With
+};
+
+use crate::automata::{AUTOMATON_HISTORY, Decay, History, Scrollback};
+use crate::ecs::{
+	CellFrame, CellPosition, evolve, EvolutionTimer, Heartbeat, Palette,
+	repaint, Scan, ScrollOffset, SlowMotion, Turbo, update_palette, update_scan
+};
+use crate::effects::blend;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for animating the arrival of each new
+/// generation over the course of its heartbeat, rather than snapping it into
+/// place. The [Transition] resource governs the style of the animation.
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.init_resource::<Transition>()
+			.add_systems(
+				Update,
+				fade_newest
+					.after(evolve)
+					.after(update_palette)
+					.after(update_scan)
+			)
+			.add_systems(Update, slide_rows.after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Resources.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How each new generation arrives on screen.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub enum Transition
+{
+	/// The new generation appears at once, and the older generations jump up
+	/// by a row.
+	#[default]
+	Snap,
+
+	/// The new generation fades in from the dead color over its heartbeat.
+	Fade,
+
+	/// Every row scrolls upward smoothly over the heartbeat, so that the new
+	/// generation slides in from below.
+	Slide
+}
+
+impl FromStr for Transition
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		match s
+		{
+			"snap" => Ok(Transition::Snap),
+			"fade" => Ok(Transition::Fade),
+			"slide" => Ok(Transition::Slide),
+			_ => Err(format!(
+				"unknown transition '{}': expected snap, fade, or slide",
+				s
+			))
+		}
+	}
+}
+
+impl Display for Transition
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		let name = match self
+		{
+			Transition::Snap => "snap",
+			Transition::Fade => "fade",
+			Transition::Slide => "slide"
+		};
+		write!(f, "{}", name)
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Systems.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// While [fading](Transition::Fade), blend each cell of the
+/// [newest](History::newest) generation of every [pane](crate::ecs::Pane) from
+/// the dead color toward its true color, in step with the progress of its
+/// heartbeat. Once the fade stops, e.g., because the evolution was paused,
+/// paint every cell its true color at once. Difference panes have no
+/// [scrollback](Scrollback), so they are left alone.
+#[allow(clippy::too_many_arguments)]
+fn fade_newest(
+	transition: Res<Transition>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	turbo: Res<Turbo>,
+	(scan, slow): (Res<Scan>, Res<SlowMotion>),
+	palette: Res<Palette>,
+	panes: Query<
+		(&History, Option<&Decay>, Option<&Heartbeat>),
+		With<Scrollback>
+	>,
+	mut cells: Query<(&CellPosition, &mut BackgroundColor)>,
+	mut fading: Local<bool>
+) {
+	// Scanning reveals the newest generation in its own way.
+	let active = *transition == Transition::Fade
+		&& animating(&timer, &scroll, &turbo)
+		&& !(scan.0 && slow.0);
+	if !active && !*fading
+	{
+		return
+	}
+	*fading = active;
+	for (position, color) in &mut cells
+	{
+		if position.row != AUTOMATON_HISTORY - 1
+		{
+			continue
+		}
+		let Ok((history, decay, heartbeat)) = panes.get(position.pane)
+		else
+		{
+			continue
+		};
+		let target = palette.cell_color(history, decay, *position);
+		let new = match active.then(|| progress(&timer, heartbeat)).flatten()
+		{
+			Some(progress) =>
+				BackgroundColor(blend(palette.dead, target.0, progress)),
+			None => target
+		};
+		repaint(color, new);
+	}
+}
+
+/// While [sliding](Transition::Slide), displace every cell of every
+/// [pane](crate::ecs::Pane) downward by the unelapsed fraction of its
+/// heartbeat, so that the rows scroll upward smoothly and the newest generation
+/// rises into place just as the next one arrives. Once the slide stops, return
+/// every cell to its place in the grid.
+#[allow(clippy::too_many_arguments)]
+fn slide_rows(
+	transition: Res<Transition>,
+	timer: Res<EvolutionTimer>,
+	scroll: Res<ScrollOffset>,
+	turbo: Res<Turbo>,
+	heartbeats: Query<Option<&Heartbeat>, With<History>>,
+	cells: Query<&CellPosition>,
+	mut frames: Query<(&mut Style, &Children), With<CellFrame>>,
+	mut sliding: Local<bool>
+) {
+	let active = *transition == Transition::Slide
+		&& animating(&timer, &scroll, &turbo);
+	if !active && !*sliding
+	{
+		return
+	}
+	*sliding = active;
+	for (mut style, children) in &mut frames
+	{
+		let Some(position) = children.first()
+			.and_then(|&child| cells.get(child).ok())
+		else
+		{
+			continue
+		};
+		// Percentages of a grid item's insets resolve against its grid area,
+		// so this shifts the cell by the unelapsed fraction of one row.
+		let top = match heartbeats.get(position.pane).ok()
+			.filter(|_| active)
+			.and_then(|heartbeat| progress(&timer, heartbeat))
+		{
+			Some(progress) => Val::Percent(100.0 * (1.0 - progress)),
+			None => Val::Auto
+		};
+		if style.top != top
+		{
+			style.top = top;
+		}
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Utilities.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Determine whether the arrival of each generation should be animated at all,
+/// which requires that the evolution is running at its natural pace and that
+/// the view shows the newest generation.
+fn animating(timer: &EvolutionTimer, scroll: &ScrollOffset, turbo: &Turbo)
+	-> bool
+{
+	timer.is_running() && scroll.0 == 0 && !turbo.0
+}
+
+/// Answer how far the current heartbeat of a pane has elapsed, in `[0,1]`,
+/// using the pane's own [heartbeat](Heartbeat) if it has one, or else the
+/// shared [timer](EvolutionTimer). Answer [None] if the heartbeat is too short
+/// to animate without flickering.
+fn progress(timer: &EvolutionTimer, heartbeat: Option<&Heartbeat>)
+	-> Option<f32>
+{
+	let (interval, progress) = match heartbeat
+	{
+		Some(heartbeat) => (heartbeat.interval(), heartbeat.progress()),
+		None => (timer.heartbeat(), timer.progress())
+	};
+	(interval >= MIN_TRANSITION_HEARTBEAT).then_some(progress)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The shortest heartbeat whose generations are animated. Briefer heartbeats
+/// snap, because their transitions would only flicker.
+const MIN_TRANSITION_HEARTBEAT: Duration = Duration::from_millis(50);
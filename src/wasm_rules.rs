@@ -0,0 +1,186 @@
+use std::fmt::{Debug, Formatter};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use bevy::log::warn;
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store, TypedFunc};
+
+use crate::automata::Automaton;
+
+////////////////////////////////////////////////////////////////////////////////
+//                            WebAssembly rules.                              //
+////////////////////////////////////////////////////////////////////////////////
+
+/// [WasmRule] is an evolutionary rule implemented by a user-supplied
+/// WebAssembly module, so that rules compiled from any language can drive an
+/// [automaton](Automaton) at near-native speed. The module must export one of
+/// the following interfaces:
+///
+/// * `next_cell(left: i32, center: i32, right: i32) -> i32`, which answers the
+///   next state of a single cell, given the current states of its
+///   neighborhood. `0` denotes a vacant cell and anything else denotes an
+///   occupied cell.
+/// * `memory`, `input(len: i32) -> i32`, and `next_row(len: i32) -> i32`. The
+///   host calls `input` to obtain the address of a buffer of `len` bytes, into
+///   which it writes the current generation, one byte per cell, in
+///   right-to-left order. The host then calls `next_row`, which answers the
+///   address of a buffer of `len` bytes that holds the next generation in the
+///   same format.
+///
+/// If both are exported, then `next_row` wins. Modules are sandboxed: they are
+/// instantiated without any imports, and every call is bounded by a fuel
+/// budget.
+pub struct WasmRule
+{
+	/// The path to the module, for display purposes.
+	path: PathBuf,
+
+	/// The store that owns the instance. Evaluation requires exclusive access.
+	store: Mutex<Store<()>>,
+
+	/// The entry point of the module.
+	entry: Entry
+}
+
+/// The entry point of a [module](WasmRule).
+enum Entry
+{
+	/// `next_cell(left, center, right)`.
+	Cell(TypedFunc<(i32, i32, i32), i32>),
+
+	/// `input(len)` and `next_row(len)`, operating on the exported `memory`.
+	Row
+	{
+		/// The exported memory.
+		memory: Memory,
+
+		/// `input(len)`.
+		input: TypedFunc<i32, i32>,
+
+		/// `next_row(len)`.
+		next_row: TypedFunc<i32, i32>
+	}
+}
+
+impl WasmRule
+{
+	/// Load, compile, and instantiate the module at the specified path.
+	pub fn load(path: impl AsRef<Path>) -> wasmtime::Result<Self>
+	{
+		let path = path.as_ref().to_path_buf();
+		let mut config = Config::new();
+		config.consume_fuel(true);
+		let engine = Engine::new(&config)?;
+		let module = Module::from_file(&engine, &path)?;
+		let mut store = Store::new(&engine, ());
+		let instance = Instance::new(&mut store, &module, &[])?;
+		let entry = match Self::row_entry(&instance, &mut store)
+		{
+			Some(entry) => entry,
+			None => Entry::Cell(
+				instance.get_typed_func(&mut store, "next_cell")?
+			)
+		};
+		Ok(Self { path, store: Mutex::new(store), entry })
+	}
+
+	/// Answer the row-oriented [entry](Entry) point of the specified instance,
+	/// if it exports one.
+	fn row_entry(instance: &Instance, store: &mut Store<()>) -> Option<Entry>
+	{
+		let memory = instance.get_memory(&mut *store, "memory")?;
+		let input = instance.get_typed_func(&mut *store, "input").ok()?;
+		let next_row = instance.get_typed_func(&mut *store, "next_row").ok()?;
+		Some(Entry::Row { memory, input, next_row })
+	}
+
+	/// Answer the file name of the module, for display purposes.
+	pub fn name(&self) -> String
+	{
+		self.path.file_name()
+			.map(|name| name.to_string_lossy().into_owned())
+			.unwrap_or_else(|| self.path.display().to_string())
+	}
+
+	/// Compute the successor [automaton][Automaton] by running the module. If
+	/// the module traps or runs out of fuel, then log the failure and answer
+	/// the unchanged automaton, so that a faulty module merely stalls the
+	/// evolution.
+	pub fn next<const K: usize>(&self, automaton: &Automaton<K>) -> Automaton<K>
+	{
+		let mut store = self.store.lock().unwrap();
+		let next = store.set_fuel(FUEL_PER_GENERATION)
+			.and_then(|_| match self.entry
+			{
+				Entry::Cell(ref next_cell) =>
+					Self::next_cells(&mut store, next_cell, automaton),
+				Entry::Row { memory, ref input, ref next_row } =>
+					Self::next_row(&mut store, memory, input, next_row, automaton)
+			});
+		next.unwrap_or_else(|e| {
+			warn!("{} failed: {}", self.name(), e);
+			*automaton
+		})
+	}
+
+	/// Compute the successor [automaton][Automaton] by calling `next_cell`
+	/// once for each cell. As usual, the two ends of the automaton are
+	/// considered adjacent.
+	fn next_cells<const K: usize>(
+		store: &mut Store<()>,
+		next_cell: &TypedFunc<(i32, i32, i32), i32>,
+		automaton: &Automaton<K>
+	) -> wasmtime::Result<Automaton<K>>
+	{
+		let mut next = [false; K];
+		for i in 0 .. K
+		{
+			let left = automaton[(i + 1) % K] as i32;
+			let center = automaton[i] as i32;
+			let right = automaton[(i + K - 1) % K] as i32;
+			next[i] = next_cell.call(&mut *store, (left, center, right))? != 0;
+		}
+		Ok(next.into())
+	}
+
+	/// Compute the successor [automaton][Automaton] by writing the current
+	/// generation into the module's memory, calling `next_row`, and reading
+	/// the next generation back out.
+	fn next_row<const K: usize>(
+		store: &mut Store<()>,
+		memory: Memory,
+		input: &TypedFunc<i32, i32>,
+		next_row: &TypedFunc<i32, i32>,
+		automaton: &Automaton<K>
+	) -> wasmtime::Result<Automaton<K>>
+	{
+		let len = K as i32;
+		let bytes = automaton.iter().map(|cell| *cell as u8).collect::<Vec<_>>();
+		let address = input.call(&mut *store, len)?;
+		memory.write(&mut *store, address as u32 as usize, &bytes)?;
+		let address = next_row.call(&mut *store, len)?;
+		let mut bytes = [0u8; K];
+		memory.read(&*store, address as u32 as usize, &mut bytes)?;
+		Ok(bytes.map(|byte| byte != 0).into())
+	}
+}
+
+impl Debug for WasmRule
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("WasmRule")
+			.field("path", &self.path)
+			.finish()
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Constants.                                 //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The fuel budget for computing a single generation with a
+/// [module](WasmRule). Roughly, each unit of fuel pays for one WebAssembly
+/// instruction.
+const FUEL_PER_GENERATION: u64 = 10_000_000;
@@ -0,0 +1,185 @@
+use std::cell::RefCell;
+
+use bevy::prelude::{
+	App, EventReader, EventWriter, IntoSystemConfigs, Plugin, Query, Update,
+	With
+};
+use js_sys::Function;
+use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
+
+use crate::automata::{Automaton, AutomatonRule};
+use crate::ecs::{
+	Action, apply_actions, evolve, Focused, GenerationEvolved, Pane
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                  Plugins.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The [plugin](Plugin) responsible for the JavaScript control API of the WASM
+/// build, through which a host page can embed the canvas and drive the
+/// simulation from its own user interface:
+///
+/// * [set_rule] replaces the schedule of the focused pane with a single rule.
+/// * [toggle_pause] pauses or resumes the evolution.
+/// * [set_seed] reseeds the focused pane from a string of digits.
+/// * [on_generation] registers a callback for every new generation.
+///
+/// Commands from the host page are queued, then carried out as
+/// [actions](Action) at the start of the next frame, just like keystrokes.
+pub struct WebApiPlugin;
+
+impl Plugin for WebApiPlugin
+{
+	fn build(&self, app: &mut App)
+	{
+		app
+			.add_systems(Update, accept_commands.before(apply_actions))
+			.add_systems(Update, notify_generations.after(evolve));
+	}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                               Exported API.                                //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Replace the schedule of the focused pane with the specified elementary
+/// rule, given as its Wolfram code.
+#[wasm_bindgen]
+pub fn set_rule(rule: u8)
+{
+	enqueue(Command::SetRule(AutomatonRule::from(rule)));
+}
+
+/// Pause the evolution if it is running, or resume it if it is paused.
+#[wasm_bindgen]
+pub fn toggle_pause()
+{
+	enqueue(Command::TogglePause);
+}
+
+/// Reseed the focused pane with the first generation given as a string of
+/// digits, in binary with a `0b` prefix, or otherwise in hexadecimal, with or
+/// without a `0x` prefix, as [on_generation] reports it. Lower numbered bits
+/// correspond to cells on the right. Throws if the string is not a valid
+/// [generation](Automaton::from_digits).
+#[wasm_bindgen]
+pub fn set_seed(digits: &str) -> Result<(), JsValue>
+{
+	let binary = digits.trim().get(.. 2)
+		.is_some_and(|prefix| prefix.eq_ignore_ascii_case("0b"));
+	let seed = match binary
+	{
+		true => Automaton::from_digits(digits),
+		false => Automaton::from_hex(digits)
+	};
+	let seed = seed.map_err(|e| JsValue::from_str(&e))?;
+	enqueue(Command::SetSeed(seed));
+	Ok(())
+}
+
+/// Register a callback to invoke after every new generation of every pane.
+/// The callback receives the index of the pane, counting from 0 on the left,
+/// the index of the generation, and the generation itself as a
+/// [hexadecimal&#32;string](Automaton::to_hex), with a digit for every four
+/// cells, that [set_seed] accepts.
+#[wasm_bindgen]
+pub fn on_generation(callback: Function)
+{
+	CALLBACKS.with(|callbacks| callbacks.borrow_mut().push(callback));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                 Commands.                                  //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A request from the host page, awaiting the next frame.
+#[derive(Copy, Clone, Debug)]
+enum Command
+{
+	/// Replace the schedule of the focused pane with a single rule.
+	SetRule(AutomatonRule),
+
+	/// Pause or resume the evolution.
+	TogglePause,
+
+	/// Reseed the focused pane.
+	SetSeed(Automaton)
+}
+
+/// Queue the specified [command](Command) for the next frame.
+fn enqueue(command: Command)
+{
+	COMMANDS.with(|commands| commands.borrow_mut().push(command));
+}
+
+thread_local! {
+	/// The [commands](Command) queued by the host page since the last frame.
+	/// The WASM build is single-threaded, so the exported functions and the
+	/// systems share the same thread.
+	static COMMANDS: RefCell<Vec<Command>> = RefCell::new(Vec::new());
+
+	/// The callbacks registered through [on_generation].
+	static CALLBACKS: RefCell<Vec<Function>> = RefCell::new(Vec::new());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                              Update systems.                               //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Carry out every [command](Command) queued by the host page, as
+/// [actions](Action) upon the [focused](Focused)&#32;[pane](Pane).
+fn accept_commands(
+	focused: Query<&Pane, With<Focused>>,
+	mut actions: EventWriter<Action>
+) {
+	let commands = COMMANDS.with(|commands| commands.take());
+	let Ok(pane) = focused.get_single() else
+	{
+		return
+	};
+	let pane = pane.0;
+	for command in commands
+	{
+		actions.send(match command
+		{
+			Command::SetRule(rule) => Action::ReplaceRule { pane, rule },
+			Command::TogglePause => Action::TogglePause,
+			Command::SetSeed(seed) => Action::Seed { pane, seed }
+		});
+	}
+}
+
+/// Invoke every callback registered through [on_generation] for each
+/// [new&#32;generation](GenerationEvolved). A callback that throws is
+/// reported to the console, but stays registered.
+fn notify_generations(
+	mut evolved: EventReader<GenerationEvolved>,
+	panes: Query<&Pane>
+) {
+	// Release the registry before calling out, so that a callback may
+	// register another.
+	let callbacks = CALLBACKS.with(|callbacks| callbacks.borrow().clone());
+	for event in evolved.read()
+	{
+		if callbacks.is_empty()
+		{
+			continue
+		}
+		let Ok(pane) = panes.get(event.pane) else
+		{
+			continue
+		};
+		let pane = JsValue::from(pane.0 as u32);
+		let index = JsValue::from(event.index as f64);
+		let automaton = JsValue::from_str(&event.automaton.to_hex());
+		for callback in &callbacks
+		{
+			if let Err(e) =
+				callback.call3(&JsValue::NULL, &pane, &index, &automaton)
+			{
+				web_sys::console::error_1(&e);
+			}
+		}
+	}
+}